@@ -3,7 +3,7 @@ defmodule GymnasiumGrpc.AttributeTypesServer do
 
   use GRPC.Server, service: Proto.Gymnasium.V1.AttributeTypes.AttributeTypes.Service
 
-  alias Gymnasium.AttributeTypes.AttributeType
+  alias Gymnasium.AttributeTypes.{AttributeType, ColumnType}
   alias GymnasiumGrpc.Util
   alias Proto.Gymnasium.V1.AttributeTypes, as: Rpc
   alias GymnasiumGrpc.AttributeTypeService
@@ -68,6 +68,39 @@ defmodule GymnasiumGrpc.AttributeTypesServer do
     %Google.Protobuf.Empty{}
   end
 
+  def set_column_type(%Rpc.SetColumnTypeRequest{} = request, _stream) do
+    %Rpc.SetColumnTypeRequest{
+      attribute_type_id: attribute_type_id,
+      dialect: dialect,
+      column_type: column_type
+    } = request
+
+    attributes = %AttributeTypeService.SetColumnTypeAttributes{
+      attribute_type_id: attribute_type_id,
+      dialect: dialect,
+      column_type: column_type
+    }
+
+    attributes
+    |> AttributeTypeService.set_column_type()
+    |> to_proto_column_type()
+  end
+
+  def list_column_types(%Rpc.ListColumnTypesRequest{} = request, _stream) do
+    %Rpc.ListColumnTypesRequest{
+      dialect: dialect
+    } = request
+
+    column_types =
+      dialect
+      |> AttributeTypeService.list_column_types()
+      |> Enum.map(fn ct -> to_proto_column_type(ct) end)
+
+    %Rpc.ListColumnTypesResponse{
+      column_types: column_types
+    }
+  end
+
   defp to_proto_attribute_type(%AttributeType{} = attribute_type) do
     %Rpc.AttributeType{
       id: attribute_type.id,
@@ -89,4 +122,15 @@ defmodule GymnasiumGrpc.AttributeTypesServer do
       updated_at: nil
     }
   end
+
+  defp to_proto_column_type(%ColumnType{} = column_type) do
+    %Rpc.ColumnType{
+      id: column_type.id,
+      attribute_type_slug: column_type.attribute_type.slug,
+      dialect: column_type.dialect,
+      column_type: column_type.column_type,
+      create_time: Util.to_proto_timestamp(column_type.inserted_at),
+      update_time: Util.to_proto_timestamp(column_type.updated_at)
+    }
+  end
 end
@@ -7,6 +7,7 @@ defmodule GymnasiumGrpc.Endpoint do
 
   run([
     GymnasiumGrpc.AttributeTypesServer,
+    GymnasiumGrpc.GeneratorsServer,
     GymnasiumGrpc.HealthServer,
     GymnasiumGrpc.ModelsServer,
     GymnasiumGrpc.ProjectsServer
@@ -0,0 +1,95 @@
+defmodule GymnasiumGrpc.GeneratorsServer do
+  @moduledoc false
+
+  use GRPC.Server, service: Proto.Gymnasium.V1.Generators.Generators.Service
+
+  alias Gymnasium.Generators.{Generator, Template}
+  alias GymnasiumGrpc.Util
+  alias Proto.Gymnasium.V1.Generators, as: Rpc
+  alias GymnasiumGrpc.GeneratorService
+
+  def create_generator(%Rpc.CreateGeneratorRequest{} = request, _stream) do
+    %Rpc.CreateGeneratorRequest{
+      description: description,
+      name: name,
+      slug: slug,
+      templates: templates
+    } = request
+
+    attributes = %GeneratorService.CreateGeneratorAttributes{
+      description: description,
+      name: name,
+      slug: slug,
+      templates: Enum.map(templates, &from_proto_template/1)
+    }
+
+    attributes
+    |> GeneratorService.create_generator()
+    |> to_proto_generator()
+  end
+
+  def find_generator(%Rpc.FindGeneratorRequest{} = request, _stream) do
+    %Rpc.FindGeneratorRequest{
+      slug: slug
+    } = request
+
+    slug
+    |> GeneratorService.find_generator()
+    |> to_proto_generator()
+  end
+
+  def list_generators(%Rpc.ListGeneratorsRequest{} = _request, _stream) do
+    generators =
+      GeneratorService.list_generators()
+      |> Enum.map(fn g -> to_proto_generator(g) end)
+
+    %Rpc.ListGeneratorsResponse{
+      generators: generators
+    }
+  end
+
+  def delete_generator(%Rpc.DeleteGeneratorRequest{} = request, _stream) do
+    %Rpc.DeleteGeneratorRequest{
+      id: id
+    } = request
+
+    GeneratorService.delete_generator(id)
+
+    %Google.Protobuf.Empty{}
+  end
+
+  defp to_proto_generator(%Generator{} = generator) do
+    %Rpc.Generator{
+      id: generator.id,
+      description: generator.description,
+      name: generator.name,
+      slug: generator.slug,
+      templates: Enum.map(generator.templates, &to_proto_template/1),
+      create_time: Util.to_proto_timestamp(generator.inserted_at),
+      update_time: Util.to_proto_timestamp(generator.updated_at)
+    }
+  end
+
+  defp to_proto_template(%Template{} = template) do
+    %Rpc.GeneratorTemplate{
+      path: template.path,
+      content: template.content,
+      scope: to_proto_template_scope(template.scope)
+    }
+  end
+
+  defp from_proto_template(%Rpc.GeneratorTemplate{} = proto_template) do
+    %{
+      path: proto_template.path,
+      content: proto_template.content,
+      scope: from_proto_template_scope(proto_template.scope)
+    }
+  end
+
+  defp from_proto_template_scope(:TEMPLATE_SCOPE_PROJECT), do: "project"
+  defp from_proto_template_scope(:TEMPLATE_SCOPE_MODEL), do: "model"
+  defp from_proto_template_scope(_), do: "unspecified"
+
+  defp to_proto_template_scope("project"), do: :TEMPLATE_SCOPE_PROJECT
+  defp to_proto_template_scope("model"), do: :TEMPLATE_SCOPE_MODEL
+end
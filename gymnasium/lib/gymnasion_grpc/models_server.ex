@@ -3,7 +3,7 @@ defmodule GymnasiumGrpc.ModelsServer do
 
   use GRPC.Server, service: Proto.Gymnasium.V1.Models.Models.Service
 
-  alias Gymnasium.Models.{Model, Association, Attribute}
+  alias Gymnasium.Models.{Model, Association, Attribute, FieldNumber}
   alias Gymnasium.AttributeTypes.{AttributeType}
   alias GymnasiumGrpc.Util
   alias Proto.Gymnasium.V1.Models, as: Rpc
@@ -290,6 +290,46 @@ defmodule GymnasiumGrpc.ModelsServer do
     end
   end
 
+  def create_field_number(%Rpc.CreateFieldNumberRequest{} = request, _stream) do
+    %Rpc.CreateFieldNumberRequest{
+      model_id: model_id,
+      name: name,
+      number: number
+    } = request
+
+    attributes = %ModelService.CreateFieldNumberAttributes{
+      model_id: model_id,
+      name: name,
+      number: number
+    }
+
+    case ModelService.create_field_number(attributes) do
+      %FieldNumber{} = field_number ->
+        to_proto_field_number(field_number)
+
+      _ ->
+        raise GRPC.RPCError, status: :internal
+    end
+  end
+
+  def list_project_model_field_numbers(
+        %Rpc.ListProjectModelFieldNumbersRequest{} = request,
+        _stream
+      ) do
+    %Rpc.ListProjectModelFieldNumbersRequest{
+      project_slug: project_slug
+    } = request
+
+    field_numbers =
+      project_slug
+      |> ModelService.list_project_model_field_numbers()
+      |> Enum.map(fn f -> to_proto_field_number(f) end)
+
+    %Rpc.ListProjectModelFieldNumbersResponse{
+      field_numbers: field_numbers
+    }
+  end
+
   defp to_proto_model(%Model{} = model) do
     %Rpc.Model{
       id: model.id,
@@ -346,6 +386,17 @@ defmodule GymnasiumGrpc.ModelsServer do
     }
   end
 
+  def to_proto_field_number(%FieldNumber{} = field_number) do
+    %Rpc.FieldNumber{
+      id: field_number.id,
+      model_id: field_number.model_id,
+      name: field_number.name,
+      number: field_number.number,
+      create_time: Util.to_proto_timestamp(field_number.inserted_at),
+      update_time: Util.to_proto_timestamp(field_number.updated_at)
+    }
+  end
+
   defp from_proto_association_kind(:ASSOCIATION_KIND_BELONGS_TO), do: "belongs_to"
   defp from_proto_association_kind(:ASSOCIATION_KIND_HAS_ONE), do: "has_one"
   defp from_proto_association_kind(:ASSOCIATION_KIND_HAS_MANY), do: "has_many"
@@ -4,8 +4,12 @@ defmodule GymnasiumGrpc.AttributeTypeService do
   """
 
   alias Gymnasium.AttributeTypes
-  alias Gymnasium.AttributeTypes.AttributeType
-  alias GymnasiumGrpc.AttributeTypeService.{CreateAttributeTypeAttributes}
+  alias Gymnasium.AttributeTypes.{AttributeType, ColumnType}
+
+  alias GymnasiumGrpc.AttributeTypeService.{
+    CreateAttributeTypeAttributes,
+    SetColumnTypeAttributes
+  }
 
   @doc """
   Create attribute type.
@@ -105,6 +109,49 @@ defmodule GymnasiumGrpc.AttributeTypeService do
     |> AttributeTypes.delete_attribute_type()
   end
 
+  @doc """
+  Set the column type of an attribute type in a dialect.
+
+  ## Examples
+
+      iex> set_column_type(%SetColumnTypeAttributes{
+      ...>   attribute_type_id: "b256b553-4ee9-4d61-acb9-e8eb4b009325",
+      ...>   dialect: "mysql",
+      ...>   column_type: "DECIMAL(12, 2)"
+      ...> })
+      %ColumnType{}
+
+  """
+  @spec set_column_type(SetColumnTypeAttributes.t()) :: ColumnType.t()
+  def set_column_type(%SetColumnTypeAttributes{} = attributes) do
+    result =
+      attributes
+      |> Map.from_struct()
+      |> AttributeTypes.set_column_type()
+
+    case result do
+      {:ok, column_type} ->
+        Gymnasium.Repo.preload(column_type, :attribute_type)
+
+      {:error, changeset} ->
+        raise Ecto.InvalidChangesetError, action: :insert, changeset: changeset
+    end
+  end
+
+  @doc """
+  Returns the column types of all attribute types in a dialect.
+
+  ## Examples
+
+      iex> list_column_types("mysql")
+      [%ColumnType{}, ...]
+
+  """
+  @spec list_column_types(String.t()) :: [ColumnType.t()]
+  def list_column_types(dialect) do
+    AttributeTypes.list_column_types(dialect)
+  end
+
   defp build_update_attrs(%AttributeType{} = attribute_type, update_mask) do
     Enum.reduce(update_mask, %{}, fn mask, acc ->
       if Enum.member?(["name", "slug", "description"], mask) do
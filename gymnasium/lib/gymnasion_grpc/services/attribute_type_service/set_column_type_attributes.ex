@@ -0,0 +1,13 @@
+defmodule GymnasiumGrpc.AttributeTypeService.SetColumnTypeAttributes do
+  @moduledoc false
+
+  defstruct attribute_type_id: "",
+            dialect: "",
+            column_type: ""
+
+  @type t() :: %__MODULE__{
+          attribute_type_id: Ecto.UUID.t(),
+          dialect: String.t(),
+          column_type: String.t()
+        }
+end
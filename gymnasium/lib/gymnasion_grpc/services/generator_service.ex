@@ -0,0 +1,82 @@
+defmodule GymnasiumGrpc.GeneratorService do
+  @moduledoc """
+  Entrypoint for all actions around generators.
+  """
+
+  alias Gymnasium.Generators
+  alias Gymnasium.Generators.Generator
+  alias GymnasiumGrpc.GeneratorService.{CreateGeneratorAttributes}
+
+  @doc """
+  Create generator along with its templates.
+
+  ## Examples
+
+      iex> create_generator(%CreateGeneratorAttributes{
+      ...>   name: "Model Docs",
+      ...>   slug: "model-docs",
+      ...>   templates: [%{path: "{{ model.slug }}.md", content: "", scope: "model"}]
+      ...> })
+      %Generator{}
+
+  """
+  @spec create_generator(CreateGeneratorAttributes.t()) :: Generator.t()
+  def create_generator(%CreateGeneratorAttributes{} = attributes) do
+    result =
+      attributes
+      |> Map.from_struct()
+      |> Generators.create_generator()
+
+    case result do
+      {:ok, generator} ->
+        generator
+
+      {:error, changeset} ->
+        raise Ecto.InvalidChangesetError, action: :insert, changeset: changeset
+    end
+  end
+
+  @doc """
+  Find generator.
+
+  ## Examples
+
+      iex> find_generator("model-docs")
+      %Generator{}
+
+  """
+  @spec find_generator(String.t()) :: Generator.t()
+  def find_generator(slug) do
+    Generators.find_generator!(slug)
+  end
+
+  @doc """
+  Returns the list of generators.
+
+  ## Examples
+
+      iex> list_generators()
+      [%Generator{}, ...]
+
+  """
+  @spec list_generators() :: [Generator.t()]
+  def list_generators() do
+    Generators.list_generators()
+  end
+
+  @doc """
+  Delete generator along with its templates.
+
+  ## Examples
+
+      iex> delete_generator("b256b553-4ee9-4d61-acb9-e8eb4b009325")
+      {:ok, %Generator{}}
+
+  """
+  @spec delete_generator(Ecto.UUID.t()) :: {:ok, Generator.t()}
+  def delete_generator(id) do
+    id
+    |> Generators.get_generator!()
+    |> Generators.delete_generator()
+  end
+end
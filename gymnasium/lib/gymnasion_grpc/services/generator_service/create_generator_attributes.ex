@@ -0,0 +1,15 @@
+defmodule GymnasiumGrpc.GeneratorService.CreateGeneratorAttributes do
+  @moduledoc false
+
+  defstruct description: "",
+            name: "",
+            slug: "",
+            templates: []
+
+  @type t() :: %__MODULE__{
+          description: String.t(),
+          name: String.t(),
+          slug: String.t(),
+          templates: [%{path: String.t(), content: String.t(), scope: String.t()}]
+        }
+end
@@ -4,11 +4,12 @@ defmodule GymnasiumGrpc.ModelService do
   """
 
   alias Gymnasium.{Models, ProjectModels}
-  alias Gymnasium.Models.{Model, Attribute, Association}
+  alias Gymnasium.Models.{Model, Attribute, Association, FieldNumber}
 
   alias GymnasiumGrpc.ModelService.{
     CreateAssociationAttributes,
     CreateAttributeAttributes,
+    CreateFieldNumberAttributes,
     CreateModelAttributes,
     FindProjectModelAssociationAttributes,
     FindProjectModelAttributeAttributes,
@@ -413,4 +414,50 @@ defmodule GymnasiumGrpc.ModelService do
       Ecto.Query.CastError -> :error
     end
   end
+
+  @doc """
+  Create a Model field number.
+
+  ## Examples
+
+      iex> create_field_number(%CreateFieldNumberAttributes{
+      ...>   model_id: "c8e47fc7-dee3-4c57-8955-9b49317f2af2",
+      ...>   name: "title",
+      ...>   number: 1
+      ...>  })
+      %FieldNumber{}
+
+      iex> create_field_number(%CreateFieldNumberAttributes{})
+      :error
+
+  """
+  @spec create_field_number(CreateFieldNumberAttributes.t()) :: FieldNumber.t() | :error
+  def create_field_number(%CreateFieldNumberAttributes{} = attributes) do
+    result =
+      attributes
+      |> Map.from_struct()
+      |> Models.create_field_number()
+
+    case result do
+      {:ok, field_number} ->
+        field_number
+
+      {:error, _changset} ->
+        :error
+    end
+  end
+
+  @doc """
+  Returns a list of field numbers of all Models of specific Project.
+
+  ## Examples
+
+      iex> list_project_model_field_numbers("book-store")
+      [%FieldNumber{}, ...]
+
+  """
+  @spec list_project_model_field_numbers(String.t()) :: [FieldNumber.t()]
+  def list_project_model_field_numbers(project_slug) do
+    ProjectModels.list_project_model_field_numbers(project_slug)
+  end
 end
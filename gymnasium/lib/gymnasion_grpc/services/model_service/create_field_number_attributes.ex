@@ -0,0 +1,13 @@
+defmodule GymnasiumGrpc.ModelService.CreateFieldNumberAttributes do
+  @moduledoc false
+
+  defstruct model_id: "",
+            name: "",
+            number: 0
+
+  @type t() :: %__MODULE__{
+          model_id: Ecto.UUID.t(),
+          name: String.t(),
+          number: integer()
+        }
+end
@@ -6,7 +6,7 @@ defmodule Gymnasium.AttributeTypes do
   import Ecto.Query, warn: false
   alias Gymnasium.Repo
 
-  alias Gymnasium.AttributeTypes.AttributeType
+  alias Gymnasium.AttributeTypes.{AttributeType, ColumnType}
   alias Gymnasium.Models
 
   @doc """
@@ -121,9 +121,54 @@ defmodule Gymnasium.AttributeTypes do
         action: :delete
     end
 
+    Repo.delete_all(from ct in ColumnType, where: ct.attribute_type_id == ^attribute_type.id)
     Repo.delete(attribute_type)
   end
 
+  @doc """
+  Sets the column type of an attribute type in a dialect, replacing the
+  previous one.
+
+  ## Examples
+
+      iex> set_column_type(%{field: value})
+      {:ok, %ColumnType{}}
+
+      iex> set_column_type(%{field: bad_value})
+      {:error, %Ecto.Changeset{}}
+
+  """
+  @spec set_column_type(map()) :: {:ok, ColumnType.t()} | {:error, Ecto.Changeset.t()}
+  def set_column_type(attrs \\ %{}) do
+    %ColumnType{}
+    |> ColumnType.changeset(attrs)
+    |> Repo.insert(
+      on_conflict: {:replace, [:column_type, :updated_at]},
+      conflict_target: [:attribute_type_id, :dialect],
+      returning: true
+    )
+  end
+
+  @doc """
+  Returns the column types of all attribute types in a dialect, with their
+  attribute types.
+
+  ## Examples
+
+      iex> list_column_types("mysql")
+      [%ColumnType{}, ...]
+
+  """
+  @spec list_column_types(String.t()) :: [ColumnType.t()]
+  def list_column_types(dialect) do
+    query =
+      from ct in ColumnType,
+        where: ct.dialect == ^dialect,
+        preload: :attribute_type
+
+    Repo.all(query)
+  end
+
   @doc """
   Returns an `%Ecto.Changeset{}` for tracking attribute_type changes.
 
@@ -0,0 +1,40 @@
+defmodule Gymnasium.AttributeTypes.ColumnType do
+  @moduledoc """
+  SQL column type of the attribute type in one of the dialects.
+  """
+
+  @type t() :: %__MODULE__{
+          id: Ecto.UUID.t(),
+          attribute_type_id: Ecto.UUID.t(),
+          dialect: String.t(),
+          column_type: String.t(),
+          inserted_at: Calendar.datetime(),
+          updated_at: Calendar.datetime()
+        }
+
+  alias Gymnasium.AttributeTypes
+
+  use Gymnasium.Schema
+  import Ecto.Changeset
+
+  @dialects ["postgresql", "sqlite", "mysql"]
+
+  schema "attribute_type_column_types" do
+    belongs_to :attribute_type, AttributeTypes.AttributeType
+
+    field :dialect, :string
+
+    field :column_type, :string
+
+    timestamps()
+  end
+
+  @doc false
+  def changeset(column_type, attrs) do
+    column_type
+    |> cast(attrs, [:attribute_type_id, :dialect, :column_type])
+    |> validate_required([:attribute_type_id, :dialect, :column_type])
+    |> validate_inclusion(:dialect, @dialects)
+    |> unique_constraint([:attribute_type_id, :dialect])
+  end
+end
@@ -0,0 +1,113 @@
+defmodule Gymnasium.Generators do
+  @moduledoc """
+  The Generators context.
+  """
+
+  import Ecto.Query, warn: false
+  alias Gymnasium.Repo
+
+  alias Gymnasium.Generators.{Generator, Template}
+
+  @doc """
+  Returns the list of generators with their templates.
+
+  ## Examples
+
+      iex> list_generators()
+      [%Generator{}, ...]
+
+  """
+  @spec list_generators() :: [Generator.t()]
+  def list_generators do
+    Generator
+    |> Repo.all()
+    |> Repo.preload(:templates)
+  end
+
+  @doc """
+  Gets a single generator with its templates.
+
+  Raises `Ecto.NoResultsError` if the Generator does not exist.
+
+  ## Examples
+
+      iex> get_generator!("b256b553-4ee9-4d61-acb9-e8eb4b009325")
+      %Generator{}
+
+      iex> get_generator!("dc35b24d-f155-4e59-ac69-500f820a2fcd")
+      ** (Ecto.NoResultsError)
+
+  """
+  @spec get_generator!(Ecto.UUID.t()) :: Generator.t()
+  def get_generator!(id) do
+    Generator
+    |> Repo.get!(id)
+    |> Repo.preload(:templates)
+  end
+
+  @doc """
+  Finds a single generator with its templates.
+
+  Raises `Ecto.NoResultsError` if the Generator does not exist.
+
+  ## Examples
+
+      iex> find_generator!("model-docs")
+      %Generator{}
+
+      iex> find_generator!("unknown")
+      ** (Ecto.NoResultsError)
+
+  """
+  @spec find_generator!(String.t()) :: Generator.t()
+  def find_generator!(slug) do
+    Generator
+    |> Repo.get_by!(slug: slug)
+    |> Repo.preload(:templates)
+  end
+
+  @doc """
+  Creates a generator along with its templates, which keep the order they
+  were given in.
+
+  ## Examples
+
+      iex> create_generator(%{field: value, templates: [%{field: value}]})
+      {:ok, %Generator{}}
+
+      iex> create_generator(%{field: bad_value})
+      {:error, %Ecto.Changeset{}}
+
+  """
+  @spec create_generator(map()) :: {:ok, Generator.t()} | {:error, Ecto.Changeset.t()}
+  def create_generator(attrs \\ %{}) do
+    attrs =
+      Map.update(attrs, :templates, [], fn templates ->
+        templates
+        |> Enum.with_index()
+        |> Enum.map(fn {template, position} -> Map.put(template, :position, position) end)
+      end)
+
+    %Generator{}
+    |> Generator.changeset(attrs)
+    |> Repo.insert()
+  end
+
+  @doc """
+  Deletes a generator along with its templates.
+
+  ## Examples
+
+      iex> delete_generator(generator)
+      {:ok, %Generator{}}
+
+  """
+  @spec delete_generator(Generator.t()) :: {:ok, Generator.t()}
+  def delete_generator(%Generator{} = generator) do
+    Repo.transaction(fn ->
+      Repo.delete_all(from t in Template, where: t.generator_id == ^generator.id)
+
+      Repo.delete!(generator)
+    end)
+  end
+end
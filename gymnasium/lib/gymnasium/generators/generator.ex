@@ -0,0 +1,42 @@
+defmodule Gymnasium.Generators.Generator do
+  @moduledoc """
+  Generator
+  """
+
+  @type t() :: %__MODULE__{
+          id: Ecto.UUID.t(),
+          description: String.t(),
+          name: String.t(),
+          slug: String.t(),
+          templates: [Gymnasium.Generators.Template.t()],
+          inserted_at: Calendar.datetime(),
+          updated_at: Calendar.datetime()
+        }
+
+  alias Gymnasium.Generators
+
+  use Gymnasium.Schema
+  import Ecto.Changeset
+
+  schema "generators" do
+    has_many :templates, Generators.Template, preload_order: [asc: :position]
+
+    field :description, :string
+
+    field :name, :string
+
+    field :slug, :string
+
+    timestamps()
+  end
+
+  @doc false
+  def changeset(generator, attrs) do
+    generator
+    |> cast(attrs, [:description, :name, :slug])
+    |> validate_required([:name, :slug])
+    |> cast_assoc(:templates, required: true)
+    |> unique_constraint(:name, name: :generators_name_index)
+    |> unique_constraint(:slug, name: :generators_slug_index)
+  end
+end
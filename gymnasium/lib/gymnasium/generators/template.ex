@@ -0,0 +1,46 @@
+defmodule Gymnasium.Generators.Template do
+  @moduledoc """
+  Template of the files produced by a generator.
+  """
+
+  @type t() :: %__MODULE__{
+          id: Ecto.UUID.t(),
+          generator_id: Ecto.UUID.t(),
+          position: integer(),
+          path: String.t(),
+          content: String.t(),
+          scope: String.t(),
+          inserted_at: Calendar.datetime(),
+          updated_at: Calendar.datetime()
+        }
+
+  alias Gymnasium.Generators
+
+  use Gymnasium.Schema
+  import Ecto.Changeset
+
+  @scopes ["project", "model"]
+
+  schema "generator_templates" do
+    belongs_to :generator, Generators.Generator
+
+    field :position, :integer
+
+    field :path, :string
+
+    field :content, :string
+
+    field :scope, :string
+
+    timestamps()
+  end
+
+  @doc false
+  def changeset(template, attrs) do
+    template
+    |> cast(attrs, [:position, :path, :content, :scope])
+    |> validate_required([:position, :path, :scope])
+    |> validate_inclusion(:scope, @scopes)
+    |> unique_constraint([:generator_id, :path])
+  end
+end
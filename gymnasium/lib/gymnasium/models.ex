@@ -5,7 +5,7 @@ defmodule Gymnasium.Models do
 
   import Ecto.Query, warn: false
 
-  alias Gymnasium.Models.{Model, Attribute, Association}
+  alias Gymnasium.Models.{Model, Attribute, Association, FieldNumber}
   alias Gymnasium.Repo
 
   @doc """
@@ -97,6 +97,25 @@ defmodule Gymnasium.Models do
     |> Repo.insert()
   end
 
+  @doc """
+  Creates a model field number.
+
+  ## Examples
+
+      iex> create_field_number(%{field: value})
+      {:ok, %FieldNumber{}}
+
+      iex> create_field_number(%{field: bad_value})
+      {:error, %Ecto.Changeset{}}
+
+  """
+  @spec create_field_number(map()) :: {:ok, FieldNumber.t()} | {:error, Ecto.Changeset.t()}
+  def create_field_number(attrs \\ %{}) do
+    %FieldNumber{}
+    |> FieldNumber.changeset(attrs)
+    |> Repo.insert()
+  end
+
   @doc """
   Deletes a Model.
 
@@ -133,6 +152,7 @@ defmodule Gymnasium.Models do
 
       if model.id != nil do
         Repo.delete_all(from ma in Association, where: ma.associated_model_id == ^model.id)
+        Repo.delete_all(from mf in FieldNumber, where: mf.model_id == ^model.id)
       end
 
       Repo.delete!(model)
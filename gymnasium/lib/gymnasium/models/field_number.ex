@@ -0,0 +1,38 @@
+defmodule Gymnasium.Models.FieldNumber do
+  @moduledoc """
+  Number of a field of the model's Protobuf message.
+  """
+  @type t() :: %__MODULE__{
+          id: Ecto.UUID.t(),
+          model_id: Ecto.UUID.t(),
+          name: String.t(),
+          number: integer(),
+          inserted_at: Calendar.datetime(),
+          updated_at: Calendar.datetime()
+        }
+
+  alias Gymnasium.Models
+
+  use Gymnasium.Schema
+  import Ecto.Changeset
+
+  schema "model_field_numbers" do
+    belongs_to :model, Models.Model
+
+    field :name, :string
+
+    field :number, :integer
+
+    timestamps()
+  end
+
+  @doc false
+  def changeset(field_number, attrs) do
+    field_number
+    |> cast(attrs, [:model_id, :name, :number])
+    |> validate_required([:model_id, :name, :number])
+    |> validate_number(:number, greater_than: 0)
+    |> unique_constraint([:model_id, :name])
+    |> unique_constraint([:model_id, :number])
+  end
+end
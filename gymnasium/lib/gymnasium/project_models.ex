@@ -5,7 +5,7 @@ defmodule Gymnasium.ProjectModels do
 
   import Ecto.Query, warn: false
 
-  alias Gymnasium.Models.{Model, Attribute, Association}
+  alias Gymnasium.Models.{Model, Attribute, Association, FieldNumber}
   alias Gymnasium.Projects.Project
   alias Gymnasium.Repo
 
@@ -201,4 +201,27 @@ defmodule Gymnasium.ProjectModels do
 
     Repo.all(query)
   end
+
+  @doc """
+  List the field numbers of all Models of some Project.
+
+  ## Examples
+
+      iex> list_project_model_field_numbers("book-store")
+      [%FieldNumber{}]
+
+  """
+  @spec list_project_model_field_numbers(String.t()) :: [FieldNumber.t()]
+  def list_project_model_field_numbers(project_slug) do
+    query =
+      from mf in FieldNumber,
+        join: m in Model,
+        on: mf.model_id == m.id,
+        join: p in Project,
+        on: p.id == m.project_id,
+        where: p.slug == ^project_slug,
+        order_by: [asc: m.name, asc: mf.number]
+
+    Repo.all(query)
+  end
 end
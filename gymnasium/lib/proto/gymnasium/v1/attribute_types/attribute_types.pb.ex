@@ -11,6 +11,19 @@ defmodule Proto.Gymnasium.V1.AttributeTypes.AttributeType do
   field :update_time, 6, type: Google.Protobuf.Timestamp, json_name: "updateTime"
 end
 
+defmodule Proto.Gymnasium.V1.AttributeTypes.ColumnType do
+  @moduledoc false
+
+  use Protobuf, syntax: :proto3, protoc_gen_elixir_version: "0.12.0"
+
+  field :id, 1, type: :string
+  field :attribute_type_slug, 2, type: :string, json_name: "attributeTypeSlug"
+  field :dialect, 3, type: :string
+  field :column_type, 4, type: :string, json_name: "columnType"
+  field :create_time, 5, type: Google.Protobuf.Timestamp, json_name: "createTime"
+  field :update_time, 6, type: Google.Protobuf.Timestamp, json_name: "updateTime"
+end
+
 defmodule Proto.Gymnasium.V1.AttributeTypes.CreateAttributeTypeRequest do
   @moduledoc false
 
@@ -66,6 +79,35 @@ defmodule Proto.Gymnasium.V1.AttributeTypes.DeleteAttributeTypeRequest do
   field :id, 1, type: :string
 end
 
+defmodule Proto.Gymnasium.V1.AttributeTypes.SetColumnTypeRequest do
+  @moduledoc false
+
+  use Protobuf, syntax: :proto3, protoc_gen_elixir_version: "0.12.0"
+
+  field :attribute_type_id, 1, type: :string, json_name: "attributeTypeId"
+  field :dialect, 2, type: :string
+  field :column_type, 3, type: :string, json_name: "columnType"
+end
+
+defmodule Proto.Gymnasium.V1.AttributeTypes.ListColumnTypesRequest do
+  @moduledoc false
+
+  use Protobuf, syntax: :proto3, protoc_gen_elixir_version: "0.12.0"
+
+  field :dialect, 1, type: :string
+end
+
+defmodule Proto.Gymnasium.V1.AttributeTypes.ListColumnTypesResponse do
+  @moduledoc false
+
+  use Protobuf, syntax: :proto3, protoc_gen_elixir_version: "0.12.0"
+
+  field :column_types, 1,
+    repeated: true,
+    type: Proto.Gymnasium.V1.AttributeTypes.ColumnType,
+    json_name: "columnTypes"
+end
+
 defmodule Proto.Gymnasium.V1.AttributeTypes.AttributeTypes.Service do
   @moduledoc false
 
@@ -92,6 +134,14 @@ defmodule Proto.Gymnasium.V1.AttributeTypes.AttributeTypes.Service do
   rpc :DeleteAttributeType,
       Proto.Gymnasium.V1.AttributeTypes.DeleteAttributeTypeRequest,
       Google.Protobuf.Empty
+
+  rpc :SetColumnType,
+      Proto.Gymnasium.V1.AttributeTypes.SetColumnTypeRequest,
+      Proto.Gymnasium.V1.AttributeTypes.ColumnType
+
+  rpc :ListColumnTypes,
+      Proto.Gymnasium.V1.AttributeTypes.ListColumnTypesRequest,
+      Proto.Gymnasium.V1.AttributeTypes.ListColumnTypesResponse
 end
 
 defmodule Proto.Gymnasium.V1.AttributeTypes.AttributeTypes.Stub do
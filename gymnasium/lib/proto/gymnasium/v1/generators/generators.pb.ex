@@ -0,0 +1,104 @@
+defmodule Proto.Gymnasium.V1.Generators.TemplateScope do
+  @moduledoc false
+
+  use Protobuf, enum: true, syntax: :proto3, protoc_gen_elixir_version: "0.12.0"
+
+  field :TEMPLATE_SCOPE_UNSPECIFIED, 0
+  field :TEMPLATE_SCOPE_PROJECT, 1
+  field :TEMPLATE_SCOPE_MODEL, 2
+end
+
+defmodule Proto.Gymnasium.V1.Generators.Generator do
+  @moduledoc false
+
+  use Protobuf, syntax: :proto3, protoc_gen_elixir_version: "0.12.0"
+
+  field :id, 1, type: :string
+  field :description, 2, type: :string
+  field :name, 3, type: :string
+  field :slug, 4, type: :string
+  field :templates, 5, repeated: true, type: Proto.Gymnasium.V1.Generators.GeneratorTemplate
+  field :create_time, 6, type: Google.Protobuf.Timestamp, json_name: "createTime"
+  field :update_time, 7, type: Google.Protobuf.Timestamp, json_name: "updateTime"
+end
+
+defmodule Proto.Gymnasium.V1.Generators.GeneratorTemplate do
+  @moduledoc false
+
+  use Protobuf, syntax: :proto3, protoc_gen_elixir_version: "0.12.0"
+
+  field :path, 1, type: :string
+  field :content, 2, type: :string
+  field :scope, 3, type: Proto.Gymnasium.V1.Generators.TemplateScope, enum: true
+end
+
+defmodule Proto.Gymnasium.V1.Generators.CreateGeneratorRequest do
+  @moduledoc false
+
+  use Protobuf, syntax: :proto3, protoc_gen_elixir_version: "0.12.0"
+
+  field :description, 1, type: :string
+  field :name, 2, type: :string
+  field :slug, 3, type: :string
+  field :templates, 4, repeated: true, type: Proto.Gymnasium.V1.Generators.GeneratorTemplate
+end
+
+defmodule Proto.Gymnasium.V1.Generators.FindGeneratorRequest do
+  @moduledoc false
+
+  use Protobuf, syntax: :proto3, protoc_gen_elixir_version: "0.12.0"
+
+  field :slug, 1, type: :string
+end
+
+defmodule Proto.Gymnasium.V1.Generators.ListGeneratorsRequest do
+  @moduledoc false
+
+  use Protobuf, syntax: :proto3, protoc_gen_elixir_version: "0.12.0"
+end
+
+defmodule Proto.Gymnasium.V1.Generators.ListGeneratorsResponse do
+  @moduledoc false
+
+  use Protobuf, syntax: :proto3, protoc_gen_elixir_version: "0.12.0"
+
+  field :generators, 1, repeated: true, type: Proto.Gymnasium.V1.Generators.Generator
+end
+
+defmodule Proto.Gymnasium.V1.Generators.DeleteGeneratorRequest do
+  @moduledoc false
+
+  use Protobuf, syntax: :proto3, protoc_gen_elixir_version: "0.12.0"
+
+  field :id, 1, type: :string
+end
+
+defmodule Proto.Gymnasium.V1.Generators.Generators.Service do
+  @moduledoc false
+
+  use GRPC.Service,
+    name: "proto.gymnasium.v1.generators.Generators",
+    protoc_gen_elixir_version: "0.12.0"
+
+  rpc :CreateGenerator,
+      Proto.Gymnasium.V1.Generators.CreateGeneratorRequest,
+      Proto.Gymnasium.V1.Generators.Generator
+
+  rpc :FindGenerator,
+      Proto.Gymnasium.V1.Generators.FindGeneratorRequest,
+      Proto.Gymnasium.V1.Generators.Generator
+
+  rpc :ListGenerators,
+      Proto.Gymnasium.V1.Generators.ListGeneratorsRequest,
+      Proto.Gymnasium.V1.Generators.ListGeneratorsResponse
+
+  rpc :DeleteGenerator,
+      Proto.Gymnasium.V1.Generators.DeleteGeneratorRequest,
+      Google.Protobuf.Empty
+end
+
+defmodule Proto.Gymnasium.V1.Generators.Generators.Stub do
+  @moduledoc false
+
+  use GRPC.Stub, service: Proto.Gymnasium.V1.Generators.Generators.Service
+end
@@ -69,6 +69,19 @@ defmodule Proto.Gymnasium.V1.Models.AttributeType do
   field :update_time, 6, type: Google.Protobuf.Timestamp, json_name: "updateTime"
 end
 
+defmodule Proto.Gymnasium.V1.Models.FieldNumber do
+  @moduledoc false
+
+  use Protobuf, syntax: :proto3, protoc_gen_elixir_version: "0.12.0"
+
+  field :id, 1, type: :string
+  field :model_id, 2, type: :string, json_name: "modelId"
+  field :name, 3, type: :string
+  field :number, 4, type: :int32
+  field :create_time, 5, type: Google.Protobuf.Timestamp, json_name: "createTime"
+  field :update_time, 6, type: Google.Protobuf.Timestamp, json_name: "updateTime"
+end
+
 defmodule Proto.Gymnasium.V1.Models.ModelOverview do
   @moduledoc false
 
@@ -244,6 +257,35 @@ defmodule Proto.Gymnasium.V1.Models.DeleteAssociationRequest do
   field :id, 1, type: :string
 end
 
+defmodule Proto.Gymnasium.V1.Models.CreateFieldNumberRequest do
+  @moduledoc false
+
+  use Protobuf, syntax: :proto3, protoc_gen_elixir_version: "0.12.0"
+
+  field :model_id, 1, type: :string, json_name: "modelId"
+  field :name, 2, type: :string
+  field :number, 3, type: :int32
+end
+
+defmodule Proto.Gymnasium.V1.Models.ListProjectModelFieldNumbersRequest do
+  @moduledoc false
+
+  use Protobuf, syntax: :proto3, protoc_gen_elixir_version: "0.12.0"
+
+  field :project_slug, 1, type: :string, json_name: "projectSlug"
+end
+
+defmodule Proto.Gymnasium.V1.Models.ListProjectModelFieldNumbersResponse do
+  @moduledoc false
+
+  use Protobuf, syntax: :proto3, protoc_gen_elixir_version: "0.12.0"
+
+  field :field_numbers, 1,
+    repeated: true,
+    type: Proto.Gymnasium.V1.Models.FieldNumber,
+    json_name: "fieldNumbers"
+end
+
 defmodule Proto.Gymnasium.V1.Models.Models.Service do
   @moduledoc false
 
@@ -298,6 +340,14 @@ defmodule Proto.Gymnasium.V1.Models.Models.Service do
       Proto.Gymnasium.V1.Models.ListProjectModelAttributesResponse
 
   rpc :DeleteAttribute, Proto.Gymnasium.V1.Models.DeleteAttributeRequest, Google.Protobuf.Empty
+
+  rpc :CreateFieldNumber,
+      Proto.Gymnasium.V1.Models.CreateFieldNumberRequest,
+      Proto.Gymnasium.V1.Models.FieldNumber
+
+  rpc :ListProjectModelFieldNumbers,
+      Proto.Gymnasium.V1.Models.ListProjectModelFieldNumbersRequest,
+      Proto.Gymnasium.V1.Models.ListProjectModelFieldNumbersResponse
 end
 
 defmodule Proto.Gymnasium.V1.Models.Models.Stub do
@@ -0,0 +1,16 @@
+defmodule Gymnasium.Repo.Migrations.CreateModelFieldNumbers do
+  use Ecto.Migration
+
+  def change do
+    create table(:model_field_numbers) do
+      add :model_id, :uuid
+      add :name, :string
+      add :number, :integer
+
+      timestamps()
+    end
+
+    create unique_index(:model_field_numbers, [:model_id, :name])
+    create unique_index(:model_field_numbers, [:model_id, :number])
+  end
+end
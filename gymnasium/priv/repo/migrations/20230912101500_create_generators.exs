@@ -0,0 +1,28 @@
+defmodule Gymnasium.Repo.Migrations.CreateGenerators do
+  use Ecto.Migration
+
+  def change do
+    create table(:generators) do
+      add :description, :string
+      add :name, :string
+      add :slug, :string
+
+      timestamps()
+    end
+
+    create table(:generator_templates) do
+      add :generator_id, :uuid
+      add :position, :integer
+      add :path, :string
+      add :content, :text
+      add :scope, :string
+
+      timestamps()
+    end
+
+    create unique_index(:generators, :name)
+    create unique_index(:generators, :slug)
+
+    create unique_index(:generator_templates, [:generator_id, :path])
+  end
+end
@@ -0,0 +1,15 @@
+defmodule Gymnasium.Repo.Migrations.CreateAttributeTypeColumnTypes do
+  use Ecto.Migration
+
+  def change do
+    create table(:attribute_type_column_types) do
+      add :attribute_type_id, :uuid
+      add :dialect, :string
+      add :column_type, :string
+
+      timestamps()
+    end
+
+    create unique_index(:attribute_type_column_types, [:attribute_type_id, :dialect])
+  end
+end
@@ -2,7 +2,7 @@ defmodule Gymnasium.AttributeTypesTest do
   use Gymnasium.DataCase
 
   alias Gymnasium.AttributeTypes
-  alias Gymnasium.AttributeTypes.AttributeType
+  alias Gymnasium.AttributeTypes.{AttributeType, ColumnType}
 
   import Gymnasium.{AttributeTypesFixtures, ModelsFixtures}
 
@@ -233,4 +233,62 @@ defmodule Gymnasium.AttributeTypesTest do
       end
     end
   end
+
+  describe "set column type" do
+    test "set_column_type/1 saves column type" do
+      %AttributeType{id: attribute_type_id} = attribute_type_fixture()
+
+      attrs = %{
+        attribute_type_id: attribute_type_id,
+        dialect: "mysql",
+        column_type: "BIGINT UNSIGNED"
+      }
+
+      assert {:ok, %ColumnType{} = column_type} = AttributeTypes.set_column_type(attrs)
+
+      assert column_type.attribute_type_id == attribute_type_id
+      assert column_type.dialect == "mysql"
+      assert column_type.column_type == "BIGINT UNSIGNED"
+    end
+
+    test "set_column_type/1 replaces column type of the dialect" do
+      %AttributeType{id: attribute_type_id} = attribute_type_fixture()
+      %ColumnType{id: id} = column_type_fixture(attribute_type_id: attribute_type_id)
+
+      attrs = %{
+        attribute_type_id: attribute_type_id,
+        dialect: "mysql",
+        column_type: "DECIMAL(20)"
+      }
+
+      assert {:ok, %ColumnType{id: ^id, column_type: "DECIMAL(20)"}} =
+               AttributeTypes.set_column_type(attrs)
+    end
+
+    test "set_column_type/1 validates dialect" do
+      attrs = %{
+        attribute_type_id: Ecto.UUID.generate(),
+        dialect: "oracle",
+        column_type: "NUMBER(19)"
+      }
+
+      assert {:error, %Ecto.Changeset{errors: errors}} = AttributeTypes.set_column_type(attrs)
+
+      assert errors == [
+               dialect:
+                 {"is invalid", [validation: :inclusion, enum: ["postgresql", "sqlite", "mysql"]]}
+             ]
+    end
+  end
+
+  describe "list column types" do
+    test "list_column_types/1 returns column types of the dialect" do
+      %AttributeType{id: attribute_type_id} = attribute_type_fixture()
+      %ColumnType{id: id} = column_type_fixture(attribute_type_id: attribute_type_id)
+      column_type_fixture(attribute_type_id: attribute_type_id, dialect: "postgresql")
+
+      assert [%ColumnType{id: ^id, attribute_type: %AttributeType{slug: "bigint"}}] =
+               AttributeTypes.list_column_types("mysql")
+    end
+  end
 end
@@ -0,0 +1,81 @@
+defmodule Gymnasium.GeneratorsTest do
+  use Gymnasium.DataCase
+
+  alias Gymnasium.Generators
+  alias Gymnasium.Generators.{Generator, Template}
+
+  import Gymnasium.GeneratorsFixtures
+
+  describe "create generator" do
+    test "create_generator/1 saves generator with ordered templates" do
+      attrs = %{
+        description: "Page per model.",
+        name: "Model Docs",
+        slug: "model-docs",
+        templates: [
+          %{path: "index.md", content: "", scope: "project"},
+          %{path: "{{ model.slug }}.md", content: "# {{ model.name }}", scope: "model"}
+        ]
+      }
+
+      assert {:ok, %Generator{} = generator} = Generators.create_generator(attrs)
+
+      assert generator.name == "Model Docs"
+      assert generator.slug == "model-docs"
+
+      assert [
+               %Template{path: "index.md", scope: "project", position: 0},
+               %Template{path: "{{ model.slug }}.md", scope: "model", position: 1}
+             ] = Generators.get_generator!(generator.id).templates
+    end
+
+    test "create_generator/1 validates template scope" do
+      attrs = %{
+        name: "Model Docs",
+        slug: "model-docs",
+        templates: [%{path: "index.md", content: "", scope: "unspecified"}]
+      }
+
+      assert {:error, %Ecto.Changeset{valid?: false}} = Generators.create_generator(attrs)
+    end
+
+    test "create_generator/1 validates slug duplicate" do
+      %Generator{slug: slug} = generator_fixture()
+
+      assert {:error, %Ecto.Changeset{errors: errors}} =
+               Generators.create_generator(%{
+                 name: "Other Docs",
+                 slug: slug,
+                 templates: [%{path: "index.md", content: "", scope: "project"}]
+               })
+
+      assert [slug: {"has already been taken", _}] = errors
+    end
+  end
+
+  describe "find generator" do
+    test "find_generator!/1 returns generator with templates" do
+      generator = generator_fixture()
+
+      assert %Generator{id: id, templates: [%Template{}]} =
+               Generators.find_generator!(generator.slug)
+
+      assert id == generator.id
+    end
+
+    test "find_generator!/1 raises NoResultsError" do
+      assert_raise Ecto.NoResultsError, fn -> Generators.find_generator!("unknown") end
+    end
+  end
+
+  describe "delete generator" do
+    test "delete_generator/1 deletes generator and its templates" do
+      generator = generator_fixture()
+
+      assert {:ok, %Generator{}} = Generators.delete_generator(generator)
+
+      assert Generators.list_generators() == []
+      assert Gymnasium.Repo.all(Template) == []
+    end
+  end
+end
@@ -4,7 +4,7 @@ defmodule Gymnasium.ModelsTest do
   alias Gymnasium.{Model, Models, Models}
   alias Gymnasium.Projects.Project
   alias Gymnasium.AttributeTypes.AttributeType
-  alias Gymnasium.Models.{Model, Attribute, Association}
+  alias Gymnasium.Models.{Model, Attribute, Association, FieldNumber}
 
   import Gymnasium.{ModelsFixtures, ProjectsFixtures, AttributeTypesFixtures}
 
@@ -380,4 +380,60 @@ defmodule Gymnasium.ModelsTest do
       end
     end
   end
+
+  describe "create model field number" do
+    test "create_field_number/1 saves Model's field number" do
+      %Model{id: model_id} = model_fixture()
+
+      attrs = %{
+        model_id: model_id,
+        name: "title",
+        number: 1
+      }
+
+      assert {:ok, %FieldNumber{} = field_number} = Models.create_field_number(attrs)
+
+      assert field_number.model_id == model_id
+      assert field_number.name == "title"
+      assert field_number.number == 1
+    end
+
+    test "create_field_number/1 returns error on invalid number" do
+      attrs = %{
+        model_id: Ecto.UUID.generate(),
+        name: "title",
+        number: 0
+      }
+
+      assert {:error, %Ecto.Changeset{errors: errors}} = Models.create_field_number(attrs)
+
+      assert errors == [
+               number:
+                 {"must be greater than %{number}",
+                  [validation: :number, kind: :greater_than, number: 0]}
+             ]
+    end
+
+    test "create_field_number/1 returns error on existing model id and number pair" do
+      %Model{id: model_id} = model_fixture()
+      %FieldNumber{number: number} = model_field_number_fixture(model_id: model_id)
+
+      attrs = %{
+        model_id: model_id,
+        name: "isbn",
+        number: number
+      }
+
+      assert {:error, %Ecto.Changeset{errors: errors}} = Models.create_field_number(attrs)
+
+      assert errors == [
+               model_id:
+                 {"has already been taken",
+                  [
+                    constraint: :unique,
+                    constraint_name: "model_field_numbers_model_id_number_index"
+                  ]}
+             ]
+    end
+  end
 end
@@ -159,4 +159,50 @@ defmodule GymnasiumGrpc.AttributeTypesServerTest do
       end
     end
   end
+
+  describe "set column type" do
+    test "set_column_type/2 saves column type" do
+      attribute_type = attribute_type_fixture()
+
+      proto_column_type =
+        AttributeTypesServer.set_column_type(
+          %Rpc.SetColumnTypeRequest{
+            attribute_type_id: attribute_type.id,
+            dialect: "mysql",
+            column_type: "BIGINT UNSIGNED"
+          },
+          nil
+        )
+
+      assert %Rpc.ColumnType{
+               attribute_type_slug: "bigint",
+               dialect: "mysql",
+               column_type: "BIGINT UNSIGNED"
+             } = proto_column_type
+    end
+
+    test "set_column_type/2 returns error when request contains malformed" do
+      assert_raise Ecto.InvalidChangesetError, fn ->
+        AttributeTypesServer.set_column_type(
+          %Rpc.SetColumnTypeRequest{},
+          nil
+        )
+      end
+    end
+  end
+
+  describe "list column types" do
+    test "list_column_types/2 returns column types of the dialect" do
+      attribute_type = attribute_type_fixture()
+      column_type = column_type_fixture(attribute_type_id: attribute_type.id)
+
+      %Rpc.ListColumnTypesResponse{column_types: column_types} =
+        AttributeTypesServer.list_column_types(
+          %Rpc.ListColumnTypesRequest{dialect: "mysql"},
+          nil
+        )
+
+      assert [column_type.id] == Enum.map(column_types, fn ct -> ct.id end)
+    end
+  end
 end
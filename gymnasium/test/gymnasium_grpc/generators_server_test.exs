@@ -0,0 +1,87 @@
+defmodule GymnasiumGrpc.GeneratorsServerTest do
+  use Gymnasium.DataCase
+
+  alias GymnasiumGrpc.GeneratorsServer
+  alias Proto.Gymnasium.V1.Generators, as: Rpc
+
+  import Gymnasium.GeneratorsFixtures
+
+  describe "create generator" do
+    test "create_generator/2 saves generator" do
+      proto_generator =
+        GeneratorsServer.create_generator(
+          %Rpc.CreateGeneratorRequest{
+            name: "Model Docs",
+            slug: "model-docs",
+            templates: [
+              %Rpc.GeneratorTemplate{
+                path: "{{ model.slug }}.md",
+                content: "# {{ model.name }}",
+                scope: :TEMPLATE_SCOPE_MODEL
+              }
+            ]
+          },
+          nil
+        )
+
+      assert %Rpc.Generator{
+               name: "Model Docs",
+               templates: [%Rpc.GeneratorTemplate{scope: :TEMPLATE_SCOPE_MODEL}]
+             } = proto_generator
+    end
+
+    test "create_generator/2 returns error when request contains malformed" do
+      assert_raise Ecto.InvalidChangesetError, fn ->
+        GeneratorsServer.create_generator(
+          %Rpc.CreateGeneratorRequest{
+            name: "",
+            slug: ""
+          },
+          nil
+        )
+      end
+    end
+  end
+
+  describe "list generators" do
+    test "list_generators/2 returns all generators" do
+      generator = generator_fixture()
+
+      %Rpc.ListGeneratorsResponse{generators: generators} =
+        GeneratorsServer.list_generators(%Rpc.ListGeneratorsRequest{}, nil)
+
+      assert [generator.id] == Enum.map(generators, fn g -> g.id end)
+    end
+  end
+
+  describe "find generator" do
+    test "find_generator/2 returns generator" do
+      generator = generator_fixture()
+
+      %Rpc.Generator{id: id} =
+        GeneratorsServer.find_generator(%Rpc.FindGeneratorRequest{slug: generator.slug}, nil)
+
+      assert id == generator.id
+    end
+
+    test "find_generator/2 raises NotFound error" do
+      assert_raise Ecto.NoResultsError, fn ->
+        GeneratorsServer.find_generator(%Rpc.FindGeneratorRequest{slug: ""}, nil)
+      end
+    end
+  end
+
+  describe "delete generator" do
+    test "delete_generator/2 deletes generator" do
+      generator = generator_fixture()
+
+      assert %Google.Protobuf.Empty{} =
+               GeneratorsServer.delete_generator(
+                 %Rpc.DeleteGeneratorRequest{id: generator.id},
+                 nil
+               )
+
+      assert Gymnasium.Generators.list_generators() == []
+    end
+  end
+end
@@ -2,7 +2,7 @@ defmodule GymnasiumGrpc.ModelsServerTest do
   use Gymnasium.DataCase
 
   alias GymnasiumGrpc.ModelsServer
-  alias Gymnasium.Models.{Model, Association, Attribute}
+  alias Gymnasium.Models.{Model, Association, Attribute, FieldNumber}
   alias Gymnasium.Projects.Project
 
   alias Proto.Gymnasium.V1.Models, as: Rpc
@@ -22,7 +22,10 @@ defmodule GymnasiumGrpc.ModelsServerTest do
     ListProjectModelAssociationsRequest,
     ListProjectModelAttributesRequest,
     ListProjectModelAssociationsResponse,
-    ListProjectModelAttributesResponse
+    ListProjectModelAttributesResponse,
+    CreateFieldNumberRequest,
+    ListProjectModelFieldNumbersRequest,
+    ListProjectModelFieldNumbersResponse
   }
 
   import Gymnasium.{ModelsFixtures, ProjectsFixtures, AttributeTypesFixtures}
@@ -30,6 +33,7 @@ defmodule GymnasiumGrpc.ModelsServerTest do
   alias Proto.Gymnasium.V1.Models.Model, as: ProtoModel
   alias Proto.Gymnasium.V1.Models.Association, as: ProtoAssociation
   alias Proto.Gymnasium.V1.Models.Attribute, as: ProtoAttribute
+  alias Proto.Gymnasium.V1.Models.FieldNumber, as: ProtoFieldNumber
 
   describe "create the Model" do
     test "create_model/2 saves Model" do
@@ -478,4 +482,52 @@ defmodule GymnasiumGrpc.ModelsServerTest do
       end
     end
   end
+
+  describe "create the Model field number" do
+    test "create_field_number/2 saves Model field number" do
+      model = model_fixture()
+
+      proto_field_number =
+        ModelsServer.create_field_number(
+          %CreateFieldNumberRequest{
+            model_id: model.id,
+            name: "title",
+            number: 1
+          },
+          nil
+        )
+
+      assert %ProtoFieldNumber{name: "title", number: 1} = proto_field_number
+    end
+
+    test "create_field_number/2 returns error when request values are malformed" do
+      assert_raise GRPC.RPCError, "Internal errors", fn ->
+        ModelsServer.create_field_number(
+          %CreateFieldNumberRequest{},
+          nil
+        )
+      end
+    end
+  end
+
+  describe "Project models field numbers listing" do
+    test "list_project_model_field_numbers/2 returns field numbers of all models for given project slug" do
+      %Project{id: project_id, slug: project_slug} = project_fixture()
+      model = model_fixture(project_id: project_id)
+      other_model = model_fixture()
+
+      %FieldNumber{id: id} = model_field_number_fixture(model_id: model.id)
+      model_field_number_fixture(model_id: other_model.id)
+
+      %ListProjectModelFieldNumbersResponse{field_numbers: list} =
+        ModelsServer.list_project_model_field_numbers(
+          %ListProjectModelFieldNumbersRequest{
+            project_slug: project_slug
+          },
+          nil
+        )
+
+      assert Enum.map(list, fn f -> f.id end) == [id]
+    end
+  end
 end
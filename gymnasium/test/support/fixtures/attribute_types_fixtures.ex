@@ -19,4 +19,20 @@ defmodule Gymnasium.AttributeTypesFixtures do
 
     attribute_type
   end
+
+  @doc """
+  Generate a column type of an attribute type.
+  """
+  def column_type_fixture(attrs \\ %{}) do
+    {:ok, column_type} =
+      attrs
+      |> Enum.into(%{
+        attribute_type_id: Ecto.UUID.generate(),
+        dialect: "mysql",
+        column_type: "BIGINT UNSIGNED"
+      })
+      |> Gymnasium.AttributeTypes.set_column_type()
+
+    column_type
+  end
 end
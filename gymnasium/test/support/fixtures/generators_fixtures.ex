@@ -0,0 +1,25 @@
+defmodule Gymnasium.GeneratorsFixtures do
+  @moduledoc """
+  This module defines test helpers for creating
+  entities via the `Gymnasium.Generators` context.
+  """
+
+  @doc """
+  Generate a generator.
+  """
+  def generator_fixture(attrs \\ %{}) do
+    {:ok, generator} =
+      attrs
+      |> Enum.into(%{
+        name: "Model Docs",
+        description: "Page per model.",
+        slug: "model-docs",
+        templates: [
+          %{path: "models/{{ model.slug }}.md", content: "# {{ model.name }}\n", scope: "model"}
+        ]
+      })
+      |> Gymnasium.Generators.create_generator()
+
+    generator
+  end
+end
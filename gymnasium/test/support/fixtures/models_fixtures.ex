@@ -57,4 +57,20 @@ defmodule Gymnasium.ModelsFixtures do
 
     model_association
   end
+
+  @doc """
+  Generate a model field number.
+  """
+  def model_field_number_fixture(attrs \\ %{}) do
+    {:ok, model_field_number} =
+      attrs
+      |> Enum.into(%{
+        model_id: Ecto.UUID.generate(),
+        name: "title",
+        number: 1
+      })
+      |> Models.create_field_number()
+
+    model_field_number
+  end
 end
@@ -0,0 +1,63 @@
+use crate::util;
+
+/// Attribute types with a well known counterpart in the SQL dialects and the
+/// programming languages, recognized by the attribute type slug.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AttributeKind {
+    SmallInteger,
+    Integer,
+    BigInteger,
+    Float,
+    Double,
+    Decimal,
+    Boolean,
+    Date,
+    Time,
+    DateTime,
+    Uuid,
+    Json,
+    Binary,
+
+    /// Any other attribute type.
+    Text,
+}
+
+impl AttributeKind {
+    pub fn from_slug(slug: &str) -> Self {
+        match util::string::snake_case(slug).as_str() {
+            "small_integer" | "small_int" | "smallint" | "i16" => Self::SmallInteger,
+            "integer" | "int" | "int32" | "i32" => Self::Integer,
+            "big_integer" | "big_int" | "bigint" | "int64" | "i64" | "long" => Self::BigInteger,
+            "float" | "f32" | "real" => Self::Float,
+            "double" | "f64" => Self::Double,
+            "decimal" | "numeric" => Self::Decimal,
+            "boolean" | "bool" => Self::Boolean,
+            "date" => Self::Date,
+            "time" => Self::Time,
+            "date_time" | "datetime" | "timestamp" => Self::DateTime,
+            "uuid" => Self::Uuid,
+            "json" => Self::Json,
+            "binary" | "bytes" | "blob" => Self::Binary,
+            _ => Self::Text,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_recognizes_attribute_kinds_by_slug() {
+        assert_eq!(AttributeKind::from_slug("integer"), AttributeKind::Integer);
+        assert_eq!(
+            AttributeKind::from_slug("big-int"),
+            AttributeKind::BigInteger
+        );
+        assert_eq!(
+            AttributeKind::from_slug("date-time"),
+            AttributeKind::DateTime
+        );
+        assert_eq!(AttributeKind::from_slug("money"), AttributeKind::Text);
+    }
+}
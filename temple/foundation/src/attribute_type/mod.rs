@@ -4,17 +4,21 @@
 mod create;
 mod delete;
 mod get;
+mod kind;
 mod list;
+mod set_column_type;
 mod update;
 
 pub mod tests;
 
-use crate::{datastore::Record, util, FoundationResult};
+use crate::{datastore::Record, model::SqlDialect, util, FoundationResult};
 
 pub use create::{execute as create, Request as CreateRequest};
 pub use delete::execute as delete;
 pub use get::execute as get;
+pub use kind::AttributeKind;
 pub use list::execute as list;
+pub use set_column_type::{execute as set_column_type, Request as SetColumnTypeRequest};
 pub use update::execute as update;
 
 #[async_trait::async_trait]
@@ -54,6 +58,23 @@ pub trait DeleteAttributeTypeRecord {
     ) -> FoundationResult<()>;
 }
 
+#[async_trait::async_trait]
+pub trait ListColumnTypeRecords {
+    async fn list_column_type_records(
+        &self,
+        dialect: SqlDialect,
+    ) -> FoundationResult<Vec<ColumnTypeRecord>>;
+}
+
+#[async_trait::async_trait]
+pub trait SetColumnTypeRecord {
+    async fn set_column_type_record(
+        &self,
+        attribute_type_record: AttributeTypeRecord,
+        column_type: ColumnType,
+    ) -> FoundationResult<ColumnTypeRecord>;
+}
+
 #[derive(Default, Clone, Debug, PartialEq)]
 /// Represents an attribute type of the model.
 pub struct AttributeType {
@@ -76,6 +97,29 @@ impl From<AttributeTypeRecord> for AttributeType {
     }
 }
 
+#[derive(Default, Clone, Debug, PartialEq)]
+/// SQL column type of an [`AttributeType`] in one of the dialects, which
+/// takes precedence over the default type of the dialect.
+pub struct ColumnType {
+    /// Slug of the [`AttributeType`].
+    pub attribute_type_slug: String,
+
+    pub dialect: SqlDialect,
+
+    /// Type of the columns, e.g. `NUMERIC(12, 2)`.
+    pub column_type: String,
+}
+
+/// Repository [`ColumnType`] representation, one per attribute type and
+/// dialect.
+pub type ColumnTypeRecord = Record<ColumnType>;
+
+impl From<ColumnTypeRecord> for ColumnType {
+    fn from(value: ColumnTypeRecord) -> Self {
+        value.into_inner()
+    }
+}
+
 fn validate_slug(slug: &str) -> FoundationResult<()> {
     let validation_errors = util::validator::Validator::new()
         .validate_required("slug", slug)
@@ -0,0 +1,169 @@
+//! [`ColumnType`]'s assignment to an [`super::AttributeType`].
+
+use super::{validate_slug, ColumnType, GetAttributeTypeRecord, SetColumnTypeRecord};
+use crate::{model::SqlDialect, util, FoundationError, FoundationResult};
+
+pub struct Request {
+    pub attribute_type_slug: String,
+    pub dialect: SqlDialect,
+    pub column_type: String,
+}
+
+/// Set the column type of the attribute type in the dialect, replacing the
+/// previous one.
+pub async fn execute(
+    repo: &(impl GetAttributeTypeRecord + SetColumnTypeRecord),
+    request: Request,
+) -> FoundationResult<ColumnType> {
+    validate_request(&request)?;
+
+    let Request {
+        attribute_type_slug,
+        dialect,
+        column_type,
+    } = request;
+
+    let attribute_type_record = repo
+        .get_attribute_type_record(&attribute_type_slug)
+        .await?
+        .ok_or(FoundationError::not_found("Attribute type not found."))?;
+
+    let column_type = repo
+        .set_column_type_record(
+            attribute_type_record,
+            ColumnType {
+                attribute_type_slug,
+                dialect,
+                column_type: column_type.trim().to_string(),
+            },
+        )
+        .await?
+        .into();
+
+    Ok(column_type)
+}
+
+fn validate_request(request: &Request) -> FoundationResult<()> {
+    let Request {
+        attribute_type_slug,
+        dialect: _,
+        column_type,
+    } = request;
+
+    validate_slug(attribute_type_slug)?;
+
+    let validation_errors = util::validator::Validator::new()
+        .validate_required("column_type", column_type.trim())
+        .validate_max_length("column_type", column_type, 100)
+        .validate();
+
+    if validation_errors.is_empty() {
+        return Ok(());
+    }
+
+    Err(validation_errors.first().cloned().unwrap().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        attribute_type::{
+            tests::{attribute_type_record_fixture, ColumnTypeRepo},
+            ListColumnTypeRecords,
+        },
+        FoundationErrorCode,
+    };
+
+    #[tokio::test]
+    async fn it_replaces_column_type_of_the_dialect() -> FoundationResult<()> {
+        let repo = ColumnTypeRepo::default();
+        let attribute_type_record = attribute_type_record_fixture(&repo.attribute_type_repo).await;
+
+        for (dialect, column_type) in [
+            (SqlDialect::PostgreSql, "INT8"),
+            (SqlDialect::MySql, "BIGINT UNSIGNED"),
+            (SqlDialect::PostgreSql, " NUMERIC(20) "),
+        ] {
+            execute(
+                &repo,
+                Request {
+                    attribute_type_slug: attribute_type_record.inner.slug.clone(),
+                    dialect,
+                    column_type: column_type.to_string(),
+                },
+            )
+            .await?;
+        }
+
+        let column_types: Vec<ColumnType> = repo
+            .list_column_type_records(SqlDialect::PostgreSql)
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        assert_eq!(
+            column_types,
+            vec![ColumnType {
+                attribute_type_slug: "bigint".to_string(),
+                dialect: SqlDialect::PostgreSql,
+                column_type: "NUMERIC(20)".to_string(),
+            }]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_returns_not_found_for_missing_attribute_type() {
+        let repo = ColumnTypeRepo::default();
+
+        let error = execute(
+            &repo,
+            Request {
+                attribute_type_slug: "money".to_string(),
+                dialect: SqlDialect::PostgreSql,
+                column_type: "MONEY".to_string(),
+            },
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(error.code(), FoundationErrorCode::NotFound));
+    }
+
+    #[tokio::test]
+    async fn it_validates_column_type() {
+        let repo = ColumnTypeRepo::default();
+
+        let test_table = [
+            (
+                " ".to_string(),
+                FoundationError::invalid_argument("column_type can't be blank"),
+            ),
+            (
+                "x".repeat(101),
+                FoundationError::invalid_argument(
+                    "column_type is too long, maximum length is 100 bytes",
+                ),
+            ),
+        ];
+
+        for (column_type, expected_error) in test_table {
+            let error = execute(
+                &repo,
+                Request {
+                    attribute_type_slug: "bigint".to_string(),
+                    dialect: SqlDialect::PostgreSql,
+                    column_type,
+                },
+            )
+            .await
+            .unwrap_err();
+
+            assert_eq!(error.code(), expected_error.code());
+            assert_eq!(error.message(), expected_error.message());
+        }
+    }
+}
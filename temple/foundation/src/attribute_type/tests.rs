@@ -5,6 +5,7 @@ use crate::{
     datastore::tests::{RecordFactory, Repo},
     Utc, Uuid,
 };
+use tokio::sync::RwLock;
 
 struct AttributeTypeFactory {}
 
@@ -98,6 +99,92 @@ impl GetAttributeTypeRecord for AttributeTypeRepo {
     }
 }
 
+/// Attribute types along with their column types.
+#[derive(Default)]
+pub struct ColumnTypeRepo {
+    pub attribute_type_repo: AttributeTypeRepo,
+    pub column_type_repo: Repo<ColumnType>,
+}
+
+impl ColumnTypeRepo {
+    pub fn seed(column_types: Vec<ColumnType>) -> Self {
+        let column_type_records = column_types
+            .iter()
+            .map(RecordFactory::build)
+            .map(|record| (record.id, record))
+            .collect();
+
+        Self {
+            attribute_type_repo: AttributeTypeRepo::new(),
+            column_type_repo: Repo {
+                records: RwLock::new(column_type_records),
+            },
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl GetAttributeTypeRecord for ColumnTypeRepo {
+    async fn get_attribute_type_record(
+        &self,
+        slug: &str,
+    ) -> FoundationResult<Option<AttributeTypeRecord>> {
+        self.attribute_type_repo
+            .get_attribute_type_record(slug)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl SetColumnTypeRecord for ColumnTypeRepo {
+    async fn set_column_type_record(
+        &self,
+        _attribute_type_record: AttributeTypeRecord,
+        column_type: ColumnType,
+    ) -> FoundationResult<ColumnTypeRecord> {
+        let existing_record = self
+            .column_type_repo
+            .records()
+            .await
+            .into_iter()
+            .find(|record| {
+                record.inner.attribute_type_slug == column_type.attribute_type_slug
+                    && record.inner.dialect == column_type.dialect
+            });
+
+        let column_type_record = match existing_record {
+            Some(record) => ColumnTypeRecord {
+                inner: column_type,
+                updated_at: Utc::now(),
+                ..record
+            },
+            None => RecordFactory::build(&column_type),
+        };
+
+        self.column_type_repo.save(column_type_record.clone()).await;
+
+        Ok(column_type_record)
+    }
+}
+
+#[async_trait::async_trait]
+impl ListColumnTypeRecords for ColumnTypeRepo {
+    async fn list_column_type_records(
+        &self,
+        dialect: SqlDialect,
+    ) -> FoundationResult<Vec<ColumnTypeRecord>> {
+        let column_type_records = self
+            .column_type_repo
+            .records()
+            .await
+            .into_iter()
+            .filter(|record| record.inner.dialect == dialect)
+            .collect();
+
+        Ok(column_type_records)
+    }
+}
+
 pub async fn attribute_type_record_fixture(repo: &AttributeTypeRepo) -> AttributeTypeRecord {
     let attribute_type = AttributeTypeFactory::build();
     let attribute_type_record = RecordFactory::build(&attribute_type);
@@ -0,0 +1,288 @@
+use super::{associated_model_overview, inverse_belongs_to};
+use crate::{attribute_type::AttributeKind, model, util, FoundationError, FoundationResult};
+
+/// Name and type of the primary key column of every table.
+const PRIMARY_KEY: (&str, &str) = ("id", "bigint");
+
+struct Table<'a> {
+    model: &'a model::Model,
+    name: String,
+    columns: Vec<Column>,
+}
+
+struct Column {
+    name: String,
+    r#type: String,
+    settings: Vec<String>,
+}
+
+/// Generates the DBML of the models, with the relational schema of the
+/// migrations: a table per model with a surrogate `id` primary key, and a
+/// foreign key column per `BelongsTo` association.
+///
+/// The `HasOne` and `HasMany` associations are the one-to-one and
+/// one-to-many sides of the reference of their inverse `BelongsTo`. When the
+/// associated model doesn't have such a `BelongsTo`, a foreign key column
+/// named after the model is added to its table. The references are named
+/// after both associations, so the import restores them. Descriptions become
+/// notes.
+pub fn dbml(model_overviews: &[model::ModelOverview]) -> FoundationResult<String> {
+    let mut tables: Vec<Table> = vec![];
+    let mut references = vec![];
+
+    for model_overview in model_overviews {
+        let model = &model_overview.model;
+        let name = identifier(&model.name)?;
+
+        if let Some(table) = tables.iter().find(|table| table.name == name) {
+            return Err(FoundationError::failed_precondition(format!(
+                "code can't be generated, `{}` and `{}` share the table name `{name}`",
+                table.model.name, model.name
+            )));
+        }
+
+        let mut columns = vec![Column {
+            name: PRIMARY_KEY.0.to_string(),
+            r#type: PRIMARY_KEY.1.to_string(),
+            settings: vec!["pk".to_string(), "increment".to_string()],
+        }];
+
+        for attribute in &model_overview.attributes {
+            columns.push(Column {
+                name: identifier(&attribute.name)?,
+                r#type: column_type(&attribute.r#type),
+                settings: note_setting(attribute.description.as_deref()),
+            });
+        }
+
+        tables.push(Table {
+            model,
+            name,
+            columns,
+        });
+    }
+
+    for (index, model_overview) in model_overviews.iter().enumerate() {
+        let model = &model_overview.model;
+
+        for association in &model_overview.associations {
+            let Some(associated_model_overview) =
+                associated_model_overview(model_overviews, association)
+            else {
+                return Err(FoundationError::failed_precondition(format!(
+                    "code can't be generated, `{}` is associated with `{}` which isn't part of the project",
+                    model.name, association.model.name
+                )));
+            };
+
+            let associated_table_name = identifier(&associated_model_overview.model.name)?;
+
+            match association.kind {
+                model::AssociationKind::BelongsTo => {
+                    let column = format!("{}_{}", identifier(&association.name)?, PRIMARY_KEY.0);
+
+                    let inverse = inverse_association(associated_model_overview, model_overview);
+
+                    tables[index].columns.push(Column {
+                        name: column.clone(),
+                        r#type: PRIMARY_KEY.1.to_string(),
+                        settings: note_setting(association.description.as_deref()),
+                    });
+
+                    references.push(reference(
+                        (
+                            Some(&association.name),
+                            inverse.map(|inverse| inverse.name.as_str()),
+                        ),
+                        &tables[index].name,
+                        &column,
+                        inverse
+                            .is_some_and(|inverse| inverse.kind == model::AssociationKind::HasOne),
+                        &associated_table_name,
+                    ));
+                }
+                model::AssociationKind::HasOne | model::AssociationKind::HasMany => {
+                    if inverse_association(model_overview, associated_model_overview)
+                        .is_some_and(|inverse| inverse.name == association.name)
+                    {
+                        continue;
+                    }
+
+                    let column = format!("{}_{}", identifier(&model.name)?, PRIMARY_KEY.0);
+
+                    let Some(associated_table) = tables
+                        .iter_mut()
+                        .find(|table| table.name == associated_table_name)
+                    else {
+                        continue;
+                    };
+
+                    associated_table.columns.push(Column {
+                        name: column.clone(),
+                        r#type: PRIMARY_KEY.1.to_string(),
+                        settings: note_setting(association.description.as_deref()),
+                    });
+
+                    references.push(reference(
+                        (None, Some(&association.name)),
+                        &associated_table_name,
+                        &column,
+                        association.kind == model::AssociationKind::HasOne,
+                        &tables[index].name,
+                    ));
+                }
+            }
+        }
+    }
+
+    let mut dbml = String::new();
+
+    for table in &tables {
+        let mut column_names = vec![];
+
+        for column in &table.columns {
+            if column_names.contains(&&column.name) {
+                return Err(FoundationError::failed_precondition(format!(
+                    "code can't be generated, `{}` has more than one column named `{}`",
+                    table.model.name, column.name
+                )));
+            }
+
+            column_names.push(&column.name);
+        }
+
+        if !dbml.is_empty() {
+            dbml.push('\n');
+        }
+
+        dbml.push_str(&format!("Table {} {{\n", table.name));
+
+        for column in &table.columns {
+            dbml.push_str(&format!("    {} {}", column.name, column.r#type));
+
+            if !column.settings.is_empty() {
+                dbml.push_str(&format!(" [{}]", column.settings.join(", ")));
+            }
+
+            dbml.push('\n');
+        }
+
+        if let Some(description) = table.model.description.as_deref() {
+            dbml.push_str(&format!("\n    Note: {}\n", string(description)));
+        }
+
+        dbml.push_str("}\n");
+    }
+
+    if !references.is_empty() {
+        dbml.push('\n');
+    }
+
+    for reference in references {
+        dbml.push_str(&reference);
+    }
+
+    Ok(dbml)
+}
+
+/// `HasOne` or `HasMany` association of the model that is the other side of
+/// the reference of the only `BelongsTo` association of the associated model
+/// to it.
+fn inverse_association<'a>(
+    model_overview: &'a model::ModelOverview,
+    associated_model_overview: &model::ModelOverview,
+) -> Option<&'a model::Association> {
+    inverse_belongs_to(model_overview, associated_model_overview)?;
+
+    model_overview.associations.iter().find(|association| {
+        association.kind != model::AssociationKind::BelongsTo
+            && association.model.slug == associated_model_overview.model.slug
+    })
+}
+
+/// `Ref` of the foreign key column, many-to-one or one-to-one, named after
+/// the `BelongsTo` association and its inverse association, e.g.
+/// `"Publisher/Books"`. The side without an association is left empty.
+fn reference(
+    (name, inverse_name): (Option<&str>, Option<&str>),
+    table: &str,
+    column: &str,
+    is_one_to_one: bool,
+    referenced_table: &str,
+) -> String {
+    let relationship = if is_one_to_one { "-" } else { ">" };
+    let name = format!(
+        "{}/{}",
+        name.unwrap_or_default(),
+        inverse_name.unwrap_or_default()
+    )
+    .replace('\\', "\\\\")
+    .replace('"', "\\\"");
+
+    format!(
+        "Ref \"{name}\": {table}.{column} {relationship} {referenced_table}.{}\n",
+        PRIMARY_KEY.0
+    )
+}
+
+/// Column type of the attribute type, the SQL type of the well known
+/// attribute types and the attribute type name otherwise.
+fn column_type(attribute_type: &crate::attribute_type::AttributeType) -> String {
+    let column_type = match AttributeKind::from_slug(&attribute_type.slug) {
+        AttributeKind::SmallInteger => "smallint",
+        AttributeKind::Integer => "integer",
+        AttributeKind::BigInteger => "bigint",
+        AttributeKind::Float => "real",
+        AttributeKind::Double => "double",
+        AttributeKind::Decimal => "decimal",
+        AttributeKind::Boolean => "boolean",
+        AttributeKind::Date => "date",
+        AttributeKind::Time => "time",
+        AttributeKind::DateTime => "timestamp",
+        AttributeKind::Uuid => "uuid",
+        AttributeKind::Json => "json",
+        AttributeKind::Binary => "binary",
+        AttributeKind::Text => {
+            let column_type = util::string::snake_case(&attribute_type.name);
+
+            if column_type.is_empty() {
+                return format!("\"{}\"", attribute_type.name.replace('"', "\\\""));
+            }
+
+            return column_type;
+        }
+    };
+
+    column_type.to_string()
+}
+
+fn note_setting(description: Option<&str>) -> Vec<String> {
+    description
+        .map(|description| format!("note: {}", string(description)))
+        .into_iter()
+        .collect()
+}
+
+/// DBML string of the trimmed text, multi-line when the text has more than
+/// one line.
+fn string(text: &str) -> String {
+    let text = text.trim().replace('\\', "\\\\").replace('\'', "\\'");
+
+    if text.contains('\n') {
+        format!("'''\n{text}\n'''")
+    } else {
+        format!("'{text}'")
+    }
+}
+
+fn identifier(name: &str) -> FoundationResult<String> {
+    let identifier = util::string::snake_case(name);
+
+    if identifier.is_empty() {
+        return Err(FoundationError::failed_precondition(format!(
+            "code can't be generated, `{name}` can't be used as a DBML name"
+        )));
+    }
+
+    Ok(identifier)
+}
@@ -0,0 +1,441 @@
+use super::{description_lines, escape, hash_comment, inverse_belongs_to, string_literal};
+use crate::{
+    attribute_type::AttributeKind,
+    migration::{self, ForeignKey, Table},
+    model,
+    util::{inflection, string},
+    FoundationError, FoundationResult, UtcDateTime,
+};
+use std::collections::{HashMap, HashSet};
+
+/// Generates an `Ecto.Schema` module with a `changeset` per model and the
+/// `Ecto.Migration` creating their tables.
+///
+/// Modules are nested in the project module and named after the singular of
+/// the model, tables and `has_many` associations are plural. Foreign keys are
+/// required, the other fields are optional.
+pub fn ecto_files(
+    model_overviews: &[model::ModelOverview],
+    project_slug: &str,
+    migration_timestamp: UtcDateTime,
+) -> FoundationResult<Vec<model::GeneratedFile>> {
+    let app_name = identifier(project_slug)?;
+    let app_module = string::pascal_case(&app_name);
+
+    let mut module_names: HashMap<String, &str> = HashMap::new();
+    let mut schemas = vec![];
+
+    for model_overview in model_overviews {
+        let schema = EctoSchema::new(model_overview, &app_module)?;
+        let model_name = &model_overview.model.name;
+
+        if let Some(other_model_name) = module_names.insert(schema.module_name.clone(), model_name)
+        {
+            return Err(FoundationError::failed_precondition(format!(
+                "code can't be generated, `{model_name}` and `{other_model_name}` share the same module name `{}`",
+                schema.module_name
+            )));
+        }
+
+        schemas.push(schema);
+    }
+
+    let associations = schemas
+        .iter()
+        .map(|schema| associations(&schemas, schema))
+        .collect::<FoundationResult<Vec<Vec<EctoAssociation>>>>()?;
+
+    let mut files = vec![];
+
+    for (schema, associations) in schemas.iter().zip(&associations) {
+        files.push(model::GeneratedFile {
+            path: format!("lib/{app_name}/{}.ex", schema.file_name),
+            content: schema_module(schema, associations)?,
+        });
+    }
+
+    let migration_name = format!("create_{app_name}_tables");
+
+    files.push(model::GeneratedFile {
+        path: format!(
+            "priv/repo/migrations/{}_{migration_name}.exs",
+            migration_timestamp.format("%Y%m%d%H%M%S")
+        ),
+        content: migration_module(
+            &format!(
+                "{app_module}.Repo.Migrations.{}",
+                string::pascal_case(&migration_name)
+            ),
+            &schemas,
+            &associations,
+        ),
+    });
+
+    Ok(files)
+}
+
+struct EctoSchema<'a> {
+    overview: &'a model::ModelOverview,
+
+    module_name: String,
+
+    /// Singular `snake_case` name, the name of the file and of the variable
+    /// of the `changeset`.
+    file_name: String,
+
+    table_name: String,
+}
+
+impl<'a> EctoSchema<'a> {
+    fn new(overview: &'a model::ModelOverview, app_module: &str) -> FoundationResult<Self> {
+        let file_name = inflection::singularize(&identifier(&overview.model.name)?);
+
+        Ok(Self {
+            overview,
+            module_name: format!("{app_module}.{}", string::pascal_case(&file_name)),
+            table_name: inflection::pluralize(&file_name),
+            file_name,
+        })
+    }
+}
+
+struct EctoAssociation<'a> {
+    association: &'a model::Association,
+
+    associated_schema: &'a EctoSchema<'a>,
+
+    /// Name of the association, plural for `has_many`.
+    name: String,
+
+    /// Field of the foreign key when it isn't the conventional one.
+    foreign_key: Option<String>,
+}
+
+impl<'a> EctoAssociation<'a> {
+    fn declaration(&self) -> String {
+        let macro_name = match self.association.kind {
+            model::AssociationKind::BelongsTo => "belongs_to",
+            model::AssociationKind::HasOne => "has_one",
+            model::AssociationKind::HasMany => "has_many",
+        };
+
+        let mut declaration = format!(
+            "{macro_name} :{}, {}",
+            self.name, self.associated_schema.module_name
+        );
+
+        if let Some(foreign_key) = &self.foreign_key {
+            declaration.push_str(&format!(", foreign_key: :{foreign_key}"));
+        }
+
+        declaration
+    }
+
+    fn is_belongs_to(&self) -> bool {
+        self.association.kind == model::AssociationKind::BelongsTo
+    }
+
+    fn foreign_key_field(&self) -> String {
+        format!("{}_id", self.name)
+    }
+}
+
+fn associations<'a>(
+    schemas: &'a [EctoSchema<'a>],
+    schema: &EctoSchema<'a>,
+) -> FoundationResult<Vec<EctoAssociation<'a>>> {
+    let mut associations = vec![];
+
+    for association in &schema.overview.associations {
+        let Some(associated_schema) = schemas
+            .iter()
+            .find(|schema| schema.overview.model.slug == association.model.slug)
+        else {
+            return Err(FoundationError::failed_precondition(format!(
+                "code can't be generated, `{}` is associated with `{}` which isn't part of the project",
+                schema.overview.model.name, association.model.name
+            )));
+        };
+
+        let name = identifier(&association.name)?;
+
+        let (name, foreign_key) = match association.kind {
+            model::AssociationKind::BelongsTo => (inflection::singularize(&name), None),
+            model::AssociationKind::HasOne => (
+                inflection::singularize(&name),
+                inverse_foreign_key(schema, associated_schema),
+            ),
+            model::AssociationKind::HasMany => (
+                inflection::pluralize(&name),
+                inverse_foreign_key(schema, associated_schema),
+            ),
+        };
+
+        associations.push(EctoAssociation {
+            association,
+            associated_schema,
+            name,
+            foreign_key,
+        });
+    }
+
+    Ok(associations)
+}
+
+/// Foreign key of the `has_one` or `has_many` association, when the associated
+/// schema belongs to the schema through a single association named after
+/// something else than the schema.
+fn inverse_foreign_key(schema: &EctoSchema, associated_schema: &EctoSchema) -> Option<String> {
+    let inverse_association = inverse_belongs_to(schema.overview, associated_schema.overview)?;
+
+    let reference = inflection::singularize(&string::snake_case(&inverse_association.name));
+
+    (reference != schema.file_name).then(|| format!("{reference}_id"))
+}
+
+fn schema_module(
+    schema: &EctoSchema,
+    associations: &[EctoAssociation],
+) -> FoundationResult<String> {
+    let model_overview = schema.overview;
+    let mut field_names = HashSet::from([migration::PRIMARY_KEY.to_string()]);
+    let mut fields = vec![];
+    let mut cast_fields = vec![];
+
+    for attribute in &model_overview.attributes {
+        let field_name = identifier(&attribute.name)?;
+        let (field_type, _column_type) = types(&attribute.r#type.slug);
+
+        fields.push((
+            vec![field_name.clone()],
+            attribute.description.as_deref(),
+            format!("field :{field_name}, {field_type}"),
+        ));
+        cast_fields.push(format!(":{field_name}"));
+    }
+
+    for association in associations {
+        let mut names = vec![association.name.clone()];
+
+        if association.is_belongs_to() {
+            names.push(association.foreign_key_field());
+            cast_fields.push(format!(":{}", association.foreign_key_field()));
+        }
+
+        fields.push((
+            names,
+            association.association.description.as_deref(),
+            association.declaration(),
+        ));
+    }
+
+    let mut code = format!("defmodule {} do\n", schema.module_name);
+
+    let description_lines = description_lines(model_overview.model.description.as_deref());
+
+    if !description_lines.is_empty() {
+        code.push_str("  @moduledoc \"\"\"\n");
+
+        for line in description_lines {
+            if line.is_empty() {
+                code.push('\n');
+            } else {
+                code.push_str(&format!("  {}\n", escape(line)));
+            }
+        }
+
+        code.push_str("  \"\"\"\n\n");
+    }
+
+    code.push_str("  use Ecto.Schema\n  import Ecto.Changeset\n\n");
+    code.push_str(&format!("  schema \"{}\" do\n", schema.table_name));
+
+    let mut field_declarations = vec![];
+
+    for (names, description, declaration) in fields {
+        for name in names {
+            if !field_names.insert(name.clone()) {
+                return Err(FoundationError::failed_precondition(format!(
+                    "code can't be generated, `{}` has more than one field named `{name}`",
+                    model_overview.model.name
+                )));
+            }
+        }
+
+        field_declarations.push(format!(
+            "{}    {declaration}\n",
+            hash_comment(description, "    ")
+        ));
+    }
+
+    code.push_str(&field_declarations.join("\n"));
+    code.push_str("  end\n\n");
+
+    let variable = &schema.file_name;
+    let belongs_to: Vec<&EctoAssociation> = associations
+        .iter()
+        .filter(|association| association.is_belongs_to())
+        .collect();
+
+    code.push_str("  @doc false\n");
+    code.push_str(&format!("  def changeset({variable}, attrs) do\n"));
+    code.push_str(&format!("    {variable}\n"));
+    code.push_str(&format!(
+        "    |> cast(attrs, [{}])\n",
+        cast_fields.join(", ")
+    ));
+
+    if !belongs_to.is_empty() {
+        code.push_str(&format!(
+            "    |> validate_required([{}])\n",
+            belongs_to
+                .iter()
+                .map(|association| format!(":{}", association.foreign_key_field()))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ));
+    }
+
+    for association in belongs_to {
+        code.push_str(&format!("    |> assoc_constraint(:{})\n", association.name));
+    }
+
+    code.push_str("  end\nend\n");
+
+    Ok(code)
+}
+
+fn migration_module(
+    module_name: &str,
+    schemas: &[EctoSchema],
+    associations: &[Vec<EctoAssociation>],
+) -> String {
+    let tables: Vec<Table> = schemas
+        .iter()
+        .zip(associations)
+        .map(|(schema, associations)| Table {
+            name: schema.table_name.clone(),
+            columns: vec![],
+            foreign_keys: associations
+                .iter()
+                .filter(|association| association.is_belongs_to())
+                .map(|association| ForeignKey {
+                    column: association.foreign_key_field(),
+                    referenced_table: association.associated_schema.table_name.clone(),
+                })
+                .collect(),
+        })
+        .collect();
+    let tables = migration::dependency_order(tables);
+
+    let mut statements = vec![];
+    let mut deferred_statements = vec![];
+
+    for table in &tables {
+        let schema = schemas
+            .iter()
+            .find(|schema| schema.table_name == table.name)
+            .expect("every table is created from a schema");
+
+        let table_options = schema
+            .overview
+            .model
+            .description
+            .as_deref()
+            .map(|description| format!(", comment: {}", string_literal(description.trim())))
+            .unwrap_or_default();
+
+        let mut statement = format!("    create table(:{}{table_options}) do\n", table.name);
+
+        for attribute in &schema.overview.attributes {
+            let (_field_type, column_type) = types(&attribute.r#type.slug);
+            let column_options = attribute
+                .description
+                .as_deref()
+                .map(|description| format!(", comment: {}", string_literal(description.trim())))
+                .unwrap_or_default();
+
+            statement.push_str(&format!(
+                "      add :{}, {column_type}{column_options}\n",
+                string::snake_case(&attribute.name)
+            ));
+        }
+
+        let mut indexes = vec![];
+
+        for foreign_key in &table.foreign_keys {
+            let column = &foreign_key.column;
+
+            if migration::is_forward_reference(&tables, table, foreign_key) {
+                statement.push_str(&format!("      add :{column}, :bigint, null: false\n"));
+                deferred_statements.push(format!(
+                    "    alter table(:{}) do\n      modify :{column}, references(:{}), from: :bigint\n    end\n",
+                    table.name, foreign_key.referenced_table
+                ));
+            } else {
+                statement.push_str(&format!(
+                    "      add :{column}, references(:{}), null: false\n",
+                    foreign_key.referenced_table
+                ));
+            }
+
+            indexes.push(format!("    create index(:{}, [:{column}])\n", table.name));
+        }
+
+        statement.push_str("    end\n");
+
+        if !indexes.is_empty() {
+            statement.push('\n');
+            statement.push_str(&indexes.concat());
+        }
+
+        statements.push(statement);
+    }
+
+    statements.extend(deferred_statements);
+
+    format!(
+        "defmodule {module_name} do\n  use Ecto.Migration\n\n  def change do\n{}  end\nend\n",
+        statements.join("\n")
+    )
+}
+
+/// Ecto type of the field and type of the column of the attribute type.
+fn types(attribute_type_slug: &str) -> (&'static str, &'static str) {
+    match AttributeKind::from_slug(attribute_type_slug) {
+        AttributeKind::SmallInteger => (":integer", ":smallint"),
+        AttributeKind::Integer => (":integer", ":integer"),
+        AttributeKind::BigInteger => (":integer", ":bigint"),
+        AttributeKind::Float | AttributeKind::Double => (":float", ":float"),
+        AttributeKind::Decimal => (":decimal", ":decimal"),
+        AttributeKind::Boolean => (":boolean", ":boolean"),
+        AttributeKind::Date => (":date", ":date"),
+        AttributeKind::Time => (":time", ":time"),
+        AttributeKind::DateTime => (":utc_datetime", ":utc_datetime"),
+        AttributeKind::Uuid => ("Ecto.UUID", ":uuid"),
+        AttributeKind::Json => (":map", ":map"),
+        AttributeKind::Binary => (":binary", ":binary"),
+        AttributeKind::Text if attribute_type_slug == "text" => (":string", ":text"),
+        AttributeKind::Text => (":string", ":string"),
+    }
+}
+
+/// `snake_case` identifier, which can also be turned into a module name.
+fn identifier(name: &str) -> FoundationResult<String> {
+    let identifier = string::snake_case(name);
+
+    if !identifier
+        .chars()
+        .next()
+        .is_some_and(|char| char.is_ascii_lowercase())
+        || !identifier
+            .chars()
+            .all(|char| char.is_ascii_lowercase() || char.is_ascii_digit() || char == '_')
+    {
+        return Err(FoundationError::failed_precondition(format!(
+            "code can't be generated, `{name}` can't be used as an Elixir identifier"
+        )));
+    }
+
+    Ok(identifier)
+}
@@ -0,0 +1,177 @@
+use super::associated_model_overview;
+use crate::{attribute_type::AttributeKind, model, util, FoundationError, FoundationResult};
+use serde_json::{json, Value};
+
+/// Dialect of the generated schemas.
+const DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Generates a schema per model, referring to the schemas of the associated
+/// models by their file name, and the project schema bundling the model
+/// schemas in its `$defs`.
+///
+/// Properties are `snake_case`. Attributes are required, associations aren't
+/// as they aren't always loaded.
+pub fn json_schemas(
+    model_overviews: &[model::ModelOverview],
+    project_slug: &str,
+) -> FoundationResult<(Vec<model::GeneratedFile>, String)> {
+    let mut model_schemas = vec![];
+    let mut defs = json!({});
+
+    for model_overview in model_overviews {
+        let slug = &model_overview.model.slug;
+
+        let mut model_schema = object_schema(model_overviews, model_overview, file_name)?;
+        model_schema["$schema"] = json!(DRAFT);
+        model_schema["$id"] = json!(file_name(slug));
+
+        model_schemas.push(model::GeneratedFile {
+            path: file_name(slug),
+            content: pretty(&model_schema)?,
+        });
+
+        defs[slug] = object_schema(model_overviews, model_overview, |slug| {
+            format!("#/$defs/{slug}")
+        })?;
+    }
+
+    let project_schema = json!({
+        "$schema": DRAFT,
+        "$id": file_name(project_slug),
+        "$defs": defs,
+    });
+
+    Ok((model_schemas, pretty(&project_schema)?))
+}
+
+fn object_schema(
+    model_overviews: &[model::ModelOverview],
+    model_overview: &model::ModelOverview,
+    reference: impl Fn(&str) -> String,
+) -> FoundationResult<Value> {
+    let model::ModelOverview {
+        model,
+        attributes,
+        associations,
+    } = model_overview;
+
+    let mut properties = json!({});
+    let mut required = vec![];
+
+    for attribute in attributes {
+        let name = property_name(&attribute.name)?;
+
+        if properties.get(&name).is_some() {
+            return Err(duplicate_property_error(model, &name));
+        }
+
+        let mut schema = attribute_schema(&AttributeKind::from_slug(&attribute.r#type.slug));
+        insert_description(&mut schema, attribute.description.as_deref());
+
+        properties[&name] = schema;
+
+        required.push(name);
+    }
+
+    for association in associations {
+        let Some(associated_model_overview) =
+            associated_model_overview(model_overviews, association)
+        else {
+            return Err(FoundationError::failed_precondition(format!(
+                "code can't be generated, `{}` is associated with `{}` which isn't part of the project",
+                model.name, association.model.name
+            )));
+        };
+
+        let name = property_name(&association.name)?;
+
+        if properties.get(&name).is_some() {
+            return Err(duplicate_property_error(model, &name));
+        }
+
+        let associated_schema = json!({ "$ref": reference(&associated_model_overview.model.slug) });
+
+        let mut schema = match association.kind {
+            model::AssociationKind::HasMany => json!({
+                "type": "array",
+                "items": associated_schema,
+            }),
+            model::AssociationKind::BelongsTo | model::AssociationKind::HasOne => associated_schema,
+        };
+        insert_description(&mut schema, association.description.as_deref());
+
+        properties[&name] = schema;
+    }
+
+    let mut schema = json!({
+        "title": model.name,
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    });
+    insert_description(&mut schema, model.description.as_deref());
+
+    Ok(schema)
+}
+
+fn attribute_schema(kind: &AttributeKind) -> Value {
+    match kind {
+        AttributeKind::SmallInteger => json!({
+            "type": "integer",
+            "minimum": i16::MIN,
+            "maximum": i16::MAX,
+        }),
+        AttributeKind::Integer => json!({
+            "type": "integer",
+            "minimum": i32::MIN,
+            "maximum": i32::MAX,
+        }),
+        AttributeKind::BigInteger => json!({ "type": "integer" }),
+        AttributeKind::Float | AttributeKind::Double => json!({ "type": "number" }),
+        // Decimals are strings to keep their precision.
+        AttributeKind::Decimal => json!({ "type": "string" }),
+        AttributeKind::Boolean => json!({ "type": "boolean" }),
+        AttributeKind::Date => json!({ "type": "string", "format": "date" }),
+        AttributeKind::Time => json!({ "type": "string", "format": "time" }),
+        AttributeKind::DateTime => json!({ "type": "string", "format": "date-time" }),
+        AttributeKind::Uuid => json!({ "type": "string", "format": "uuid" }),
+        AttributeKind::Json => json!({}),
+        AttributeKind::Binary => json!({ "type": "string", "contentEncoding": "base64" }),
+        AttributeKind::Text => json!({ "type": "string" }),
+    }
+}
+
+fn insert_description(schema: &mut Value, description: Option<&str>) {
+    if let Some(description) = description.map(str::trim) {
+        schema["description"] = json!(description);
+    }
+}
+
+fn file_name(slug: &str) -> String {
+    format!("{slug}.schema.json")
+}
+
+fn property_name(name: &str) -> FoundationResult<String> {
+    let property_name = util::string::snake_case(name);
+
+    if property_name.is_empty() {
+        return Err(FoundationError::failed_precondition(format!(
+            "code can't be generated, `{name}` can't be used as a property name"
+        )));
+    }
+
+    Ok(property_name)
+}
+
+fn duplicate_property_error(model: &model::Model, name: &str) -> FoundationError {
+    FoundationError::failed_precondition(format!(
+        "code can't be generated, `{}` has more than one property named `{name}`",
+        model.name
+    ))
+}
+
+fn pretty(schema: &Value) -> FoundationResult<String> {
+    serde_json::to_string_pretty(schema)
+        .map(|json| format!("{json}\n"))
+        .map_err(|err| FoundationError::internal(err.to_string()))
+}
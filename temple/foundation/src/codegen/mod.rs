@@ -0,0 +1,113 @@
+//! Source code generated from the project [`model::Model`]s.
+
+mod dbml;
+mod elixir;
+mod json_schema;
+mod prisma;
+mod proto;
+mod ruby;
+mod rust;
+mod template;
+mod typescript;
+
+pub use dbml::dbml;
+pub use elixir::ecto_files;
+pub use json_schema::json_schemas;
+pub use prisma::{prisma_schema, IGNORE_TAG, TYPE_TAG};
+pub use proto::{proto_file, FieldNumbers};
+pub use ruby::rails_files;
+pub use rust::rust_modules;
+pub use template::{compile_templates, generator_files};
+pub use typescript::typescript_interfaces;
+
+use crate::model;
+
+/// Lines of the trimmed description, to be used in comments.
+fn description_lines(description: Option<&str>) -> Vec<&str> {
+    description
+        .map(|description| description.trim().lines().map(str::trim_end).collect())
+        .unwrap_or_default()
+}
+
+/// Model of the association, among the generated models.
+fn associated_model_overview<'a>(
+    model_overviews: &'a [model::ModelOverview],
+    association: &model::Association,
+) -> Option<&'a model::ModelOverview> {
+    model_overviews
+        .iter()
+        .find(|model_overview| model_overview.model.slug == association.model.slug)
+}
+
+/// The only `BelongsTo` association of the associated model to the model, the
+/// inverse side of a `HasOne` or `HasMany` association of the model.
+fn inverse_belongs_to<'a>(
+    model_overview: &model::ModelOverview,
+    associated_model_overview: &'a model::ModelOverview,
+) -> Option<&'a model::Association> {
+    let inverse_associations: Vec<&model::Association> = associated_model_overview
+        .associations
+        .iter()
+        .filter(|association| {
+            association.kind == model::AssociationKind::BelongsTo
+                && association.model.slug == model_overview.model.slug
+        })
+        .collect();
+
+    match inverse_associations.as_slice() {
+        [inverse_association] => Some(inverse_association),
+        _ => None,
+    }
+}
+
+/// Comment of the description in the languages commenting with `#`.
+fn hash_comment(description: Option<&str>, indentation: &str) -> String {
+    description_lines(description)
+        .into_iter()
+        .map(|line| {
+            if line.is_empty() {
+                format!("{indentation}#\n")
+            } else {
+                format!("{indentation}# {line}\n")
+            }
+        })
+        .collect()
+}
+
+/// Double quoted string literal of the languages interpolating `#{}`, like
+/// Ruby and Elixir.
+fn string_literal(value: &str) -> String {
+    format!("\"{}\"", escape(value))
+}
+
+/// Value escaped to be put in a double quoted string literal.
+fn escape(value: &str) -> String {
+    let mut escaped = String::new();
+
+    for char in value.chars() {
+        match char {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '#' => escaped.push_str("\\#"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            char => escaped.push(char),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_escapes_string_literals() {
+        assert_eq!(
+            string_literal("Say \"hi\" to #{name}\\\nBye"),
+            r#""Say \"hi\" to \#{name}\\\nBye""#
+        );
+    }
+}
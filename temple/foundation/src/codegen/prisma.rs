@@ -0,0 +1,467 @@
+use super::{associated_model_overview, description_lines, inverse_belongs_to};
+use crate::{attribute_type::AttributeKind, model, util, FoundationError, FoundationResult};
+use std::collections::HashSet;
+
+/// Doc comment tag with the name of the attribute type, for the attributes
+/// whose Prisma type doesn't tell it.
+pub const TYPE_TAG: &str = "@metropolis.type";
+
+/// Doc comment tag of the relation fields which are only there because Prisma
+/// requires both sides of a relation.
+pub const IGNORE_TAG: &str = "@metropolis.ignore";
+
+const DATASOURCE: &str = r#"datasource db {
+  provider = "postgresql"
+  url      = env("DATABASE_URL")
+}
+
+generator client {
+  provider = "prisma-client-js"
+}
+"#;
+
+/// Relation between the model with the foreign key and the associated model.
+struct Relation<'a> {
+    model: usize,
+
+    /// `None` when the model doesn't have a `BelongsTo` association.
+    belongs_to: Option<&'a model::Association>,
+
+    associated_model: usize,
+
+    /// `HasOne` or `HasMany` association of the associated model, `None` when
+    /// it doesn't have one.
+    inverse: Option<&'a model::Association>,
+
+    /// Set when there is more than one relation between the two models.
+    name: Option<String>,
+}
+
+struct Field {
+    name: String,
+    r#type: String,
+    attributes: Vec<String>,
+    doc_lines: Vec<String>,
+}
+
+/// Generates a Prisma schema with a `model` per model, for PostgreSQL.
+///
+/// Every model gets an `id` primary key, attributes are scalar fields typed
+/// after the well known [`AttributeKind`] of their attribute type, and
+/// associations are relation fields. A `BelongsTo` association adds the
+/// foreign key field of its relation. Descriptions become `///` comments.
+///
+/// Prisma needs both sides of a relation, the missing side is added with the
+/// [`IGNORE_TAG`] so that the import leaves it out.
+pub fn prisma_schema(model_overviews: &[model::ModelOverview]) -> FoundationResult<String> {
+    let mut model_names = HashSet::new();
+
+    for model_overview in model_overviews {
+        let name = model_name(&model_overview.model.name)?;
+
+        if !model_names.insert(name.clone()) {
+            return Err(FoundationError::failed_precondition(format!(
+                "code can't be generated, more than one model is named `{name}`"
+            )));
+        }
+    }
+
+    let relations = relations(model_overviews)?;
+
+    let mut schema = DATASOURCE.to_string();
+
+    for index in 0..model_overviews.len() {
+        schema.push('\n');
+        schema.push_str(&model(model_overviews, &relations, index)?);
+    }
+
+    Ok(schema)
+}
+
+fn relations(model_overviews: &[model::ModelOverview]) -> FoundationResult<Vec<Relation>> {
+    let mut relations: Vec<Relation> = vec![];
+
+    let position = |model_overview: &model::ModelOverview| {
+        model_overviews
+            .iter()
+            .position(|other| other.model.slug == model_overview.model.slug)
+            .unwrap_or_default()
+    };
+
+    for (index, model_overview) in model_overviews.iter().enumerate() {
+        for association in &model_overview.associations {
+            let Some(associated_model_overview) =
+                associated_model_overview(model_overviews, association)
+            else {
+                return Err(FoundationError::failed_precondition(format!(
+                    "code can't be generated, `{}` is associated with `{}` which isn't part of the project",
+                    model_overview.model.name, association.model.name
+                )));
+            };
+
+            if association.kind == model::AssociationKind::BelongsTo {
+                relations.push(Relation {
+                    model: index,
+                    belongs_to: Some(association),
+                    associated_model: position(associated_model_overview),
+                    inverse: None,
+                    name: None,
+                });
+            }
+        }
+    }
+
+    for (index, model_overview) in model_overviews.iter().enumerate() {
+        for association in &model_overview.associations {
+            if association.kind == model::AssociationKind::BelongsTo {
+                continue;
+            }
+
+            let Some(associated_model_overview) =
+                associated_model_overview(model_overviews, association)
+            else {
+                continue;
+            };
+
+            let associated_model = position(associated_model_overview);
+            let belongs_to = inverse_belongs_to(model_overview, associated_model_overview);
+
+            let relation = relations.iter_mut().find(|relation| {
+                relation.model == associated_model
+                    && relation.associated_model == index
+                    && relation.inverse.is_none()
+                    && relation.belongs_to.is_some_and(|relation_belongs_to| {
+                        belongs_to
+                            .is_some_and(|belongs_to| std::ptr::eq(relation_belongs_to, belongs_to))
+                    })
+            });
+
+            match relation {
+                Some(relation) => relation.inverse = Some(association),
+                None => relations.push(Relation {
+                    model: associated_model,
+                    belongs_to: None,
+                    associated_model: index,
+                    inverse: Some(association),
+                    name: None,
+                }),
+            }
+        }
+    }
+
+    // Prisma tells apart the relations between the same models by their name.
+    for index in 0..relations.len() {
+        let relation = &relations[index];
+        let models = [relation.model, relation.associated_model];
+
+        let is_ambiguous = relation.model == relation.associated_model
+            || relations
+                .iter()
+                .filter(|other| {
+                    models.contains(&other.model) && models.contains(&other.associated_model)
+                })
+                .count()
+                > 1;
+
+        if is_ambiguous {
+            let model_name = model_name(&model_overviews[relation.model].model.name)?;
+            let field_name =
+                util::string::pascal_case(&belongs_to_field_name(model_overviews, relation));
+
+            relations[index].name = Some(format!("{model_name}{field_name}"));
+        }
+    }
+
+    Ok(relations)
+}
+
+fn model(
+    model_overviews: &[model::ModelOverview],
+    relations: &[Relation],
+    index: usize,
+) -> FoundationResult<String> {
+    let model_overview = &model_overviews[index];
+
+    let mut fields = vec![Field {
+        name: "id".to_string(),
+        r#type: "Int".to_string(),
+        attributes: vec!["@id".to_string(), "@default(autoincrement())".to_string()],
+        doc_lines: vec![],
+    }];
+
+    for attribute in &model_overview.attributes {
+        let (r#type, native_type, attribute_type_name) =
+            scalar(AttributeKind::from_slug(&attribute.r#type.slug));
+
+        let mut doc_lines = doc_lines(attribute.description.as_deref());
+
+        if attribute.r#type.name != attribute_type_name {
+            doc_lines.push(format!(
+                "{TYPE_TAG}(\"{}\")",
+                attribute
+                    .r#type
+                    .name
+                    .replace('\\', "\\\\")
+                    .replace('"', "\\\"")
+            ));
+        }
+
+        fields.push(Field {
+            name: field_name(&attribute.name)?,
+            r#type: r#type.to_string(),
+            attributes: native_type.map(str::to_string).into_iter().collect(),
+            doc_lines,
+        });
+    }
+
+    let is_belongs_to = |relation: &&Relation, association| {
+        relation.model == index
+            && relation
+                .belongs_to
+                .is_some_and(|belongs_to| std::ptr::eq(belongs_to, association))
+    };
+    let is_inverse = |relation: &&Relation, association| {
+        relation.associated_model == index
+            && relation
+                .inverse
+                .is_some_and(|inverse| std::ptr::eq(inverse, association))
+    };
+
+    for association in &model_overview.associations {
+        if let Some(relation) = relations
+            .iter()
+            .find(|relation| is_belongs_to(relation, association))
+        {
+            fields.append(&mut belongs_to_fields(model_overviews, relation)?);
+        } else if let Some(relation) = relations
+            .iter()
+            .find(|relation| is_inverse(relation, association))
+        {
+            fields.push(inverse_field(model_overviews, relation)?);
+        }
+    }
+
+    for relation in relations {
+        if relation.model == index && relation.belongs_to.is_none() {
+            fields.append(&mut belongs_to_fields(model_overviews, relation)?);
+        }
+
+        if relation.associated_model == index && relation.inverse.is_none() {
+            fields.push(inverse_field(model_overviews, relation)?);
+        }
+    }
+
+    let mut field_names = HashSet::new();
+
+    for field in &fields {
+        if !field_names.insert(&field.name) {
+            return Err(FoundationError::failed_precondition(format!(
+                "code can't be generated, `{}` has more than one field named `{}`",
+                model_overview.model.name, field.name
+            )));
+        }
+    }
+
+    let name_width = fields.iter().map(|field| field.name.len()).max();
+    let type_width = fields.iter().map(|field| field.r#type.len()).max();
+
+    let mut model = String::new();
+
+    for line in doc_lines(model_overview.model.description.as_deref()) {
+        model.push_str(&format!("{}\n", doc_comment(&line)));
+    }
+
+    model.push_str(&format!(
+        "model {} {{\n",
+        model_name(&model_overview.model.name)?
+    ));
+
+    for field in fields {
+        for line in &field.doc_lines {
+            model.push_str(&format!("  {}\n", doc_comment(line)));
+        }
+
+        let line = format!(
+            "  {:name_width$} {:type_width$} {}",
+            field.name,
+            field.r#type,
+            field.attributes.join(" "),
+            name_width = name_width.unwrap_or_default(),
+            type_width = type_width.unwrap_or_default(),
+        );
+
+        model.push_str(line.trim_end());
+        model.push('\n');
+    }
+
+    model.push_str("}\n");
+
+    Ok(model)
+}
+
+/// Relation field of the model with the foreign key, and the foreign key
+/// field.
+fn belongs_to_fields(
+    model_overviews: &[model::ModelOverview],
+    relation: &Relation,
+) -> FoundationResult<Vec<Field>> {
+    let name = belongs_to_field_name(model_overviews, relation);
+    let foreign_key = format!("{name}Id");
+
+    let mut arguments = vec![];
+
+    if let Some(relation_name) = &relation.name {
+        arguments.push(format!("\"{relation_name}\""));
+    }
+
+    arguments.push(format!("fields: [{foreign_key}]"));
+    arguments.push("references: [id]".to_string());
+
+    let is_one_to_one = relation
+        .inverse
+        .is_some_and(|inverse| inverse.kind == model::AssociationKind::HasOne);
+
+    let doc_lines = match relation.belongs_to {
+        Some(belongs_to) => doc_lines(belongs_to.description.as_deref()),
+        None => vec![IGNORE_TAG.to_string()],
+    };
+
+    Ok(vec![
+        Field {
+            name,
+            r#type: model_name(&model_overviews[relation.associated_model].model.name)?,
+            attributes: vec![format!("@relation({})", arguments.join(", "))],
+            doc_lines,
+        },
+        Field {
+            name: foreign_key,
+            r#type: "Int".to_string(),
+            attributes: if is_one_to_one {
+                vec!["@unique".to_string()]
+            } else {
+                vec![]
+            },
+            doc_lines: vec![],
+        },
+    ])
+}
+
+/// Relation field of the associated model, the other side of the relation.
+fn inverse_field(
+    model_overviews: &[model::ModelOverview],
+    relation: &Relation,
+) -> FoundationResult<Field> {
+    let model_name = model_name(&model_overviews[relation.model].model.name)?;
+
+    let (name, r#type, doc_lines) = match relation.inverse {
+        Some(inverse) => {
+            let r#type = match inverse.kind {
+                model::AssociationKind::HasOne => format!("{model_name}?"),
+                _ => format!("{model_name}[]"),
+            };
+
+            (
+                field_name(&inverse.name)?,
+                r#type,
+                doc_lines(inverse.description.as_deref()),
+            )
+        }
+        None => {
+            let name = util::inflection::pluralize(&util::string::snake_case(
+                &model_overviews[relation.model].model.name,
+            ));
+
+            (
+                field_name(&name)?,
+                format!("{model_name}[]"),
+                vec![IGNORE_TAG.to_string()],
+            )
+        }
+    };
+
+    Ok(Field {
+        name,
+        r#type,
+        attributes: relation
+            .name
+            .iter()
+            .map(|relation_name| format!("@relation(\"{relation_name}\")"))
+            .collect(),
+        doc_lines,
+    })
+}
+
+fn belongs_to_field_name(model_overviews: &[model::ModelOverview], relation: &Relation) -> String {
+    let name = match relation.belongs_to {
+        Some(belongs_to) => &belongs_to.name,
+        None => &model_overviews[relation.associated_model].model.name,
+    };
+
+    camel_case(name)
+}
+
+/// Prisma scalar type, native type attribute and name of the attribute type
+/// of the attribute kind.
+fn scalar(kind: AttributeKind) -> (&'static str, Option<&'static str>, &'static str) {
+    match kind {
+        AttributeKind::SmallInteger => ("Int", Some("@db.SmallInt"), "Small Integer"),
+        AttributeKind::Integer => ("Int", None, "Integer"),
+        AttributeKind::BigInteger => ("BigInt", None, "Big Integer"),
+        AttributeKind::Float => ("Float", Some("@db.Real"), "Float"),
+        AttributeKind::Double => ("Float", None, "Double"),
+        AttributeKind::Decimal => ("Decimal", None, "Decimal"),
+        AttributeKind::Boolean => ("Boolean", None, "Boolean"),
+        AttributeKind::Date => ("DateTime", Some("@db.Date"), "Date"),
+        AttributeKind::Time => ("DateTime", Some("@db.Time"), "Time"),
+        AttributeKind::DateTime => ("DateTime", None, "Date Time"),
+        AttributeKind::Uuid => ("String", Some("@db.Uuid"), "Uuid"),
+        AttributeKind::Json => ("Json", None, "Json"),
+        AttributeKind::Binary => ("Bytes", None, "Binary"),
+        AttributeKind::Text => ("String", None, "Text"),
+    }
+}
+
+fn doc_lines(description: Option<&str>) -> Vec<String> {
+    description_lines(description)
+        .into_iter()
+        .map(str::to_string)
+        .collect()
+}
+
+fn doc_comment(line: &str) -> String {
+    if line.is_empty() {
+        "///".to_string()
+    } else {
+        format!("/// {line}")
+    }
+}
+
+fn model_name(name: &str) -> FoundationResult<String> {
+    let model_name = util::string::pascal_case(name);
+
+    if !model_name
+        .chars()
+        .next()
+        .is_some_and(|char| char.is_ascii_alphabetic())
+    {
+        return Err(FoundationError::failed_precondition(format!(
+            "code can't be generated, `{name}` can't be used as a Prisma name"
+        )));
+    }
+
+    Ok(model_name)
+}
+
+fn field_name(name: &str) -> FoundationResult<String> {
+    model_name(name).map(|_| camel_case(name))
+}
+
+fn camel_case(name: &str) -> String {
+    let pascal_case = util::string::pascal_case(name);
+    let mut chars = pascal_case.chars();
+
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
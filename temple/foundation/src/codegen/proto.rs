@@ -0,0 +1,367 @@
+use super::{associated_model_overview, description_lines};
+use crate::{attribute_type::AttributeKind, model, util, FoundationError, FoundationResult};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// Field numbers reserved for the Protocol Buffers implementation.
+const IMPLEMENTATION_RESERVED_NUMBERS: std::ops::RangeInclusive<i32> = 19_000..=19_999;
+
+/// Largest field number a message can have.
+const MAX_NUMBER: i32 = 536_870_911;
+
+/// Field numbers of the messages, by the slug of their model.
+pub type FieldNumbers = HashMap<String, Vec<model::FieldNumber>>;
+
+pub struct Proto {
+    /// Content of the `.proto` file.
+    pub content: String,
+
+    /// Numbers given to the fields which didn't have one yet, by the slug of
+    /// their model.
+    pub assigned_field_numbers: Vec<(String, model::FieldNumber)>,
+}
+
+/// Generates a `proto3` file with a message per model.
+///
+/// Fields keep the numbers they were given before, new fields are numbered
+/// after the highest number ever given in the message. Numbers and names of
+/// the fields which are gone are `reserved` so they don't get reused by hand.
+pub fn proto_file(
+    model_overviews: &[model::ModelOverview],
+    project_slug: &str,
+    field_numbers: &FieldNumbers,
+) -> FoundationResult<Proto> {
+    let package = identifier(project_slug)?;
+    let mut message_names: HashMap<String, &str> = HashMap::new();
+    let mut imports = BTreeSet::new();
+    let mut messages = vec![];
+    let mut assigned_field_numbers = vec![];
+
+    for model_overview in model_overviews {
+        let model_name = &model_overview.model.name;
+        let name = message_name(model_name)?;
+
+        if let Some(other_model_name) = message_names.insert(name.clone(), model_name) {
+            return Err(FoundationError::failed_precondition(format!(
+                "code can't be generated, `{model_name}` and `{other_model_name}` share the same message name `{name}`"
+            )));
+        }
+
+        let model_field_numbers = field_numbers
+            .get(&model_overview.model.slug)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        let (message, assigned) = message(
+            model_overviews,
+            model_overview,
+            model_field_numbers,
+            &mut imports,
+        )?;
+
+        messages.push(message);
+        assigned_field_numbers.extend(
+            assigned
+                .into_iter()
+                .map(|field_number| (model_overview.model.slug.clone(), field_number)),
+        );
+    }
+
+    let mut content = "syntax = \"proto3\";\n\n".to_string();
+
+    if !imports.is_empty() {
+        for import in imports {
+            content.push_str(&format!("import \"{import}\";\n"));
+        }
+
+        content.push('\n');
+    }
+
+    content.push_str(&format!("package {package}.v1;\n"));
+
+    for message in messages {
+        content.push('\n');
+        content.push_str(&message);
+    }
+
+    Ok(Proto {
+        content,
+        assigned_field_numbers,
+    })
+}
+
+fn message(
+    model_overviews: &[model::ModelOverview],
+    model_overview: &model::ModelOverview,
+    field_numbers: &[model::FieldNumber],
+    imports: &mut BTreeSet<&'static str>,
+) -> FoundationResult<(String, Vec<model::FieldNumber>)> {
+    let model::ModelOverview {
+        model,
+        attributes,
+        associations,
+    } = model_overview;
+
+    let mut fields = vec![];
+
+    for attribute in attributes {
+        let (r#type, import) = field_type(&AttributeKind::from_slug(&attribute.r#type.slug));
+
+        if let Some(import) = import {
+            imports.insert(import);
+        }
+
+        fields.push((
+            identifier(&attribute.name)?,
+            attribute.description.as_deref(),
+            r#type.to_string(),
+        ));
+    }
+
+    for association in associations {
+        let Some(associated_model_overview) =
+            associated_model_overview(model_overviews, association)
+        else {
+            return Err(FoundationError::failed_precondition(format!(
+                "code can't be generated, `{}` is associated with `{}` which isn't part of the project",
+                model.name, association.model.name
+            )));
+        };
+
+        let associated_message_name = message_name(&associated_model_overview.model.name)?;
+
+        let r#type = match association.kind {
+            model::AssociationKind::HasMany => format!("repeated {associated_message_name}"),
+            model::AssociationKind::BelongsTo | model::AssociationKind::HasOne => {
+                associated_message_name
+            }
+        };
+
+        fields.push((
+            identifier(&association.name)?,
+            association.description.as_deref(),
+            r#type,
+        ));
+    }
+
+    let mut field_names = HashSet::new();
+
+    for (name, _description, _type) in &fields {
+        if !field_names.insert(name.clone()) {
+            return Err(FoundationError::failed_precondition(format!(
+                "code can't be generated, `{}` has more than one field named `{name}`",
+                model.name
+            )));
+        }
+    }
+
+    let numbers: HashMap<&str, i32> = field_numbers
+        .iter()
+        .map(|field_number| (field_number.name.as_str(), field_number.number))
+        .collect();
+    let mut next_number = numbers.values().copied().max().unwrap_or_default() + 1;
+    let mut assigned = vec![];
+    let mut numbered_fields = vec![];
+
+    for (name, description, r#type) in fields {
+        let number = match numbers.get(name.as_str()) {
+            Some(number) => *number,
+            None => {
+                if IMPLEMENTATION_RESERVED_NUMBERS.contains(&next_number) {
+                    next_number = IMPLEMENTATION_RESERVED_NUMBERS.end() + 1;
+                }
+
+                if next_number > MAX_NUMBER {
+                    return Err(FoundationError::failed_precondition(format!(
+                        "code can't be generated, `{}` has no field numbers left for `{name}`",
+                        model.name
+                    )));
+                }
+
+                let number = next_number;
+                next_number += 1;
+
+                assigned.push(model::FieldNumber {
+                    name: name.clone(),
+                    number,
+                });
+
+                number
+            }
+        };
+
+        numbered_fields.push((number, name, description, r#type));
+    }
+
+    numbered_fields.sort_by_key(|(number, ..)| *number);
+
+    let mut reserved_fields: Vec<&model::FieldNumber> = field_numbers
+        .iter()
+        .filter(|field_number| !field_names.contains(&field_number.name))
+        .collect();
+    reserved_fields.sort_by_key(|field_number| field_number.number);
+
+    let mut declarations = vec![];
+
+    if !reserved_fields.is_empty() {
+        declarations.push(format!(
+            "    reserved {};\n    reserved {};\n",
+            reserved_fields
+                .iter()
+                .map(|field_number| field_number.number.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
+            reserved_fields
+                .iter()
+                .map(|field_number| format!("\"{}\"", field_number.name))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ));
+    }
+
+    for (number, name, description, r#type) in numbered_fields {
+        declarations.push(format!(
+            "{}    {type} {name} = {number};\n",
+            comment(description, "    ")
+        ));
+    }
+
+    let name = message_name(&model.name)?;
+    let comment = comment(model.description.as_deref(), "");
+
+    let message = if declarations.is_empty() {
+        format!("{comment}message {name} {{}}\n")
+    } else {
+        format!(
+            "{comment}message {name} {{\n{}}}\n",
+            declarations.join("\n")
+        )
+    };
+
+    Ok((message, assigned))
+}
+
+/// Type of the field and the file to import for it.
+fn field_type(kind: &AttributeKind) -> (&'static str, Option<&'static str>) {
+    match kind {
+        AttributeKind::SmallInteger | AttributeKind::Integer => ("int32", None),
+        AttributeKind::BigInteger => ("int64", None),
+        AttributeKind::Float => ("float", None),
+        AttributeKind::Double => ("double", None),
+        AttributeKind::Boolean => ("bool", None),
+        AttributeKind::DateTime => (
+            "google.protobuf.Timestamp",
+            Some("google/protobuf/timestamp.proto"),
+        ),
+        AttributeKind::Json => (
+            "google.protobuf.Struct",
+            Some("google/protobuf/struct.proto"),
+        ),
+        AttributeKind::Binary => ("bytes", None),
+        // Decimals keep their precision, dates and times have no well-known
+        // type and are ISO 8601 strings.
+        AttributeKind::Decimal
+        | AttributeKind::Date
+        | AttributeKind::Time
+        | AttributeKind::Uuid
+        | AttributeKind::Text => ("string", None),
+    }
+}
+
+fn comment(description: Option<&str>, indentation: &str) -> String {
+    description_lines(description)
+        .into_iter()
+        .map(|line| {
+            if line.is_empty() {
+                format!("{indentation}//\n")
+            } else {
+                format!("{indentation}// {line}\n")
+            }
+        })
+        .collect()
+}
+
+fn message_name(name: &str) -> FoundationResult<String> {
+    let message_name = util::string::pascal_case(name);
+
+    if !is_identifier(&message_name) {
+        return Err(identifier_error(name));
+    }
+
+    Ok(message_name)
+}
+
+/// `snake_case` identifier of the fields and the package.
+fn identifier(name: &str) -> FoundationResult<String> {
+    let identifier = util::string::snake_case(name);
+
+    if !is_identifier(&identifier) {
+        return Err(identifier_error(name));
+    }
+
+    Ok(identifier)
+}
+
+fn is_identifier(value: &str) -> bool {
+    value
+        .chars()
+        .next()
+        .is_some_and(|char| char.is_ascii_alphabetic())
+        && value
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || char == '_')
+}
+
+fn identifier_error(name: &str) -> FoundationError {
+    FoundationError::failed_precondition(format!(
+        "code can't be generated, `{name}` can't be used as a Protobuf identifier"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attribute_type::AttributeType;
+
+    #[test]
+    fn it_skips_implementation_reserved_numbers() -> FoundationResult<()> {
+        let model_overview = model::ModelOverview {
+            model: model::Model {
+                description: None,
+                name: "Book".to_string(),
+                slug: "book".to_string(),
+            },
+            attributes: vec![model::Attribute {
+                description: None,
+                r#type: AttributeType {
+                    description: None,
+                    name: "String".to_string(),
+                    slug: "string".to_string(),
+                },
+                name: "Title".to_string(),
+            }],
+            associations: vec![],
+        };
+        let field_numbers = HashMap::from([(
+            "book".to_string(),
+            vec![model::FieldNumber {
+                name: "isbn".to_string(),
+                number: 18_999,
+            }],
+        )]);
+
+        let proto = proto_file(&[model_overview], "book-store", &field_numbers)?;
+
+        assert_eq!(
+            proto.assigned_field_numbers,
+            vec![(
+                "book".to_string(),
+                model::FieldNumber {
+                    name: "title".to_string(),
+                    number: 20_000,
+                }
+            )]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,392 @@
+use super::{hash_comment, inverse_belongs_to, string_literal};
+use crate::{
+    attribute_type::AttributeKind,
+    migration::{self, ForeignKey, Table},
+    model,
+    util::{inflection, string},
+    FoundationError, FoundationResult, UtcDateTime,
+};
+use std::collections::{HashMap, HashSet};
+
+/// Version of the `ActiveRecord::Migration` the migration is written for.
+const RAILS_VERSION: &str = "7.1";
+
+/// Generates an `ActiveRecord` model class per model and the migration
+/// creating their tables.
+///
+/// Names follow the Rails conventions, models are singular, tables and
+/// `has_many` associations are plural. Options are only added where the
+/// conventions can't infer the associated class or the foreign key.
+pub fn rails_files(
+    model_overviews: &[model::ModelOverview],
+    project_slug: &str,
+    migration_timestamp: UtcDateTime,
+) -> FoundationResult<Vec<model::GeneratedFile>> {
+    let mut class_names: HashMap<String, &str> = HashMap::new();
+    let mut models = vec![];
+
+    for model_overview in model_overviews {
+        let model = RailsModel::new(model_overview)?;
+        let model_name = &model_overview.model.name;
+
+        if let Some(other_model_name) = class_names.insert(model.class_name.clone(), model_name) {
+            return Err(FoundationError::failed_precondition(format!(
+                "code can't be generated, `{model_name}` and `{other_model_name}` share the same class name `{}`",
+                model.class_name
+            )));
+        }
+
+        models.push(model);
+    }
+
+    let associations = models
+        .iter()
+        .map(|model| associations(&models, model))
+        .collect::<FoundationResult<Vec<Vec<RailsAssociation>>>>()?;
+
+    let mut files = vec![];
+
+    for (model, associations) in models.iter().zip(&associations) {
+        files.push(model::GeneratedFile {
+            path: format!("app/models/{}.rb", model.file_name),
+            content: model_class(model, associations),
+        });
+    }
+
+    let migration_name = format!("create_{}_tables", string::snake_case(project_slug));
+
+    files.push(model::GeneratedFile {
+        path: format!(
+            "db/migrate/{}_{migration_name}.rb",
+            migration_timestamp.format("%Y%m%d%H%M%S")
+        ),
+        content: migration_class(&migration_name, &models, &associations)?,
+    });
+
+    Ok(files)
+}
+
+struct RailsModel<'a> {
+    overview: &'a model::ModelOverview,
+
+    class_name: String,
+
+    /// Singular `snake_case` name, the name of the file and of the
+    /// references to the model.
+    file_name: String,
+
+    table_name: String,
+}
+
+impl<'a> RailsModel<'a> {
+    fn new(overview: &'a model::ModelOverview) -> FoundationResult<Self> {
+        let file_name = inflection::singularize(&identifier(&overview.model.name)?);
+
+        Ok(Self {
+            overview,
+            class_name: class_name(&file_name),
+            table_name: inflection::pluralize(&file_name),
+            file_name,
+        })
+    }
+}
+
+struct RailsAssociation<'a> {
+    association: &'a model::Association,
+
+    associated_model: &'a RailsModel<'a>,
+
+    /// Name of the association, plural for `has_many`.
+    name: String,
+
+    /// Column of the foreign key when it isn't the conventional one.
+    foreign_key: Option<String>,
+}
+
+impl<'a> RailsAssociation<'a> {
+    fn macro_name(&self) -> &'static str {
+        match self.association.kind {
+            model::AssociationKind::BelongsTo => "belongs_to",
+            model::AssociationKind::HasOne => "has_one",
+            model::AssociationKind::HasMany => "has_many",
+        }
+    }
+
+    fn declaration(&self) -> String {
+        let mut declaration = format!("{} :{}", self.macro_name(), self.name);
+
+        if class_name(&inflection::singularize(&self.name)) != self.associated_model.class_name {
+            declaration.push_str(&format!(
+                ", class_name: {}",
+                string_literal(&self.associated_model.class_name)
+            ));
+        }
+
+        if let Some(foreign_key) = &self.foreign_key {
+            declaration.push_str(&format!(", foreign_key: :{foreign_key}"));
+        }
+
+        declaration
+    }
+}
+
+fn associations<'a>(
+    models: &'a [RailsModel<'a>],
+    model: &RailsModel<'a>,
+) -> FoundationResult<Vec<RailsAssociation<'a>>> {
+    let model_name = &model.overview.model.name;
+    let mut names = HashSet::new();
+    let mut associations = vec![];
+
+    for association in &model.overview.associations {
+        let Some(associated_model) = associated_model(models, association) else {
+            return Err(FoundationError::failed_precondition(format!(
+                "code can't be generated, `{model_name}` is associated with `{}` which isn't part of the project",
+                association.model.name
+            )));
+        };
+
+        let name = identifier(&association.name)?;
+
+        let (name, foreign_key) = match association.kind {
+            model::AssociationKind::BelongsTo => (inflection::singularize(&name), None),
+            model::AssociationKind::HasOne => (
+                inflection::singularize(&name),
+                inverse_foreign_key(model, associated_model),
+            ),
+            model::AssociationKind::HasMany => (
+                inflection::pluralize(&name),
+                inverse_foreign_key(model, associated_model),
+            ),
+        };
+
+        if !names.insert(name.clone()) {
+            return Err(FoundationError::failed_precondition(format!(
+                "code can't be generated, `{model_name}` has more than one association named `{name}`, `{}` can't be added",
+                association.name
+            )));
+        }
+
+        associations.push(RailsAssociation {
+            association,
+            associated_model,
+            name,
+            foreign_key,
+        });
+    }
+
+    Ok(associations)
+}
+
+fn associated_model<'a>(
+    models: &'a [RailsModel<'a>],
+    association: &model::Association,
+) -> Option<&'a RailsModel<'a>> {
+    models
+        .iter()
+        .find(|model| model.overview.model.slug == association.model.slug)
+}
+
+/// Foreign key of the `has_one` or `has_many` association, when the associated
+/// model belongs to the model through a single association named after
+/// something else than the model.
+fn inverse_foreign_key(model: &RailsModel, associated_model: &RailsModel) -> Option<String> {
+    let inverse_association = inverse_belongs_to(model.overview, associated_model.overview)?;
+
+    let reference = inflection::singularize(&string::snake_case(&inverse_association.name));
+
+    (reference != model.file_name).then(|| format!("{reference}_id"))
+}
+
+fn model_class(model: &RailsModel, associations: &[RailsAssociation]) -> String {
+    let mut code = hash_comment(model.overview.model.description.as_deref(), "");
+
+    code.push_str(&format!("class {} < ApplicationRecord\n", model.class_name));
+
+    for association in associations {
+        code.push_str(&hash_comment(
+            association.association.description.as_deref(),
+            "  ",
+        ));
+        code.push_str(&format!("  {}\n", association.declaration()));
+    }
+
+    code.push_str("end\n");
+
+    code
+}
+
+fn migration_class(
+    migration_name: &str,
+    models: &[RailsModel],
+    associations: &[Vec<RailsAssociation>],
+) -> FoundationResult<String> {
+    let tables: Vec<Table> = models
+        .iter()
+        .zip(associations)
+        .map(|(model, associations)| Table {
+            name: model.table_name.clone(),
+            columns: vec![],
+            foreign_keys: associations
+                .iter()
+                .filter(|association| {
+                    association.association.kind == model::AssociationKind::BelongsTo
+                })
+                .map(|association| ForeignKey {
+                    column: format!("{}_id", association.name),
+                    referenced_table: association.associated_model.table_name.clone(),
+                })
+                .collect(),
+        })
+        .collect();
+    let tables = migration::dependency_order(tables);
+
+    let mut statements = vec![];
+    let mut deferred_statements = vec![];
+
+    for table in &tables {
+        let (model, associations) = models
+            .iter()
+            .zip(associations)
+            .find(|(model, _associations)| model.table_name == table.name)
+            .expect("every table is created from a model");
+
+        let mut column_names = HashSet::from([migration::PRIMARY_KEY.to_string()]);
+        let mut columns = vec![];
+
+        for attribute in &model.overview.attributes {
+            let column_name = identifier(&attribute.name)?;
+            let (column_type, options) = column_type(&attribute.r#type.slug);
+            let comment = attribute
+                .description
+                .as_deref()
+                .map(|description| format!(", comment: {}", string_literal(description.trim())))
+                .unwrap_or_default();
+
+            columns.push((
+                column_name.clone(),
+                format!("t.{column_type} :{column_name}{options}{comment}"),
+            ));
+        }
+
+        for association in associations {
+            if association.association.kind != model::AssociationKind::BelongsTo {
+                continue;
+            }
+
+            let foreign_key = ForeignKey {
+                column: format!("{}_id", association.name),
+                referenced_table: association.associated_model.table_name.clone(),
+            };
+            let referenced_table = &foreign_key.referenced_table;
+
+            let constraint = if migration::is_forward_reference(&tables, table, &foreign_key) {
+                let mut statement = format!("add_foreign_key :{}, :{referenced_table}", table.name);
+
+                if foreign_key.column != format!("{}_id", inflection::singularize(referenced_table))
+                {
+                    statement.push_str(&format!(", column: :{}", foreign_key.column));
+                }
+
+                deferred_statements.push(statement);
+
+                String::new()
+            } else if inflection::pluralize(&association.name) == *referenced_table {
+                ", foreign_key: true".to_string()
+            } else {
+                format!(", foreign_key: {{ to_table: :{referenced_table} }}")
+            };
+
+            columns.push((
+                foreign_key.column,
+                format!(
+                    "t.references :{}, null: false{constraint}",
+                    association.name
+                ),
+            ));
+        }
+
+        for (column_name, _column) in &columns {
+            if !column_names.insert(column_name.clone()) {
+                return Err(FoundationError::failed_precondition(format!(
+                    "code can't be generated, `{}` has more than one column named `{column_name}`",
+                    model.overview.model.name
+                )));
+            }
+        }
+
+        let table_comment = model
+            .overview
+            .model
+            .description
+            .as_deref()
+            .map(|description| format!(", comment: {}", string_literal(description.trim())))
+            .unwrap_or_default();
+
+        let mut statement = format!("    create_table :{}{table_comment} do |t|\n", table.name);
+
+        for (_column_name, column) in columns {
+            statement.push_str(&format!("      {column}\n"));
+        }
+
+        statement.push_str("    end\n");
+        statements.push(statement);
+    }
+
+    if !deferred_statements.is_empty() {
+        statements.push(
+            deferred_statements
+                .into_iter()
+                .map(|statement| format!("    {statement}\n"))
+                .collect(),
+        );
+    }
+
+    Ok(format!(
+        "class {} < ActiveRecord::Migration[{RAILS_VERSION}]\n  def change\n{}  end\nend\n",
+        string::pascal_case(migration_name),
+        statements.join("\n")
+    ))
+}
+
+/// Column type and options of the attribute type.
+fn column_type(attribute_type_slug: &str) -> (&'static str, &'static str) {
+    match AttributeKind::from_slug(attribute_type_slug) {
+        AttributeKind::SmallInteger => ("integer", ", limit: 2"),
+        AttributeKind::Integer => ("integer", ""),
+        AttributeKind::BigInteger => ("bigint", ""),
+        AttributeKind::Float => ("float", ""),
+        AttributeKind::Double => ("float", ", limit: 53"),
+        AttributeKind::Decimal => ("decimal", ""),
+        AttributeKind::Boolean => ("boolean", ""),
+        AttributeKind::Date => ("date", ""),
+        AttributeKind::Time => ("time", ""),
+        AttributeKind::DateTime => ("datetime", ""),
+        AttributeKind::Uuid => ("uuid", ""),
+        AttributeKind::Json => ("json", ""),
+        AttributeKind::Binary => ("binary", ""),
+        AttributeKind::Text if attribute_type_slug == "text" => ("text", ""),
+        AttributeKind::Text => ("string", ""),
+    }
+}
+
+/// Ruby class name of the singular `snake_case` name.
+fn class_name(name: &str) -> String {
+    string::pascal_case(name)
+}
+
+fn identifier(name: &str) -> FoundationResult<String> {
+    let identifier = string::snake_case(name);
+
+    if !identifier
+        .chars()
+        .next()
+        .is_some_and(|char| char.is_alphabetic())
+    {
+        return Err(FoundationError::failed_precondition(format!(
+            "code can't be generated, `{name}` can't be used as a Ruby identifier"
+        )));
+    }
+
+    Ok(identifier)
+}
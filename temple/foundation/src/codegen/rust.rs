@@ -0,0 +1,322 @@
+use super::{associated_model_overview, description_lines};
+use crate::{attribute_type::AttributeKind, model, util, FoundationError, FoundationResult};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// Words that can't be used as field names without the `r#` prefix.
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual",
+    "where", "while", "yield",
+];
+
+/// Words that can't be used as field names even with the `r#` prefix.
+const RESERVED_WORDS: &[&str] = &["crate", "self", "super"];
+
+/// Generates a module with a struct per model and the `mod.rs` re-exporting
+/// the structs.
+///
+/// Associations to a single model are `Option`s, boxed when the associated
+/// model refers back to the model through single associations too, otherwise
+/// the struct would have an infinite size. `HasMany` associations are `Vec`s.
+pub fn rust_modules(
+    model_overviews: &[model::ModelOverview],
+    serde: bool,
+) -> FoundationResult<Vec<model::GeneratedFile>> {
+    let mut module_names: HashMap<String, &str> = HashMap::new();
+
+    for model_overview in model_overviews {
+        let model_name = &model_overview.model.name;
+        let module_name = field_name(model_name)?;
+
+        if let Some(other_model_name) = module_names.insert(module_name.clone(), model_name) {
+            return Err(FoundationError::failed_precondition(format!(
+                "code can't be generated, `{model_name}` and `{other_model_name}` share the same module name `{module_name}`"
+            )));
+        }
+    }
+
+    let mut mod_declarations = vec![];
+    let mut use_declarations = vec![];
+    let mut files = vec![];
+
+    for model_overview in model_overviews {
+        let module_name = field_name(&model_overview.model.name)?;
+        let struct_name = struct_name(&model_overview.model.name)?;
+
+        mod_declarations.push(format!("mod {module_name};"));
+        use_declarations.push(format!("pub use {module_name}::{struct_name};"));
+
+        files.push(model::GeneratedFile {
+            path: format!("{}.rs", module_name.trim_start_matches("r#")),
+            content: module(model_overviews, model_overview, serde)?,
+        });
+    }
+
+    let mod_content = if mod_declarations.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "{}\n\n{}\n",
+            mod_declarations.join("\n"),
+            use_declarations.join("\n")
+        )
+    };
+
+    files.insert(
+        0,
+        model::GeneratedFile {
+            path: "mod.rs".to_string(),
+            content: mod_content,
+        },
+    );
+
+    Ok(files)
+}
+
+fn module(
+    model_overviews: &[model::ModelOverview],
+    model_overview: &model::ModelOverview,
+    serde: bool,
+) -> FoundationResult<String> {
+    let model::ModelOverview {
+        model,
+        attributes,
+        associations,
+    } = model_overview;
+
+    let model_struct_name = struct_name(&model.name)?;
+    let mut imports = BTreeSet::new();
+    let mut fields = vec![];
+    let mut field_names = HashSet::new();
+
+    for attribute in attributes {
+        fields.push((
+            attribute.name.as_str(),
+            attribute.description.as_deref(),
+            field_name(&attribute.name)?,
+            field_type(&AttributeKind::from_slug(&attribute.r#type.slug)).to_string(),
+        ));
+    }
+
+    for association in associations {
+        let Some(associated_model_overview) =
+            associated_model_overview(model_overviews, association)
+        else {
+            return Err(FoundationError::failed_precondition(format!(
+                "code can't be generated, `{}` is associated with `{}` which isn't part of the project",
+                model.name, association.model.name
+            )));
+        };
+
+        let associated_struct_name = struct_name(&associated_model_overview.model.name)?;
+
+        let r#type = match association.kind {
+            model::AssociationKind::HasMany => format!("Vec<{associated_struct_name}>"),
+            model::AssociationKind::BelongsTo | model::AssociationKind::HasOne
+                if refers_back(model_overviews, associated_model_overview, model) =>
+            {
+                format!("Option<Box<{associated_struct_name}>>")
+            }
+            model::AssociationKind::BelongsTo | model::AssociationKind::HasOne => {
+                format!("Option<{associated_struct_name}>")
+            }
+        };
+
+        if associated_struct_name != model_struct_name {
+            imports.insert(associated_struct_name);
+        }
+
+        fields.push((
+            association.name.as_str(),
+            association.description.as_deref(),
+            field_name(&association.name)?,
+            r#type,
+        ));
+    }
+
+    let mut code = String::new();
+
+    if !imports.is_empty() {
+        let imports: Vec<String> = imports.into_iter().collect();
+
+        if imports.len() == 1 {
+            code.push_str(&format!("use super::{};\n", imports[0]));
+        } else {
+            code.push_str(&format!("use super::{{{}}};\n", imports.join(", ")));
+        }
+    }
+
+    if serde {
+        code.push_str("use serde::{Deserialize, Serialize};\n");
+    }
+
+    if !code.is_empty() {
+        code.push('\n');
+    }
+
+    code.push_str(&doc_comment(model.description.as_deref(), ""));
+
+    if serde {
+        code.push_str("#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]\n");
+    } else {
+        code.push_str("#[derive(Clone, Debug, PartialEq)]\n");
+    }
+
+    let mut field_definitions = vec![];
+
+    for (name, description, field_name, r#type) in fields {
+        if !field_names.insert(field_name.clone()) {
+            return Err(FoundationError::failed_precondition(format!(
+                "code can't be generated, `{}` has more than one field named `{field_name}`, `{name}` can't be added",
+                model.name
+            )));
+        }
+
+        field_definitions.push(format!(
+            "{}    pub {field_name}: {type},\n",
+            doc_comment(description, "    ")
+        ));
+    }
+
+    if field_definitions.is_empty() {
+        code.push_str(&format!("pub struct {model_struct_name} {{}}\n"));
+    } else {
+        code.push_str(&format!(
+            "pub struct {model_struct_name} {{\n{}}}\n",
+            field_definitions.join("\n")
+        ));
+    }
+
+    Ok(code)
+}
+
+/// Whether the associated model refers back to the model through single
+/// associations, directly or through other models.
+fn refers_back(
+    model_overviews: &[model::ModelOverview],
+    start: &model::ModelOverview,
+    model: &model::Model,
+) -> bool {
+    let mut visited = HashSet::new();
+    let mut queue = vec![start];
+
+    while let Some(model_overview) = queue.pop() {
+        if model_overview.model.slug == model.slug {
+            return true;
+        }
+
+        if !visited.insert(&model_overview.model.slug) {
+            continue;
+        }
+
+        for association in &model_overview.associations {
+            if association.kind == model::AssociationKind::HasMany {
+                continue;
+            }
+
+            if let Some(next) = associated_model_overview(model_overviews, association) {
+                queue.push(next);
+            }
+        }
+    }
+
+    false
+}
+
+fn field_type(kind: &AttributeKind) -> &'static str {
+    match kind {
+        AttributeKind::SmallInteger => "i16",
+        AttributeKind::Integer => "i32",
+        AttributeKind::BigInteger => "i64",
+        AttributeKind::Float => "f32",
+        AttributeKind::Double => "f64",
+        AttributeKind::Decimal => "rust_decimal::Decimal",
+        AttributeKind::Boolean => "bool",
+        AttributeKind::Date => "chrono::NaiveDate",
+        AttributeKind::Time => "chrono::NaiveTime",
+        AttributeKind::DateTime => "chrono::DateTime<chrono::Utc>",
+        AttributeKind::Uuid => "uuid::Uuid",
+        AttributeKind::Json => "serde_json::Value",
+        AttributeKind::Binary => "Vec<u8>",
+        AttributeKind::Text => "String",
+    }
+}
+
+fn doc_comment(description: Option<&str>, indentation: &str) -> String {
+    description_lines(description)
+        .into_iter()
+        .map(|line| {
+            if line.is_empty() {
+                format!("{indentation}///\n")
+            } else {
+                format!("{indentation}/// {line}\n")
+            }
+        })
+        .collect()
+}
+
+fn struct_name(name: &str) -> FoundationResult<String> {
+    let struct_name = util::string::pascal_case(name);
+
+    if !struct_name
+        .chars()
+        .next()
+        .is_some_and(|char| char.is_alphabetic())
+    {
+        return Err(identifier_error(name));
+    }
+
+    Ok(struct_name)
+}
+
+fn field_name(name: &str) -> FoundationResult<String> {
+    let field_name = util::string::snake_case(name);
+
+    if !field_name
+        .chars()
+        .next()
+        .is_some_and(|char| char.is_alphabetic())
+        || RESERVED_WORDS.contains(&field_name.as_str())
+    {
+        return Err(identifier_error(name));
+    }
+
+    if KEYWORDS.contains(&field_name.as_str()) {
+        return Ok(format!("r#{field_name}"));
+    }
+
+    Ok(field_name)
+}
+
+fn identifier_error(name: &str) -> FoundationError {
+    FoundationError::failed_precondition(format!(
+        "code can't be generated, `{name}` can't be used as a Rust identifier"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FoundationErrorCode;
+
+    #[test]
+    fn it_escapes_field_names() -> FoundationResult<()> {
+        assert_eq!(field_name("Page Count")?, "page_count");
+        assert_eq!(field_name("Type")?, "r#type");
+
+        let error = field_name("self").err().unwrap();
+
+        assert!(matches!(
+            error.code(),
+            FoundationErrorCode::FailedPrecondition
+        ));
+        assert_eq!(
+            error.message(),
+            "code can't be generated, `self` can't be used as a Rust identifier"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,230 @@
+use crate::{
+    attribute_type::AttributeKind, generator, model, util, FoundationError, FoundationResult,
+};
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+use tera::{Context, Tera};
+
+/// Checks the templates of the generator can be compiled.
+pub fn compile_templates(templates: &[generator::GeneratorTemplate]) -> FoundationResult<()> {
+    let mut paths = HashSet::new();
+
+    for template in templates {
+        if !paths.insert(template.path.as_str()) {
+            return Err(FoundationError::invalid_argument(format!(
+                "more than one template has the path `{}`",
+                template.path
+            )));
+        }
+    }
+
+    tera(templates)
+        .map(|_| ())
+        .map_err(FoundationError::invalid_argument)
+}
+
+/// Renders the templates of the generator, a file for the project templates
+/// and a file per model for the model templates, in the order of the
+/// templates.
+///
+/// See the [`generator`] module for the context given to the templates.
+pub fn generator_files(
+    templates: &[generator::GeneratorTemplate],
+    model_overviews: &[model::ModelOverview],
+    project_slug: &str,
+) -> FoundationResult<Vec<model::GeneratedFile>> {
+    let tera = tera(templates).map_err(generation_error)?;
+    let models: Vec<Value> = model_overviews.iter().map(model_context).collect();
+    let mut paths = HashSet::new();
+    let mut files = vec![];
+
+    for template in templates {
+        let project_context = json!({
+            "project": { "slug": project_slug },
+            "models": models,
+        });
+
+        let contexts = match template.scope {
+            generator::TemplateScope::Project => vec![project_context],
+            generator::TemplateScope::Model => models
+                .iter()
+                .map(|model| {
+                    let mut context = project_context.clone();
+                    context["model"] = model.clone();
+                    context
+                })
+                .collect(),
+        };
+
+        for context in contexts {
+            let context = Context::from_value(context)
+                .map_err(|err| FoundationError::internal(err.to_string()))?;
+
+            let path = tera
+                .render(&path_template_name(template), &context)
+                .map_err(|err| generation_error(error_message(template, &err)))?
+                .trim()
+                .to_string();
+
+            if path.is_empty() {
+                return Err(generation_error(format!(
+                    "`{}` renders an empty path",
+                    template.path
+                )));
+            }
+
+            if !paths.insert(path.clone()) {
+                return Err(generation_error(format!(
+                    "more than one file has the path `{path}`"
+                )));
+            }
+
+            let content = tera
+                .render(&template.path, &context)
+                .map_err(|err| generation_error(error_message(template, &err)))?;
+
+            files.push(model::GeneratedFile { path, content });
+        }
+    }
+
+    Ok(files)
+}
+
+/// Templates compiled under their path, along with the templates of their
+/// paths.
+fn tera(templates: &[generator::GeneratorTemplate]) -> Result<Tera, String> {
+    let mut tera = Tera::default();
+
+    // Generated files are source code, not HTML.
+    tera.autoescape_on(vec![]);
+
+    tera.register_filter("snake_case", string_filter(util::string::snake_case));
+    tera.register_filter("pascal_case", string_filter(util::string::pascal_case));
+    tera.register_filter("pluralize", string_filter(util::inflection::pluralize));
+    tera.register_filter("singularize", string_filter(util::inflection::singularize));
+
+    for template in templates {
+        if template.path.trim().is_empty() {
+            return Err("template path can't be blank".to_string());
+        }
+
+        tera.add_raw_template(&path_template_name(template), &template.path)
+            .map_err(|err| error_message(template, &err))?;
+
+        tera.add_raw_template(&template.path, &template.content)
+            .map_err(|err| error_message(template, &err))?;
+    }
+
+    Ok(tera)
+}
+
+fn path_template_name(template: &generator::GeneratorTemplate) -> String {
+    format!("{} (path)", template.path)
+}
+
+fn string_filter(
+    transform: fn(&str) -> String,
+) -> impl Fn(&Value, &HashMap<String, Value>) -> tera::Result<Value> {
+    move |value, _args| match value {
+        Value::String(value) => Ok(json!(transform(value))),
+        value => Err(tera::Error::msg(format!(
+            "expected a string, got `{value}`"
+        ))),
+    }
+}
+
+fn model_context(model_overview: &model::ModelOverview) -> Value {
+    let model::ModelOverview {
+        model,
+        attributes,
+        associations,
+    } = model_overview;
+
+    let attributes: Vec<Value> = attributes
+        .iter()
+        .map(|attribute| {
+            json!({
+                "name": attribute.name,
+                "description": attribute.description,
+                "type": {
+                    "name": attribute.r#type.name,
+                    "slug": attribute.r#type.slug,
+                    "description": attribute.r#type.description,
+                    "kind": kind_name(&AttributeKind::from_slug(&attribute.r#type.slug)),
+                },
+            })
+        })
+        .collect();
+
+    let associations: Vec<Value> = associations
+        .iter()
+        .map(|association| {
+            json!({
+                "name": association.name,
+                "description": association.description,
+                "kind": match association.kind {
+                    model::AssociationKind::BelongsTo => "belongs_to",
+                    model::AssociationKind::HasOne => "has_one",
+                    model::AssociationKind::HasMany => "has_many",
+                },
+                "model": {
+                    "name": association.model.name,
+                    "slug": association.model.slug,
+                    "description": association.model.description,
+                },
+            })
+        })
+        .collect();
+
+    json!({
+        "name": model.name,
+        "slug": model.slug,
+        "description": model.description,
+        "attributes": attributes,
+        "associations": associations,
+    })
+}
+
+fn kind_name(kind: &AttributeKind) -> &'static str {
+    match kind {
+        AttributeKind::SmallInteger => "small_integer",
+        AttributeKind::Integer => "integer",
+        AttributeKind::BigInteger => "big_integer",
+        AttributeKind::Float => "float",
+        AttributeKind::Double => "double",
+        AttributeKind::Decimal => "decimal",
+        AttributeKind::Boolean => "boolean",
+        AttributeKind::Date => "date",
+        AttributeKind::Time => "time",
+        AttributeKind::DateTime => "date_time",
+        AttributeKind::Uuid => "uuid",
+        AttributeKind::Json => "json",
+        AttributeKind::Binary => "binary",
+        AttributeKind::Text => "text",
+    }
+}
+
+/// Message of the Tera error, which keeps the details in its sources.
+fn error_message(template: &generator::GeneratorTemplate, err: &tera::Error) -> String {
+    let mut details = vec![];
+    let mut source = std::error::Error::source(err);
+
+    while let Some(err) = source {
+        details.push(err.to_string().trim().to_string());
+        source = err.source();
+    }
+
+    if details.is_empty() {
+        details.push(err.to_string());
+    }
+
+    format!(
+        "template `{}` is invalid, {}",
+        template.path,
+        details.join(", ")
+    )
+}
+
+fn generation_error(message: impl std::fmt::Display) -> FoundationError {
+    FoundationError::failed_precondition(format!("code can't be generated, {message}"))
+}
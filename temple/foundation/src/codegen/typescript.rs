@@ -0,0 +1,203 @@
+use super::{associated_model_overview, description_lines};
+use crate::{attribute_type::AttributeKind, model, util, FoundationError, FoundationResult};
+use std::collections::{HashMap, HashSet};
+
+/// TypeScript types of the attribute types, by the attribute type slug, which
+/// take precedence over the default ones.
+pub type TypeMappings = HashMap<String, String>;
+
+/// Generates an exported `interface` per model.
+///
+/// Properties are `camelCase`, associations are optional as they aren't
+/// always loaded, `HasMany` associations are arrays.
+pub fn typescript_interfaces(
+    model_overviews: &[model::ModelOverview],
+    type_mappings: &TypeMappings,
+) -> FoundationResult<String> {
+    let mut interface_names: HashMap<String, &str> = HashMap::new();
+    let mut interfaces = vec![];
+
+    for model_overview in model_overviews {
+        let model_name = &model_overview.model.name;
+        let name = interface_name(model_name)?;
+
+        if let Some(other_model_name) = interface_names.insert(name.clone(), model_name) {
+            return Err(FoundationError::failed_precondition(format!(
+                "code can't be generated, `{model_name}` and `{other_model_name}` share the same interface name `{name}`"
+            )));
+        }
+
+        interfaces.push(interface(model_overviews, model_overview, type_mappings)?);
+    }
+
+    Ok(interfaces.join("\n"))
+}
+
+fn interface(
+    model_overviews: &[model::ModelOverview],
+    model_overview: &model::ModelOverview,
+    type_mappings: &TypeMappings,
+) -> FoundationResult<String> {
+    let model::ModelOverview {
+        model,
+        attributes,
+        associations,
+    } = model_overview;
+
+    let mut properties = vec![];
+
+    for attribute in attributes {
+        let r#type = match type_mappings.get(&attribute.r#type.slug) {
+            Some(r#type) => r#type.clone(),
+            None => default_type(AttributeKind::from_slug(&attribute.r#type.slug)).to_string(),
+        };
+
+        properties.push((
+            property_name(&attribute.name),
+            attribute.description.as_deref(),
+            format!(": {type}"),
+        ));
+    }
+
+    for association in associations {
+        let Some(associated_model_overview) =
+            associated_model_overview(model_overviews, association)
+        else {
+            return Err(FoundationError::failed_precondition(format!(
+                "code can't be generated, `{}` is associated with `{}` which isn't part of the project",
+                model.name, association.model.name
+            )));
+        };
+
+        let associated_interface_name = interface_name(&associated_model_overview.model.name)?;
+
+        let r#type = match association.kind {
+            model::AssociationKind::HasMany => format!("?: {associated_interface_name}[]"),
+            model::AssociationKind::BelongsTo | model::AssociationKind::HasOne => {
+                format!("?: {associated_interface_name}")
+            }
+        };
+
+        properties.push((
+            property_name(&association.name),
+            association.description.as_deref(),
+            r#type,
+        ));
+    }
+
+    let mut property_names = HashSet::new();
+    let mut property_declarations = vec![];
+
+    for (name, description, r#type) in properties {
+        if !property_names.insert(name.clone()) {
+            return Err(FoundationError::failed_precondition(format!(
+                "code can't be generated, `{}` has more than one property named `{name}`",
+                model.name
+            )));
+        }
+
+        property_declarations.push(format!("{}  {name}{type};\n", js_doc(description, "  ")));
+    }
+
+    Ok(format!(
+        "{}export interface {} {{\n{}}}\n",
+        js_doc(model.description.as_deref(), ""),
+        interface_name(&model.name)?,
+        property_declarations.join("\n")
+    ))
+}
+
+fn default_type(kind: AttributeKind) -> &'static str {
+    match kind {
+        AttributeKind::SmallInteger
+        | AttributeKind::Integer
+        | AttributeKind::BigInteger
+        | AttributeKind::Float
+        | AttributeKind::Double => "number",
+        AttributeKind::Boolean => "boolean",
+        AttributeKind::Json => "unknown",
+        // Decimals keep their precision, the others are what JSON turns them into.
+        AttributeKind::Decimal
+        | AttributeKind::Date
+        | AttributeKind::Time
+        | AttributeKind::DateTime
+        | AttributeKind::Uuid
+        | AttributeKind::Binary
+        | AttributeKind::Text => "string",
+    }
+}
+
+fn js_doc(description: Option<&str>, indentation: &str) -> String {
+    let lines: Vec<String> = description_lines(description)
+        .into_iter()
+        .map(|line| line.replace("*/", "*\\/"))
+        .collect();
+
+    match lines.as_slice() {
+        [] => String::new(),
+        [line] => format!("{indentation}/** {line} */\n"),
+        lines => {
+            let mut comment = format!("{indentation}/**\n");
+
+            for line in lines {
+                if line.is_empty() {
+                    comment.push_str(&format!("{indentation} *\n"));
+                } else {
+                    comment.push_str(&format!("{indentation} * {line}\n"));
+                }
+            }
+
+            comment.push_str(&format!("{indentation} */\n"));
+
+            comment
+        }
+    }
+}
+
+fn interface_name(name: &str) -> FoundationResult<String> {
+    let interface_name = util::string::pascal_case(name);
+
+    if !interface_name
+        .chars()
+        .next()
+        .is_some_and(|char| char.is_alphabetic())
+    {
+        return Err(FoundationError::failed_precondition(format!(
+            "code can't be generated, `{name}` can't be used as a TypeScript identifier"
+        )));
+    }
+
+    Ok(interface_name)
+}
+
+/// `camelCase` property name, quoted when it isn't an identifier.
+fn property_name(name: &str) -> String {
+    let pascal_case = util::string::pascal_case(name);
+    let mut chars = pascal_case.chars();
+
+    let property_name: String = match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    };
+
+    if property_name
+        .chars()
+        .next()
+        .is_some_and(|char| char.is_alphabetic())
+    {
+        property_name
+    } else {
+        format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_quotes_property_names_when_needed() {
+        assert_eq!(property_name("Founded At"), "foundedAt");
+        assert_eq!(property_name("2FA Code"), "\"2FA Code\"");
+    }
+}
@@ -63,6 +63,22 @@ pub enum AssociationKind {
     HasMany,
 }
 
+/// Number assigned to a field of the model's Protobuf message.
+#[derive(Clone)]
+pub struct FieldNumber {
+    pub id: Uuid,
+
+    pub model_id: Uuid,
+
+    pub name: String,
+
+    pub number: i32,
+
+    pub inserted_at: UtcDateTime,
+
+    pub updated_at: UtcDateTime,
+}
+
 #[derive(Clone)]
 pub struct ModelOverview {
     pub model: Model,
@@ -103,3 +119,18 @@ impl Default for Attribute {
         }
     }
 }
+
+impl Default for FieldNumber {
+    fn default() -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: Uuid::new_v4(),
+            model_id: Uuid::new_v4(),
+            name: Default::default(),
+            number: Default::default(),
+            inserted_at: now,
+            updated_at: now,
+        }
+    }
+}
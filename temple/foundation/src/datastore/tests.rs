@@ -28,6 +28,12 @@ pub struct Repo<T> {
     pub records: RwLock<HashMap<Uuid, Record<T>>>,
 }
 
+impl<T> Default for Repo<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> Repo<T> {
     pub fn new() -> Self {
         Self {
@@ -0,0 +1,126 @@
+use super::{association_label, multiplicities, ModelClass};
+use crate::{model, FoundationError, FoundationResult};
+
+/// Generates a [Graphviz](https://graphviz.org/doc/info/lang.html) directed
+/// graph, where every model is a record-shaped node listing its attributes.
+pub fn project_dot_graph(project_class: Vec<ModelClass>) -> FoundationResult<String> {
+    let mut nodes = vec![];
+    let mut edges = vec![];
+
+    for model_class in &project_class {
+        let attributes = model_class
+            .attributes
+            .iter()
+            .map(|attribute| {
+                Ok(format!(
+                    "+ {} : {}\\l",
+                    record_text(&attribute.name)?,
+                    record_text(&attribute.r#type.name)?
+                ))
+            })
+            .collect::<FoundationResult<Vec<String>>>()?;
+
+        nodes.push(format!(
+            "    {} [label=\"{{{}|{}}}\"];",
+            identifier(&model_class.model.name)?,
+            record_text(&model_class.model.name)?,
+            attributes.join("")
+        ));
+    }
+
+    for model_class in &project_class {
+        for association in &model_class.associations {
+            edges.push(edge(model_class.model, association)?);
+        }
+    }
+
+    let mut code = r#"digraph project {
+    rankdir=LR;
+    node [shape=record, fontname="Helvetica"];
+    edge [fontname="Helvetica"];
+"#
+    .to_string();
+
+    if !nodes.is_empty() {
+        code.push_str(&format!("\n{}\n", nodes.join("\n")));
+    }
+
+    if !edges.is_empty() {
+        code.push_str(&format!("\n{}\n", edges.join("\n")));
+    }
+
+    code.push_str("}\n");
+
+    Ok(code)
+}
+
+/// Edges follow the class diagram notation: an arrow for `BelongsTo`, a
+/// filled diamond for `HasOne` and a hollow diamond for `HasMany`.
+fn edge(model: &model::Model, association: &model::Association) -> FoundationResult<String> {
+    let style = match association.kind {
+        model::AssociationKind::BelongsTo => "arrowhead=normal",
+        model::AssociationKind::HasOne => "dir=both, arrowtail=diamond, arrowhead=none",
+        model::AssociationKind::HasMany => "dir=both, arrowtail=odiamond, arrowhead=none",
+    };
+
+    let (tail_label, head_label) = multiplicities(&association.kind);
+
+    Ok(format!(
+        "    {} -> {} [label={}, taillabel=\"{tail_label}\", headlabel=\"{head_label}\", {style}];",
+        identifier(&model.name)?,
+        identifier(&association.model.name)?,
+        identifier(&association_label(association))?,
+    ))
+}
+
+/// Quoted DOT identifier.
+fn identifier(name: &str) -> FoundationResult<String> {
+    validate_text(name)?;
+
+    Ok(format!(
+        "\"{}\"",
+        name.replace('\\', "\\\\").replace('"', "\\\"")
+    ))
+}
+
+/// Escapes characters that have a special meaning in record labels.
+fn record_text(text: &str) -> FoundationResult<String> {
+    validate_text(text)?;
+
+    let mut escaped = String::with_capacity(text.len());
+
+    for char in text.chars() {
+        if matches!(char, '{' | '}' | '|' | '<' | '>' | '"' | '\\') {
+            escaped.push('\\');
+        }
+
+        escaped.push(char);
+    }
+
+    Ok(escaped)
+}
+
+fn validate_text(text: &str) -> FoundationResult<()> {
+    if text.chars().any(char::is_control) {
+        return Err(FoundationError::failed_precondition(format!(
+            "diagram can't be generated, text `{text}` can't be represented in DOT"
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_escapes_record_text() -> FoundationResult<()> {
+        assert_eq!(record_text("Vec<String>")?, r"Vec\<String\>");
+        assert_eq!(record_text("a|b {c}")?, r"a\|b \{c\}");
+        assert_eq!(identifier(r#"Say "hi""#)?, r#""Say \"hi\"""#);
+        assert!(identifier("two\nlines").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+use super::{association_label, multiplicities, ClassDiagramBackend, ModelClass, RenderedDiagram};
+use crate::{model, FoundationResult};
+use mermaid::class_diagram;
+
+/// Output of the [`MermaidBackend`].
+pub enum MermaidOutput {
+    /// Mermaid source code.
+    Code,
+
+    /// SVG rendered without a browser.
+    Svg,
+
+    /// PNG rasterized from the SVG, with the `png` feature.
+    Png,
+}
+
+pub struct MermaidBackend {
+    output: MermaidOutput,
+}
+
+impl MermaidBackend {
+    pub fn new(output: MermaidOutput) -> Self {
+        Self { output }
+    }
+}
+
+impl ClassDiagramBackend for MermaidBackend {
+    fn class_diagram(
+        &self,
+        model_classes: &[ModelClass],
+        options: &model::DiagramOptions,
+    ) -> FoundationResult<RenderedDiagram> {
+        let mut diagram = class_diagram::owned::ClassDiagram {
+            direction: options.direction.map(to_diagram_direction),
+            ..Default::default()
+        };
+
+        for model_class in model_classes {
+            diagram.add_class(to_diagram_class(model_class));
+        }
+
+        for model_class in model_classes {
+            for association in &model_class.associations {
+                diagram.add_association(to_diagram_association(model_class.model, association));
+            }
+        }
+
+        for model_class in model_classes {
+            if let Some(text) = &model_class.note {
+                diagram.add_note(class_diagram::Note::class(&model_class.model.name, text));
+            }
+        }
+
+        let rendered_diagram = match self.output {
+            MermaidOutput::Code => RenderedDiagram {
+                diagram: diagram.generate()?,
+                image: None,
+            },
+            MermaidOutput::Svg => RenderedDiagram {
+                diagram: class_diagram::render::svg(&diagram)?,
+                image: None,
+            },
+            MermaidOutput::Png => {
+                let svg = class_diagram::render::svg(&diagram)?;
+
+                RenderedDiagram {
+                    image: Some(png(&svg)?),
+                    diagram: svg,
+                }
+            }
+        };
+
+        Ok(rendered_diagram)
+    }
+}
+
+#[cfg(feature = "png")]
+fn png(svg: &str) -> FoundationResult<Vec<u8>> {
+    Ok(class_diagram::render::png(svg)?)
+}
+
+#[cfg(not(feature = "png"))]
+fn png(_svg: &str) -> FoundationResult<Vec<u8>> {
+    Err(crate::FoundationError::failed_precondition(
+        "diagram can't be generated, PNG images need the `png` feature",
+    ))
+}
+
+fn to_diagram_class(model_class: &ModelClass) -> class_diagram::owned::Class {
+    model_class.attributes.iter().fold(
+        class_diagram::owned::Class::new(&model_class.model.name),
+        |class, attribute| class.attribute(&attribute.r#type.name, &attribute.name),
+    )
+}
+
+fn to_diagram_direction(direction: model::DiagramDirection) -> class_diagram::Direction {
+    match direction {
+        model::DiagramDirection::TopToBottom => class_diagram::Direction::TopToBottom,
+        model::DiagramDirection::BottomToTop => class_diagram::Direction::BottomToTop,
+        model::DiagramDirection::LeftToRight => class_diagram::Direction::LeftToRight,
+        model::DiagramDirection::RightToLeft => class_diagram::Direction::RightToLeft,
+    }
+}
+
+fn to_diagram_association(
+    model: &model::Model,
+    association: &model::Association,
+) -> class_diagram::owned::Association {
+    use class_diagram::Relationship;
+
+    let relationship = match association.kind {
+        model::AssociationKind::BelongsTo => Relationship::Association,
+        model::AssociationKind::HasOne => Relationship::Composition,
+        model::AssociationKind::HasMany => Relationship::Aggregation,
+    };
+
+    let (class_cardinality, associated_class_cardinality) = multiplicities(&association.kind);
+
+    class_diagram::owned::Association::new(&model.name, &association.model.name)
+        .relationship(relationship)
+        .cardinalities(class_cardinality, associated_class_cardinality)
+        .label(association_label(association))
+}
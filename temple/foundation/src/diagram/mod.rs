@@ -1,94 +1,356 @@
-use crate::model;
+mod dot;
+mod mermaid_backend;
+mod plantuml_backend;
+
+pub use dot::project_dot_graph;
+pub use mermaid_backend::MermaidBackend;
+pub use plantuml_backend::PlantUmlBackend;
+
+use crate::{model, util, FoundationResult};
+use mermaid::{class_diagram, er_diagram};
 
 pub struct ModelClass<'a> {
     pub model: &'a model::Model,
-    pub attributes: &'a [model::Attribute],
-    pub associations: &'a [model::Association],
+    pub attributes: Vec<&'a model::Attribute>,
+    pub associations: Vec<&'a model::Association>,
+
+    /// Model and attribute descriptions, set by the `descriptions_as_notes`
+    /// option.
+    pub note: Option<String>,
 }
 
-pub fn project_class_diagram(project_class: Vec<ModelClass>) -> String {
-    let diagram_associations = project_class
-        .iter()
-        .flat_map(|model_class| {
+impl<'a> From<&'a model::ModelOverview> for ModelClass<'a> {
+    fn from(value: &'a model::ModelOverview) -> Self {
+        Self {
+            model: &value.model,
+            attributes: value.attributes.iter().collect(),
+            associations: value.associations.iter().collect(),
+            note: None,
+        }
+    }
+}
+
+/// Class diagram in the requested [`model::DiagramFormat`]. The `image` is
+/// only present for the PNG format, in which case the `diagram` holds the SVG
+/// source of the image.
+pub struct RenderedDiagram {
+    pub diagram: String,
+    pub image: Option<Vec<u8>>,
+}
+
+/// Generator of class diagrams in a specific format.
+pub trait ClassDiagramBackend {
+    /// Draws the classes together with their associations. Associated models
+    /// that are not among the classes are drawn without attributes.
+    ///
+    /// Model classes are already filtered, sorted, stripped of attributes
+    /// and given their notes according to the options, backends only handle
+    /// the direction and render the notes.
+    fn class_diagram(
+        &self,
+        model_classes: &[ModelClass],
+        options: &model::DiagramOptions,
+    ) -> FoundationResult<RenderedDiagram>;
+}
+
+pub fn class_diagram_backend(format: model::DiagramFormat) -> Box<dyn ClassDiagramBackend> {
+    use mermaid_backend::MermaidOutput;
+
+    match format {
+        model::DiagramFormat::Mermaid => Box::new(MermaidBackend::new(MermaidOutput::Code)),
+        model::DiagramFormat::Svg => Box::new(MermaidBackend::new(MermaidOutput::Svg)),
+        model::DiagramFormat::Png => Box::new(MermaidBackend::new(MermaidOutput::Png)),
+        model::DiagramFormat::PlantUml => Box::new(PlantUmlBackend),
+    }
+}
+
+pub fn project_class_diagram(
+    project_class: Vec<ModelClass>,
+    format: model::DiagramFormat,
+    options: &model::DiagramOptions,
+) -> FoundationResult<RenderedDiagram> {
+    let project_class = apply_options(project_class, options);
+
+    class_diagram_backend(format).class_diagram(&project_class, options)
+}
+
+pub fn model_class_diagram(
+    model_class: ModelClass,
+    format: model::DiagramFormat,
+    options: &model::DiagramOptions,
+) -> FoundationResult<RenderedDiagram> {
+    let model_class = apply_options(vec![model_class], options);
+
+    class_diagram_backend(format).class_diagram(&model_class, options)
+}
+
+/// Applies the filtering, notes, attribute hiding and ordering options.
+fn apply_options<'a>(
+    model_classes: Vec<ModelClass<'a>>,
+    options: &model::DiagramOptions,
+) -> Vec<ModelClass<'a>> {
+    let is_included = |slug: &str| {
+        options.model_slugs.is_empty()
+            || options
+                .model_slugs
+                .iter()
+                .any(|model_slug| model_slug == slug)
+    };
+
+    let mut model_classes: Vec<ModelClass> = model_classes
+        .into_iter()
+        .filter(|model_class| is_included(&model_class.model.slug))
+        .map(|mut model_class| {
             model_class
                 .associations
-                .iter()
-                .map(|association| to_diagram_association(model_class.model, association))
-                .collect::<Vec<mermaid::class_diagram::Association>>()
+                .retain(|association| is_included(&association.model.slug));
+
+            // Notes are built first, so they keep the attribute descriptions
+            // of the hidden attributes.
+            if options.descriptions_as_notes {
+                model_class.note = description_note(&model_class);
+            }
+
+            if options.hide_attributes {
+                model_class.attributes.clear();
+            }
+
+            if options.sort_alphabetically {
+                model_class.attributes.sort_by(|a, b| a.name.cmp(&b.name));
+                model_class
+                    .associations
+                    .sort_by(|a, b| (&a.name, &a.model.name).cmp(&(&b.name, &b.model.name)));
+            }
+
+            model_class
         })
-        .collect::<Vec<mermaid::class_diagram::Association>>();
+        .collect();
+
+    if options.sort_alphabetically {
+        model_classes.sort_by(|a, b| a.model.name.cmp(&b.model.name));
+    }
+
+    model_classes
+}
+
+/// Label of an association: its description, or the name when the
+/// association has no description.
+fn association_label(association: &model::Association) -> String {
+    let description = association
+        .description
+        .as_deref()
+        .unwrap_or_default()
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ");
+
+    if description.is_empty() {
+        association.name.clone()
+    } else {
+        description
+    }
+}
+
+/// Name and description of an association read from its label, the inverse
+/// of [`association_label`]. A label of words only, like `Books`, is the name,
+/// any other label is the description of an association named after the
+/// associated class.
+pub fn label_association(
+    label: Option<&str>,
+    associated_class_name: &str,
+) -> (String, Option<String>) {
+    let label = label.map(str::trim).unwrap_or_default();
 
-    let diagram_class_attributes = project_class
+    if label.is_empty() {
+        return (associated_class_name.to_string(), None);
+    }
+
+    let is_name = label
+        .chars()
+        .all(|char| char.is_alphanumeric() || char == ' ' || char == '_');
+
+    if is_name {
+        (label.to_string(), None)
+    } else {
+        (associated_class_name.to_string(), Some(label.to_string()))
+    }
+}
+
+/// Text of the note with the model and attribute descriptions.
+fn description_note(model_class: &ModelClass) -> Option<String> {
+    let model_description = model_class
+        .model
+        .description
         .iter()
-        .map(|model_class| {
-            let diagram_class_attributes = model_class
-                .attributes
-                .iter()
-                .map(to_diagram_class_attribute)
-                .collect::<Vec<mermaid::class_diagram::Attribute>>();
+        .filter(|description| !description.trim().is_empty())
+        .map(|description| description.trim().to_string());
 
-            (model_class.model.name.as_str(), diagram_class_attributes)
-        })
-        .collect::<Vec<(&str, Vec<mermaid::class_diagram::Attribute>)>>();
+    let attribute_descriptions = model_class.attributes.iter().filter_map(|attribute| {
+        let description = attribute.description.as_deref()?.trim();
+
+        if description.is_empty() {
+            return None;
+        }
+
+        Some(format!("{}: {description}", attribute.name))
+    });
 
-    let diagram_classes = diagram_class_attributes
+    let lines: Vec<String> = model_description.chain(attribute_descriptions).collect();
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    }
+}
+
+pub fn project_er_diagram(project_class: Vec<ModelClass>) -> FoundationResult<String> {
+    let mut diagram = er_diagram::owned::ErDiagram::default();
+
+    for model_class in &project_class {
+        diagram.add_entity(to_diagram_entity(model_class));
+    }
+
+    for model_class in &project_class {
+        for association in &model_class.associations {
+            // A BelongsTo and its inverse HasOne/HasMany are one relationship,
+            // drawn from the BelongsTo side.
+            if association.kind != model::AssociationKind::BelongsTo
+                && has_inverse_belongs_to(&project_class, model_class.model, association)
+            {
+                continue;
+            }
+
+            diagram.add_relationship(to_diagram_relationship(model_class.model, association));
+        }
+    }
+
+    Ok(diagram.generate()?)
+}
+
+fn has_inverse_belongs_to(
+    project_class: &[ModelClass],
+    model: &model::Model,
+    association: &model::Association,
+) -> bool {
+    project_class
         .iter()
-        .map(|(name, attributes)| mermaid::class_diagram::Class {
-            name,
-            attributes: attributes.as_slice(),
+        .filter(|model_class| model_class.model.slug == association.model.slug)
+        .flat_map(|model_class| &model_class.associations)
+        .any(|associated_model_association| {
+            associated_model_association.kind == model::AssociationKind::BelongsTo
+                && associated_model_association.model.slug == model.slug
         })
-        .collect::<Vec<mermaid::class_diagram::Class>>();
+}
+
+/// Maps class diagram relationship back to the [`model::AssociationKind`].
+/// This is the inverse of the mapping used to draw associations.
+pub fn association_kind(
+    relationship: class_diagram::Relationship,
+    associated_class_cardinality: Option<&str>,
+) -> Option<model::AssociationKind> {
+    use class_diagram::Relationship;
 
-    mermaid::class_diagram::ClassDiagram {
-        associations: diagram_associations.as_slice(),
-        classes: diagram_classes.as_slice(),
+    let is_many = associated_class_cardinality
+        .is_some_and(|cardinality| cardinality.contains('*') || cardinality.contains("many"));
+
+    match relationship {
+        Relationship::Association | Relationship::Aggregation | Relationship::Composition
+            if is_many =>
+        {
+            Some(model::AssociationKind::HasMany)
+        }
+        Relationship::Association => Some(model::AssociationKind::BelongsTo),
+        Relationship::Aggregation | Relationship::Composition => {
+            Some(model::AssociationKind::HasOne)
+        }
+        Relationship::Dependency | Relationship::Inheritance | Relationship::Link => None,
     }
-    .generate()
 }
 
-pub fn model_class_diagram(model_class: ModelClass) -> String {
-    let ModelClass {
-        model,
-        attributes,
-        associations,
-    } = model_class;
+/// Names of the declared classes followed by the names of the classes that
+/// are mentioned in relationships only.
+pub fn class_names(class_diagram: &class_diagram::owned::ClassDiagram) -> Vec<&str> {
+    let mut class_names: Vec<&str> = vec![];
 
-    let diagram_associations = associations
+    let declared_class_names = class_diagram
+        .classes
         .iter()
-        .map(|association| to_diagram_association(model, association))
-        .collect::<Vec<mermaid::class_diagram::Association>>();
+        .map(|class| class.name.as_str());
+    let related_class_names = class_diagram.associations.iter().flat_map(|association| {
+        [
+            association.class_name.as_str(),
+            association.associated_class_name.as_str(),
+        ]
+    });
 
-    let diagram_class_attributes = attributes
-        .iter()
-        .map(to_diagram_class_attribute)
-        .collect::<Vec<mermaid::class_diagram::Attribute>>();
-
-    mermaid::class_diagram::ClassDiagram {
-        associations: diagram_associations.as_slice(),
-        classes: &[mermaid::class_diagram::Class {
-            name: &model.name,
-            attributes: diagram_class_attributes.as_slice(),
-        }],
+    for class_name in declared_class_names.chain(related_class_names) {
+        if !class_names.contains(&class_name) {
+            class_names.push(class_name);
+        }
     }
-    .generate()
+
+    class_names
 }
 
-fn to_diagram_association<'a>(
-    model: &'a model::Model,
-    association: &'a model::Association,
-) -> mermaid::class_diagram::Association<'a> {
-    mermaid::class_diagram::Association {
-        class_name: &model.name,
-        associated_class_name: &association.model.name,
-        description: association.description.as_deref(),
+/// Multiplicities of the model and the associated model.
+fn multiplicities(kind: &model::AssociationKind) -> (&'static str, &'static str) {
+    match kind {
+        model::AssociationKind::BelongsTo => ("*", "1"),
+        model::AssociationKind::HasOne => ("1", "0..1"),
+        model::AssociationKind::HasMany => ("1", "*"),
     }
 }
 
-fn to_diagram_class_attribute(
-    attribute: &model::Attribute,
-) -> mermaid::class_diagram::Attribute<'_> {
-    mermaid::class_diagram::Attribute {
-        kind: &attribute.r#type.name,
-        name: &attribute.name,
+fn to_diagram_entity(model_class: &ModelClass) -> er_diagram::owned::Entity {
+    let mut entity = er_diagram::owned::Entity::new(&model_class.model.name);
+
+    entity.attributes.push(er_diagram::owned::Attribute::new(
+        "uuid",
+        "id",
+        Some(er_diagram::Key::Primary),
+    ));
+
+    for attribute in &model_class.attributes {
+        entity.attributes.push(er_diagram::owned::Attribute::new(
+            &attribute.r#type.name,
+            &attribute.name,
+            None,
+        ));
     }
+
+    for association in &model_class.associations {
+        if association.kind != model::AssociationKind::BelongsTo {
+            continue;
+        }
+
+        entity.attributes.push(er_diagram::owned::Attribute::new(
+            "uuid",
+            format!("{}_id", util::string::snake_case(&association.name)),
+            Some(er_diagram::Key::Foreign),
+        ));
+    }
+
+    entity
+}
+
+fn to_diagram_relationship(
+    model: &model::Model,
+    association: &model::Association,
+) -> er_diagram::owned::Relationship {
+    use er_diagram::Cardinality::*;
+
+    let (entity_cardinality, related_entity_cardinality) = match association.kind {
+        model::AssociationKind::BelongsTo => (ZeroOrMore, ExactlyOne),
+        model::AssociationKind::HasOne => (ExactlyOne, ZeroOrOne),
+        model::AssociationKind::HasMany => (ExactlyOne, ZeroOrMore),
+    };
+
+    er_diagram::owned::Relationship::new(
+        &model.name,
+        entity_cardinality,
+        &association.model.name,
+        related_entity_cardinality,
+        &association.name,
+    )
 }
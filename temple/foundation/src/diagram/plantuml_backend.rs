@@ -0,0 +1,224 @@
+use super::{association_label, multiplicities, ClassDiagramBackend, ModelClass, RenderedDiagram};
+use crate::{model, FoundationError, FoundationResult};
+use std::collections::HashMap;
+
+/// Generates [PlantUML](https://plantuml.com/class-diagram) class diagrams.
+///
+/// Class names which are not valid PlantUML identifiers are declared with an
+/// alias, e.g. `class "Order Item" as Order_Item`.
+pub struct PlantUmlBackend;
+
+impl ClassDiagramBackend for PlantUmlBackend {
+    fn class_diagram(
+        &self,
+        model_classes: &[ModelClass],
+        options: &model::DiagramOptions,
+    ) -> FoundationResult<RenderedDiagram> {
+        let mut identifiers = Identifiers::default();
+        let mut classes = vec![];
+
+        for model_class in model_classes {
+            let attributes = model_class
+                .attributes
+                .iter()
+                .map(|attribute| {
+                    Ok(format!(
+                        "    +{} : {}",
+                        text(&attribute.name)?,
+                        text(&attribute.r#type.name)?
+                    ))
+                })
+                .collect::<FoundationResult<Vec<String>>>()?;
+
+            let declaration = identifiers.declaration(&model_class.model.name)?;
+
+            if attributes.is_empty() {
+                classes.push(format!("class {declaration}"));
+            } else {
+                classes.push(format!(
+                    "class {declaration} {{\n{}\n}}",
+                    attributes.join("\n")
+                ));
+            }
+        }
+
+        let mut associations = vec![];
+
+        for model_class in model_classes {
+            for association in &model_class.associations {
+                let associated_model_name = &association.model.name;
+
+                if !identifiers.contains(associated_model_name) {
+                    classes.push(format!(
+                        "class {}",
+                        identifiers.declaration(associated_model_name)?
+                    ));
+                }
+
+                associations.push(association_code(
+                    &identifiers,
+                    model_class.model,
+                    association,
+                )?);
+            }
+        }
+
+        let mut notes = vec![];
+
+        for model_class in model_classes {
+            if let Some(note) = &model_class.note {
+                notes.push(format!(
+                    "note right of {}\n{}\nend note",
+                    identifiers.get(&model_class.model.name),
+                    note
+                ));
+            }
+        }
+
+        let mut code = "@startuml\n".to_string();
+
+        // PlantUML has no bottom to top or right to left layouts.
+        match options.direction {
+            Some(model::DiagramDirection::TopToBottom | model::DiagramDirection::BottomToTop) => {
+                code.push_str("top to bottom direction\n")
+            }
+            Some(model::DiagramDirection::LeftToRight | model::DiagramDirection::RightToLeft) => {
+                code.push_str("left to right direction\n")
+            }
+            None => {}
+        }
+
+        if !classes.is_empty() {
+            code.push_str(&format!("{}\n", classes.join("\n")));
+        }
+
+        if !associations.is_empty() {
+            code.push_str(&format!("\n{}\n", associations.join("\n")));
+        }
+
+        if !notes.is_empty() {
+            code.push_str(&format!("\n{}\n", notes.join("\n")));
+        }
+
+        code.push_str("@enduml\n");
+
+        Ok(RenderedDiagram {
+            diagram: code,
+            image: None,
+        })
+    }
+}
+
+fn association_code(
+    identifiers: &Identifiers,
+    model: &model::Model,
+    association: &model::Association,
+) -> FoundationResult<String> {
+    let arrow = match association.kind {
+        model::AssociationKind::BelongsTo => "-->",
+        model::AssociationKind::HasOne => "*--",
+        model::AssociationKind::HasMany => "o--",
+    };
+
+    let (class_multiplicity, associated_class_multiplicity) = multiplicities(&association.kind);
+
+    Ok(format!(
+        "{} \"{class_multiplicity}\" {arrow} \"{associated_class_multiplicity}\" {} : {}",
+        identifiers.get(&model.name),
+        identifiers.get(&association.model.name),
+        text(&association_label(association))?
+    ))
+}
+
+/// Unique PlantUML identifiers of the declared classes.
+#[derive(Default)]
+struct Identifiers {
+    identifiers: HashMap<String, String>,
+    names: HashMap<String, String>,
+}
+
+impl Identifiers {
+    fn contains(&self, name: &str) -> bool {
+        self.identifiers.contains_key(name)
+    }
+
+    fn get<'a>(&'a self, name: &'a str) -> &'a str {
+        self.identifiers
+            .get(name)
+            .map(String::as_str)
+            .unwrap_or(name)
+    }
+
+    /// Registers the class name and returns its declaration.
+    fn declaration(&mut self, name: &str) -> FoundationResult<String> {
+        let name = text(name)?;
+
+        if name.contains('"') || !name.chars().any(char::is_alphanumeric) {
+            return Err(FoundationError::failed_precondition(format!(
+                "diagram can't be generated, class name `{name}` can't be represented in PlantUML"
+            )));
+        }
+
+        let identifier: String = name
+            .chars()
+            .map(|char| if char.is_alphanumeric() { char } else { '_' })
+            .collect();
+
+        if let Some(other_name) = self.names.get(&identifier) {
+            return Err(FoundationError::failed_precondition(format!(
+                "diagram can't be generated, `{name}` and `{other_name}` share the same identifier `{identifier}`"
+            )));
+        }
+
+        self.identifiers.insert(name.clone(), identifier.clone());
+        self.names.insert(identifier.clone(), name.clone());
+
+        if identifier == name {
+            Ok(identifier)
+        } else {
+            Ok(format!("\"{name}\" as {identifier}"))
+        }
+    }
+}
+
+/// PlantUML statements are line based, text can't contain line breaks.
+fn text(text: &str) -> FoundationResult<String> {
+    if text.chars().any(char::is_control) {
+        return Err(FoundationError::failed_precondition(format!(
+            "diagram can't be generated, text `{text}` can't be represented in PlantUML"
+        )));
+    }
+
+    Ok(text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FoundationErrorCode;
+
+    #[test]
+    fn it_declares_classes_with_aliases() -> FoundationResult<()> {
+        let mut identifiers = Identifiers::default();
+
+        assert_eq!(identifiers.declaration("Book")?, "Book");
+        assert_eq!(
+            identifiers.declaration("Order Item")?,
+            r#""Order Item" as Order_Item"#
+        );
+        assert_eq!(identifiers.get("Order Item"), "Order_Item");
+
+        let error = identifiers.declaration("Order-Item").err().unwrap();
+
+        assert!(matches!(
+            error.code(),
+            FoundationErrorCode::FailedPrecondition
+        ));
+        assert_eq!(
+            error.message(),
+            "diagram can't be generated, `Order-Item` and `Order Item` share the same identifier `Order_Item`"
+        );
+
+        Ok(())
+    }
+}
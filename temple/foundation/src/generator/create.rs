@@ -0,0 +1,213 @@
+//! [`Generator`]'s creation.
+
+use super::{CreateGeneratorRecord, Generator, GeneratorTemplate};
+use crate::{codegen, util, FoundationError, FoundationResult};
+
+pub struct Request {
+    pub name: String,
+    pub description: String,
+    pub templates: Vec<GeneratorTemplate>,
+}
+
+/// Create a [`Generator`] with the templates that compile.
+pub async fn execute(
+    repo: &impl CreateGeneratorRecord,
+    request: Request,
+) -> FoundationResult<Generator> {
+    validate_request(&request)?;
+
+    let Request {
+        name,
+        description,
+        templates,
+    } = request;
+
+    let generator = repo
+        .create_generator_record(Generator {
+            description: util::string::optional(&description),
+            slug: util::slug::sluggify(&name),
+            name,
+            templates,
+        })
+        .await?
+        .into();
+
+    Ok(generator)
+}
+
+fn validate_request(request: &Request) -> FoundationResult<()> {
+    let Request {
+        name,
+        description: _,
+        templates,
+    } = request;
+
+    let validation_errors = util::validator::Validator::new()
+        .validate_required("name", name)
+        .validate_max_length("name", name, 50)
+        .validate();
+
+    if let Some(validation_error) = validation_errors.first() {
+        return Err(validation_error.clone().into());
+    }
+
+    if templates.is_empty() {
+        return Err(FoundationError::invalid_argument(
+            "templates can't be blank",
+        ));
+    }
+
+    codegen::compile_templates(templates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generator::{tests::GeneratorRepo, TemplateScope},
+        FoundationErrorCode,
+    };
+
+    fn template() -> GeneratorTemplate {
+        GeneratorTemplate {
+            path: "models/{{ model.slug }}.md".to_string(),
+            content: "# {{ model.name }}\n".to_string(),
+            scope: TemplateScope::Model,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_creates_generator() -> FoundationResult<()> {
+        let repo = GeneratorRepo::new();
+
+        let generator = execute(
+            &repo,
+            Request {
+                name: "Model Docs".to_string(),
+                description: "Page per model".to_string(),
+                templates: vec![template()],
+            },
+        )
+        .await?;
+
+        assert_eq!(
+            repo.records()
+                .await
+                .into_iter()
+                .map(Into::<Generator>::into)
+                .collect::<Vec<Generator>>(),
+            vec![generator.clone()]
+        );
+
+        assert_eq!(
+            generator,
+            Generator {
+                description: Some("Page per model".to_string()),
+                name: "Model Docs".to_string(),
+                slug: "model-docs".to_string(),
+                templates: vec![template()],
+            }
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_validates_templates() -> FoundationResult<()> {
+        let repo = GeneratorRepo::new();
+
+        let test_table = [
+            (vec![], "templates can't be blank"),
+            (
+                vec![template(), template()],
+                "more than one template has the path `models/{{ model.slug }}.md`",
+            ),
+            (
+                vec![GeneratorTemplate {
+                    path: " ".to_string(),
+                    ..template()
+                }],
+                "template path can't be blank",
+            ),
+        ];
+
+        for (templates, expected_message) in test_table {
+            let error = execute(
+                &repo,
+                Request {
+                    name: "Model Docs".to_string(),
+                    description: "".to_string(),
+                    templates,
+                },
+            )
+            .await
+            .unwrap_err();
+
+            assert!(matches!(error.code(), FoundationErrorCode::InvalidArgument));
+            assert_eq!(error.message(), expected_message);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_rejects_templates_which_do_not_compile() -> FoundationResult<()> {
+        let repo = GeneratorRepo::new();
+
+        let error = execute(
+            &repo,
+            Request {
+                name: "Model Docs".to_string(),
+                description: "".to_string(),
+                templates: vec![GeneratorTemplate {
+                    content: "# {{ model.name }\n".to_string(),
+                    ..template()
+                }],
+            },
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(error.code(), FoundationErrorCode::InvalidArgument));
+        assert!(error
+            .message()
+            .starts_with("template `models/{{ model.slug }}.md` is invalid, "));
+        assert!(repo.records().await.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_validates_name() -> FoundationResult<()> {
+        let repo = GeneratorRepo::new();
+
+        let test_table = [
+            (
+                "".to_string(),
+                FoundationError::invalid_argument("name can't be blank"),
+            ),
+            (
+                "x".repeat(51),
+                FoundationError::invalid_argument("name is too long, maximum length is 50 bytes"),
+            ),
+        ];
+
+        for (name, expected_error) in test_table {
+            let error = execute(
+                &repo,
+                Request {
+                    name,
+                    description: "".to_string(),
+                    templates: vec![template()],
+                },
+            )
+            .await
+            .unwrap_err();
+
+            assert_eq!(error.code(), expected_error.code());
+            assert_eq!(error.message(), expected_error.message());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+//! [`Generator`]'s deletion logic.
+
+use super::{validate_slug, DeleteGeneratorRecord, GetGeneratorRecord};
+use crate::{FoundationError, FoundationResult};
+
+/// Delete [`Generator`] by slug.
+pub async fn execute(
+    repo: &(impl GetGeneratorRecord + DeleteGeneratorRecord),
+    slug: &str,
+) -> FoundationResult<()> {
+    validate_slug(slug)?;
+
+    let generator_record = repo
+        .get_generator_record(slug)
+        .await?
+        .ok_or(FoundationError::not_found("generator not found"))?;
+
+    repo.delete_generator_record(generator_record).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generator::tests::{generator_record_fixture, GeneratorRepo},
+        FoundationErrorCode,
+    };
+
+    #[tokio::test]
+    async fn it_deletes_generator() -> FoundationResult<()> {
+        let repo = GeneratorRepo::new();
+        let record = generator_record_fixture(&repo).await;
+
+        execute(&repo, &record.inner.slug).await?;
+
+        assert!(repo.records().await.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_returns_invalid_argument_error() -> FoundationResult<()> {
+        let repo = GeneratorRepo::new();
+
+        let error = execute(&repo, "").await.unwrap_err();
+
+        assert!(matches!(error.code(), FoundationErrorCode::InvalidArgument));
+        assert_eq!(error.message(), "slug can't be blank");
+
+        Ok(())
+    }
+}
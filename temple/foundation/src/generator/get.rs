@@ -0,0 +1,51 @@
+//! Get single [`Generator`].
+
+use super::{validate_slug, Generator, GetGeneratorRecord};
+use crate::{FoundationError, FoundationResult};
+
+/// Find [`Generator`] by slug.
+pub async fn execute(repo: &impl GetGeneratorRecord, slug: &str) -> FoundationResult<Generator> {
+    validate_slug(slug)?;
+
+    let generator = repo
+        .get_generator_record(slug)
+        .await?
+        .ok_or(FoundationError::not_found("generator not found"))?
+        .into();
+
+    Ok(generator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generator::tests::{generator_record_fixture, GeneratorRepo},
+        FoundationErrorCode,
+    };
+
+    #[tokio::test]
+    async fn it_returns_generator() -> FoundationResult<()> {
+        let repo = GeneratorRepo::new();
+        let record = generator_record_fixture(&repo).await;
+        let generator: Generator = record.into();
+
+        let found_generator = execute(&repo, &generator.slug).await?;
+
+        assert_eq!(found_generator, generator);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_returns_not_found_error() -> FoundationResult<()> {
+        let repo = GeneratorRepo::new();
+
+        let error = execute(&repo, "model-docs").await.unwrap_err();
+
+        assert!(matches!(error.code(), FoundationErrorCode::NotFound));
+        assert_eq!(error.message(), "generator not found");
+
+        Ok(())
+    }
+}
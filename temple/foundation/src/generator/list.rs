@@ -0,0 +1,37 @@
+//! [`Generator`]s listing.
+
+use crate::{
+    generator::{Generator, ListGeneratorRecords},
+    FoundationResult,
+};
+
+/// List all [`Generator`]s.
+pub async fn execute(repo: &impl ListGeneratorRecords) -> FoundationResult<Vec<Generator>> {
+    let generators = repo
+        .list_generator_records()
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    Ok(generators)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::tests::{generator_record_fixture, GeneratorRepo};
+
+    #[tokio::test]
+    async fn it_returns_generators_list() -> FoundationResult<()> {
+        let repo = GeneratorRepo::new();
+        let record = generator_record_fixture(&repo).await;
+        let generator: Generator = record.into();
+
+        let generators = execute(&repo).await?;
+
+        assert_eq!(vec![generator], generators);
+
+        Ok(())
+    }
+}
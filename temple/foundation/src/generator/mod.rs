@@ -0,0 +1,130 @@
+//! This module is dedicated to the [`Generator`] entity and the operations
+//! on it.
+//!
+//! Generators are user defined sets of [Tera](https://keats.github.io/tera/)
+//! templates, rendered with the project models to produce source files which
+//! follow the conventions of the team.
+//!
+//! Both the path and the content of a [`GeneratorTemplate`] are templates,
+//! rendered with the following context:
+//!
+//! - `project.slug` is the slug of the project.
+//! - `models` lists the models of the project, ordered by name.
+//! - `model` is the model the file is rendered for, only given to the
+//!   [`TemplateScope::Model`] templates.
+//!
+//! Each model is an object with the following fields:
+//!
+//! - `name`, `slug` and `description` of the model, `description` is `null`
+//!   when blank.
+//! - `attributes`, each with its `name`, `description` and `type`. The type
+//!   has the `name`, `slug` and `description` of the attribute type, and its
+//!   `kind`, the `snake_case` name of the well known type recognized by the
+//!   slug like `big_integer` or `date_time`, `text` for any other type.
+//! - `associations`, each with its `name`, `description`, `kind`, one of
+//!   `belongs_to`, `has_one` and `has_many`, and the associated `model` with
+//!   its `name`, `slug` and `description`.
+//!
+//! On top of the Tera built-in filters, the `snake_case`, `pascal_case`,
+//! `pluralize` and `singularize` filters are available.
+
+mod create;
+mod delete;
+mod get;
+mod list;
+
+pub mod tests;
+
+use crate::{datastore::Record, util, FoundationResult};
+
+pub use create::{execute as create, Request as CreateRequest};
+pub use delete::execute as delete;
+pub use get::execute as get;
+pub use list::execute as list;
+
+#[async_trait::async_trait]
+pub trait CreateGeneratorRecord {
+    async fn create_generator_record(
+        &self,
+        generator: Generator,
+    ) -> FoundationResult<GeneratorRecord>;
+}
+
+#[async_trait::async_trait]
+pub trait ListGeneratorRecords {
+    async fn list_generator_records(&self) -> FoundationResult<Vec<GeneratorRecord>>;
+}
+
+#[async_trait::async_trait]
+pub trait GetGeneratorRecord {
+    async fn get_generator_record(&self, slug: &str) -> FoundationResult<Option<GeneratorRecord>>;
+}
+
+#[async_trait::async_trait]
+pub trait DeleteGeneratorRecord {
+    async fn delete_generator_record(
+        &self,
+        generator_record: GeneratorRecord,
+    ) -> FoundationResult<()>;
+}
+
+#[derive(Default, Clone, Debug, PartialEq)]
+/// Represents a user defined code generator.
+pub struct Generator {
+    /// An optional hint about what the generated files are for.
+    pub description: Option<String>,
+
+    /// Name that is unique within the list of all [`Generator`]s.
+    pub name: String,
+
+    /// Web identifier that is unique within the list of all [`Generator`]s.
+    pub slug: String,
+
+    /// Templates of the generated files.
+    pub templates: Vec<GeneratorTemplate>,
+}
+
+#[derive(Default, Clone, Debug, PartialEq)]
+/// Template of the files produced by a [`Generator`].
+pub struct GeneratorTemplate {
+    /// Template of the path of the file, relative to the root of the
+    /// generated files.
+    pub path: String,
+
+    /// Template of the content of the file.
+    pub content: String,
+
+    pub scope: TemplateScope,
+}
+
+/// How many files a [`GeneratorTemplate`] produces.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TemplateScope {
+    /// Single file for the whole project.
+    #[default]
+    Project,
+
+    /// File per model, the path has to be different for every model.
+    Model,
+}
+
+/// Repository [`Generator`] representation.
+pub type GeneratorRecord = Record<Generator>;
+
+impl From<GeneratorRecord> for Generator {
+    fn from(value: GeneratorRecord) -> Self {
+        value.into_inner()
+    }
+}
+
+fn validate_slug(slug: &str) -> FoundationResult<()> {
+    let validation_errors = util::validator::Validator::new()
+        .validate_required("slug", slug)
+        .validate();
+
+    if validation_errors.is_empty() {
+        return Ok(());
+    }
+
+    Err(validation_errors.first().cloned().unwrap().into())
+}
@@ -0,0 +1,90 @@
+#![cfg(test)]
+
+use super::*;
+use crate::{
+    datastore::tests::{RecordFactory, Repo},
+    Utc, Uuid,
+};
+
+struct GeneratorFactory {}
+
+impl GeneratorFactory {
+    fn build() -> Generator {
+        Generator {
+            description: Some("Page per model".to_string()),
+            name: "Model Docs".to_string(),
+            slug: "model-docs".to_string(),
+            templates: vec![GeneratorTemplate {
+                path: "models/{{ model.slug }}.md".to_string(),
+                content: "# {{ model.name }}\n".to_string(),
+                scope: TemplateScope::Model,
+            }],
+        }
+    }
+}
+
+pub type GeneratorRepo = Repo<Generator>;
+
+#[async_trait::async_trait]
+impl CreateGeneratorRecord for GeneratorRepo {
+    async fn create_generator_record(
+        &self,
+        generator: Generator,
+    ) -> FoundationResult<GeneratorRecord> {
+        let now = Utc::now();
+
+        let generator_record = GeneratorRecord {
+            id: Uuid::new_v4(),
+            inner: generator,
+            inserted_at: now,
+            updated_at: now,
+        };
+
+        self.save(generator_record.clone()).await;
+
+        Ok(generator_record)
+    }
+}
+
+#[async_trait::async_trait]
+impl DeleteGeneratorRecord for GeneratorRepo {
+    async fn delete_generator_record(
+        &self,
+        generator_record: GeneratorRecord,
+    ) -> FoundationResult<()> {
+        let mut records = self.records.write().await;
+
+        records.remove(&generator_record.id);
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ListGeneratorRecords for GeneratorRepo {
+    async fn list_generator_records(&self) -> FoundationResult<Vec<GeneratorRecord>> {
+        Ok(self.records().await)
+    }
+}
+
+#[async_trait::async_trait]
+impl GetGeneratorRecord for GeneratorRepo {
+    async fn get_generator_record(&self, slug: &str) -> FoundationResult<Option<GeneratorRecord>> {
+        let maybe_record = self
+            .records()
+            .await
+            .iter()
+            .find(|record| record.inner.slug == slug)
+            .cloned();
+
+        Ok(maybe_record)
+    }
+}
+
+pub async fn generator_record_fixture(repo: &GeneratorRepo) -> GeneratorRecord {
+    let generator = GeneratorFactory::build();
+    let generator_record = RecordFactory::build(&generator);
+    repo.save(generator_record.clone()).await;
+
+    generator_record
+}
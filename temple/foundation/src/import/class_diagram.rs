@@ -0,0 +1,153 @@
+use super::{Schema, SchemaAssociation, SchemaAttribute, SchemaModel};
+use crate::{diagram, model, FoundationError, FoundationResult};
+use mermaid::class_diagram::{owned::Association, parser};
+
+/// Reads the classes of a mermaid `classDiagram`.
+///
+/// Classes, declared or only related, become models, and their members become
+/// attributes whose attribute type is named after the member type.
+/// Associations, aggregations and compositions become associations, read from
+/// their labels as the exported diagrams write them, see
+/// [`diagram::label_association`].
+///
+/// The other relationships are reported as skipped.
+pub fn class_diagram_schema(diagram: &str) -> FoundationResult<Schema> {
+    let class_diagram = parser::parse(diagram)
+        .map_err(|err| FoundationError::invalid_argument(format!("invalid diagram, {err}")))?;
+
+    let mut schema = Schema::default();
+
+    for class_name in diagram::class_names(&class_diagram) {
+        let attributes = class_diagram
+            .classes
+            .iter()
+            .filter(|class| class.name == class_name)
+            .flat_map(|class| &class.attributes)
+            .map(|attribute| SchemaAttribute {
+                name: attribute.name.clone(),
+                description: None,
+                type_name: attribute.kind.clone(),
+            })
+            .collect();
+
+        schema.models.push(SchemaModel {
+            name: class_name.to_string(),
+            description: None,
+            attributes,
+            associations: vec![],
+        });
+    }
+
+    for association in &class_diagram.associations {
+        let Association {
+            class_name,
+            relationship,
+            associated_class_name,
+            associated_class_cardinality,
+            label,
+            ..
+        } = association;
+
+        let (name, description) =
+            diagram::label_association(label.as_deref(), associated_class_name);
+
+        let Some(kind) =
+            diagram::association_kind(*relationship, associated_class_cardinality.as_deref())
+        else {
+            schema.skipped.push(model::SkippedConstruct {
+                location: format!("{class_name}.{name}"),
+                construct: format!("{relationship:?}").to_lowercase(),
+                reason: "only associations, aggregations and compositions are imported"
+                    .to_string(),
+            });
+
+            continue;
+        };
+
+        let Some(schema_model) = schema
+            .models
+            .iter_mut()
+            .find(|schema_model| &schema_model.name == class_name)
+        else {
+            return Err(FoundationError::internal("imported model is missing"));
+        };
+
+        schema_model.associations.push(SchemaAssociation {
+            name,
+            description,
+            kind,
+            model_name: associated_class_name.clone(),
+        });
+    }
+
+    Ok(schema)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_reads_classes_and_their_relationships() -> FoundationResult<()> {
+        let schema = class_diagram_schema(
+            r#"classDiagram
+    class Book {
+        +Bigint pages
+    }
+
+    Book "*" --> "1" Publisher : Publisher
+    Publisher "1" o-- "*" Book : Books in print, newest first.
+    Publisher <|-- Imprint
+"#,
+        )?;
+
+        assert_eq!(
+            schema.models,
+            vec![
+                SchemaModel {
+                    name: "Book".to_string(),
+                    description: None,
+                    attributes: vec![SchemaAttribute {
+                        name: "pages".to_string(),
+                        description: None,
+                        type_name: "Bigint".to_string(),
+                    }],
+                    associations: vec![SchemaAssociation {
+                        name: "Publisher".to_string(),
+                        description: None,
+                        kind: model::AssociationKind::BelongsTo,
+                        model_name: "Publisher".to_string(),
+                    }],
+                },
+                SchemaModel {
+                    name: "Publisher".to_string(),
+                    description: None,
+                    attributes: vec![],
+                    associations: vec![SchemaAssociation {
+                        name: "Book".to_string(),
+                        description: Some("Books in print, newest first.".to_string()),
+                        kind: model::AssociationKind::HasMany,
+                        model_name: "Book".to_string(),
+                    }],
+                },
+                SchemaModel {
+                    name: "Imprint".to_string(),
+                    description: None,
+                    attributes: vec![],
+                    associations: vec![],
+                },
+            ]
+        );
+
+        assert_eq!(
+            schema.skipped,
+            vec![model::SkippedConstruct {
+                location: "Publisher.Imprint".to_string(),
+                construct: "inheritance".to_string(),
+                reason: "only associations, aggregations and compositions are imported".to_string(),
+            }]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1076 @@
+use super::{
+    table::{self, AssociationNames, Column, ForeignKey, Table},
+    Schema,
+};
+use crate::{model, util, FoundationError, FoundationResult};
+
+/// Reads the tables of a DBML schema, the language of dbdiagram.io.
+///
+/// Tables become models and their columns become attributes typed after the
+/// well known attribute type of the column type, like the tables of
+/// [`super::postgresql_schema`]. References become a `BelongsTo` association
+/// of the table with the foreign key, many-to-one references add the inverse
+/// `HasMany` association to the referenced model and one-to-one references
+/// the inverse `HasOne` association. References named like
+/// `Ref "Publisher/Books"`, as the DBML exports name them, keep the names of
+/// both associations and leave out the side with an empty name.
+/// Notes of the tables and the columns become descriptions.
+///
+/// Everything else, like the `Project`, `Enum` and sticky `Note` blocks, is
+/// reported as skipped.
+pub fn dbml_schema(dbml: &str) -> FoundationResult<Schema> {
+    let tokens = tokens(dbml)?;
+    let mut parser = Parser::new(&tokens);
+
+    parser.schema()?;
+
+    let Parser {
+        mut tables,
+        references,
+        aliases,
+        mut skipped,
+        ..
+    } = parser;
+
+    let table_name = |name: String| {
+        aliases
+            .iter()
+            .find(|(alias, _)| *alias == name)
+            .map(|(_, table_name)| table_name.clone())
+            .unwrap_or(name)
+    };
+
+    for reference in references {
+        let Reference {
+            line,
+            name,
+            left,
+            relationship,
+            right,
+        } = reference;
+
+        let (foreign_key, referenced) = match relationship {
+            Relationship::ManyToOne => (left, right),
+            Relationship::OneToMany => (right, left),
+            Relationship::OneToOne => {
+                let is_primary_key = |endpoint: &Endpoint| {
+                    tables.iter().any(|table| {
+                        table.name == table_name(endpoint.table.clone())
+                            && table.primary_key == endpoint.columns
+                    })
+                };
+
+                if is_primary_key(&left) && !is_primary_key(&right) {
+                    (right, left)
+                } else {
+                    (left, right)
+                }
+            }
+            Relationship::ManyToMany => {
+                skipped.push(model::SkippedConstruct {
+                    location: format!("line {line}"),
+                    construct: "Ref".to_string(),
+                    reason: "many-to-many references need a join table".to_string(),
+                });
+
+                continue;
+            }
+        };
+
+        let foreign_key_table = table_name(foreign_key.table);
+
+        let Some(table) = tables
+            .iter_mut()
+            .find(|table| table.name == foreign_key_table)
+        else {
+            skipped.push(model::SkippedConstruct {
+                location: format!("line {line}"),
+                construct: "Ref".to_string(),
+                reason: format!("table `{foreign_key_table}` isn't part of the schema"),
+            });
+
+            continue;
+        };
+
+        if relationship == Relationship::OneToOne {
+            table.unique_keys.push(foreign_key.columns.clone());
+        }
+
+        table.foreign_keys.push(ForeignKey {
+            columns: foreign_key.columns,
+            referenced_table: table_name(referenced.table),
+            association_names: name.as_deref().and_then(association_names),
+        });
+    }
+
+    let mut schema = table::models(&tables);
+    skipped.append(&mut schema.skipped);
+    schema.skipped = skipped;
+
+    Ok(schema)
+}
+
+/// Association names of a `Ref` named `"Publisher/Books"`, the name of the
+/// `BelongsTo` association and the name of its inverse association. An empty
+/// name leaves out the association, e.g. `"/Orders"` for a `HasMany` without
+/// an inverse `BelongsTo`.
+fn association_names(name: &str) -> Option<AssociationNames> {
+    let (name, inverse_name) = name.split_once('/')?;
+    let association_name =
+        |name: &str| Some(name.trim().to_string()).filter(|name| !name.is_empty());
+
+    Some(AssociationNames {
+        name: association_name(name),
+        inverse_name: association_name(inverse_name),
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Relationship {
+    /// `>`
+    ManyToOne,
+
+    /// `<`
+    OneToMany,
+
+    /// `-`
+    OneToOne,
+
+    /// `<>`
+    ManyToMany,
+}
+
+struct Endpoint {
+    table: String,
+    columns: Vec<String>,
+}
+
+struct Reference {
+    line: usize,
+    name: Option<String>,
+    left: Endpoint,
+    relationship: Relationship,
+    right: Endpoint,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Word(String),
+
+    /// Double quoted name.
+    QuotedName(String),
+
+    /// Single or triple quoted string.
+    String(String),
+
+    /// Backtick quoted expression, like `now()`.
+    Expression(String),
+
+    Symbol(char),
+
+    Newline,
+}
+
+struct LineToken {
+    value: Token,
+    line: usize,
+}
+
+fn tokens(dbml: &str) -> FoundationResult<Vec<LineToken>> {
+    let chars: Vec<char> = dbml.chars().collect();
+    let mut tokens = vec![];
+    let mut line = 1;
+    let mut index = 0;
+
+    while let Some(&char) = chars.get(index) {
+        let start_line = line;
+
+        let value = match char {
+            '\n' => {
+                line += 1;
+                index += 1;
+
+                Token::Newline
+            }
+            char if char.is_whitespace() => {
+                index += 1;
+
+                continue;
+            }
+            '/' if chars.get(index + 1) == Some(&'/') => {
+                while chars.get(index).is_some_and(|char| *char != '\n') {
+                    index += 1;
+                }
+
+                continue;
+            }
+            '/' if chars.get(index + 1) == Some(&'*') => {
+                index += 2;
+
+                loop {
+                    match (chars.get(index), chars.get(index + 1)) {
+                        (Some('*'), Some('/')) => {
+                            index += 2;
+
+                            break;
+                        }
+                        (Some(char), _) => {
+                            if *char == '\n' {
+                                line += 1;
+                            }
+
+                            index += 1;
+                        }
+                        (None, _) => return Err(syntax_error(start_line, "unterminated comment")),
+                    }
+                }
+
+                continue;
+            }
+            '\'' if chars[index..].starts_with(&['\'', '\'', '\'']) => {
+                index += 3;
+
+                let mut text = String::new();
+
+                loop {
+                    if chars[index..].starts_with(&['\'', '\'', '\'']) {
+                        index += 3;
+
+                        break;
+                    }
+
+                    match chars.get(index) {
+                        Some('\\') if index + 1 < chars.len() => {
+                            text.push(chars[index + 1]);
+                            index += 2;
+                        }
+                        Some(char) => {
+                            if *char == '\n' {
+                                line += 1;
+                            }
+
+                            text.push(*char);
+                            index += 1;
+                        }
+                        None => return Err(syntax_error(start_line, "unterminated string")),
+                    }
+                }
+
+                Token::String(dedent(&text))
+            }
+            '\'' | '"' | '`' => {
+                index += 1;
+
+                let mut text = String::new();
+
+                loop {
+                    match chars.get(index) {
+                        Some('\\') if index + 1 < chars.len() => {
+                            let escaped = match chars[index + 1] {
+                                'n' => '\n',
+                                't' => '\t',
+                                escaped => escaped,
+                            };
+
+                            text.push(escaped);
+                            index += 2;
+                        }
+                        Some(quote) if *quote == char => {
+                            index += 1;
+
+                            break;
+                        }
+                        Some('\n') | None => {
+                            return Err(syntax_error(start_line, "unterminated string"));
+                        }
+                        Some(other) => {
+                            text.push(*other);
+                            index += 1;
+                        }
+                    }
+                }
+
+                match char {
+                    '\'' => Token::String(text),
+                    '"' => Token::QuotedName(text),
+                    _ => Token::Expression(text),
+                }
+            }
+            char if char.is_alphanumeric() || char == '_' => {
+                let start = index;
+
+                while chars
+                    .get(index)
+                    .is_some_and(|char| char.is_alphanumeric() || *char == '_')
+                {
+                    index += 1;
+                }
+
+                Token::Word(chars[start..index].iter().collect())
+            }
+            char => {
+                index += 1;
+
+                Token::Symbol(char)
+            }
+        };
+
+        tokens.push(LineToken {
+            value,
+            line: start_line,
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// Text of a triple quoted string without its first and last line breaks and
+/// the indentation its lines have in common.
+fn dedent(text: &str) -> String {
+    let text = text.strip_prefix('\n').unwrap_or(text);
+    let text = text.trim_end();
+
+    let indentation = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    text.lines()
+        .map(|line| line.get(indentation..).unwrap_or(line.trim_start()))
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+struct Parser<'a> {
+    tokens: &'a [LineToken],
+    position: usize,
+    tables: Vec<Table>,
+    references: Vec<Reference>,
+
+    /// Table names by their alias.
+    aliases: Vec<(String, String)>,
+
+    skipped: Vec<model::SkippedConstruct>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(tokens: &'a [LineToken]) -> Self {
+        Self {
+            tokens,
+            position: 0,
+            tables: vec![],
+            references: vec![],
+            aliases: vec![],
+            skipped: vec![],
+        }
+    }
+
+    fn schema(&mut self) -> FoundationResult<()> {
+        loop {
+            self.skip_newlines();
+
+            let Some(token) = self.peek() else {
+                return Ok(());
+            };
+
+            match token {
+                Token::Word(word) if word.eq_ignore_ascii_case("table") => {
+                    self.position += 1;
+                    self.table()?;
+                }
+                Token::Word(word) if word.eq_ignore_ascii_case("ref") => {
+                    self.position += 1;
+                    self.reference()?;
+                }
+                Token::Word(word) => {
+                    self.skipped.push(model::SkippedConstruct {
+                        location: format!("line {}", self.line()),
+                        construct: word.clone(),
+                        reason: "only tables and references are imported".to_string(),
+                    });
+
+                    self.skip_definition()?;
+                }
+                _ => {
+                    self.position += 1;
+
+                    return Err(self.error("expected a definition"));
+                }
+            }
+        }
+    }
+
+    fn table(&mut self) -> FoundationResult<()> {
+        let name = self.qualified_name()?;
+
+        let mut table = Table {
+            name: name.clone(),
+            ..Default::default()
+        };
+
+        if self.eat_word("as") {
+            let alias = self.name()?;
+
+            self.aliases.push((alias, name));
+        }
+
+        if self.is_symbol('[') {
+            for setting in self.settings()? {
+                if let Some(note) = note_setting(&setting) {
+                    table.description = note;
+                }
+            }
+        }
+
+        self.expect_symbol('{')?;
+
+        loop {
+            self.skip_newlines();
+
+            if self.eat_symbol('}') {
+                break;
+            }
+
+            if self.is_word("note") && self.is_symbol_at(1, ':') {
+                self.position += 2;
+                table.description = self.text()?;
+            } else if self.is_word("note") && self.is_symbol_at(1, '{') {
+                self.position += 2;
+                self.skip_newlines();
+                table.description = self.text()?;
+                self.skip_newlines();
+                self.expect_symbol('}')?;
+            } else if self.is_word("indexes") && self.is_symbol_at(1, '{') {
+                self.position += 2;
+                self.indexes(&mut table)?;
+            } else {
+                self.column(&mut table)?;
+            }
+
+            if !self.is_symbol('}') {
+                self.expect_newline()?;
+            }
+        }
+
+        self.tables.push(table);
+
+        Ok(())
+    }
+
+    fn column(&mut self, table: &mut Table) -> FoundationResult<()> {
+        let line = self.line();
+        let name = self.name()?;
+        let location = format!("{}.{name}", table.name);
+
+        let mut type_name = self.name()?;
+
+        // Schema of the type.
+        while self.eat_symbol('.') {
+            type_name = self.name()?;
+        }
+
+        if self.is_symbol('(') {
+            self.skip_group('(', ')')?;
+        }
+
+        let mut column = Column {
+            name: name.clone(),
+            type_name: table::attribute_type_name(&type_name.to_lowercase()),
+            ..Default::default()
+        };
+
+        if self.is_symbol('[') && self.is_symbol_at(1, ']') {
+            self.position += 2;
+            column.is_array = true;
+        }
+
+        if self.is_symbol('[') {
+            for setting in self.settings()? {
+                let words: Vec<String> = setting
+                    .iter()
+                    .map_while(|token| match token {
+                        Token::Word(word) => Some(word.to_lowercase()),
+                        _ => None,
+                    })
+                    .collect();
+
+                match words.join(" ").as_str() {
+                    "pk" | "primary key" => table.primary_key = vec![name.clone()],
+                    "unique" => table.unique_keys.push(vec![name.clone()]),
+                    "note" => column.description = note_setting(&setting).flatten(),
+                    "ref" => {
+                        let tokens: Vec<LineToken> = setting
+                            .iter()
+                            .skip_while(|token| **token != Token::Symbol(':'))
+                            .skip(1)
+                            .map(|value| LineToken {
+                                value: value.clone(),
+                                line,
+                            })
+                            .collect();
+                        let mut parser = Parser::new(&tokens);
+
+                        let relationship = parser.relationship()?;
+                        let right = parser.endpoint()?;
+
+                        self.references.push(Reference {
+                            line,
+                            name: None,
+                            left: Endpoint {
+                                table: table.name.clone(),
+                                columns: vec![name.clone()],
+                            },
+                            relationship,
+                            right,
+                        });
+                    }
+                    "default" | "check" => table.skipped.push(model::SkippedConstruct {
+                        location: location.clone(),
+                        construct: words.join(" "),
+                        reason: "attributes only have a name and a type".to_string(),
+                    }),
+                    _ => {}
+                }
+            }
+        }
+
+        table.columns.push(column);
+
+        Ok(())
+    }
+
+    fn indexes(&mut self, table: &mut Table) -> FoundationResult<()> {
+        loop {
+            self.skip_newlines();
+
+            if self.eat_symbol('}') {
+                return Ok(());
+            }
+
+            let columns = if self.eat_symbol('(') {
+                let mut columns = vec![];
+
+                while !self.eat_symbol(')') {
+                    match self.next() {
+                        Some(Token::Word(name) | Token::QuotedName(name)) => {
+                            columns.push(name.clone())
+                        }
+                        Some(Token::Expression(expression)) => {
+                            columns.push(format!("`{expression}`"))
+                        }
+                        Some(Token::Symbol(',')) => {}
+                        _ => return Err(self.error("expected `)`")),
+                    }
+                }
+
+                columns
+            } else if let Some(Token::Expression(expression)) = self.peek() {
+                self.position += 1;
+
+                vec![format!("`{expression}`")]
+            } else {
+                vec![self.name()?]
+            };
+
+            let mut is_unique = false;
+            let mut is_primary_key = false;
+
+            if self.is_symbol('[') {
+                for setting in self.settings()? {
+                    match setting.as_slice() {
+                        [Token::Word(word)] if word.eq_ignore_ascii_case("unique") => {
+                            is_unique = true
+                        }
+                        [Token::Word(word)] if word.eq_ignore_ascii_case("pk") => {
+                            is_primary_key = true
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            if is_primary_key {
+                table.primary_key = columns;
+            } else if is_unique && columns.len() == 1 {
+                table.unique_keys.push(columns);
+            } else {
+                table.skipped.push(model::SkippedConstruct {
+                    location: format!("{}.{}", table.name, columns.join(", ")),
+                    construct: "index".to_string(),
+                    reason: "models don't have indexes".to_string(),
+                });
+            }
+
+            if !self.is_symbol('}') {
+                self.expect_newline()?;
+            }
+        }
+    }
+
+    /// `Ref name: a.b > c.d` or a `Ref name { ... }` block of references.
+    fn reference(&mut self) -> FoundationResult<()> {
+        let name = if !self.is_symbol(':') && !self.is_symbol('{') {
+            Some(self.name()?)
+        } else {
+            None
+        };
+
+        if self.eat_symbol(':') {
+            return self.relation(name);
+        }
+
+        self.expect_symbol('{')?;
+
+        loop {
+            self.skip_newlines();
+
+            if self.eat_symbol('}') {
+                return Ok(());
+            }
+
+            self.relation(name.clone())?;
+
+            if !self.is_symbol('}') {
+                self.expect_newline()?;
+            }
+        }
+    }
+
+    fn relation(&mut self, name: Option<String>) -> FoundationResult<()> {
+        let line = self.line();
+        let left = self.endpoint()?;
+        let relationship = self.relationship()?;
+        let right = self.endpoint()?;
+
+        if self.is_symbol('[') {
+            self.settings()?;
+        }
+
+        self.references.push(Reference {
+            line,
+            name,
+            left,
+            relationship,
+            right,
+        });
+
+        Ok(())
+    }
+
+    fn relationship(&mut self) -> FoundationResult<Relationship> {
+        let relationship = match self.next() {
+            Some(Token::Symbol('>')) => Relationship::ManyToOne,
+            Some(Token::Symbol('-')) => Relationship::OneToOne,
+            Some(Token::Symbol('<')) if self.eat_symbol('>') => Relationship::ManyToMany,
+            Some(Token::Symbol('<')) => Relationship::OneToMany,
+            _ => return Err(self.error("expected `>`, `<`, `-` or `<>`")),
+        };
+
+        Ok(relationship)
+    }
+
+    /// `table.column`, `schema.table.column` or `table.(column, column)`.
+    fn endpoint(&mut self) -> FoundationResult<Endpoint> {
+        let mut names = vec![self.name()?];
+        let mut columns = vec![];
+
+        while self.eat_symbol('.') {
+            if self.eat_symbol('(') {
+                columns.push(self.name()?);
+
+                while self.eat_symbol(',') {
+                    columns.push(self.name()?);
+                }
+
+                self.expect_symbol(')')?;
+
+                break;
+            }
+
+            names.push(self.name()?);
+        }
+
+        if columns.is_empty() && names.len() > 1 {
+            columns = names.pop().into_iter().collect();
+        }
+
+        match (names.pop(), columns.is_empty()) {
+            (Some(table), false) => Ok(Endpoint { table, columns }),
+            _ => Err(self.error("expected `table.column`")),
+        }
+    }
+
+    /// Tokens of the settings between `[` and `]`, split by commas.
+    fn settings(&mut self) -> FoundationResult<Vec<Vec<Token>>> {
+        self.expect_symbol('[')?;
+
+        let mut settings = vec![vec![]];
+
+        loop {
+            match self.next() {
+                Some(Token::Symbol(']')) => break,
+                Some(Token::Symbol(',')) => settings.push(vec![]),
+                Some(Token::Newline) => {}
+                Some(token) => settings
+                    .last_mut()
+                    .expect("settings start with a setting")
+                    .push(token.clone()),
+                None => return Err(self.error("expected `]`")),
+            }
+        }
+
+        Ok(settings
+            .into_iter()
+            .filter(|setting| !setting.is_empty())
+            .collect())
+    }
+
+    /// Skips a definition which isn't imported, up to the end of its block or
+    /// its line.
+    fn skip_definition(&mut self) -> FoundationResult<()> {
+        while let Some(token) = self.peek() {
+            match token {
+                Token::Newline => return Ok(()),
+                Token::Symbol('{') => return self.skip_group('{', '}'),
+                _ => self.position += 1,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn skip_group(&mut self, open: char, close: char) -> FoundationResult<()> {
+        self.expect_symbol(open)?;
+
+        let mut depth = 1;
+
+        while depth > 0 {
+            match self.next() {
+                Some(Token::Symbol(symbol)) if *symbol == open => depth += 1,
+                Some(Token::Symbol(symbol)) if *symbol == close => depth -= 1,
+                Some(_) => {}
+                None => return Err(self.error(&format!("expected `{close}`"))),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn text(&mut self) -> FoundationResult<Option<String>> {
+        match self.next() {
+            Some(Token::String(text)) => Ok(util::string::optional(text.trim())),
+            _ => Err(self.error("expected a string")),
+        }
+    }
+
+    /// Name without its schema.
+    fn qualified_name(&mut self) -> FoundationResult<String> {
+        let mut name = self.name()?;
+
+        while self.eat_symbol('.') {
+            name = self.name()?;
+        }
+
+        Ok(name)
+    }
+
+    fn name(&mut self) -> FoundationResult<String> {
+        match self.next() {
+            Some(Token::Word(name) | Token::QuotedName(name)) => Ok(name.clone()),
+            _ => Err(self.error("expected a name")),
+        }
+    }
+
+    fn skip_newlines(&mut self) {
+        while self.peek() == Some(&Token::Newline) {
+            self.position += 1;
+        }
+    }
+
+    fn expect_newline(&mut self) -> FoundationResult<()> {
+        match self.next() {
+            Some(Token::Newline) | None => Ok(()),
+            _ => Err(self.error("expected a line break")),
+        }
+    }
+
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.position).map(|token| &token.value)
+    }
+
+    fn next(&mut self) -> Option<&'a Token> {
+        let token = self.peek();
+
+        if token.is_some() {
+            self.position += 1;
+        }
+
+        token
+    }
+
+    fn is_word(&self, word: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(other)) if other.eq_ignore_ascii_case(word))
+    }
+
+    fn eat_word(&mut self, word: &str) -> bool {
+        let is_word = self.is_word(word);
+
+        if is_word {
+            self.position += 1;
+        }
+
+        is_word
+    }
+
+    fn is_symbol(&self, symbol: char) -> bool {
+        self.is_symbol_at(0, symbol)
+    }
+
+    fn is_symbol_at(&self, offset: usize, symbol: char) -> bool {
+        self.tokens
+            .get(self.position + offset)
+            .is_some_and(|token| token.value == Token::Symbol(symbol))
+    }
+
+    fn eat_symbol(&mut self, symbol: char) -> bool {
+        let is_symbol = self.is_symbol(symbol);
+
+        if is_symbol {
+            self.position += 1;
+        }
+
+        is_symbol
+    }
+
+    fn expect_symbol(&mut self, symbol: char) -> FoundationResult<()> {
+        if self.eat_symbol(symbol) {
+            return Ok(());
+        }
+
+        Err(self.error(&format!("expected `{symbol}`")))
+    }
+
+    /// Line of the current token, the last line at the end.
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.position)
+            .or(self.tokens.last())
+            .map(|token| token.line)
+            .unwrap_or(1)
+    }
+
+    fn error(&self, message: &str) -> FoundationError {
+        // The line of the token which was just read.
+        let line = self
+            .tokens
+            .get(self.position.saturating_sub(1))
+            .map(|token| token.line)
+            .unwrap_or(1);
+
+        syntax_error(line, message)
+    }
+}
+
+/// Text of a `note: '...'` setting, `None` for the other settings.
+fn note_setting(setting: &[Token]) -> Option<Option<String>> {
+    match setting {
+        [Token::Word(word), Token::Symbol(':'), Token::String(text)]
+            if word.eq_ignore_ascii_case("note") =>
+        {
+            Some(util::string::optional(text.trim()))
+        }
+        _ => None,
+    }
+}
+
+fn syntax_error(line: usize, message: &str) -> FoundationError {
+    FoundationError::invalid_argument(format!("invalid DBML, line {line}: {message}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::{SchemaAssociation, SchemaAttribute, SchemaModel};
+
+    #[test]
+    fn it_reads_aliases_and_notes() -> FoundationResult<()> {
+        let schema = dbml_schema(
+            r#"
+Table publishers as P [note: 'Registered publishers'] {
+  id integer [pk]
+  name varchar(255) [not null, note: 'Registered name']
+}
+
+Table books {
+  id integer [pk, increment]
+  publisher_id integer [ref: > P.id]
+
+  Note {
+    '''
+    Printed books,
+    not the e-books.
+    '''
+  }
+}
+
+Ref: reviews.book_id > books.id
+
+Note editors {
+  'Reviewed by the editors.'
+}
+"#,
+        )?;
+
+        assert_eq!(
+            schema.models,
+            vec![
+                SchemaModel {
+                    name: "Publisher".to_string(),
+                    description: Some("Registered publishers".to_string()),
+                    attributes: vec![SchemaAttribute {
+                        name: "Name".to_string(),
+                        description: Some("Registered name".to_string()),
+                        type_name: "Text".to_string(),
+                    }],
+                    associations: vec![SchemaAssociation {
+                        name: "Books".to_string(),
+                        description: None,
+                        kind: model::AssociationKind::HasMany,
+                        model_name: "Book".to_string(),
+                    }],
+                },
+                SchemaModel {
+                    name: "Book".to_string(),
+                    description: Some("Printed books,\nnot the e-books.".to_string()),
+                    attributes: vec![],
+                    associations: vec![SchemaAssociation {
+                        name: "Publisher".to_string(),
+                        description: None,
+                        kind: model::AssociationKind::BelongsTo,
+                        model_name: "Publisher".to_string(),
+                    }],
+                },
+            ]
+        );
+
+        assert_eq!(
+            schema
+                .skipped
+                .iter()
+                .map(|skipped| format!("{}: {}", skipped.location, skipped.construct))
+                .collect::<Vec<String>>(),
+            vec!["line 21: Note", "line 19: Ref"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_keeps_the_association_names_of_named_refs() -> FoundationResult<()> {
+        let schema = dbml_schema(
+            r#"
+Table customers {
+  id integer [pk]
+}
+
+Table addresses {
+  id integer [pk]
+  customer_id integer
+}
+
+Table orders {
+  id integer [pk]
+  customer_id integer [note: 'Orders of the customer']
+}
+
+Table invoices {
+  id integer [pk]
+  order_id integer
+  agent_id integer
+}
+
+Ref "Customer/Address": addresses.customer_id - customers.id
+Ref "/Orders": orders.customer_id > customers.id
+Ref "Order/": invoices.order_id > orders.id
+Ref fk_invoices_agent_id: invoices.agent_id > customers.id
+"#,
+        )?;
+
+        let association =
+            |name: &str, description: Option<&str>, kind, model_name: &str| SchemaAssociation {
+                name: name.to_string(),
+                description: description.map(str::to_string),
+                kind,
+                model_name: model_name.to_string(),
+            };
+
+        assert_eq!(
+            schema
+                .models
+                .iter()
+                .map(|model| (model.name.as_str(), model.associations.clone()))
+                .collect::<Vec<(&str, Vec<SchemaAssociation>)>>(),
+            vec![
+                (
+                    "Customer",
+                    vec![
+                        association("Address", None, model::AssociationKind::HasOne, "Address"),
+                        association(
+                            "Orders",
+                            Some("Orders of the customer"),
+                            model::AssociationKind::HasMany,
+                            "Order"
+                        ),
+                        association("Invoices", None, model::AssociationKind::HasMany, "Invoice"),
+                    ]
+                ),
+                (
+                    "Address",
+                    vec![association(
+                        "Customer",
+                        None,
+                        model::AssociationKind::BelongsTo,
+                        "Customer"
+                    )]
+                ),
+                ("Order", vec![]),
+                (
+                    "Invoice",
+                    vec![
+                        association("Order", None, model::AssociationKind::BelongsTo, "Order"),
+                        association("Agent", None, model::AssociationKind::BelongsTo, "Customer"),
+                    ]
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_returns_syntax_errors_with_the_line() {
+        let test_table = [
+            (
+                "Table books {\n  id integer [pk\n}\n",
+                "invalid DBML, line 3: expected `]`",
+            ),
+            (
+                "Table books {\n  title 'untitled\n}\n",
+                "invalid DBML, line 2: unterminated string",
+            ),
+            (
+                "\nRef: books.author_id = authors.id\n",
+                "invalid DBML, line 2: expected `>`, `<`, `-` or `<>`",
+            ),
+            ("\n\n{\n", "invalid DBML, line 3: expected a definition"),
+        ];
+
+        for (dbml, message) in test_table {
+            let error = dbml_schema(dbml).err().unwrap();
+
+            assert_eq!(error.message(), message, "{dbml}");
+        }
+    }
+}
@@ -0,0 +1,272 @@
+//! Models read from the schemas of other tools and their creation in a
+//! project.
+//!
+//! Every importer reads its source into a [`Schema`], which is then added to
+//! the project by [`create_records`].
+
+mod class_diagram;
+mod dbml;
+mod postgresql;
+mod prisma;
+mod rails;
+mod rust;
+mod table;
+
+pub use class_diagram::class_diagram_schema;
+pub use dbml::dbml_schema;
+pub use postgresql::postgresql_schema;
+pub use prisma::prisma_schema;
+pub use rails::rails_schema;
+pub use rust::rust_schema;
+
+use crate::{
+    attribute_type::{
+        AttributeType, AttributeTypeRecord, CreateAttributeTypeRecord, ListAttributeTypeRecords,
+    },
+    datastore,
+    model::{
+        self, CreateModelAssociationRecord, CreateModelAttributeRecord, CreateModelRecord,
+        ListModelOverviewRecords,
+    },
+    util, FoundationError, FoundationResult, Uuid,
+};
+use std::collections::HashSet;
+
+/// Models read from a source, in the order they are declared.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Schema {
+    pub models: Vec<SchemaModel>,
+
+    /// Constructs of the source that the models can't represent.
+    pub skipped: Vec<model::SkippedConstruct>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaModel {
+    pub name: String,
+
+    pub description: Option<String>,
+
+    pub attributes: Vec<SchemaAttribute>,
+
+    pub associations: Vec<SchemaAssociation>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaAttribute {
+    pub name: String,
+
+    pub description: Option<String>,
+
+    /// Name of the attribute type, see [`create_records`] for how it's
+    /// matched.
+    pub type_name: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaAssociation {
+    pub name: String,
+
+    pub description: Option<String>,
+
+    pub kind: model::AssociationKind,
+
+    /// Name of the associated model.
+    pub model_name: String,
+}
+
+/// Adds the models of the schema to the project.
+///
+/// Models are matched to the project models by name or slug, and only the
+/// attributes and associations they don't have yet are added. Attributes get
+/// the attribute type with the same name or slug, an attribute type is created
+/// when there is none.
+pub async fn create_records(
+    repo: &(impl ListModelOverviewRecords
+          + ListAttributeTypeRecords
+          + CreateAttributeTypeRecord
+          + CreateModelRecord
+          + CreateModelAttributeRecord
+          + CreateModelAssociationRecord),
+    project_record: datastore::project::Project,
+    schema: Schema,
+) -> FoundationResult<model::ImportSummary> {
+    let Schema { models, skipped } = schema;
+
+    let model_overview_records = repo
+        .list_model_overview_records(&project_record.slug)
+        .await?;
+    let mut attribute_type_records = repo.list_attribute_type_records().await?;
+
+    let mut model_records: Vec<datastore::model::Model> = model_overview_records
+        .iter()
+        .map(|model_overview_record| model_overview_record.model.clone())
+        .collect();
+
+    // Attributes and associations share the names of the model.
+    let mut names: HashSet<(Uuid, String)> = model_overview_records
+        .iter()
+        .flat_map(|model_overview_record| {
+            let attribute_names = model_overview_record
+                .attributes
+                .iter()
+                .map(|attribute| (attribute.model_id, attribute.name.clone()));
+            let association_names = model_overview_record
+                .associations
+                .iter()
+                .map(|association| (association.model_id, association.name.clone()));
+
+            attribute_names.chain(association_names)
+        })
+        .collect();
+
+    let mut summary = model::ImportSummary {
+        skipped,
+        ..Default::default()
+    };
+
+    for schema_model in &models {
+        if find_model_record(&model_records, &schema_model.name).is_some() {
+            continue;
+        }
+
+        let model_record = repo
+            .create_model_record(
+                project_record.clone(),
+                model::Model {
+                    description: schema_model.description.clone(),
+                    name: schema_model.name.clone(),
+                    slug: util::slug::sluggify(&schema_model.name),
+                },
+            )
+            .await?;
+
+        summary.models.push(model_record.clone().into());
+        model_records.push(model_record);
+    }
+
+    for schema_model in &models {
+        let model_record = find_model_record(&model_records, &schema_model.name)
+            .cloned()
+            .ok_or(FoundationError::internal("imported model is missing"))?;
+
+        for attribute in &schema_model.attributes {
+            if !names.insert((model_record.id, attribute.name.clone())) {
+                summary
+                    .skipped
+                    .push(name_taken(&schema_model.name, &attribute.name, "attribute"));
+
+                continue;
+            }
+
+            let attribute_type_record =
+                match find_attribute_type_record(&attribute_type_records, &attribute.type_name) {
+                    Some(attribute_type_record) => attribute_type_record.clone(),
+                    None => {
+                        let attribute_type_record = repo
+                            .create_attribute_type_record(AttributeType {
+                                description: None,
+                                name: attribute.type_name.clone(),
+                                slug: util::slug::sluggify(&attribute.type_name),
+                            })
+                            .await?;
+
+                        summary
+                            .attribute_types
+                            .push(attribute_type_record.clone().into());
+                        attribute_type_records.push(attribute_type_record.clone());
+
+                        attribute_type_record
+                    }
+                };
+
+            let model_attribute_record = repo
+                .create_model_attribute_record(
+                    model_record.clone(),
+                    attribute_type_record.clone(),
+                    model::Attribute {
+                        description: attribute.description.clone(),
+                        r#type: attribute_type_record.into(),
+                        name: attribute.name.clone(),
+                    },
+                )
+                .await?;
+
+            summary.attributes.push(model_attribute_record.into());
+        }
+
+        for association in &schema_model.associations {
+            let Some(associated_model_record) =
+                find_model_record(&model_records, &association.model_name).cloned()
+            else {
+                summary.skipped.push(model::SkippedConstruct {
+                    location: format!("{}.{}", schema_model.name, association.name),
+                    construct: "association".to_string(),
+                    reason: format!(
+                        "`{}` isn't part of the project",
+                        association.model_name
+                    ),
+                });
+
+                continue;
+            };
+
+            if !names.insert((model_record.id, association.name.clone())) {
+                summary.skipped.push(name_taken(
+                    &schema_model.name,
+                    &association.name,
+                    "association",
+                ));
+
+                continue;
+            }
+
+            let model_association_record = repo
+                .create_model_association_record(
+                    model_record.clone(),
+                    associated_model_record.clone(),
+                    model::Association {
+                        description: association.description.clone(),
+                        kind: association.kind.clone(),
+                        model: associated_model_record.into(),
+                        name: association.name.clone(),
+                    },
+                )
+                .await?;
+
+            summary.associations.push(model_association_record.into());
+        }
+    }
+
+    Ok(summary)
+}
+
+fn find_model_record<'a>(
+    model_records: &'a [datastore::model::Model],
+    name: &str,
+) -> Option<&'a datastore::model::Model> {
+    let slug = util::slug::sluggify(name);
+
+    model_records
+        .iter()
+        .find(|model_record| model_record.name == name || model_record.slug == slug)
+}
+
+fn find_attribute_type_record<'a>(
+    attribute_type_records: &'a [AttributeTypeRecord],
+    name: &str,
+) -> Option<&'a AttributeTypeRecord> {
+    let slug = util::slug::sluggify(name);
+
+    attribute_type_records.iter().find(|attribute_type_record| {
+        attribute_type_record.inner.name == name || attribute_type_record.inner.slug == slug
+    })
+}
+
+fn name_taken(model_name: &str, name: &str, construct: &str) -> model::SkippedConstruct {
+    model::SkippedConstruct {
+        location: format!("{model_name}.{name}"),
+        construct: construct.to_string(),
+        reason: "the model already has an attribute or association with this name".to_string(),
+    }
+}
@@ -0,0 +1,981 @@
+use super::{
+    table::{self, Column, ForeignKey, Table},
+    Schema,
+};
+use crate::{model, util, FoundationError, FoundationResult};
+
+/// Keywords starting a column constraint, which ends the column type.
+const COLUMN_CONSTRAINT_KEYWORDS: [&str; 10] = [
+    "constraint",
+    "not",
+    "null",
+    "default",
+    "primary",
+    "unique",
+    "check",
+    "references",
+    "generated",
+    "collate",
+];
+
+/// Reads the tables of a PostgreSQL script made of `CREATE TABLE`,
+/// `ALTER TABLE ... ADD FOREIGN KEY` and `COMMENT ON` statements, like the
+/// ones of `pg_dump --schema-only`.
+///
+/// Tables become models named after the singular of the table name, and
+/// columns become attributes typed after the well known attribute type of the
+/// column type. Single column foreign keys become `BelongsTo` associations
+/// named after the column without its `_id` suffix, along with the inverse
+/// `HasMany` association of the referenced model, `HasOne` when the column is
+/// unique. The `id` primary key is left out, models have a surrogate key.
+///
+/// Everything else is reported as skipped.
+pub fn postgresql_schema(sql: &str) -> FoundationResult<Schema> {
+    let tokens = tokens(sql)?;
+    let mut tables: Vec<Table> = vec![];
+    let mut alterations: Vec<(usize, Alteration)> = vec![];
+    let mut skipped = vec![];
+
+    for statement in tokens.split(|token| token.value == Token::Symbol(';')) {
+        let Some(first) = statement.first() else {
+            continue;
+        };
+
+        let line = first.line;
+        let mut parser = Parser::new(statement);
+
+        match parser.statement()? {
+            Statement::CreateTable(table) => tables.push(table),
+            Statement::Alterations(table_alterations) => alterations.extend(
+                table_alterations
+                    .into_iter()
+                    .map(|alteration| (line, alteration)),
+            ),
+            Statement::Other(construct) => skipped.push(model::SkippedConstruct {
+                location: format!("line {line}"),
+                construct,
+                reason: "only tables, foreign keys and comments are imported".to_string(),
+            }),
+        }
+    }
+
+    for (line, alteration) in alterations {
+        let table_name = alteration.table_name();
+
+        let Some(table) = tables.iter_mut().find(|table| table.name == table_name) else {
+            skipped.push(model::SkippedConstruct {
+                location: format!("line {line}"),
+                construct: alteration.construct(),
+                reason: format!("table `{table_name}` isn't created by the script"),
+            });
+
+            continue;
+        };
+
+        if let Err(skipped_construct) = alter(table, alteration) {
+            skipped.push(skipped_construct);
+        }
+    }
+
+    let mut schema = table::models(&tables);
+    skipped.append(&mut schema.skipped);
+    schema.skipped = skipped;
+
+    Ok(schema)
+}
+
+enum Statement {
+    CreateTable(Table),
+    Alterations(Vec<Alteration>),
+    Other(String),
+}
+
+/// Changes made to a table after it's created.
+enum Alteration {
+    PrimaryKey {
+        table: String,
+        columns: Vec<String>,
+    },
+    Unique {
+        table: String,
+        columns: Vec<String>,
+    },
+    ForeignKey {
+        table: String,
+        foreign_key: ForeignKey,
+    },
+    TableComment {
+        table: String,
+        comment: Option<String>,
+    },
+    ColumnComment {
+        table: String,
+        column: String,
+        comment: Option<String>,
+    },
+    Other {
+        table: String,
+        construct: String,
+    },
+}
+
+impl Alteration {
+    fn table_name(&self) -> &str {
+        match self {
+            Alteration::PrimaryKey { table, .. }
+            | Alteration::Unique { table, .. }
+            | Alteration::ForeignKey { table, .. }
+            | Alteration::TableComment { table, .. }
+            | Alteration::ColumnComment { table, .. }
+            | Alteration::Other { table, .. } => table,
+        }
+    }
+
+    fn construct(&self) -> String {
+        match self {
+            Alteration::PrimaryKey { .. } => "PRIMARY KEY".to_string(),
+            Alteration::Unique { .. } => "UNIQUE".to_string(),
+            Alteration::ForeignKey { .. } => "FOREIGN KEY".to_string(),
+            Alteration::TableComment { .. } | Alteration::ColumnComment { .. } => {
+                "COMMENT".to_string()
+            }
+            Alteration::Other { construct, .. } => construct.clone(),
+        }
+    }
+}
+
+fn alter(table: &mut Table, alteration: Alteration) -> Result<(), model::SkippedConstruct> {
+    match alteration {
+        Alteration::PrimaryKey { columns, .. } => table.primary_key = columns,
+        Alteration::Unique { columns, .. } => table.unique_keys.push(columns),
+        Alteration::ForeignKey { foreign_key, .. } => table.foreign_keys.push(foreign_key),
+        Alteration::TableComment { comment, .. } => table.description = comment,
+        Alteration::ColumnComment {
+            column, comment, ..
+        } => {
+            let Some(column) = table.columns.iter_mut().find(|c| c.name == column) else {
+                return Err(model::SkippedConstruct {
+                    location: format!("{}.{column}", table.name),
+                    construct: "COMMENT".to_string(),
+                    reason: "the column doesn't exist".to_string(),
+                });
+            };
+
+            column.description = comment;
+        }
+        Alteration::Other { construct, .. } => {
+            return Err(model::SkippedConstruct {
+                location: table.name.clone(),
+                construct,
+                reason: "only foreign keys are imported from table alterations".to_string(),
+            })
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    /// Unquoted identifier or keyword, folded to lower case like PostgreSQL
+    /// does.
+    Word(String),
+
+    /// Double quoted identifier.
+    QuotedIdentifier(String),
+
+    /// Single or dollar quoted string.
+    String(String),
+
+    Number(String),
+
+    Symbol(char),
+}
+
+#[derive(Debug)]
+struct LineToken {
+    value: Token,
+    line: usize,
+}
+
+fn tokens(sql: &str) -> FoundationResult<Vec<LineToken>> {
+    let chars: Vec<char> = sql.chars().collect();
+    let mut tokens = vec![];
+    let mut line = 1;
+    let mut index = 0;
+
+    while let Some(&char) = chars.get(index) {
+        let start_line = line;
+
+        let value = match char {
+            '\n' => {
+                line += 1;
+                index += 1;
+
+                continue;
+            }
+            char if char.is_whitespace() => {
+                index += 1;
+
+                continue;
+            }
+            '-' if chars.get(index + 1) == Some(&'-') => {
+                while chars.get(index).is_some_and(|char| *char != '\n') {
+                    index += 1;
+                }
+
+                continue;
+            }
+            '/' if chars.get(index + 1) == Some(&'*') => {
+                let mut depth = 0;
+
+                loop {
+                    match (chars.get(index), chars.get(index + 1)) {
+                        (Some('/'), Some('*')) => {
+                            depth += 1;
+                            index += 2;
+                        }
+                        (Some('*'), Some('/')) => {
+                            depth -= 1;
+                            index += 2;
+
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        (Some(char), _) => {
+                            if *char == '\n' {
+                                line += 1;
+                            }
+
+                            index += 1;
+                        }
+                        (None, _) => return Err(syntax_error(start_line, "unterminated comment")),
+                    }
+                }
+
+                continue;
+            }
+            '\'' | '"' => {
+                let mut value = String::new();
+                index += 1;
+
+                loop {
+                    match chars.get(index) {
+                        Some(quote) if *quote == char => {
+                            if chars.get(index + 1) == Some(&char) {
+                                value.push(char);
+                                index += 2;
+                            } else {
+                                index += 1;
+
+                                break;
+                            }
+                        }
+                        Some(other) => {
+                            if *other == '\n' {
+                                line += 1;
+                            }
+
+                            value.push(*other);
+                            index += 1;
+                        }
+                        None => {
+                            return Err(syntax_error(start_line, "unterminated quoted text"));
+                        }
+                    }
+                }
+
+                if char == '"' {
+                    Token::QuotedIdentifier(value)
+                } else {
+                    Token::String(value)
+                }
+            }
+            '$' => {
+                let tag_end = chars[index + 1..]
+                    .iter()
+                    .position(|char| !(char.is_alphanumeric() || *char == '_'))
+                    .map(|position| index + 1 + position);
+
+                match tag_end {
+                    Some(tag_end) if chars[tag_end] == '$' => {
+                        let tag: Vec<char> = chars[index..=tag_end].to_vec();
+                        let body_start = tag_end + 1;
+
+                        let Some(body_end) = (body_start..chars.len())
+                            .find(|start| chars[*start..].starts_with(&tag))
+                        else {
+                            return Err(syntax_error(start_line, "unterminated quoted text"));
+                        };
+
+                        let body: String = chars[body_start..body_end].iter().collect();
+                        line += body.matches('\n').count();
+                        index = body_end + tag.len();
+
+                        Token::String(body)
+                    }
+                    _ => {
+                        index += 1;
+
+                        Token::Symbol('$')
+                    }
+                }
+            }
+            char if char.is_ascii_digit() => {
+                let start = index;
+
+                while chars
+                    .get(index)
+                    .is_some_and(|char| char.is_ascii_digit() || *char == '.')
+                {
+                    index += 1;
+                }
+
+                Token::Number(chars[start..index].iter().collect())
+            }
+            char if char.is_alphabetic() || char == '_' => {
+                let start = index;
+
+                while chars
+                    .get(index)
+                    .is_some_and(|char| char.is_alphanumeric() || *char == '_' || *char == '$')
+                {
+                    index += 1;
+                }
+
+                Token::Word(
+                    chars[start..index]
+                        .iter()
+                        .collect::<String>()
+                        .to_lowercase(),
+                )
+            }
+            char => {
+                index += 1;
+
+                Token::Symbol(char)
+            }
+        };
+
+        tokens.push(LineToken {
+            value,
+            line: start_line,
+        });
+    }
+
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: &'a [LineToken],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(tokens: &'a [LineToken]) -> Self {
+        Self {
+            tokens,
+            position: 0,
+        }
+    }
+
+    fn statement(&mut self) -> FoundationResult<Statement> {
+        if self.eat_keyword("create") {
+            for modifier in ["global", "local", "temporary", "temp", "unlogged"] {
+                self.eat_keyword(modifier);
+            }
+
+            if self.eat_keyword("table") {
+                return self.create_table();
+            }
+        } else if self.eat_keywords(&["alter", "table"]) {
+            return self.alter_table();
+        } else if self.eat_keywords(&["comment", "on"]) {
+            if let Some(statement) = self.comment()? {
+                return Ok(statement);
+            }
+        }
+
+        Ok(Statement::Other(self.construct()))
+    }
+
+    /// Leading keywords of the statement, like `CREATE INDEX`.
+    fn construct(&self) -> String {
+        let mut words = self.tokens.iter().map_while(|token| match &token.value {
+            Token::Word(word) => Some(word.to_uppercase()),
+            _ => None,
+        });
+
+        let Some(first) = words.next() else {
+            return String::new();
+        };
+
+        if !["CREATE", "ALTER", "DROP", "COMMENT"].contains(&first.as_str()) {
+            return first;
+        }
+
+        let modifiers = [
+            "OR",
+            "REPLACE",
+            "UNIQUE",
+            "TEMPORARY",
+            "TEMP",
+            "UNLOGGED",
+            "ON",
+        ];
+
+        match words.find(|word| !modifiers.contains(&word.as_str())) {
+            Some(object) => format!("{first} {object}"),
+            None => first,
+        }
+    }
+
+    fn create_table(&mut self) -> FoundationResult<Statement> {
+        self.eat_keywords(&["if", "not", "exists"]);
+
+        let name = self.name()?;
+
+        if !self.eat_symbol('(') {
+            return Ok(Statement::Other("CREATE TABLE".to_string()));
+        }
+
+        let mut table = Table {
+            name,
+            ..Default::default()
+        };
+
+        loop {
+            if self.eat_symbol(')') {
+                break;
+            }
+
+            self.table_element(&mut table)?;
+
+            if !self.eat_symbol(',') {
+                self.expect_symbol(')')?;
+
+                break;
+            }
+        }
+
+        if !self.is_at_end() {
+            table.skipped.push(model::SkippedConstruct {
+                location: table.name.clone(),
+                construct: self.word_at_position().unwrap_or_default().to_uppercase(),
+                reason: "table options aren't imported".to_string(),
+            });
+        }
+
+        Ok(Statement::CreateTable(table))
+    }
+
+    fn table_element(&mut self, table: &mut Table) -> FoundationResult<()> {
+        if self.eat_keyword("constraint") {
+            self.identifier()?;
+        }
+
+        if self.eat_keywords(&["primary", "key"]) {
+            table.primary_key = self.identifier_list()?;
+        } else if self.eat_keyword("unique") {
+            self.eat_keywords(&["nulls", "not", "distinct"]);
+            self.eat_keywords(&["nulls", "distinct"]);
+
+            table.unique_keys.push(self.identifier_list()?);
+        } else if self.eat_keywords(&["foreign", "key"]) {
+            let columns = self.identifier_list()?;
+
+            self.expect_keyword("references")?;
+
+            table.foreign_keys.push(ForeignKey {
+                columns,
+                referenced_table: self.name()?,
+                association_names: None,
+            });
+        } else if let Some(keyword) = ["check", "exclude", "like"]
+            .into_iter()
+            .find(|keyword| self.is_keyword(keyword))
+        {
+            table.skipped.push(model::SkippedConstruct {
+                location: table.name.clone(),
+                construct: keyword.to_uppercase(),
+                reason: "models don't have constraints".to_string(),
+            });
+        } else {
+            self.column(table)?;
+        }
+
+        self.skip_element();
+
+        Ok(())
+    }
+
+    fn column(&mut self, table: &mut Table) -> FoundationResult<()> {
+        let mut column = Column {
+            name: self.identifier()?,
+            ..Default::default()
+        };
+        let location = format!("{}.{}", table.name, column.name);
+        let mut type_words: Vec<String> = vec![];
+
+        while let Some(token) = self.peek() {
+            match token {
+                Token::Word(word) if COLUMN_CONSTRAINT_KEYWORDS.contains(&word.as_str()) => break,
+                Token::Word(word) if word == "array" => column.is_array = true,
+                Token::Word(word) | Token::QuotedIdentifier(word) => type_words.push(word.clone()),
+                // Schema of the type.
+                Token::Symbol('.') => type_words.clear(),
+                Token::Symbol('[') => column.is_array = true,
+                Token::Symbol(',') | Token::Symbol(')') => break,
+                Token::Symbol('(') => {
+                    self.skip_group()?;
+
+                    continue;
+                }
+                _ => {}
+            }
+
+            self.position += 1;
+        }
+
+        if type_words.is_empty() {
+            return Err(syntax_error(
+                self.line(),
+                &format!("column `{}` has no type", column.name),
+            ));
+        }
+
+        column.type_name = table::attribute_type_name(&type_words.join(" "));
+
+        while let Some(token) = self.peek() {
+            match token {
+                Token::Symbol(',') | Token::Symbol(')') => break,
+                Token::Symbol('(') => {
+                    self.skip_group()?;
+
+                    continue;
+                }
+                _ => {}
+            }
+
+            if self.eat_keyword("constraint") {
+                self.identifier()?;
+            } else if self.eat_keywords(&["primary", "key"]) {
+                table.primary_key = vec![column.name.clone()];
+            } else if self.eat_keyword("unique") {
+                table.unique_keys.push(vec![column.name.clone()]);
+            } else if self.eat_keyword("references") {
+                table.foreign_keys.push(ForeignKey {
+                    columns: vec![column.name.clone()],
+                    referenced_table: self.name()?,
+                    association_names: None,
+                });
+            } else if let Some(keyword) = ["default", "check", "generated", "collate"]
+                .into_iter()
+                .find(|keyword| self.is_keyword(keyword))
+            {
+                self.position += 1;
+
+                table.skipped.push(model::SkippedConstruct {
+                    location: location.clone(),
+                    construct: keyword.to_uppercase(),
+                    reason: "attributes only have a name and a type".to_string(),
+                });
+            } else {
+                self.position += 1;
+            }
+        }
+
+        table.columns.push(column);
+
+        Ok(())
+    }
+
+    fn alter_table(&mut self) -> FoundationResult<Statement> {
+        self.eat_keywords(&["if", "exists"]);
+        self.eat_keyword("only");
+
+        let table = self.name()?;
+        let mut alterations = vec![];
+
+        loop {
+            let start = self.position;
+
+            let alteration = if self.eat_keyword("add") {
+                if self.eat_keyword("constraint") {
+                    self.identifier()?;
+                }
+
+                if self.eat_keywords(&["primary", "key"]) {
+                    Some(Alteration::PrimaryKey {
+                        table: table.clone(),
+                        columns: self.identifier_list()?,
+                    })
+                } else if self.eat_keyword("unique") {
+                    Some(Alteration::Unique {
+                        table: table.clone(),
+                        columns: self.identifier_list()?,
+                    })
+                } else if self.eat_keywords(&["foreign", "key"]) {
+                    let columns = self.identifier_list()?;
+
+                    self.expect_keyword("references")?;
+
+                    Some(Alteration::ForeignKey {
+                        table: table.clone(),
+                        foreign_key: ForeignKey {
+                            columns,
+                            referenced_table: self.name()?,
+                            association_names: None,
+                        },
+                    })
+                } else {
+                    None
+                }
+            } else {
+                None
+            };
+
+            let alteration = alteration.unwrap_or_else(|| {
+                let words: Vec<String> = self.tokens[start..]
+                    .iter()
+                    .map_while(|token| match &token.value {
+                        Token::Word(word) => Some(word.to_uppercase()),
+                        _ => None,
+                    })
+                    .take(2)
+                    .collect();
+
+                Alteration::Other {
+                    table: table.clone(),
+                    construct: format!("ALTER TABLE {}", words.join(" ")),
+                }
+            });
+
+            alterations.push(alteration);
+
+            self.skip_element();
+
+            if !self.eat_symbol(',') {
+                break;
+            }
+        }
+
+        Ok(Statement::Alterations(alterations))
+    }
+
+    /// `COMMENT ON TABLE` and `COMMENT ON COLUMN`, the comments of the other
+    /// objects aren't imported.
+    fn comment(&mut self) -> FoundationResult<Option<Statement>> {
+        let alteration = if self.eat_keyword("table") {
+            let table = self.name()?;
+
+            self.expect_keyword("is")?;
+
+            Alteration::TableComment {
+                table,
+                comment: self.comment_text()?,
+            }
+        } else if self.eat_keyword("column") {
+            let mut names = self.qualified_name()?;
+
+            let (Some(column), Some(table)) = (names.pop(), names.pop()) else {
+                return Err(syntax_error(self.line(), "expected `table.column`"));
+            };
+
+            self.expect_keyword("is")?;
+
+            Alteration::ColumnComment {
+                table,
+                column,
+                comment: self.comment_text()?,
+            }
+        } else {
+            return Ok(None);
+        };
+
+        Ok(Some(Statement::Alterations(vec![alteration])))
+    }
+
+    fn comment_text(&mut self) -> FoundationResult<Option<String>> {
+        if self.eat_keyword("null") {
+            return Ok(None);
+        }
+
+        match self.next() {
+            Some(Token::String(text)) => Ok(util::string::optional(text.trim())),
+            _ => Err(syntax_error(self.line(), "expected comment text")),
+        }
+    }
+
+    /// Skips the rest of the table element or the alteration, up to the next
+    /// `,` or the closing `)`.
+    fn skip_element(&mut self) {
+        while let Some(token) = self.peek() {
+            match token {
+                Token::Symbol(',') | Token::Symbol(')') => break,
+                Token::Symbol('(') => {
+                    if self.skip_group().is_err() {
+                        break;
+                    }
+                }
+                _ => self.position += 1,
+            }
+        }
+    }
+
+    fn skip_group(&mut self) -> FoundationResult<()> {
+        self.expect_symbol('(')?;
+
+        let mut depth = 1;
+
+        while depth > 0 {
+            match self.next() {
+                Some(Token::Symbol('(')) => depth += 1,
+                Some(Token::Symbol(')')) => depth -= 1,
+                Some(_) => {}
+                None => return Err(syntax_error(self.line(), "unbalanced parentheses")),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Name of the table, without its schema.
+    fn name(&mut self) -> FoundationResult<String> {
+        let mut names = self.qualified_name()?;
+
+        Ok(names.pop().unwrap_or_default())
+    }
+
+    fn qualified_name(&mut self) -> FoundationResult<Vec<String>> {
+        let mut names = vec![self.identifier()?];
+
+        while self.eat_symbol('.') {
+            names.push(self.identifier()?);
+        }
+
+        Ok(names)
+    }
+
+    fn identifier_list(&mut self) -> FoundationResult<Vec<String>> {
+        self.expect_symbol('(')?;
+
+        let mut identifiers = vec![self.identifier()?];
+
+        while self.eat_symbol(',') {
+            identifiers.push(self.identifier()?);
+        }
+
+        self.expect_symbol(')')?;
+
+        Ok(identifiers)
+    }
+
+    fn identifier(&mut self) -> FoundationResult<String> {
+        match self.next() {
+            Some(Token::Word(identifier)) | Some(Token::QuotedIdentifier(identifier)) => {
+                Ok(identifier.clone())
+            }
+            _ => Err(syntax_error(self.line(), "expected identifier")),
+        }
+    }
+
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.position).map(|token| &token.value)
+    }
+
+    fn next(&mut self) -> Option<&'a Token> {
+        let token = self.peek();
+        self.position += 1;
+
+        token
+    }
+
+    fn word_at_position(&self) -> Option<&'a str> {
+        match self.peek() {
+            Some(Token::Word(word)) => Some(word),
+            _ => None,
+        }
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.position >= self.tokens.len()
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        self.word_at_position() == Some(keyword)
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let is_keyword = self.is_keyword(keyword);
+
+        if is_keyword {
+            self.position += 1;
+        }
+
+        is_keyword
+    }
+
+    /// Consumes the keywords only when all of them follow.
+    fn eat_keywords(&mut self, keywords: &[&str]) -> bool {
+        let start = self.position;
+
+        if keywords.iter().all(|keyword| self.eat_keyword(keyword)) {
+            return true;
+        }
+
+        self.position = start;
+
+        false
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> FoundationResult<()> {
+        if self.eat_keyword(keyword) {
+            return Ok(());
+        }
+
+        Err(syntax_error(
+            self.line(),
+            &format!("expected `{}`", keyword.to_uppercase()),
+        ))
+    }
+
+    fn eat_symbol(&mut self, symbol: char) -> bool {
+        let is_symbol = self.peek() == Some(&Token::Symbol(symbol));
+
+        if is_symbol {
+            self.position += 1;
+        }
+
+        is_symbol
+    }
+
+    fn expect_symbol(&mut self, symbol: char) -> FoundationResult<()> {
+        if self.eat_symbol(symbol) {
+            return Ok(());
+        }
+
+        Err(syntax_error(self.line(), &format!("expected `{symbol}`")))
+    }
+
+    /// Line of the current token, the last line of the statement at its end.
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.position)
+            .or(self.tokens.last())
+            .map(|token| token.line)
+            .unwrap_or(1)
+    }
+}
+
+fn syntax_error(line: usize, message: &str) -> FoundationError {
+    FoundationError::invalid_argument(format!("invalid DDL, line {line}: {message}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::{SchemaAssociation, SchemaAttribute, SchemaModel};
+
+    #[test]
+    fn it_reads_pg_dump_constraints() -> FoundationResult<()> {
+        let schema = postgresql_schema(
+            r#"
+SET statement_timeout = 0;
+
+CREATE TABLE public.users (
+    id bigint NOT NULL,
+    email character varying(255) NOT NULL
+);
+
+CREATE TABLE public.profiles (
+    id bigint NOT NULL,
+    user_id bigint NOT NULL,
+    tags text[]
+);
+
+ALTER TABLE ONLY public.users
+    ADD CONSTRAINT users_pkey PRIMARY KEY (id);
+
+ALTER TABLE ONLY public.profiles
+    ADD CONSTRAINT profiles_user_id_key UNIQUE (user_id);
+
+ALTER TABLE ONLY public.profiles
+    ADD CONSTRAINT profiles_user_id_fkey FOREIGN KEY (user_id) REFERENCES public.users(id);
+
+ALTER TABLE public.users OWNER TO postgres;
+"#,
+        )?;
+
+        assert_eq!(
+            schema.models,
+            vec![
+                SchemaModel {
+                    name: "User".to_string(),
+                    description: None,
+                    attributes: vec![SchemaAttribute {
+                        name: "Email".to_string(),
+                        description: None,
+                        type_name: "Text".to_string(),
+                    }],
+                    associations: vec![SchemaAssociation {
+                        name: "Profile".to_string(),
+                        description: None,
+                        kind: model::AssociationKind::HasOne,
+                        model_name: "Profile".to_string(),
+                    }],
+                },
+                SchemaModel {
+                    name: "Profile".to_string(),
+                    description: None,
+                    attributes: vec![
+                        SchemaAttribute {
+                            name: "Id".to_string(),
+                            description: None,
+                            type_name: "Big Integer".to_string(),
+                        },
+                        SchemaAttribute {
+                            name: "Tags".to_string(),
+                            description: None,
+                            type_name: "Json".to_string(),
+                        },
+                    ],
+                    associations: vec![SchemaAssociation {
+                        name: "User".to_string(),
+                        description: None,
+                        kind: model::AssociationKind::BelongsTo,
+                        model_name: "User".to_string(),
+                    }],
+                },
+            ]
+        );
+
+        assert_eq!(
+            schema
+                .skipped
+                .iter()
+                .map(|skipped| format!("{}: {}", skipped.location, skipped.construct))
+                .collect::<Vec<String>>(),
+            vec![
+                "line 2: SET",
+                "users: ALTER TABLE OWNER TO",
+                "profiles.tags: array",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_returns_syntax_errors_with_the_line() {
+        let error = postgresql_schema("CREATE TABLE books (\n    title\n);")
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.message(),
+            "invalid DDL, line 3: column `title` has no type"
+        );
+    }
+}
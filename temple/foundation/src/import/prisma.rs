@@ -0,0 +1,755 @@
+use super::{Schema, SchemaAssociation, SchemaAttribute, SchemaModel};
+use crate::{codegen, model, util, FoundationError, FoundationResult};
+use std::collections::HashSet;
+
+/// Reads the models of a Prisma schema, like [`codegen::prisma_schema`]
+/// writes them.
+///
+/// Scalar fields become attributes, named after the Prisma type and its
+/// native type attribute, like `Date` for `DateTime @db.Date`, or the name of
+/// the [`codegen::TYPE_TAG`]. Relation fields with the `fields` of the
+/// relation become `BelongsTo` associations, the other relation fields become
+/// `HasMany` associations when they are lists and `HasOne` associations
+/// otherwise. The foreign key fields, the `id` primary key and the fields
+/// with the [`codegen::IGNORE_TAG`] are left out. `///` comments become
+/// descriptions.
+///
+/// Everything else, like enums, defaults and indexes, is reported as
+/// skipped.
+pub fn prisma_schema(source: &str) -> FoundationResult<Schema> {
+    let tokens = tokens(source)?;
+    let mut parser = Parser {
+        tokens: &tokens,
+        position: 0,
+        models: vec![],
+        skipped: vec![],
+    };
+
+    parser.schema()?;
+
+    let Parser {
+        models,
+        mut skipped,
+        ..
+    } = parser;
+
+    let model_names: HashSet<&str> = models.iter().map(|model| model.name.as_str()).collect();
+    let mut schema_models = vec![];
+
+    for model in &models {
+        let (description, _) = doc_description(&model.doc_lines);
+
+        let foreign_keys: Vec<&str> = model
+            .fields
+            .iter()
+            .flat_map(|field| field.attribute("relation"))
+            .flat_map(relation_fields)
+            .collect();
+
+        let mut schema_model = SchemaModel {
+            name: util::string::title_case(&model.name),
+            description,
+            attributes: vec![],
+            associations: vec![],
+        };
+
+        for field in &model.fields {
+            let (description, tags) = doc_description(&field.doc_lines);
+            let location = format!("{}.{}", model.name, field.name);
+
+            if tags.iter().any(|tag| tag == codegen::IGNORE_TAG) {
+                continue;
+            }
+
+            if model_names.contains(field.type_name.as_str()) {
+                let kind = if field.is_list {
+                    model::AssociationKind::HasMany
+                } else if field
+                    .attribute("relation")
+                    .is_some_and(|arguments| !relation_fields(arguments).is_empty())
+                {
+                    model::AssociationKind::BelongsTo
+                } else {
+                    model::AssociationKind::HasOne
+                };
+
+                schema_model.associations.push(SchemaAssociation {
+                    name: util::string::title_case(&field.name),
+                    description,
+                    kind,
+                    model_name: util::string::title_case(&field.type_name),
+                });
+
+                continue;
+            }
+
+            if foreign_keys.contains(&field.name.as_str())
+                || (field.name == "id" && field.attribute("id").is_some())
+            {
+                continue;
+            }
+
+            let type_name = tags
+                .iter()
+                .find_map(|tag| type_tag(tag))
+                .unwrap_or_else(|| attribute_type_name(field));
+
+            let type_name = if field.is_list {
+                skipped.push(model::SkippedConstruct {
+                    location: location.clone(),
+                    construct: "list".to_string(),
+                    reason: "attributes have a single value, it's imported as Json".to_string(),
+                });
+
+                "Json".to_string()
+            } else {
+                type_name
+            };
+
+            for (name, _) in &field.attributes {
+                if !name.starts_with("db.") {
+                    skipped.push(model::SkippedConstruct {
+                        location: location.clone(),
+                        construct: format!("@{name}"),
+                        reason: "attributes only have a name and a type".to_string(),
+                    });
+                }
+            }
+
+            schema_model.attributes.push(SchemaAttribute {
+                name: util::string::title_case(&field.name),
+                description,
+                type_name,
+            });
+        }
+
+        for name in &model.block_attributes {
+            skipped.push(model::SkippedConstruct {
+                location: model.name.clone(),
+                construct: format!("@@{name}"),
+                reason: "models only have attributes and associations".to_string(),
+            });
+        }
+
+        schema_models.push(schema_model);
+    }
+
+    Ok(Schema {
+        models: schema_models,
+        skipped,
+    })
+}
+
+/// Name of the attribute type of the scalar field, the well known attribute
+/// type of its Prisma type and native type, the title cased type otherwise.
+fn attribute_type_name(field: &Field) -> String {
+    let native_type = field
+        .attributes
+        .iter()
+        .find_map(|(name, _)| name.strip_prefix("db."));
+
+    let attribute_type_name = match (field.type_name.as_str(), native_type) {
+        ("Int", Some("SmallInt")) => "Small Integer",
+        ("Int", _) => "Integer",
+        ("BigInt", _) => "Big Integer",
+        ("Float", Some("Real")) => "Float",
+        ("Float", _) => "Double",
+        ("Decimal", _) => "Decimal",
+        ("Boolean", _) => "Boolean",
+        ("DateTime", Some("Date")) => "Date",
+        ("DateTime", Some("Time" | "Timetz")) => "Time",
+        ("DateTime", _) => "Date Time",
+        ("String", Some("Uuid")) => "Uuid",
+        ("String", _) => "Text",
+        ("Json", _) => "Json",
+        ("Bytes", _) => "Binary",
+        (type_name, _) => return util::string::title_case(type_name),
+    };
+
+    attribute_type_name.to_string()
+}
+
+/// Description of the `///` comment lines, without the lines of the tags.
+fn doc_description(doc_lines: &[String]) -> (Option<String>, Vec<String>) {
+    let (tags, lines): (Vec<&String>, Vec<&String>) = doc_lines
+        .iter()
+        .partition(|line| line.starts_with("@metropolis."));
+
+    let description = lines
+        .iter()
+        .map(|line| line.as_str())
+        .collect::<Vec<&str>>()
+        .join("\n");
+
+    (
+        util::string::optional(description.trim()),
+        tags.into_iter().cloned().collect(),
+    )
+}
+
+/// Attribute type name of the `@metropolis.type("...")` tag.
+fn type_tag(tag: &str) -> Option<String> {
+    let arguments = tag.strip_prefix(codegen::TYPE_TAG)?;
+
+    match tokens(arguments).ok()?.as_slice() {
+        [Token {
+            value: TokenValue::Symbol('('),
+            ..
+        }, Token {
+            value: TokenValue::String(name),
+            ..
+        }, Token {
+            value: TokenValue::Symbol(')'),
+            ..
+        }] => Some(name.clone()),
+        _ => None,
+    }
+}
+
+/// Names of the `fields: [...]` argument of a `@relation` attribute.
+fn relation_fields(arguments: &[TokenValue]) -> Vec<&str> {
+    let mut names = vec![];
+    let mut is_in_fields = false;
+
+    for (index, token) in arguments.iter().enumerate() {
+        match token {
+            TokenValue::Word(word)
+                if word == "fields"
+                    && arguments.get(index + 1) == Some(&TokenValue::Symbol(':')) =>
+            {
+                is_in_fields = true;
+            }
+            TokenValue::Symbol(']') => is_in_fields = false,
+            TokenValue::Word(word) if is_in_fields => names.push(word.as_str()),
+            _ => {}
+        }
+    }
+
+    names
+}
+
+struct Model {
+    name: String,
+    doc_lines: Vec<String>,
+    fields: Vec<Field>,
+
+    /// Names of the `@@` attributes.
+    block_attributes: Vec<String>,
+}
+
+struct Field {
+    name: String,
+    type_name: String,
+    is_list: bool,
+    doc_lines: Vec<String>,
+
+    /// Names of the `@` attributes, like `relation` or `db.Uuid`, and the
+    /// tokens of their arguments.
+    attributes: Vec<(String, Vec<TokenValue>)>,
+}
+
+impl Field {
+    fn attribute(&self, name: &str) -> Option<&[TokenValue]> {
+        self.attributes
+            .iter()
+            .find(|(attribute_name, _)| attribute_name == name)
+            .map(|(_, arguments)| arguments.as_slice())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum TokenValue {
+    Word(String),
+    String(String),
+
+    /// Text of a `///` comment.
+    DocComment(String),
+
+    Symbol(char),
+    Newline,
+}
+
+struct Token {
+    value: TokenValue,
+    line: usize,
+}
+
+fn tokens(source: &str) -> FoundationResult<Vec<Token>> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = vec![];
+    let mut line = 1;
+    let mut index = 0;
+
+    while let Some(&char) = chars.get(index) {
+        let start_line = line;
+
+        let value = match char {
+            '\n' => {
+                index += 1;
+                line += 1;
+
+                TokenValue::Newline
+            }
+            char if char.is_whitespace() => {
+                index += 1;
+
+                continue;
+            }
+            '/' if chars.get(index + 1) == Some(&'/') => {
+                let start = index;
+
+                while chars.get(index).is_some_and(|char| *char != '\n') {
+                    index += 1;
+                }
+
+                let comment: String = chars[start..index].iter().collect();
+
+                match comment.strip_prefix("///") {
+                    Some(text) => {
+                        TokenValue::DocComment(text.strip_prefix(' ').unwrap_or(text).to_string())
+                    }
+                    None => continue,
+                }
+            }
+            '"' => {
+                index += 1;
+
+                let mut text = String::new();
+
+                loop {
+                    match chars.get(index) {
+                        Some('\\') if index + 1 < chars.len() => {
+                            text.push(chars[index + 1]);
+                            index += 2;
+                        }
+                        Some('"') => {
+                            index += 1;
+
+                            break;
+                        }
+                        Some('\n') | None => {
+                            return Err(syntax_error(line, "unterminated string"));
+                        }
+                        Some(char) => {
+                            text.push(*char);
+                            index += 1;
+                        }
+                    }
+                }
+
+                TokenValue::String(text)
+            }
+            char if char.is_alphanumeric() || char == '_' => {
+                let start = index;
+
+                while chars
+                    .get(index)
+                    .is_some_and(|char| char.is_alphanumeric() || *char == '_')
+                {
+                    index += 1;
+                }
+
+                TokenValue::Word(chars[start..index].iter().collect())
+            }
+            char => {
+                index += 1;
+
+                TokenValue::Symbol(char)
+            }
+        };
+
+        tokens.push(Token {
+            value,
+            line: start_line,
+        });
+    }
+
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    position: usize,
+    models: Vec<Model>,
+    skipped: Vec<model::SkippedConstruct>,
+}
+
+impl<'a> Parser<'a> {
+    fn schema(&mut self) -> FoundationResult<()> {
+        loop {
+            let doc_lines = self.doc_lines();
+
+            let Some(token) = self.next() else {
+                return Ok(());
+            };
+
+            let TokenValue::Word(keyword) = &token.value else {
+                return Err(self.error("expected a block"));
+            };
+
+            match keyword.as_str() {
+                "model" => {
+                    let model = self.model(doc_lines)?;
+
+                    self.models.push(model);
+                }
+                "datasource" | "generator" => {
+                    self.name()?;
+                    self.skip_group('{', '}')?;
+                }
+                _ => {
+                    let name = self.name()?;
+
+                    self.skipped.push(model::SkippedConstruct {
+                        location: name,
+                        construct: keyword.clone(),
+                        reason: "only models are imported".to_string(),
+                    });
+
+                    self.skip_group('{', '}')?;
+                }
+            }
+        }
+    }
+
+    fn model(&mut self, doc_lines: Vec<String>) -> FoundationResult<Model> {
+        let mut model = Model {
+            name: self.name()?,
+            doc_lines,
+            fields: vec![],
+            block_attributes: vec![],
+        };
+
+        self.expect_symbol('{')?;
+
+        loop {
+            let doc_lines = self.doc_lines();
+
+            if self.eat_symbol('}') {
+                return Ok(model);
+            }
+
+            if self.eat_symbol('@') {
+                self.expect_symbol('@')?;
+
+                let (name, _) = self.attribute()?;
+
+                model.block_attributes.push(name);
+            } else {
+                let field = self.field(doc_lines)?;
+
+                model.fields.push(field);
+            }
+
+            if !self.is_symbol('}') {
+                self.expect_newline()?;
+            }
+        }
+    }
+
+    fn field(&mut self, doc_lines: Vec<String>) -> FoundationResult<Field> {
+        let name = self.name()?;
+        let mut type_name = self.name()?;
+
+        // `Unsupported("...")` types are named after their database type.
+        if type_name == "Unsupported" && self.eat_symbol('(') {
+            type_name = match self.next().map(|token| &token.value) {
+                Some(TokenValue::String(database_type)) => database_type.clone(),
+                _ => return Err(self.error("expected a string")),
+            };
+
+            self.expect_symbol(')')?;
+        }
+
+        let mut is_list = false;
+
+        if self.eat_symbol('[') {
+            self.expect_symbol(']')?;
+            is_list = true;
+        } else {
+            self.eat_symbol('?');
+        }
+
+        let mut attributes = vec![];
+
+        while self.eat_symbol('@') {
+            attributes.push(self.attribute()?);
+        }
+
+        Ok(Field {
+            name,
+            type_name,
+            is_list,
+            doc_lines,
+            attributes,
+        })
+    }
+
+    /// Name of an attribute after its `@`, like `db.VarChar`, and the tokens
+    /// of its arguments.
+    fn attribute(&mut self) -> FoundationResult<(String, Vec<TokenValue>)> {
+        let mut name = self.name()?;
+
+        while self.eat_symbol('.') {
+            name = format!("{name}.{}", self.name()?);
+        }
+
+        let start = self.position;
+
+        if self.is_symbol('(') {
+            self.skip_group('(', ')')?;
+        }
+
+        let arguments = self.tokens[start..self.position]
+            .iter()
+            .map(|token| token.value.clone())
+            .collect();
+
+        Ok((name, arguments))
+    }
+
+    /// `///` comment lines, skipping the empty lines around them.
+    fn doc_lines(&mut self) -> Vec<String> {
+        let mut doc_lines = vec![];
+
+        while let Some(token) = self.tokens.get(self.position) {
+            match &token.value {
+                TokenValue::Newline => {}
+                TokenValue::DocComment(text) => doc_lines.push(text.clone()),
+                _ => break,
+            }
+
+            self.position += 1;
+        }
+
+        doc_lines
+    }
+
+    fn skip_group(&mut self, open: char, close: char) -> FoundationResult<()> {
+        self.expect_symbol(open)?;
+
+        let mut depth = 1;
+
+        while depth > 0 {
+            match self.next().map(|token| &token.value) {
+                Some(TokenValue::Symbol(symbol)) if *symbol == open => depth += 1,
+                Some(TokenValue::Symbol(symbol)) if *symbol == close => depth -= 1,
+                Some(_) => {}
+                None => return Err(self.error(&format!("expected `{close}`"))),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn name(&mut self) -> FoundationResult<String> {
+        match self.next().map(|token| &token.value) {
+            Some(TokenValue::Word(name)) => Ok(name.clone()),
+            _ => Err(self.error("expected a name")),
+        }
+    }
+
+    fn expect_newline(&mut self) -> FoundationResult<()> {
+        match self.next().map(|token| &token.value) {
+            Some(TokenValue::Newline) | None => Ok(()),
+            _ => Err(self.error("expected a line break")),
+        }
+    }
+
+    fn next(&mut self) -> Option<&'a Token> {
+        let token = self.tokens.get(self.position);
+
+        if token.is_some() {
+            self.position += 1;
+        }
+
+        token
+    }
+
+    fn is_symbol(&self, symbol: char) -> bool {
+        self.tokens
+            .get(self.position)
+            .is_some_and(|token| token.value == TokenValue::Symbol(symbol))
+    }
+
+    fn eat_symbol(&mut self, symbol: char) -> bool {
+        let is_symbol = self.is_symbol(symbol);
+
+        if is_symbol {
+            self.position += 1;
+        }
+
+        is_symbol
+    }
+
+    fn expect_symbol(&mut self, symbol: char) -> FoundationResult<()> {
+        if self.eat_symbol(symbol) {
+            return Ok(());
+        }
+
+        Err(self.error(&format!("expected `{symbol}`")))
+    }
+
+    fn error(&self, message: &str) -> FoundationError {
+        // The line of the token which was just read.
+        let line = self
+            .tokens
+            .get(self.position.saturating_sub(1))
+            .map(|token| token.line)
+            .unwrap_or(1);
+
+        syntax_error(line, message)
+    }
+}
+
+fn syntax_error(line: usize, message: &str) -> FoundationError {
+    FoundationError::invalid_argument(format!("invalid Prisma schema, line {line}: {message}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_reads_relation_variants() -> FoundationResult<()> {
+        let schema = prisma_schema(
+            r#"
+model User {
+  id      Int      @id @default(autoincrement())
+  posts   Post[]   @relation("author")
+  reviews Post[]   @relation(name: "reviewer")
+  profile Profile?
+}
+
+model Profile {
+  id     Int  @id
+  user   User @relation(fields: [userId], references: [id], onDelete: Cascade)
+  userId Int  @unique
+}
+
+model Post {
+  id         Int   @id
+  author     User  @relation("author", fields: [authorId], references: [id])
+  authorId   Int
+  reviewer   User? @relation(name: "reviewer", fields: [reviewerId], references: [id])
+  reviewerId Int?
+}
+"#,
+        )?;
+
+        let association = |name: &str, kind, model_name: &str| SchemaAssociation {
+            name: name.to_string(),
+            description: None,
+            kind,
+            model_name: model_name.to_string(),
+        };
+
+        assert_eq!(
+            schema
+                .models
+                .iter()
+                .map(|model| (model.name.as_str(), model.associations.clone()))
+                .collect::<Vec<(&str, Vec<SchemaAssociation>)>>(),
+            vec![
+                (
+                    "User",
+                    vec![
+                        association("Posts", model::AssociationKind::HasMany, "Post"),
+                        association("Reviews", model::AssociationKind::HasMany, "Post"),
+                        association("Profile", model::AssociationKind::HasOne, "Profile"),
+                    ]
+                ),
+                (
+                    "Profile",
+                    vec![association(
+                        "User",
+                        model::AssociationKind::BelongsTo,
+                        "User"
+                    )]
+                ),
+                (
+                    "Post",
+                    vec![
+                        association("Author", model::AssociationKind::BelongsTo, "User"),
+                        association("Reviewer", model::AssociationKind::BelongsTo, "User"),
+                    ]
+                ),
+            ]
+        );
+        assert!(schema
+            .models
+            .iter()
+            .all(|model| model.attributes.is_empty()));
+        assert_eq!(schema.skipped, vec![]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_reads_doc_comments() -> FoundationResult<()> {
+        let schema = prisma_schema(
+            r#"
+/// Author of the posts,
+/// with a profile.
+model User {
+  id     Int    @id
+  /// Contact address.
+  /// @metropolis.type("Email")
+  email  String
+  /// @metropolis.ignore
+  legacy String
+  // Not a doc comment.
+  name   String
+}
+"#,
+        )?;
+
+        assert_eq!(
+            schema.models,
+            vec![SchemaModel {
+                name: "User".to_string(),
+                description: Some("Author of the posts,\nwith a profile.".to_string()),
+                attributes: vec![
+                    SchemaAttribute {
+                        name: "Email".to_string(),
+                        description: Some("Contact address.".to_string()),
+                        type_name: "Email".to_string(),
+                    },
+                    SchemaAttribute {
+                        name: "Name".to_string(),
+                        description: None,
+                        type_name: "Text".to_string(),
+                    },
+                ],
+                associations: vec![],
+            }]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_returns_syntax_errors_with_the_line() {
+        let test_table = [
+            (
+                "model Post {\n  id Int @id\n  title String @db.VarChar(255\n}\n",
+                "invalid Prisma schema, line 4: expected `)`",
+            ),
+            (
+                "model Post {\n  title String @default(\"untitled)\n}\n",
+                "invalid Prisma schema, line 2: unterminated string",
+            ),
+            (
+                "model Post {\n  title\n}\n",
+                "invalid Prisma schema, line 2: expected a name",
+            ),
+            ("\n\n{\n", "invalid Prisma schema, line 3: expected a block"),
+        ];
+
+        for (source, message) in test_table {
+            let error = prisma_schema(source).err().unwrap();
+
+            assert_eq!(error.message(), message, "{source}");
+        }
+    }
+}
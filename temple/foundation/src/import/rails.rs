@@ -0,0 +1,916 @@
+use super::{
+    table::{self, Column, ForeignKey, Table},
+    Schema,
+};
+use crate::{model, util, FoundationError, FoundationResult};
+
+/// Reads the tables of a Rails `db/schema.rb`, the `ActiveRecord::Schema`
+/// definition dumped by `rails db:schema:dump`.
+///
+/// `create_table` blocks become models and their columns become attributes
+/// typed after the well known attribute type of the column type, like the
+/// tables of [`super::postgresql_schema`]. `t.references` columns and the
+/// columns of `add_foreign_key` become `BelongsTo` associations, along with
+/// the inverse `HasMany` association of the referenced model, `HasOne` when
+/// the column has a unique index.
+///
+/// Everything else is reported as skipped.
+pub fn rails_schema(source: &str) -> FoundationResult<Schema> {
+    let mut tables: Vec<Table> = vec![];
+    let mut foreign_keys: Vec<(usize, String, ForeignKey)> = vec![];
+    let mut skipped = vec![];
+    let mut blocks: Vec<Block> = vec![];
+    let mut is_defined = false;
+
+    for (index, text) in source.lines().enumerate() {
+        let line = index + 1;
+        let text = text.trim();
+
+        if text.is_empty() || text.starts_with('#') {
+            continue;
+        }
+
+        if text == "end" {
+            if blocks.pop().is_none() {
+                return Err(syntax_error(line, "unexpected `end`"));
+            }
+
+            continue;
+        }
+
+        if text.starts_with("ActiveRecord::Schema") {
+            if !text.contains(".define") || !blocks.is_empty() {
+                return Err(syntax_error(line, "expected `ActiveRecord::Schema.define`"));
+            }
+
+            is_defined = true;
+            blocks.push(Block::Schema);
+
+            continue;
+        }
+
+        let call = LineParser::new(text, line).call()?;
+
+        match (blocks.last(), call.method.as_str()) {
+            (Some(Block::Schema), "create_table") => {
+                let table = create_table(&call)?;
+
+                blocks.push(Block::Table(tables.len()));
+                tables.push(table);
+
+                continue;
+            }
+            (Some(Block::Schema), "add_foreign_key") => {
+                let (table_name, foreign_key) = add_foreign_key(&call)?;
+
+                foreign_keys.push((line, table_name, foreign_key));
+            }
+            (Some(Block::Table(table_index)), _) if call.receiver.is_some() => {
+                table_column(&mut tables[*table_index], &call)?;
+            }
+            (Some(Block::Schema), method) | (Some(Block::Table(_)), method) => {
+                skipped.push(model::SkippedConstruct {
+                    location: format!("line {line}"),
+                    construct: method.to_string(),
+                    reason: "only tables and foreign keys are imported".to_string(),
+                })
+            }
+            (Some(Block::Other), _) => {}
+            (None, _) => {
+                return Err(syntax_error(line, "expected `ActiveRecord::Schema.define`"));
+            }
+        }
+
+        if call.opens_block {
+            blocks.push(Block::Other);
+        }
+    }
+
+    if !is_defined {
+        return Err(syntax_error(1, "expected `ActiveRecord::Schema.define`"));
+    }
+
+    if !blocks.is_empty() {
+        return Err(syntax_error(source.lines().count(), "missing `end`"));
+    }
+
+    for (line, table_name, foreign_key) in foreign_keys {
+        let Some(table) = tables.iter_mut().find(|table| table.name == table_name) else {
+            skipped.push(model::SkippedConstruct {
+                location: format!("line {line}"),
+                construct: "add_foreign_key".to_string(),
+                reason: format!("table `{table_name}` isn't part of the schema"),
+            });
+
+            continue;
+        };
+
+        table.foreign_keys.push(foreign_key);
+    }
+
+    let mut schema = table::models(&tables);
+    skipped.append(&mut schema.skipped);
+    schema.skipped = skipped;
+
+    Ok(schema)
+}
+
+/// Block opened by a `do`, closed by an `end`.
+enum Block {
+    Schema,
+    Table(usize),
+    Other,
+}
+
+/// Method call taking up a line, like
+/// `t.string "title", null: false, comment: "Full title."`.
+#[derive(Debug, PartialEq)]
+struct Call {
+    receiver: Option<String>,
+    method: String,
+    arguments: Vec<Value>,
+    options: Vec<(String, Value)>,
+    opens_block: bool,
+    line: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    String(String),
+    Symbol(String),
+    Number(String),
+    Boolean(bool),
+    Nil,
+    Array(Vec<Value>),
+    Hash(Vec<(String, Value)>),
+
+    /// Any other expression, like a lambda, as it's written.
+    Other(String),
+}
+
+impl Value {
+    /// Text of strings and symbols.
+    fn text(&self) -> Option<&str> {
+        match self {
+            Value::String(text) | Value::Symbol(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    fn is_truthy(&self) -> bool {
+        !matches!(self, Value::Boolean(false) | Value::Nil)
+    }
+}
+
+impl Call {
+    fn option(&self, name: &str) -> Option<&Value> {
+        option(&self.options, name)
+    }
+
+    /// Text of the first argument, the name of the table or the column.
+    fn name(&self) -> FoundationResult<String> {
+        self.arguments
+            .first()
+            .and_then(Value::text)
+            .map(ToString::to_string)
+            .ok_or_else(|| syntax_error(self.line, &format!("`{}` expects a name", self.method)))
+    }
+}
+
+fn option<'a>(options: &'a [(String, Value)], name: &str) -> Option<&'a Value> {
+    options
+        .iter()
+        .find(|(option_name, _)| option_name == name)
+        .map(|(_, value)| value)
+}
+
+fn create_table(call: &Call) -> FoundationResult<Table> {
+    let name = call.name()?;
+
+    let mut table = Table {
+        name: name.clone(),
+        description: call
+            .option("comment")
+            .and_then(Value::text)
+            .and_then(|comment| util::string::optional(comment.trim())),
+        ..Default::default()
+    };
+
+    // Rails adds the `id` column unless the table has another primary key.
+    match call.option("primary_key") {
+        Some(Value::Array(columns)) => {
+            table.primary_key = columns
+                .iter()
+                .filter_map(Value::text)
+                .map(ToString::to_string)
+                .collect();
+        }
+        Some(value) if value.text().is_some_and(|column| column != "id") => {
+            let column = value.text().unwrap_or_default().to_string();
+            let type_name = call.option("id").and_then(Value::text).unwrap_or("bigint");
+
+            table.columns.push(Column {
+                name: column.clone(),
+                type_name: attribute_type_name(type_name, None),
+                ..Default::default()
+            });
+            table.primary_key = vec![column];
+        }
+        _ => {}
+    }
+
+    Ok(table)
+}
+
+fn add_foreign_key(call: &Call) -> FoundationResult<(String, ForeignKey)> {
+    let line = call.line;
+
+    let (Some(table_name), Some(referenced_table)) = (
+        call.arguments.first().and_then(Value::text),
+        call.arguments.get(1).and_then(Value::text),
+    ) else {
+        return Err(syntax_error(line, "`add_foreign_key` expects two tables"));
+    };
+
+    let column = match call.option("column").and_then(Value::text) {
+        Some(column) => column.to_string(),
+        None => format!("{}_id", util::inflection::singularize(referenced_table)),
+    };
+
+    let foreign_key = ForeignKey {
+        columns: vec![column],
+        referenced_table: referenced_table.to_string(),
+        association_names: None,
+    };
+
+    Ok((table_name.to_string(), foreign_key))
+}
+
+/// Adds the column, or the columns, of the `t.{method}` call of a
+/// `create_table` block.
+fn table_column(table: &mut Table, call: &Call) -> FoundationResult<()> {
+    let line = call.line;
+
+    let method = match call.method.as_str() {
+        "index" => {
+            let columns: Vec<String> = match call.arguments.first() {
+                Some(Value::Array(columns)) => columns
+                    .iter()
+                    .filter_map(Value::text)
+                    .map(ToString::to_string)
+                    .collect(),
+                Some(value) => value.text().map(ToString::to_string).into_iter().collect(),
+                None => vec![],
+            };
+
+            if columns.len() == 1 && call.option("unique").is_some_and(Value::is_truthy) {
+                table.unique_keys.push(columns);
+            } else {
+                table.skipped.push(model::SkippedConstruct {
+                    location: format!("{}.{}", table.name, columns.join(", ")),
+                    construct: "index".to_string(),
+                    reason: "models don't have indexes".to_string(),
+                });
+            }
+
+            return Ok(());
+        }
+        "timestamps" => {
+            for name in ["created_at", "updated_at"] {
+                table.columns.push(Column {
+                    name: name.to_string(),
+                    type_name: attribute_type_name("datetime", None),
+                    ..Default::default()
+                });
+            }
+
+            return Ok(());
+        }
+        // `t.column "name", :type`
+        "column" => call
+            .arguments
+            .get(1)
+            .and_then(Value::text)
+            .ok_or_else(|| syntax_error(line, "`column` expects a type"))?,
+        method => method,
+    };
+
+    let name = call.name()?;
+    let location = format!("{}.{name}", table.name);
+
+    let column = if method == "references" || method == "belongs_to" {
+        if call.option("polymorphic").is_some_and(Value::is_truthy) {
+            table.skipped.push(model::SkippedConstruct {
+                location,
+                construct: "polymorphic reference".to_string(),
+                reason: "associations have a single model".to_string(),
+            });
+
+            return Ok(());
+        }
+
+        let column = format!("{name}_id");
+
+        let referenced_table = match call.option("foreign_key") {
+            Some(Value::Hash(options)) => option(options, "to_table")
+                .and_then(Value::text)
+                .map(ToString::to_string),
+            _ => None,
+        }
+        .unwrap_or_else(|| util::inflection::pluralize(&name));
+
+        table.foreign_keys.push(ForeignKey {
+            columns: vec![column.clone()],
+            referenced_table,
+            association_names: None,
+        });
+
+        if let Some(Value::Hash(options)) = call.option("index") {
+            if option(options, "unique").is_some_and(Value::is_truthy) {
+                table.unique_keys.push(vec![column.clone()]);
+            }
+        }
+
+        let type_name = call
+            .option("type")
+            .and_then(Value::text)
+            .unwrap_or("bigint");
+
+        Column {
+            name: column,
+            type_name: attribute_type_name(type_name, None),
+            ..Default::default()
+        }
+    } else {
+        Column {
+            name,
+            type_name: attribute_type_name(method, call.option("limit")),
+            is_array: call.option("array").is_some_and(Value::is_truthy),
+            ..Default::default()
+        }
+    };
+
+    if call.option("default").is_some() {
+        table.skipped.push(model::SkippedConstruct {
+            location: format!("{}.{}", table.name, column.name),
+            construct: "default".to_string(),
+            reason: "attributes only have a name and a type".to_string(),
+        });
+    }
+
+    table.columns.push(Column {
+        description: call
+            .option("comment")
+            .and_then(Value::text)
+            .and_then(|comment| util::string::optional(comment.trim())),
+        ..column
+    });
+
+    Ok(())
+}
+
+/// Name of the attribute type of the column type, the name of the well known
+/// attribute type when there is one.
+fn attribute_type_name(type_name: &str, limit: Option<&Value>) -> String {
+    let limit = match limit {
+        Some(Value::Number(limit)) => limit.parse().ok(),
+        _ => None,
+    };
+
+    let name = match (type_name, limit) {
+        ("integer", Some(1 | 2)) => "Small Integer",
+        ("integer", Some(8)) | ("bigint", _) => "Big Integer",
+        ("integer", _) => "Integer",
+        ("float", Some(53)) => "Double",
+        ("float", _) => "Float",
+        ("decimal" | "numeric", _) => "Decimal",
+        ("boolean", _) => "Boolean",
+        ("date", _) => "Date",
+        ("time", _) => "Time",
+        ("datetime" | "timestamp" | "timestamptz", _) => "Date Time",
+        ("uuid", _) => "Uuid",
+        ("json" | "jsonb", _) => "Json",
+        ("binary", _) => "Binary",
+        ("string", _) => "String",
+        ("text", _) => "Text",
+        (type_name, _) => return util::string::title_case(type_name),
+    };
+
+    name.to_string()
+}
+
+/// Reads the method call of a line, the Ruby of `db/schema.rb` is made of a
+/// method call per line.
+struct LineParser {
+    chars: Vec<char>,
+    position: usize,
+    line: usize,
+}
+
+impl LineParser {
+    fn new(text: &str, line: usize) -> Self {
+        Self {
+            chars: text.chars().collect(),
+            position: 0,
+            line,
+        }
+    }
+
+    fn call(&mut self) -> FoundationResult<Call> {
+        let mut method = self.identifier()?;
+        let mut receiver = None;
+
+        if self.eat('.') {
+            receiver = Some(method);
+            method = self.identifier()?;
+        }
+
+        let mut call = Call {
+            receiver,
+            method,
+            arguments: vec![],
+            options: vec![],
+            opens_block: false,
+            line: self.line,
+        };
+
+        let has_parentheses = self.eat('(');
+
+        loop {
+            self.skip_whitespace();
+
+            if self.is_at_end() || self.is_keyword("do") {
+                break;
+            }
+
+            if has_parentheses && self.eat(')') {
+                self.skip_whitespace();
+
+                break;
+            }
+
+            match self.option_name() {
+                Some(name) => {
+                    let value = self.value()?;
+
+                    call.options.push((name, value));
+                }
+                None => call.arguments.push(self.value()?),
+            }
+
+            self.skip_whitespace();
+
+            if !self.eat(',') {
+                if has_parentheses {
+                    self.skip_whitespace();
+                    self.expect(')')?;
+                    self.skip_whitespace();
+                }
+
+                break;
+            }
+        }
+
+        if self.is_keyword("do") {
+            self.position += 2;
+            call.opens_block = true;
+
+            // Block parameters, like `|t|`.
+            self.skip_whitespace();
+
+            if self.eat('|') {
+                while !self.eat('|') {
+                    if self.is_at_end() {
+                        return Err(self.error("unterminated block parameters"));
+                    }
+
+                    self.position += 1;
+                }
+            }
+        }
+
+        self.skip_whitespace();
+
+        if !self.is_at_end() && self.peek() != Some('#') {
+            return Err(self.error(&format!(
+                "unexpected `{}`",
+                self.chars[self.position..].iter().collect::<String>()
+            )));
+        }
+
+        Ok(call)
+    }
+
+    /// Name of the option when the value is preceded by `name:`, `:name =>`
+    /// or `"name" =>`.
+    fn option_name(&mut self) -> Option<String> {
+        let start = self.position;
+
+        if let Ok(name) = self.identifier() {
+            if self.peek() == Some(':') && self.chars.get(self.position + 1) != Some(&':') {
+                self.position += 1;
+
+                return Some(name);
+            }
+        }
+
+        self.position = start;
+
+        if let Ok(Some(name)) = self
+            .value()
+            .map(|value| value.text().map(ToString::to_string))
+        {
+            self.skip_whitespace();
+
+            if self.eat('=') && self.eat('>') {
+                return Some(name);
+            }
+        }
+
+        self.position = start;
+
+        None
+    }
+
+    fn value(&mut self) -> FoundationResult<Value> {
+        self.skip_whitespace();
+
+        let Some(char) = self.peek() else {
+            return Err(self.error("expected a value"));
+        };
+
+        let value = match char {
+            '"' | '\'' => Value::String(self.string()?),
+            ':' if self.chars.get(self.position + 1).is_some_and(|c| *c == '"') => {
+                self.position += 1;
+
+                Value::Symbol(self.string()?)
+            }
+            ':' => {
+                self.position += 1;
+
+                Value::Symbol(self.identifier()?)
+            }
+            '[' => {
+                self.position += 1;
+
+                let mut values = vec![];
+
+                loop {
+                    self.skip_whitespace();
+
+                    if self.eat(']') {
+                        break;
+                    }
+
+                    values.push(self.value()?);
+                    self.skip_whitespace();
+
+                    if !self.eat(',') {
+                        self.skip_whitespace();
+                        self.expect(']')?;
+
+                        break;
+                    }
+                }
+
+                Value::Array(values)
+            }
+            '{' => {
+                self.position += 1;
+
+                let mut options = vec![];
+
+                loop {
+                    self.skip_whitespace();
+
+                    if self.eat('}') {
+                        break;
+                    }
+
+                    let Some(name) = self.option_name() else {
+                        return Err(self.error("expected a hash key"));
+                    };
+
+                    options.push((name, self.value()?));
+                    self.skip_whitespace();
+
+                    if !self.eat(',') {
+                        self.skip_whitespace();
+                        self.expect('}')?;
+
+                        break;
+                    }
+                }
+
+                Value::Hash(options)
+            }
+            char if char.is_ascii_digit() || (char == '-' && self.is_number_ahead()) => {
+                let start = self.position;
+                self.position += 1;
+
+                while self
+                    .peek()
+                    .is_some_and(|c| c.is_ascii_digit() || c == '.' || c == '_')
+                {
+                    self.position += 1;
+                }
+
+                Value::Number(self.chars[start..self.position].iter().collect())
+            }
+            _ => self.expression()?,
+        };
+
+        Ok(value)
+    }
+
+    /// Expression which isn't a literal, like `-> { "now()" }` or
+    /// `Float::INFINITY`, up to the next `,` or the closing bracket.
+    fn expression(&mut self) -> FoundationResult<Value> {
+        let start = self.position;
+        let mut depth = 0;
+
+        while let Some(char) = self.peek() {
+            match char {
+                '"' | '\'' => {
+                    self.string()?;
+
+                    continue;
+                }
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' if depth == 0 => break,
+                ')' | ']' | '}' => depth -= 1,
+                ',' if depth == 0 => break,
+                _ => {}
+            }
+
+            self.position += 1;
+        }
+
+        let text: String = self.chars[start..self.position].iter().collect();
+        let text = text.trim();
+
+        let value = match text {
+            "" => return Err(self.error("expected a value")),
+            "true" => Value::Boolean(true),
+            "false" => Value::Boolean(false),
+            "nil" => Value::Nil,
+            text => Value::Other(text.to_string()),
+        };
+
+        Ok(value)
+    }
+
+    fn string(&mut self) -> FoundationResult<String> {
+        let Some(quote) = self.peek() else {
+            return Err(self.error("expected a string"));
+        };
+
+        self.position += 1;
+
+        let mut text = String::new();
+
+        loop {
+            match self.peek() {
+                Some('\\') => {
+                    let escaped = match self.chars.get(self.position + 1) {
+                        Some('n') if quote == '"' => '\n',
+                        Some('t') if quote == '"' => '\t',
+                        Some(char) => *char,
+                        None => return Err(self.error("unterminated string")),
+                    };
+
+                    text.push(escaped);
+                    self.position += 2;
+                }
+                Some(char) if char == quote => {
+                    self.position += 1;
+
+                    return Ok(text);
+                }
+                Some(char) => {
+                    text.push(char);
+                    self.position += 1;
+                }
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    fn identifier(&mut self) -> FoundationResult<String> {
+        let start = self.position;
+
+        while self
+            .peek()
+            .is_some_and(|char| char.is_alphanumeric() || char == '_')
+        {
+            self.position += 1;
+        }
+
+        if self.peek().is_some_and(|char| char == '?' || char == '!') {
+            self.position += 1;
+        }
+
+        if self.position == start {
+            return Err(self.error("expected an identifier"));
+        }
+
+        Ok(self.chars[start..self.position].iter().collect())
+    }
+
+    fn is_number_ahead(&self) -> bool {
+        self.chars
+            .get(self.position + 1)
+            .is_some_and(char::is_ascii_digit)
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        let end = self.position + keyword.len();
+
+        self.chars.get(self.position..end).is_some_and(|chars| {
+            chars.iter().copied().eq(keyword.chars())
+                && self
+                    .chars
+                    .get(end)
+                    .map_or(true, |char| !(char.is_alphanumeric() || *char == '_'))
+        })
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.position >= self.chars.len()
+    }
+
+    fn eat(&mut self, char: char) -> bool {
+        let is_char = self.peek() == Some(char);
+
+        if is_char {
+            self.position += 1;
+        }
+
+        is_char
+    }
+
+    fn expect(&mut self, char: char) -> FoundationResult<()> {
+        if self.eat(char) {
+            return Ok(());
+        }
+
+        Err(self.error(&format!("expected `{char}`")))
+    }
+
+    fn error(&self, message: &str) -> FoundationError {
+        syntax_error(self.line, message)
+    }
+}
+
+fn syntax_error(line: usize, message: &str) -> FoundationError {
+    FoundationError::invalid_argument(format!("invalid schema, line {line}: {message}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::{SchemaAssociation, SchemaAttribute, SchemaModel};
+
+    #[test]
+    fn it_reads_create_table_blocks_and_foreign_keys() -> FoundationResult<()> {
+        let schema = rails_schema(
+            r#"# This file is auto-generated from the current state of the database.
+
+ActiveRecord::Schema[7.0].define(version: 2023_09_15_101500) do
+  enable_extension "plpgsql"
+
+  create_table "authors", force: :cascade do |t|
+    t.string "name", null: false
+  end
+
+  create_table "books", force: :cascade, comment: "Printed book." do |t|
+    t.string "title", null: false, comment: "Full title."
+    t.integer "pages", limit: 2, default: 0
+    t.references "author", null: false, index: { unique: true }
+    t.bigint "publisher_id"
+    t.datetime "created_at", null: false
+    t.index ["title"], name: "index_books_on_title"
+  end
+
+  create_table "publishers", id: :uuid, force: :cascade do |t|
+    t.string "name"
+  end
+
+  add_foreign_key "books", "publishers"
+  add_foreign_key "books", "authors"
+end
+"#,
+        )?;
+
+        assert_eq!(
+            schema.models,
+            vec![
+                SchemaModel {
+                    name: "Author".to_string(),
+                    description: None,
+                    attributes: vec![SchemaAttribute {
+                        name: "Name".to_string(),
+                        description: None,
+                        type_name: "String".to_string(),
+                    }],
+                    associations: vec![SchemaAssociation {
+                        name: "Book".to_string(),
+                        description: None,
+                        kind: model::AssociationKind::HasOne,
+                        model_name: "Book".to_string(),
+                    }],
+                },
+                SchemaModel {
+                    name: "Book".to_string(),
+                    description: Some("Printed book.".to_string()),
+                    attributes: vec![
+                        SchemaAttribute {
+                            name: "Title".to_string(),
+                            description: Some("Full title.".to_string()),
+                            type_name: "String".to_string(),
+                        },
+                        SchemaAttribute {
+                            name: "Pages".to_string(),
+                            description: None,
+                            type_name: "Small Integer".to_string(),
+                        },
+                        SchemaAttribute {
+                            name: "Created At".to_string(),
+                            description: None,
+                            type_name: "Date Time".to_string(),
+                        },
+                    ],
+                    associations: vec![
+                        SchemaAssociation {
+                            name: "Author".to_string(),
+                            description: None,
+                            kind: model::AssociationKind::BelongsTo,
+                            model_name: "Author".to_string(),
+                        },
+                        SchemaAssociation {
+                            name: "Publisher".to_string(),
+                            description: None,
+                            kind: model::AssociationKind::BelongsTo,
+                            model_name: "Publisher".to_string(),
+                        },
+                    ],
+                },
+                SchemaModel {
+                    name: "Publisher".to_string(),
+                    description: None,
+                    attributes: vec![SchemaAttribute {
+                        name: "Name".to_string(),
+                        description: None,
+                        type_name: "String".to_string(),
+                    }],
+                    associations: vec![SchemaAssociation {
+                        name: "Books".to_string(),
+                        description: None,
+                        kind: model::AssociationKind::HasMany,
+                        model_name: "Book".to_string(),
+                    }],
+                },
+            ]
+        );
+
+        assert_eq!(
+            schema
+                .skipped
+                .iter()
+                .map(|skipped| format!("{}: {}", skipped.location, skipped.construct))
+                .collect::<Vec<String>>(),
+            vec![
+                "line 4: enable_extension",
+                "books.pages: default",
+                "books.title: index",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_returns_syntax_errors_with_the_line() {
+        let error = rails_schema(
+            "ActiveRecord::Schema.define(version: 1) do\n  create_table \"books\" do |t|\n    t.string \"title\n  end\nend\n",
+        )
+        .err()
+        .unwrap();
+
+        assert_eq!(
+            error.message(),
+            "invalid schema, line 3: unterminated string"
+        );
+    }
+}
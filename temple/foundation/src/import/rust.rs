@@ -0,0 +1,386 @@
+use super::{Schema, SchemaAssociation, SchemaAttribute, SchemaModel};
+use crate::{model, util, FoundationError, FoundationResult};
+
+/// Reads the structs of a Rust source file, including the ones of its inline
+/// modules.
+///
+/// Structs become models, and named fields become attributes whose attribute
+/// type is named after the field type path, like `String` or
+/// `chrono::DateTime<Utc>`, without the `Option` of optional fields. Fields of
+/// one of the structs become associations, `BelongsTo` for `Struct`, `HasOne`
+/// for `Option<Struct>` and `HasMany` for `Vec<Struct>`. Doc comments become
+/// descriptions.
+///
+/// Enums, unions, generic structs and the fields which can't be represented
+/// are reported as skipped.
+pub fn rust_schema(source: &str) -> FoundationResult<Schema> {
+    let file = syn::parse_file(source)
+        .map_err(|err| FoundationError::invalid_argument(format!("invalid Rust source, {err}")))?;
+
+    let mut schema = Schema::default();
+    let mut structs = vec![];
+
+    collect_structs(&file.items, &mut structs, &mut schema.skipped);
+
+    let struct_names: Vec<String> = structs
+        .iter()
+        .map(|item_struct| item_struct.ident.to_string())
+        .collect();
+
+    for item_struct in structs {
+        let struct_name = item_struct.ident.to_string();
+
+        let mut model = SchemaModel {
+            name: model_name(&struct_name),
+            description: description(&item_struct.attrs),
+            attributes: vec![],
+            associations: vec![],
+        };
+
+        for (index, field) in item_struct.fields.iter().enumerate() {
+            let Some(ident) = &field.ident else {
+                schema.skipped.push(model::SkippedConstruct {
+                    location: format!("{struct_name}.{index}"),
+                    construct: "tuple field".to_string(),
+                    reason: "attributes are named".to_string(),
+                });
+
+                continue;
+            };
+
+            let field_name = ident.to_string();
+            let field_name = field_name.trim_start_matches("r#");
+            let name = util::string::title_case(field_name);
+            let description = description(&field.attrs);
+
+            if let Some((kind, associated_struct_name)) = association(&field.ty, &struct_names) {
+                model.associations.push(SchemaAssociation {
+                    name,
+                    description,
+                    kind,
+                    model_name: model_name(&associated_struct_name),
+                });
+
+                continue;
+            }
+
+            let ty = generic_argument(&field.ty, "Option").unwrap_or(&field.ty);
+
+            let Some(type_name) = type_path(ty) else {
+                schema.skipped.push(model::SkippedConstruct {
+                    location: format!("{struct_name}.{field_name}"),
+                    construct: "field".to_string(),
+                    reason: "only fields with a type path are imported".to_string(),
+                });
+
+                continue;
+            };
+
+            model.attributes.push(SchemaAttribute {
+                name,
+                description,
+                type_name,
+            });
+        }
+
+        schema.models.push(model);
+    }
+
+    Ok(schema)
+}
+
+fn collect_structs<'a>(
+    items: &'a [syn::Item],
+    structs: &mut Vec<&'a syn::ItemStruct>,
+    skipped: &mut Vec<model::SkippedConstruct>,
+) {
+    for item in items {
+        match item {
+            syn::Item::Struct(item_struct) if !item_struct.generics.params.is_empty() => skipped
+                .push(model::SkippedConstruct {
+                    location: item_struct.ident.to_string(),
+                    construct: "generic struct".to_string(),
+                    reason: "models aren't generic".to_string(),
+                }),
+            syn::Item::Struct(item_struct) => structs.push(item_struct),
+            syn::Item::Mod(syn::ItemMod {
+                content: Some((_, items)),
+                ..
+            }) => collect_structs(items, structs, skipped),
+            syn::Item::Enum(syn::ItemEnum { ident, .. }) => {
+                skipped.push(skipped_item(ident, "enum"))
+            }
+            syn::Item::Union(syn::ItemUnion { ident, .. }) => {
+                skipped.push(skipped_item(ident, "union"))
+            }
+            _ => {}
+        }
+    }
+}
+
+fn skipped_item(ident: &syn::Ident, construct: &str) -> model::SkippedConstruct {
+    model::SkippedConstruct {
+        location: ident.to_string(),
+        construct: construct.to_string(),
+        reason: "only structs are imported".to_string(),
+    }
+}
+
+/// Words of the struct name, e.g. `Order Item` for `OrderItem`.
+fn model_name(struct_name: &str) -> String {
+    util::string::title_case(struct_name)
+}
+
+/// Kind of the association and name of the associated struct when the type
+/// is one of the structs, possibly wrapped in an `Option` or a `Vec`.
+fn association(
+    ty: &syn::Type,
+    struct_names: &[String],
+) -> Option<(model::AssociationKind, String)> {
+    let (kind, ty) = if let Some(ty) = generic_argument(ty, "Option") {
+        (model::AssociationKind::HasOne, ty)
+    } else if let Some(ty) = generic_argument(ty, "Vec") {
+        (model::AssociationKind::HasMany, ty)
+    } else {
+        (model::AssociationKind::BelongsTo, ty)
+    };
+
+    let segment = last_segment(ty)?;
+
+    if !segment.arguments.is_empty() {
+        return None;
+    }
+
+    let name = segment.ident.to_string();
+
+    struct_names.contains(&name).then_some((kind, name))
+}
+
+/// Type argument of the type when it's the given wrapper, like `T` for
+/// `Option<T>`.
+fn generic_argument<'a>(ty: &'a syn::Type, wrapper: &str) -> Option<&'a syn::Type> {
+    let segment = last_segment(ty)?;
+
+    if segment.ident != wrapper {
+        return None;
+    }
+
+    let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+
+    match arguments.args.iter().collect::<Vec<_>>().as_slice() {
+        [syn::GenericArgument::Type(ty)] => Some(ty),
+        _ => None,
+    }
+}
+
+fn last_segment(ty: &syn::Type) -> Option<&syn::PathSegment> {
+    match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => path.segments.last(),
+        _ => None,
+    }
+}
+
+/// Type path as it's usually written, e.g. `HashMap<String, i64>`.
+fn type_path(ty: &syn::Type) -> Option<String> {
+    match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => {
+            let mut segments = vec![];
+
+            for segment in &path.segments {
+                let mut segment_path = segment.ident.to_string();
+
+                match &segment.arguments {
+                    syn::PathArguments::None => {}
+                    syn::PathArguments::AngleBracketed(arguments) => {
+                        let arguments = arguments
+                            .args
+                            .iter()
+                            .filter_map(|argument| match argument {
+                                syn::GenericArgument::Lifetime(_) => None,
+                                syn::GenericArgument::Type(ty) => Some(type_path(ty)),
+                                _ => Some(None),
+                            })
+                            .collect::<Option<Vec<String>>>()?;
+
+                        if !arguments.is_empty() {
+                            segment_path = format!("{segment_path}<{}>", arguments.join(", "));
+                        }
+                    }
+                    syn::PathArguments::Parenthesized(_) => return None,
+                }
+
+                segments.push(segment_path);
+            }
+
+            let type_path = segments.join("::");
+
+            match path.leading_colon {
+                Some(_) => Some(format!("::{type_path}")),
+                None => Some(type_path),
+            }
+        }
+        syn::Type::Reference(reference) => type_path(&reference.elem),
+        syn::Type::Paren(paren) => type_path(&paren.elem),
+        syn::Type::Group(group) => type_path(&group.elem),
+        _ => None,
+    }
+}
+
+/// Text of the doc comments, without the leading space of their lines.
+fn description(attrs: &[syn::Attribute]) -> Option<String> {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(text),
+                        ..
+                    }),
+                ..
+            }) => Some(text.value()),
+            _ => None,
+        })
+        .flat_map(|text| {
+            text.split('\n')
+                .map(|line| {
+                    line.strip_prefix(' ')
+                        .unwrap_or(line)
+                        .trim_end()
+                        .to_string()
+                })
+                .collect::<Vec<String>>()
+        })
+        .collect();
+
+    util::string::optional(lines.join("\n").trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_reads_structs_and_their_associations() -> FoundationResult<()> {
+        let schema = rust_schema(
+            r#"
+use chrono::{DateTime, Utc};
+
+/// Company publishing books.
+///
+/// Also known as a publishing house.
+pub struct Publisher {
+    pub name: String,
+    pub books: Vec<Book>,
+}
+
+mod books {
+    pub struct Book {
+        /// Full title.
+        pub title: Option<String>,
+        pub published_at: chrono::DateTime<Utc>,
+        pub publisher: Publisher,
+        pub cover: Option<Cover>,
+        pub tags: Vec<String>,
+        pub dimensions: (u32, u32),
+    }
+
+    pub struct Cover(String);
+}
+
+pub enum Format {
+    Paperback,
+    Hardcover,
+}
+
+pub struct Page<T> {
+    pub items: Vec<T>,
+}
+"#,
+        )?;
+
+        assert_eq!(
+            schema.models,
+            vec![
+                SchemaModel {
+                    name: "Publisher".to_string(),
+                    description: Some(
+                        "Company publishing books.\n\nAlso known as a publishing house."
+                            .to_string()
+                    ),
+                    attributes: vec![SchemaAttribute {
+                        name: "Name".to_string(),
+                        description: None,
+                        type_name: "String".to_string(),
+                    }],
+                    associations: vec![SchemaAssociation {
+                        name: "Books".to_string(),
+                        description: None,
+                        kind: model::AssociationKind::HasMany,
+                        model_name: "Book".to_string(),
+                    }],
+                },
+                SchemaModel {
+                    name: "Book".to_string(),
+                    description: None,
+                    attributes: vec![
+                        SchemaAttribute {
+                            name: "Title".to_string(),
+                            description: Some("Full title.".to_string()),
+                            type_name: "String".to_string(),
+                        },
+                        SchemaAttribute {
+                            name: "Published At".to_string(),
+                            description: None,
+                            type_name: "chrono::DateTime<Utc>".to_string(),
+                        },
+                        SchemaAttribute {
+                            name: "Tags".to_string(),
+                            description: None,
+                            type_name: "Vec<String>".to_string(),
+                        },
+                    ],
+                    associations: vec![
+                        SchemaAssociation {
+                            name: "Publisher".to_string(),
+                            description: None,
+                            kind: model::AssociationKind::BelongsTo,
+                            model_name: "Publisher".to_string(),
+                        },
+                        SchemaAssociation {
+                            name: "Cover".to_string(),
+                            description: None,
+                            kind: model::AssociationKind::HasOne,
+                            model_name: "Cover".to_string(),
+                        },
+                    ],
+                },
+                SchemaModel {
+                    name: "Cover".to_string(),
+                    description: None,
+                    attributes: vec![],
+                    associations: vec![],
+                },
+            ]
+        );
+
+        assert_eq!(
+            schema
+                .skipped
+                .iter()
+                .map(|skipped| format!("{}: {}", skipped.location, skipped.construct))
+                .collect::<Vec<String>>(),
+            vec![
+                "Format: enum",
+                "Page: generic struct",
+                "Book.dimensions: field",
+                "Cover.0: tuple field"
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,320 @@
+//! Tables of the relational schemas and the models they become.
+
+use super::{Schema, SchemaAssociation, SchemaAttribute, SchemaModel};
+use crate::{model, util};
+use std::collections::HashSet;
+
+#[derive(Debug, Default)]
+pub struct Table {
+    pub name: String,
+    pub description: Option<String>,
+    pub columns: Vec<Column>,
+    pub primary_key: Vec<String>,
+    pub unique_keys: Vec<Vec<String>>,
+    pub foreign_keys: Vec<ForeignKey>,
+    pub skipped: Vec<model::SkippedConstruct>,
+}
+
+#[derive(Debug, Default)]
+pub struct Column {
+    pub name: String,
+    pub description: Option<String>,
+
+    /// Name of the attribute type of the column.
+    pub type_name: String,
+
+    pub is_array: bool,
+}
+
+#[derive(Debug)]
+pub struct ForeignKey {
+    pub columns: Vec<String>,
+    pub referenced_table: String,
+
+    /// Names of the associations of the foreign key when the schema names
+    /// them, instead of the names derived from the column and the table.
+    pub association_names: Option<AssociationNames>,
+}
+
+/// Names of the `BelongsTo` association of a foreign key and of its inverse
+/// `HasOne` or `HasMany` association. The association without a name isn't
+/// imported.
+#[derive(Debug)]
+pub struct AssociationNames {
+    pub name: Option<String>,
+    pub inverse_name: Option<String>,
+}
+
+impl Table {
+    /// The foreign key of the column when it's the only column of a foreign
+    /// key to one of the tables.
+    pub fn foreign_key<'a>(&'a self, column: &str, tables: &[Table]) -> Option<&'a ForeignKey> {
+        self.foreign_keys.iter().find(|foreign_key| {
+            foreign_key.columns == [column]
+                && tables
+                    .iter()
+                    .any(|table| table.name == foreign_key.referenced_table)
+        })
+    }
+}
+
+/// Models of the tables, along with the table constraints they can't
+/// represent.
+pub fn models(tables: &[Table]) -> Schema {
+    let mut schema = Schema::default();
+    let mut model_names = HashSet::new();
+    let mut table_models = vec![];
+
+    for table in tables {
+        let name = model_name(&table.name);
+
+        if !model_names.insert(name.clone()) {
+            schema.skipped.push(model::SkippedConstruct {
+                location: table.name.clone(),
+                construct: "table".to_string(),
+                reason: format!("another table is already imported as `{name}`"),
+            });
+
+            continue;
+        }
+
+        table_models.push((table, name));
+    }
+
+    let mut models: Vec<SchemaModel> = table_models
+        .iter()
+        .map(|(table, name)| SchemaModel {
+            name: name.clone(),
+            description: table.description.clone(),
+            attributes: vec![],
+            associations: vec![],
+        })
+        .collect();
+
+    let imported_tables: Vec<&Table> = table_models.iter().map(|(table, _)| *table).collect();
+
+    for (index, (table, model_name)) in table_models.iter().enumerate() {
+        schema.skipped.extend(table.skipped.iter().cloned());
+
+        let mut consumed_unique_keys = vec![];
+
+        for column in &table.columns {
+            let location = format!("{}.{}", table.name, column.name);
+
+            if let Some(foreign_key) = table.foreign_key(&column.name, tables) {
+                let Some(referenced_index) = imported_tables
+                    .iter()
+                    .position(|table| table.name == foreign_key.referenced_table)
+                else {
+                    continue;
+                };
+
+                let is_unique = table.primary_key == [column.name.as_str()]
+                    || table
+                        .unique_keys
+                        .iter()
+                        .any(|unique_key| unique_key == &[column.name.as_str()]);
+
+                if is_unique {
+                    consumed_unique_keys.push(vec![column.name.clone()]);
+                }
+
+                let association_names = foreign_key.association_names.as_ref();
+
+                let name = match association_names {
+                    Some(association_names) => association_names.name.clone(),
+                    None => Some(util::string::title_case(
+                        column
+                            .name
+                            .strip_suffix("_id")
+                            .filter(|name| !name.is_empty())
+                            .unwrap_or(&column.name),
+                    )),
+                };
+
+                if let Some(name) = &name {
+                    models[index].associations.push(SchemaAssociation {
+                        name: name.clone(),
+                        description: column.description.clone(),
+                        kind: model::AssociationKind::BelongsTo,
+                        model_name: table_models[referenced_index].1.clone(),
+                    });
+                }
+
+                let inverse_name = match (association_names, &name) {
+                    (Some(association_names), _) => association_names.inverse_name.clone(),
+                    (None, Some(name)) => {
+                        let inverse_name = if is_unique {
+                            model_name.clone()
+                        } else {
+                            util::string::title_case(&util::inflection::pluralize(
+                                &util::string::snake_case(model_name),
+                            ))
+                        };
+
+                        if is_name_taken(&models[referenced_index], &inverse_name) {
+                            Some(format!("{name} {inverse_name}"))
+                        } else {
+                            Some(inverse_name)
+                        }
+                    }
+                    (None, None) => None,
+                };
+
+                let Some(inverse_name) = inverse_name else {
+                    continue;
+                };
+
+                let kind = if is_unique {
+                    model::AssociationKind::HasOne
+                } else {
+                    model::AssociationKind::HasMany
+                };
+
+                let referenced_model = &mut models[referenced_index];
+
+                if is_name_taken(referenced_model, &inverse_name) {
+                    schema.skipped.push(model::SkippedConstruct {
+                        location,
+                        construct: "inverse association".to_string(),
+                        reason: format!(
+                            "`{}` already has an association named `{inverse_name}`",
+                            referenced_model.name
+                        ),
+                    });
+
+                    continue;
+                }
+
+                // Without the `BelongsTo` association the column note describes
+                // the inverse association.
+                referenced_model.associations.push(SchemaAssociation {
+                    name: inverse_name,
+                    description: match name {
+                        Some(_) => None,
+                        None => column.description.clone(),
+                    },
+                    kind,
+                    model_name: model_name.clone(),
+                });
+
+                continue;
+            }
+
+            if table.primary_key == [column.name.as_str()] && column.name == "id" {
+                continue;
+            }
+
+            let type_name = if column.is_array {
+                schema.skipped.push(model::SkippedConstruct {
+                    location,
+                    construct: "array".to_string(),
+                    reason: "arrays are imported as `Json`".to_string(),
+                });
+
+                "Json".to_string()
+            } else {
+                column.type_name.clone()
+            };
+
+            models[index].attributes.push(SchemaAttribute {
+                name: util::string::title_case(&column.name),
+                description: column.description.clone(),
+                type_name,
+            });
+        }
+
+        if !table.primary_key.is_empty() && table.primary_key != ["id"] {
+            let is_foreign_key = table.primary_key.len() == 1
+                && table.foreign_key(&table.primary_key[0], tables).is_some();
+
+            if !is_foreign_key {
+                schema.skipped.push(model::SkippedConstruct {
+                    location: table.name.clone(),
+                    construct: format!("PRIMARY KEY ({})", table.primary_key.join(", ")),
+                    reason: "models have a surrogate `id` key".to_string(),
+                });
+            }
+        }
+
+        for unique_key in &table.unique_keys {
+            if consumed_unique_keys.contains(unique_key) {
+                continue;
+            }
+
+            schema.skipped.push(model::SkippedConstruct {
+                location: table.name.clone(),
+                construct: format!("UNIQUE ({})", unique_key.join(", ")),
+                reason: "models don't have constraints".to_string(),
+            });
+        }
+
+        for foreign_key in &table.foreign_keys {
+            if foreign_key.columns.len() == 1
+                && table.foreign_key(&foreign_key.columns[0], tables).is_some()
+            {
+                continue;
+            }
+
+            let reason = if foreign_key.columns.len() > 1 {
+                "only single column foreign keys are imported".to_string()
+            } else {
+                format!(
+                    "table `{}` isn't part of the schema",
+                    foreign_key.referenced_table
+                )
+            };
+
+            schema.skipped.push(model::SkippedConstruct {
+                location: table.name.clone(),
+                construct: format!("FOREIGN KEY ({})", foreign_key.columns.join(", ")),
+                reason,
+            });
+        }
+    }
+
+    schema.models = models;
+    schema
+}
+
+fn is_name_taken(model: &SchemaModel, name: &str) -> bool {
+    model.attributes.iter().any(|a| a.name == name)
+        || model.associations.iter().any(|a| a.name == name)
+}
+
+/// Singular of the table name, e.g. `Order Item` for `order_items`.
+fn model_name(table_name: &str) -> String {
+    util::string::title_case(&util::inflection::singularize(&util::string::snake_case(
+        table_name,
+    )))
+}
+
+/// Name of the attribute type of the SQL column type, the name of the well
+/// known attribute type when there is one.
+pub fn attribute_type_name(type_name: &str) -> String {
+    let name = match type_name {
+        "smallint" | "int2" | "smallserial" | "serial2" => "Small Integer",
+        "integer" | "int" | "int4" | "serial" | "serial4" => "Integer",
+        "bigint" | "int8" | "bigserial" | "serial8" => "Big Integer",
+        "real" | "float4" => "Float",
+        "double precision" | "double" | "float8" | "float" => "Double",
+        "numeric" | "decimal" => "Decimal",
+        "boolean" | "bool" => "Boolean",
+        "date" => "Date",
+        "time" | "time without time zone" | "time with time zone" | "timetz" => "Time",
+        "timestamp"
+        | "datetime"
+        | "timestamp without time zone"
+        | "timestamp with time zone"
+        | "timestamptz" => "Date Time",
+        "uuid" => "Uuid",
+        "json" | "jsonb" => "Json",
+        "bytea" | "binary" | "blob" => "Binary",
+        "text" | "varchar" | "character varying" | "char" | "character" | "bpchar" | "citext" => {
+            "Text"
+        }
+        type_name => return util::string::title_case(type_name),
+    };
+
+    name.to_string()
+}
@@ -3,10 +3,14 @@
 
 pub mod attribute_type;
 pub mod datastore;
+pub mod generator;
 pub mod model;
 pub mod project;
 
+mod codegen;
 mod diagram;
+mod import;
+mod migration;
 mod result;
 mod util;
 
@@ -0,0 +1,191 @@
+use super::{is_forward_reference, Column, ColumnType, ForeignKey, Table, PRIMARY_KEY};
+use crate::attribute_type::AttributeKind;
+use std::collections::HashMap;
+
+/// Column types of the attribute types, by the attribute type slug, which
+/// take precedence over the defaults of the dialect.
+pub type TypeMappings = HashMap<String, String>;
+
+/// Differences between the SQL dialects.
+pub trait Dialect {
+    /// Default column type of the attribute kind.
+    fn column_type(&self, kind: AttributeKind) -> &'static str;
+
+    /// Type and constraints of the `id` column, the key strategy.
+    fn primary_key_definition(&self) -> &'static str;
+
+    /// Type of the columns referencing the `id` column.
+    fn foreign_key_type(&self) -> &'static str;
+
+    /// Words which have to be quoted to be used as identifiers.
+    fn reserved_words(&self) -> &'static [&'static str];
+
+    /// Quoted form of the identifier.
+    fn quote(&self, name: &str) -> String;
+
+    /// Whether foreign keys referencing tables created later have to be added
+    /// with `ALTER TABLE` once all tables are created.
+    fn defers_forward_references(&self) -> bool;
+}
+
+/// SQL statements generator for a dialect.
+pub struct Sql<'a> {
+    dialect: &'a dyn Dialect,
+    type_mappings: &'a TypeMappings,
+}
+
+impl<'a> Sql<'a> {
+    pub fn new(dialect: &'a dyn Dialect, type_mappings: &'a TypeMappings) -> Self {
+        Self {
+            dialect,
+            type_mappings,
+        }
+    }
+
+    /// Generates the `CREATE TABLE` statements of the tables.
+    pub fn create_tables(&self, tables: &[Table]) -> String {
+        let mut statements = vec![];
+        let mut deferred_constraints = vec![];
+
+        for table in tables {
+            let mut definitions: Vec<String> = table
+                .columns
+                .iter()
+                .map(|column| self.column_definition(column))
+                .collect();
+
+            for foreign_key in &table.foreign_keys {
+                if self.dialect.defers_forward_references()
+                    && is_forward_reference(tables, table, foreign_key)
+                {
+                    deferred_constraints.push(self.add_constraint(&table.name, foreign_key));
+                } else {
+                    definitions.push(self.constraint_definition(&table.name, foreign_key));
+                }
+            }
+
+            statements.push(self.create_table(&table.name, definitions));
+        }
+
+        statements.extend(deferred_constraints);
+
+        script(statements)
+    }
+
+    pub fn create_table(&self, table_name: &str, definitions: Vec<String>) -> String {
+        format!(
+            "CREATE TABLE {} (\n    {}\n);",
+            self.identifier(table_name),
+            definitions.join(",\n    ")
+        )
+    }
+
+    pub fn add_constraint(&self, table_name: &str, foreign_key: &ForeignKey) -> String {
+        format!(
+            "ALTER TABLE {} ADD {};",
+            self.identifier(table_name),
+            self.constraint_definition(table_name, foreign_key)
+        )
+    }
+
+    pub fn column_definition(&self, column: &Column) -> String {
+        let definition = match &column.r#type {
+            ColumnType::PrimaryKey => self.dialect.primary_key_definition().to_string(),
+            ColumnType::ForeignKey => format!("{} NOT NULL", self.dialect.foreign_key_type()),
+            ColumnType::Attribute(slug) => self.column_type(slug).to_string(),
+        };
+
+        format!("{} {definition}", self.identifier(&column.name))
+    }
+
+    pub fn constraint_definition(&self, table_name: &str, foreign_key: &ForeignKey) -> String {
+        format!(
+            "CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {} ({})",
+            self.identifier(&foreign_key.constraint_name(table_name)),
+            self.identifier(&foreign_key.column),
+            self.identifier(&foreign_key.referenced_table),
+            self.identifier(PRIMARY_KEY)
+        )
+    }
+
+    /// Column type of the attribute type, the mapped one or the dialect
+    /// default.
+    pub fn column_type(&self, attribute_type_slug: &str) -> &str {
+        match self.type_mappings.get(attribute_type_slug) {
+            Some(column_type) => column_type,
+            None => self
+                .dialect
+                .column_type(AttributeKind::from_slug(attribute_type_slug)),
+        }
+    }
+
+    /// Identifier, quoted when it isn't a plain lowercase word or it's a
+    /// reserved one.
+    pub fn identifier(&self, name: &str) -> String {
+        let is_plain = name
+            .chars()
+            .next()
+            .is_some_and(|char| char.is_ascii_lowercase() || char == '_')
+            && name
+                .chars()
+                .all(|char| char.is_ascii_lowercase() || char.is_ascii_digit() || char == '_');
+
+        if is_plain && !self.dialect.reserved_words().contains(&name) {
+            name.to_string()
+        } else {
+            self.dialect.quote(name)
+        }
+    }
+}
+
+pub fn script(statements: Vec<String>) -> String {
+    if statements.is_empty() {
+        return String::new();
+    }
+
+    format!("{}\n", statements.join("\n\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::migration::{MySql, PostgreSql, Sqlite};
+
+    #[test]
+    fn it_prefers_mapped_column_types() {
+        let type_mappings = TypeMappings::from([("money".to_string(), "MONEY".to_string())]);
+        let sql = Sql::new(&PostgreSql, &type_mappings);
+
+        assert_eq!(sql.column_type("money"), "MONEY");
+        assert_eq!(sql.column_type("string"), "TEXT");
+    }
+
+    #[test]
+    fn it_quotes_identifiers_when_needed() {
+        let type_mappings = TypeMappings::new();
+        let sql = Sql::new(&PostgreSql, &type_mappings);
+
+        assert_eq!(sql.identifier("order_item"), "order_item");
+        assert_eq!(sql.identifier("order"), "\"order\"");
+        assert_eq!(sql.identifier("2fa_code"), "\"2fa_code\"");
+        assert_eq!(sql.identifier("straße"), "\"straße\"");
+    }
+
+    #[test]
+    fn it_quotes_reserved_words_of_the_dialect() {
+        let type_mappings = TypeMappings::new();
+        let (postgresql, sqlite, mysql) = (
+            Sql::new(&PostgreSql, &type_mappings),
+            Sql::new(&Sqlite, &type_mappings),
+            Sql::new(&MySql, &type_mappings),
+        );
+
+        assert_eq!(postgresql.identifier("user"), "\"user\"");
+        assert_eq!(sqlite.identifier("user"), "user");
+        assert_eq!(mysql.identifier("user"), "user");
+
+        assert_eq!(postgresql.identifier("key"), "key");
+        assert_eq!(sqlite.identifier("key"), "\"key\"");
+        assert_eq!(mysql.identifier("key"), "`key`");
+    }
+}
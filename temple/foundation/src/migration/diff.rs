@@ -0,0 +1,522 @@
+use super::{Column, ColumnType, ForeignKey, Table};
+
+/// Migration between two versions of the tables.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Migration {
+    /// Steps from the baseline to the current tables.
+    pub up: Vec<Step>,
+
+    /// Steps reverting the `up` steps, from the current to the baseline tables.
+    pub down: Vec<Step>,
+}
+
+/// Single schema change, independent of the SQL dialect.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Step {
+    /// Creates the table without its foreign keys, they are added by separate
+    /// [`Step::AddForeignKey`] steps.
+    CreateTable(Table),
+
+    /// Drops the table, its foreign keys are dropped by preceding
+    /// [`Step::DropForeignKey`] steps.
+    DropTable(Table),
+
+    AddColumn {
+        table: String,
+        column: Column,
+    },
+
+    DropColumn {
+        table: String,
+        column: Column,
+    },
+
+    RenameColumn {
+        table: String,
+        from: String,
+        to: String,
+    },
+
+    /// Changes the attribute type of the column.
+    AlterColumnType {
+        table: String,
+        column: String,
+        from: String,
+        to: String,
+    },
+
+    AddForeignKey {
+        table: String,
+        foreign_key: ForeignKey,
+    },
+
+    DropForeignKey {
+        table: String,
+        foreign_key: ForeignKey,
+    },
+}
+
+impl Step {
+    /// Step that reverts this one.
+    pub fn inverse(&self) -> Step {
+        match self.clone() {
+            Step::CreateTable(table) => Step::DropTable(table),
+            Step::DropTable(table) => Step::CreateTable(table),
+            Step::AddColumn { table, column } => Step::DropColumn { table, column },
+            Step::DropColumn { table, column } => Step::AddColumn { table, column },
+            Step::RenameColumn { table, from, to } => Step::RenameColumn {
+                table,
+                from: to,
+                to: from,
+            },
+            Step::AlterColumnType {
+                table,
+                column,
+                from,
+                to,
+            } => Step::AlterColumnType {
+                table,
+                column,
+                from: to,
+                to: from,
+            },
+            Step::AddForeignKey { table, foreign_key } => {
+                Step::DropForeignKey { table, foreign_key }
+            }
+            Step::DropForeignKey { table, foreign_key } => {
+                Step::AddForeignKey { table, foreign_key }
+            }
+        }
+    }
+}
+
+/// Column renamed between the baseline and the current tables. The differ
+/// doesn't guess renames, a removed and an added column are only renamed
+/// when the caller says so.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnRename {
+    pub table: String,
+    pub from: String,
+    pub to: String,
+}
+
+/// Steps grouped by kind, in the order they have to be applied.
+#[derive(Default)]
+struct Steps {
+    drop_foreign_keys: Vec<Step>,
+    drop_columns: Vec<Step>,
+    drop_tables: Vec<Step>,
+    rename_columns: Vec<Step>,
+    create_tables: Vec<Step>,
+    add_columns: Vec<Step>,
+    alter_columns: Vec<Step>,
+    add_foreign_keys: Vec<Step>,
+}
+
+/// Computes the steps migrating the baseline tables to the current ones.
+///
+/// Foreign keys are dropped first and added last, so the steps in between
+/// don't depend on the order of the tables. Tables are matched by name, a
+/// renamed model can't be told apart from a removed and an added one. Within
+/// a table, a removed and an added attribute column are only renamed when
+/// the rename is one of the `renames`, otherwise the removed column is
+/// dropped and the added one is added.
+pub fn diff(baseline: &[Table], current: &[Table], renames: &[ColumnRename]) -> Migration {
+    let mut steps = Steps::default();
+
+    for baseline_table in baseline {
+        match find_table(current, &baseline_table.name) {
+            Some(current_table) => diff_table(&mut steps, baseline_table, current_table, renames),
+            None => {
+                for foreign_key in &baseline_table.foreign_keys {
+                    steps.drop_foreign_keys.push(Step::DropForeignKey {
+                        table: baseline_table.name.clone(),
+                        foreign_key: foreign_key.clone(),
+                    });
+                }
+
+                steps.drop_tables.push(Step::DropTable(Table {
+                    foreign_keys: vec![],
+                    ..baseline_table.clone()
+                }));
+            }
+        }
+    }
+
+    for current_table in current {
+        if find_table(baseline, &current_table.name).is_some() {
+            continue;
+        }
+
+        steps.create_tables.push(Step::CreateTable(Table {
+            foreign_keys: vec![],
+            ..current_table.clone()
+        }));
+
+        for foreign_key in &current_table.foreign_keys {
+            steps.add_foreign_keys.push(Step::AddForeignKey {
+                table: current_table.name.clone(),
+                foreign_key: foreign_key.clone(),
+            });
+        }
+    }
+
+    let Steps {
+        drop_foreign_keys,
+        drop_columns,
+        drop_tables,
+        rename_columns,
+        create_tables,
+        add_columns,
+        alter_columns,
+        add_foreign_keys,
+    } = steps;
+
+    let up: Vec<Step> = [
+        drop_foreign_keys,
+        drop_columns,
+        drop_tables,
+        rename_columns,
+        create_tables,
+        add_columns,
+        alter_columns,
+        add_foreign_keys,
+    ]
+    .concat();
+
+    let down = up.iter().rev().map(Step::inverse).collect();
+
+    Migration { up, down }
+}
+
+fn diff_table(
+    steps: &mut Steps,
+    baseline_table: &Table,
+    current_table: &Table,
+    renames: &[ColumnRename],
+) {
+    let table = &current_table.name;
+
+    for foreign_key in &baseline_table.foreign_keys {
+        if !current_table.foreign_keys.contains(foreign_key) {
+            steps.drop_foreign_keys.push(Step::DropForeignKey {
+                table: table.clone(),
+                foreign_key: foreign_key.clone(),
+            });
+        }
+    }
+
+    for foreign_key in &current_table.foreign_keys {
+        if !baseline_table.foreign_keys.contains(foreign_key) {
+            steps.add_foreign_keys.push(Step::AddForeignKey {
+                table: table.clone(),
+                foreign_key: foreign_key.clone(),
+            });
+        }
+    }
+
+    let mut dropped_columns: Vec<&Column> = vec![];
+    let mut added_columns: Vec<&Column> = vec![];
+
+    for baseline_column in &baseline_table.columns {
+        let Some(current_column) = find_column(current_table, &baseline_column.name) else {
+            dropped_columns.push(baseline_column);
+
+            continue;
+        };
+
+        match (&baseline_column.r#type, &current_column.r#type) {
+            (ColumnType::Attribute(from), ColumnType::Attribute(to)) if from != to => {
+                steps.alter_columns.push(Step::AlterColumnType {
+                    table: table.clone(),
+                    column: current_column.name.clone(),
+                    from: from.clone(),
+                    to: to.clone(),
+                });
+            }
+            (baseline_type, current_type) if baseline_type != current_type => {
+                dropped_columns.push(baseline_column);
+                added_columns.push(current_column);
+            }
+            _ => {}
+        }
+    }
+
+    for current_column in &current_table.columns {
+        if find_column(baseline_table, &current_column.name).is_none() {
+            added_columns.push(current_column);
+        }
+    }
+
+    for rename in renames.iter().filter(|rename| rename.table == *table) {
+        let position = |columns: &[&Column], name: &str| {
+            columns.iter().position(|column| {
+                column.name == name && matches!(column.r#type, ColumnType::Attribute(_))
+            })
+        };
+
+        let (Some(from), Some(to)) = (
+            position(&dropped_columns, &rename.from),
+            position(&added_columns, &rename.to),
+        ) else {
+            continue;
+        };
+
+        let from = dropped_columns.remove(from);
+        let to = added_columns.remove(to);
+
+        steps.rename_columns.push(Step::RenameColumn {
+            table: table.clone(),
+            from: from.name.clone(),
+            to: to.name.clone(),
+        });
+
+        if let (ColumnType::Attribute(from_type), ColumnType::Attribute(to_type)) =
+            (&from.r#type, &to.r#type)
+        {
+            if from_type != to_type {
+                steps.alter_columns.push(Step::AlterColumnType {
+                    table: table.clone(),
+                    column: to.name.clone(),
+                    from: from_type.clone(),
+                    to: to_type.clone(),
+                });
+            }
+        }
+    }
+
+    for column in dropped_columns {
+        steps.drop_columns.push(Step::DropColumn {
+            table: table.clone(),
+            column: column.clone(),
+        });
+    }
+
+    for column in added_columns {
+        steps.add_columns.push(Step::AddColumn {
+            table: table.clone(),
+            column: column.clone(),
+        });
+    }
+}
+
+fn find_table<'a>(tables: &'a [Table], name: &str) -> Option<&'a Table> {
+    tables.iter().find(|table| table.name == name)
+}
+
+fn find_column<'a>(table: &'a Table, name: &str) -> Option<&'a Column> {
+    table.columns.iter().find(|column| column.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::migration::PRIMARY_KEY;
+
+    fn table(name: &str, attributes: &[(&str, &str)], referenced_tables: &[&str]) -> Table {
+        let mut columns = vec![Column {
+            name: PRIMARY_KEY.to_string(),
+            r#type: ColumnType::PrimaryKey,
+        }];
+        let mut foreign_keys = vec![];
+
+        for (name, r#type) in attributes {
+            columns.push(Column {
+                name: name.to_string(),
+                r#type: ColumnType::Attribute(r#type.to_string()),
+            });
+        }
+
+        for referenced_table in referenced_tables {
+            let column = format!("{referenced_table}_id");
+
+            columns.push(Column {
+                name: column.clone(),
+                r#type: ColumnType::ForeignKey,
+            });
+            foreign_keys.push(ForeignKey {
+                column,
+                referenced_table: referenced_table.to_string(),
+            });
+        }
+
+        Table {
+            name: name.to_string(),
+            columns,
+            foreign_keys,
+        }
+    }
+
+    fn foreign_key(referenced_table: &str) -> ForeignKey {
+        ForeignKey {
+            column: format!("{referenced_table}_id"),
+            referenced_table: referenced_table.to_string(),
+        }
+    }
+
+    fn attribute_column(name: &str, r#type: &str) -> Column {
+        Column {
+            name: name.to_string(),
+            r#type: ColumnType::Attribute(r#type.to_string()),
+        }
+    }
+
+    #[test]
+    fn it_returns_no_steps_for_equal_tables() {
+        let tables = vec![
+            table("publisher", &[("name", "String")], &[]),
+            table("book", &[("title", "String")], &["publisher"]),
+        ];
+
+        let migration = diff(&tables, &tables, &[]);
+
+        assert!(migration.up.is_empty());
+        assert!(migration.down.is_empty());
+    }
+
+    #[test]
+    fn it_orders_steps_and_reverts_them() {
+        let baseline = vec![
+            table("author", &[("name", "String")], &[]),
+            table(
+                "book",
+                &[("title", "String"), ("pages", "Integer")],
+                &["author"],
+            ),
+        ];
+        let current = vec![
+            table("publisher", &[("name", "String")], &[]),
+            table(
+                "book",
+                &[("name", "String"), ("pages", "BigInt"), ("year", "Integer")],
+                &["publisher"],
+            ),
+        ];
+
+        let migration = diff(
+            &baseline,
+            &current,
+            &[ColumnRename {
+                table: "book".to_string(),
+                from: "title".to_string(),
+                to: "name".to_string(),
+            }],
+        );
+
+        assert_eq!(
+            migration.up,
+            vec![
+                Step::DropForeignKey {
+                    table: "book".to_string(),
+                    foreign_key: foreign_key("author"),
+                },
+                Step::DropColumn {
+                    table: "book".to_string(),
+                    column: Column {
+                        name: "author_id".to_string(),
+                        r#type: ColumnType::ForeignKey,
+                    },
+                },
+                Step::DropTable(table("author", &[("name", "String")], &[])),
+                Step::RenameColumn {
+                    table: "book".to_string(),
+                    from: "title".to_string(),
+                    to: "name".to_string(),
+                },
+                Step::CreateTable(table("publisher", &[("name", "String")], &[])),
+                Step::AddColumn {
+                    table: "book".to_string(),
+                    column: attribute_column("year", "Integer"),
+                },
+                Step::AddColumn {
+                    table: "book".to_string(),
+                    column: Column {
+                        name: "publisher_id".to_string(),
+                        r#type: ColumnType::ForeignKey,
+                    },
+                },
+                Step::AlterColumnType {
+                    table: "book".to_string(),
+                    column: "pages".to_string(),
+                    from: "Integer".to_string(),
+                    to: "BigInt".to_string(),
+                },
+                Step::AddForeignKey {
+                    table: "book".to_string(),
+                    foreign_key: foreign_key("publisher"),
+                },
+            ]
+        );
+        assert_eq!(
+            migration.down,
+            migration
+                .up
+                .iter()
+                .rev()
+                .map(Step::inverse)
+                .collect::<Vec<Step>>()
+        );
+        assert_eq!(
+            migration.down.first(),
+            Some(&Step::DropForeignKey {
+                table: "book".to_string(),
+                foreign_key: foreign_key("publisher"),
+            })
+        );
+    }
+
+    #[test]
+    fn it_does_not_guess_renames() {
+        let baseline = vec![table("user", &[("nickname", "String")], &[])];
+        let current = vec![table("user", &[("email", "String")], &[])];
+
+        let migration = diff(&baseline, &current, &[]);
+
+        assert_eq!(
+            migration.up,
+            vec![
+                Step::DropColumn {
+                    table: "user".to_string(),
+                    column: attribute_column("nickname", "String"),
+                },
+                Step::AddColumn {
+                    table: "user".to_string(),
+                    column: attribute_column("email", "String"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_alters_the_type_of_renamed_columns() {
+        let baseline = vec![table("book", &[("pages", "Integer")], &[])];
+        let current = vec![table("book", &[("page_count", "BigInt")], &[])];
+
+        let migration = diff(
+            &baseline,
+            &current,
+            &[ColumnRename {
+                table: "book".to_string(),
+                from: "pages".to_string(),
+                to: "page_count".to_string(),
+            }],
+        );
+
+        assert_eq!(
+            migration.up,
+            vec![
+                Step::RenameColumn {
+                    table: "book".to_string(),
+                    from: "pages".to_string(),
+                    to: "page_count".to_string(),
+                },
+                Step::AlterColumnType {
+                    table: "book".to_string(),
+                    column: "page_count".to_string(),
+                    from: "Integer".to_string(),
+                    to: "BigInt".to_string(),
+                },
+            ]
+        );
+    }
+}
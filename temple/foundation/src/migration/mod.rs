@@ -0,0 +1,309 @@
+//! Relational schema of the project [`model::Model`]s and the SQL migrations
+//! generated from it.
+//!
+//! Every model becomes a table with a surrogate `id` primary key, every
+//! attribute becomes a column and every [`model::AssociationKind::BelongsTo`]
+//! association becomes a foreign key column. The other association kinds are
+//! the inverse sides of a `BelongsTo` and don't add columns.
+
+mod dialect;
+mod diff;
+mod mysql;
+mod postgresql;
+mod snapshot;
+mod sqlite;
+
+pub use dialect::{Dialect, Sql, TypeMappings};
+pub use diff::{diff, ColumnRename, Migration, Step};
+pub use mysql::MySql;
+pub use postgresql::{alter_tables as postgresql_alter_tables, PostgreSql};
+pub use snapshot::{parse_snapshot, snapshot};
+pub use sqlite::Sqlite;
+
+use crate::{attribute_type, model, util, FoundationError, FoundationResult};
+use std::collections::{HashMap, HashSet};
+
+/// Name of the primary key column of every table.
+pub const PRIMARY_KEY: &str = "id";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Table {
+    pub name: String,
+
+    pub columns: Vec<Column>,
+
+    pub foreign_keys: Vec<ForeignKey>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Column {
+    pub name: String,
+
+    pub r#type: ColumnType,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ColumnType {
+    /// Surrogate key of the table.
+    PrimaryKey,
+
+    /// Reference to the primary key of another table, required.
+    ForeignKey,
+
+    /// Nullable column typed after the slug of the attribute type.
+    Attribute(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForeignKey {
+    pub column: String,
+
+    pub referenced_table: String,
+}
+
+impl ForeignKey {
+    /// Constraint name, PostgreSQL's default `<table>_<column>_fkey`.
+    pub fn constraint_name(&self, table_name: &str) -> String {
+        format!("{table_name}_{}_fkey", self.column)
+    }
+}
+
+/// Builds the tables of the models, ordered so that referenced tables come
+/// before the tables referencing them.
+///
+/// Models that reference each other in a cycle keep their original order,
+/// generators have to add the constraints of such foreign keys after all the
+/// tables are created, see [`is_forward_reference`].
+pub fn tables(model_overviews: &[model::ModelOverview]) -> FoundationResult<Vec<Table>> {
+    let mut table_names: HashMap<String, &str> = HashMap::new();
+
+    for model_overview in model_overviews {
+        let model_name = &model_overview.model.name;
+        let table_name = identifier(model_name)?;
+
+        if let Some(other_model_name) = table_names.insert(table_name.clone(), model_name) {
+            return Err(FoundationError::failed_precondition(format!(
+                "migration can't be generated, `{model_name}` and `{other_model_name}` share the same table name `{table_name}`"
+            )));
+        }
+    }
+
+    let tables = model_overviews
+        .iter()
+        .map(|model_overview| table(model_overview, &table_names))
+        .collect::<FoundationResult<Vec<Table>>>()?;
+
+    Ok(dependency_order(tables))
+}
+
+pub fn dialect(sql_dialect: model::SqlDialect) -> Box<dyn Dialect> {
+    match sql_dialect {
+        model::SqlDialect::PostgreSql => Box::new(PostgreSql),
+        model::SqlDialect::Sqlite => Box::new(Sqlite),
+        model::SqlDialect::MySql => Box::new(MySql),
+    }
+}
+
+/// Generates the `CREATE TABLE` statements of the tables in the dialect.
+pub fn create_tables(
+    sql_dialect: model::SqlDialect,
+    type_mappings: &TypeMappings,
+    tables: &[Table],
+) -> String {
+    Sql::new(dialect(sql_dialect).as_ref(), type_mappings).create_tables(tables)
+}
+
+/// Column types set for the attribute types, by the attribute type slug.
+pub fn type_mappings(column_type_records: Vec<attribute_type::ColumnTypeRecord>) -> TypeMappings {
+    column_type_records
+        .into_iter()
+        .map(|column_type_record| {
+            let attribute_type::ColumnType {
+                attribute_type_slug,
+                column_type,
+                ..
+            } = column_type_record.into_inner();
+
+            (attribute_type_slug, column_type)
+        })
+        .collect()
+}
+
+/// Whether the foreign key references a table which is created after the
+/// table with the foreign key.
+pub fn is_forward_reference(tables: &[Table], table: &Table, foreign_key: &ForeignKey) -> bool {
+    let position = |name: &str| tables.iter().position(|table| table.name == name);
+
+    position(&foreign_key.referenced_table) > position(&table.name)
+}
+
+/// Rename of the column of an attribute of the model.
+pub fn attribute_rename(model_name: &str, from: &str, to: &str) -> FoundationResult<ColumnRename> {
+    Ok(ColumnRename {
+        table: identifier(model_name)?,
+        from: identifier(from)?,
+        to: identifier(to)?,
+    })
+}
+
+fn table(
+    model_overview: &model::ModelOverview,
+    table_names: &HashMap<String, &str>,
+) -> FoundationResult<Table> {
+    let model::ModelOverview {
+        model,
+        attributes,
+        associations,
+    } = model_overview;
+
+    let mut columns = vec![Column {
+        name: PRIMARY_KEY.to_string(),
+        r#type: ColumnType::PrimaryKey,
+    }];
+    let mut foreign_keys = vec![];
+
+    for attribute in attributes {
+        columns.push(Column {
+            name: identifier(&attribute.name)?,
+            r#type: ColumnType::Attribute(attribute.r#type.slug.clone()),
+        });
+    }
+
+    for association in associations {
+        if association.kind != model::AssociationKind::BelongsTo {
+            continue;
+        }
+
+        let referenced_table = identifier(&association.model.name)?;
+
+        if !table_names.contains_key(&referenced_table) {
+            return Err(FoundationError::failed_precondition(format!(
+                "migration can't be generated, `{}` belongs to `{}` which isn't part of the project",
+                model.name, association.model.name
+            )));
+        }
+
+        let column = format!("{}_{PRIMARY_KEY}", identifier(&association.name)?);
+
+        columns.push(Column {
+            name: column.clone(),
+            r#type: ColumnType::ForeignKey,
+        });
+        foreign_keys.push(ForeignKey {
+            column,
+            referenced_table,
+        });
+    }
+
+    let mut column_names = HashSet::new();
+
+    for column in &columns {
+        if !column_names.insert(&column.name) {
+            return Err(FoundationError::failed_precondition(format!(
+                "migration can't be generated, `{}` has more than one column named `{}`",
+                model.name, column.name
+            )));
+        }
+    }
+
+    Ok(Table {
+        name: identifier(&model.name)?,
+        columns,
+        foreign_keys,
+    })
+}
+
+fn identifier(name: &str) -> FoundationResult<String> {
+    let identifier = util::string::snake_case(name);
+
+    if identifier.is_empty() {
+        return Err(FoundationError::failed_precondition(format!(
+            "migration can't be generated, `{name}` can't be used as an SQL identifier"
+        )));
+    }
+
+    Ok(identifier)
+}
+
+/// Topological order of the tables, stable for the tables that don't depend
+/// on each other.
+pub fn dependency_order(mut tables: Vec<Table>) -> Vec<Table> {
+    let mut ordered: Vec<Table> = vec![];
+
+    loop {
+        let created: HashSet<String> = ordered.iter().map(|table| table.name.clone()).collect();
+
+        let ready = tables.iter().position(|table| {
+            table.foreign_keys.iter().all(|foreign_key| {
+                foreign_key.referenced_table == table.name
+                    || created.contains(&foreign_key.referenced_table)
+            })
+        });
+
+        match ready {
+            Some(index) => ordered.push(tables.remove(index)),
+            None => break,
+        }
+    }
+
+    // What's left references each other in cycles.
+    ordered.extend(tables);
+
+    ordered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(name: &str, referenced_tables: &[&str]) -> Table {
+        Table {
+            name: name.to_string(),
+            columns: vec![],
+            foreign_keys: referenced_tables
+                .iter()
+                .map(|referenced_table| ForeignKey {
+                    column: format!("{referenced_table}_id"),
+                    referenced_table: referenced_table.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    fn names(tables: &[Table]) -> Vec<&str> {
+        tables.iter().map(|table| table.name.as_str()).collect()
+    }
+
+    #[test]
+    fn it_orders_tables_by_dependencies() {
+        let tables = dependency_order(vec![
+            table("book", &["publisher", "author"]),
+            table("author", &["author"]),
+            table("publisher", &["country"]),
+            table("country", &[]),
+        ]);
+
+        assert_eq!(names(&tables), ["author", "country", "publisher", "book"]);
+    }
+
+    #[test]
+    fn it_keeps_tables_in_cycles_last() {
+        let tables = dependency_order(vec![
+            table("employee", &["department"]),
+            table("department", &["employee"]),
+            table("company", &[]),
+        ]);
+
+        assert_eq!(names(&tables), ["company", "employee", "department"]);
+        assert!(is_forward_reference(
+            &tables,
+            &tables[1],
+            &tables[1].foreign_keys[0]
+        ));
+        assert!(!is_forward_reference(
+            &tables,
+            &tables[2],
+            &tables[2].foreign_keys[0]
+        ));
+    }
+}
@@ -0,0 +1,227 @@
+use super::{
+    dialect::{script, Dialect, Sql, TypeMappings},
+    Step,
+};
+use crate::attribute_type::AttributeKind;
+
+/// The [reserved key words](https://www.postgresql.org/docs/current/sql-keywords-appendix.html) of PostgreSQL.
+const RESERVED_WORDS: &[&str] = &[
+    "all",
+    "analyse",
+    "analyze",
+    "and",
+    "any",
+    "array",
+    "as",
+    "asc",
+    "asymmetric",
+    "authorization",
+    "binary",
+    "both",
+    "case",
+    "cast",
+    "check",
+    "collate",
+    "collation",
+    "column",
+    "concurrently",
+    "constraint",
+    "create",
+    "cross",
+    "current_catalog",
+    "current_date",
+    "current_role",
+    "current_schema",
+    "current_time",
+    "current_timestamp",
+    "current_user",
+    "default",
+    "deferrable",
+    "desc",
+    "distinct",
+    "do",
+    "else",
+    "end",
+    "except",
+    "false",
+    "fetch",
+    "for",
+    "foreign",
+    "freeze",
+    "from",
+    "full",
+    "grant",
+    "group",
+    "having",
+    "ilike",
+    "in",
+    "initially",
+    "inner",
+    "intersect",
+    "into",
+    "is",
+    "isnull",
+    "join",
+    "lateral",
+    "leading",
+    "left",
+    "like",
+    "limit",
+    "localtime",
+    "localtimestamp",
+    "natural",
+    "not",
+    "notnull",
+    "null",
+    "offset",
+    "on",
+    "only",
+    "or",
+    "order",
+    "outer",
+    "overlaps",
+    "placing",
+    "primary",
+    "references",
+    "returning",
+    "right",
+    "select",
+    "session_user",
+    "similar",
+    "some",
+    "symmetric",
+    "system_user",
+    "table",
+    "tablesample",
+    "then",
+    "to",
+    "trailing",
+    "true",
+    "union",
+    "unique",
+    "user",
+    "using",
+    "variadic",
+    "verbose",
+    "when",
+    "where",
+    "window",
+    "with",
+];
+
+/// [PostgreSQL](https://www.postgresql.org/) dialect, keys are UUIDs
+/// generated by the database.
+pub struct PostgreSql;
+
+impl Dialect for PostgreSql {
+    fn column_type(&self, kind: AttributeKind) -> &'static str {
+        match kind {
+            AttributeKind::SmallInteger => "SMALLINT",
+            AttributeKind::Integer => "INTEGER",
+            AttributeKind::BigInteger => "BIGINT",
+            AttributeKind::Float => "REAL",
+            AttributeKind::Double => "DOUBLE PRECISION",
+            AttributeKind::Decimal => "NUMERIC",
+            AttributeKind::Boolean => "BOOLEAN",
+            AttributeKind::Date => "DATE",
+            AttributeKind::Time => "TIME",
+            AttributeKind::DateTime => "TIMESTAMPTZ",
+            AttributeKind::Uuid => "UUID",
+            AttributeKind::Json => "JSONB",
+            AttributeKind::Binary => "BYTEA",
+            AttributeKind::Text => "TEXT",
+        }
+    }
+
+    fn primary_key_definition(&self) -> &'static str {
+        "UUID PRIMARY KEY DEFAULT gen_random_uuid()"
+    }
+
+    fn foreign_key_type(&self) -> &'static str {
+        "UUID"
+    }
+
+    fn reserved_words(&self) -> &'static [&'static str] {
+        RESERVED_WORDS
+    }
+
+    fn quote(&self, name: &str) -> String {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+
+    fn defers_forward_references(&self) -> bool {
+        true
+    }
+}
+
+/// Generates the statements of the migration steps.
+///
+/// Columns of the foreign keys are `NOT NULL`, adding one to a table that
+/// already has rows requires filling it in by hand.
+pub fn alter_tables(steps: &[Step], type_mappings: &TypeMappings) -> String {
+    let sql = Sql::new(&PostgreSql, type_mappings);
+
+    let statements = steps
+        .iter()
+        .filter_map(|step| step_statement(&sql, step))
+        .collect();
+
+    script(statements)
+}
+
+fn step_statement(sql: &Sql, step: &Step) -> Option<String> {
+    let statement = match step {
+        Step::CreateTable(table) => sql.create_table(
+            &table.name,
+            table
+                .columns
+                .iter()
+                .map(|column| sql.column_definition(column))
+                .collect(),
+        ),
+        Step::DropTable(table) => format!("DROP TABLE {};", sql.identifier(&table.name)),
+        Step::AddColumn { table, column } => format!(
+            "ALTER TABLE {} ADD COLUMN {};",
+            sql.identifier(table),
+            sql.column_definition(column)
+        ),
+        Step::DropColumn { table, column } => format!(
+            "ALTER TABLE {} DROP COLUMN {};",
+            sql.identifier(table),
+            sql.identifier(&column.name)
+        ),
+        Step::RenameColumn { table, from, to } => format!(
+            "ALTER TABLE {} RENAME COLUMN {} TO {};",
+            sql.identifier(table),
+            sql.identifier(from),
+            sql.identifier(to)
+        ),
+        Step::AlterColumnType {
+            table,
+            column,
+            from,
+            to,
+        } => {
+            let (from, to) = (sql.column_type(from), sql.column_type(to));
+
+            // Different attribute types can share the same column type.
+            if from == to {
+                return None;
+            }
+
+            format!(
+                "ALTER TABLE {} ALTER COLUMN {column} TYPE {to} USING {column}::{to};",
+                sql.identifier(table),
+                column = sql.identifier(column)
+            )
+        }
+        Step::AddForeignKey { table, foreign_key } => sql.add_constraint(table, foreign_key),
+        Step::DropForeignKey { table, foreign_key } => format!(
+            "ALTER TABLE {} DROP CONSTRAINT {};",
+            sql.identifier(table),
+            sql.identifier(&foreign_key.constraint_name(table))
+        ),
+    };
+
+    Some(statement)
+}
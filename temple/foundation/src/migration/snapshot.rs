@@ -0,0 +1,285 @@
+//! Lossless JSON snapshot of the project [`model::ModelOverview`]s, the
+//! baseline of the `ALTER TABLE` migrations.
+//!
+//! Unlike the diagram exports, the snapshot keeps every attribute and
+//! association together with the attribute type slugs, so the migration
+//! doesn't depend on how an earlier diagram was filtered.
+
+use crate::{attribute_type::AttributeType, model, FoundationError, FoundationResult};
+use serde::{Deserialize, Serialize};
+
+/// Version of the snapshot format.
+const VERSION: u64 = 1;
+
+#[derive(Serialize, Deserialize)]
+struct Snapshot {
+    version: u64,
+    models: Vec<SnapshotModel>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SnapshotModel {
+    name: String,
+    slug: String,
+    description: Option<String>,
+    attributes: Vec<SnapshotAttribute>,
+    associations: Vec<SnapshotAssociation>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SnapshotAttribute {
+    name: String,
+    description: Option<String>,
+    r#type: SnapshotAttributeType,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SnapshotAttributeType {
+    name: String,
+    slug: String,
+    description: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SnapshotAssociation {
+    name: String,
+    description: Option<String>,
+    kind: SnapshotAssociationKind,
+    model: SnapshotAssociatedModel,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum SnapshotAssociationKind {
+    BelongsTo,
+    HasOne,
+    HasMany,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SnapshotAssociatedModel {
+    name: String,
+    slug: String,
+    description: Option<String>,
+}
+
+pub fn snapshot(model_overviews: &[model::ModelOverview]) -> FoundationResult<String> {
+    let snapshot = Snapshot {
+        version: VERSION,
+        models: model_overviews.iter().map(SnapshotModel::from).collect(),
+    };
+
+    serde_json::to_string_pretty(&snapshot)
+        .map(|json| format!("{json}\n"))
+        .map_err(|err| FoundationError::internal(err.to_string()))
+}
+
+/// Reads the models back from a [`snapshot`].
+pub fn parse_snapshot(snapshot: &str) -> FoundationResult<Vec<model::ModelOverview>> {
+    let snapshot: Snapshot = serde_json::from_str(snapshot)
+        .map_err(|err| FoundationError::invalid_argument(format!("invalid snapshot, {err}")))?;
+
+    if snapshot.version != VERSION {
+        return Err(FoundationError::invalid_argument(format!(
+            "invalid snapshot, unsupported version `{}`",
+            snapshot.version
+        )));
+    }
+
+    Ok(snapshot.models.into_iter().map(Into::into).collect())
+}
+
+impl From<&model::ModelOverview> for SnapshotModel {
+    fn from(model_overview: &model::ModelOverview) -> Self {
+        let model::ModelOverview {
+            model,
+            attributes,
+            associations,
+        } = model_overview;
+
+        Self {
+            name: model.name.clone(),
+            slug: model.slug.clone(),
+            description: model.description.clone(),
+            attributes: attributes
+                .iter()
+                .map(|attribute| SnapshotAttribute {
+                    name: attribute.name.clone(),
+                    description: attribute.description.clone(),
+                    r#type: SnapshotAttributeType {
+                        name: attribute.r#type.name.clone(),
+                        slug: attribute.r#type.slug.clone(),
+                        description: attribute.r#type.description.clone(),
+                    },
+                })
+                .collect(),
+            associations: associations
+                .iter()
+                .map(|association| SnapshotAssociation {
+                    name: association.name.clone(),
+                    description: association.description.clone(),
+                    kind: match association.kind {
+                        model::AssociationKind::BelongsTo => SnapshotAssociationKind::BelongsTo,
+                        model::AssociationKind::HasOne => SnapshotAssociationKind::HasOne,
+                        model::AssociationKind::HasMany => SnapshotAssociationKind::HasMany,
+                    },
+                    model: SnapshotAssociatedModel {
+                        name: association.model.name.clone(),
+                        slug: association.model.slug.clone(),
+                        description: association.model.description.clone(),
+                    },
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<SnapshotModel> for model::ModelOverview {
+    fn from(snapshot_model: SnapshotModel) -> Self {
+        let SnapshotModel {
+            name,
+            slug,
+            description,
+            attributes,
+            associations,
+        } = snapshot_model;
+
+        Self {
+            model: model::Model {
+                description,
+                name,
+                slug,
+            },
+            attributes: attributes
+                .into_iter()
+                .map(|attribute| model::Attribute {
+                    description: attribute.description,
+                    r#type: AttributeType {
+                        description: attribute.r#type.description,
+                        name: attribute.r#type.name,
+                        slug: attribute.r#type.slug,
+                    },
+                    name: attribute.name,
+                })
+                .collect(),
+            associations: associations
+                .into_iter()
+                .map(|association| model::Association {
+                    description: association.description,
+                    kind: match association.kind {
+                        SnapshotAssociationKind::BelongsTo => model::AssociationKind::BelongsTo,
+                        SnapshotAssociationKind::HasOne => model::AssociationKind::HasOne,
+                        SnapshotAssociationKind::HasMany => model::AssociationKind::HasMany,
+                    },
+                    model: model::Model {
+                        description: association.model.description,
+                        name: association.model.name,
+                        slug: association.model.slug,
+                    },
+                    name: association.name,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FoundationErrorCode;
+
+    fn model_overviews() -> Vec<model::ModelOverview> {
+        let model = |name: &str, description: Option<&str>| model::Model {
+            description: description.map(ToString::to_string),
+            name: name.to_string(),
+            slug: name.to_lowercase(),
+        };
+
+        vec![
+            model::ModelOverview {
+                model: model("Book", Some("Printed book")),
+                attributes: vec![model::Attribute {
+                    description: None,
+                    r#type: AttributeType {
+                        description: Some("Whole number".to_string()),
+                        name: "Big Int".to_string(),
+                        slug: "big-int".to_string(),
+                    },
+                    name: "Pages".to_string(),
+                }],
+                associations: vec![model::Association {
+                    description: Some("Printed by".to_string()),
+                    kind: model::AssociationKind::BelongsTo,
+                    model: model("Publisher", None),
+                    name: "Publisher".to_string(),
+                }],
+            },
+            model::ModelOverview {
+                model: model("Publisher", None),
+                attributes: vec![],
+                associations: vec![model::Association {
+                    description: None,
+                    kind: model::AssociationKind::HasMany,
+                    model: model("Book", Some("Printed book")),
+                    name: "Books".to_string(),
+                }],
+            },
+        ]
+    }
+
+    #[test]
+    fn it_round_trips_model_overviews() -> FoundationResult<()> {
+        let model_overviews = model_overviews();
+
+        let parsed_model_overviews = parse_snapshot(&snapshot(&model_overviews)?)?;
+
+        assert_eq!(parsed_model_overviews.len(), model_overviews.len());
+
+        for (parsed, expected) in parsed_model_overviews.iter().zip(&model_overviews) {
+            assert_eq!(parsed.model, expected.model);
+            assert_eq!(parsed.attributes, expected.attributes);
+            assert_eq!(parsed.associations, expected.associations);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_returns_errors_for_invalid_snapshots() {
+        let test_table = [
+            (
+                "classDiagram",
+                "invalid snapshot, expected value at line 1 column 1",
+            ),
+            (
+                "42",
+                "invalid snapshot, invalid type: integer `42`, expected struct Snapshot at line 1 column 2",
+            ),
+            (
+                r#"{"models": []}"#,
+                "invalid snapshot, missing field `version` at line 1 column 14",
+            ),
+            (
+                r#"{"version": 2, "models": []}"#,
+                "invalid snapshot, unsupported version `2`",
+            ),
+            (
+                r#"{"version": 1, "models": [{"name": "Book", "slug": "book", "attributes": []}]}"#,
+                "invalid snapshot, missing field `associations` at line 1 column 76",
+            ),
+            (
+                r#"{"version": 1, "models": [{"name": "Book", "slug": "book", "attributes": [],
+                    "associations": [{"name": "Author", "kind": "owns",
+                        "model": {"name": "Author", "slug": "author"}}]}]}"#,
+                "invalid snapshot, unknown variant `owns`, expected one of `belongs_to`, `has_one`, `has_many` at line 2 column 70",
+            ),
+        ];
+
+        for (snapshot, message) in test_table {
+            let error = parse_snapshot(snapshot).err().unwrap();
+
+            assert!(matches!(error.code(), FoundationErrorCode::InvalidArgument));
+            assert_eq!(error.message(), message, "{snapshot}");
+        }
+    }
+}
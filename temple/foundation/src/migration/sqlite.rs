@@ -0,0 +1,201 @@
+use super::dialect::Dialect;
+use crate::attribute_type::AttributeKind;
+
+/// The [keywords](https://www.sqlite.org/lang_keywords.html) of SQLite.
+const RESERVED_WORDS: &[&str] = &[
+    "abort",
+    "action",
+    "add",
+    "after",
+    "all",
+    "alter",
+    "always",
+    "analyze",
+    "and",
+    "as",
+    "asc",
+    "attach",
+    "autoincrement",
+    "before",
+    "begin",
+    "between",
+    "by",
+    "cascade",
+    "case",
+    "cast",
+    "check",
+    "collate",
+    "column",
+    "commit",
+    "conflict",
+    "constraint",
+    "create",
+    "cross",
+    "current",
+    "current_date",
+    "current_time",
+    "current_timestamp",
+    "database",
+    "default",
+    "deferrable",
+    "deferred",
+    "delete",
+    "desc",
+    "detach",
+    "distinct",
+    "do",
+    "drop",
+    "each",
+    "else",
+    "end",
+    "escape",
+    "except",
+    "exclude",
+    "exclusive",
+    "exists",
+    "explain",
+    "fail",
+    "filter",
+    "first",
+    "following",
+    "for",
+    "foreign",
+    "from",
+    "full",
+    "generated",
+    "glob",
+    "group",
+    "groups",
+    "having",
+    "if",
+    "ignore",
+    "immediate",
+    "in",
+    "index",
+    "indexed",
+    "initially",
+    "inner",
+    "insert",
+    "instead",
+    "intersect",
+    "into",
+    "is",
+    "isnull",
+    "join",
+    "key",
+    "last",
+    "left",
+    "like",
+    "limit",
+    "match",
+    "materialized",
+    "natural",
+    "no",
+    "not",
+    "nothing",
+    "notnull",
+    "null",
+    "nulls",
+    "of",
+    "offset",
+    "on",
+    "or",
+    "order",
+    "others",
+    "outer",
+    "over",
+    "partition",
+    "plan",
+    "pragma",
+    "preceding",
+    "primary",
+    "query",
+    "raise",
+    "range",
+    "recursive",
+    "references",
+    "regexp",
+    "reindex",
+    "release",
+    "rename",
+    "replace",
+    "restrict",
+    "returning",
+    "right",
+    "rollback",
+    "row",
+    "rows",
+    "savepoint",
+    "select",
+    "set",
+    "table",
+    "temp",
+    "temporary",
+    "then",
+    "ties",
+    "to",
+    "transaction",
+    "trigger",
+    "unbounded",
+    "union",
+    "unique",
+    "update",
+    "using",
+    "vacuum",
+    "values",
+    "view",
+    "virtual",
+    "when",
+    "where",
+    "window",
+    "with",
+    "without",
+];
+
+/// [SQLite](https://www.sqlite.org/) dialect, keys are auto incremented
+/// integers.
+///
+/// SQLite can't add constraints to existing tables, but it checks foreign
+/// keys only when rows are written, so all of them are declared in the
+/// `CREATE TABLE` statements.
+pub struct Sqlite;
+
+impl Dialect for Sqlite {
+    fn column_type(&self, kind: AttributeKind) -> &'static str {
+        match kind {
+            AttributeKind::SmallInteger
+            | AttributeKind::Integer
+            | AttributeKind::BigInteger
+            | AttributeKind::Boolean => "INTEGER",
+            AttributeKind::Float | AttributeKind::Double => "REAL",
+            AttributeKind::Decimal => "NUMERIC",
+            AttributeKind::Binary => "BLOB",
+            AttributeKind::Date
+            | AttributeKind::Time
+            | AttributeKind::DateTime
+            | AttributeKind::Uuid
+            | AttributeKind::Json
+            | AttributeKind::Text => "TEXT",
+        }
+    }
+
+    fn primary_key_definition(&self) -> &'static str {
+        "INTEGER PRIMARY KEY AUTOINCREMENT"
+    }
+
+    fn foreign_key_type(&self) -> &'static str {
+        "INTEGER"
+    }
+
+    fn reserved_words(&self) -> &'static [&'static str] {
+        RESERVED_WORDS
+    }
+
+    fn quote(&self, name: &str) -> String {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+
+    fn defers_forward_references(&self) -> bool {
+        false
+    }
+}
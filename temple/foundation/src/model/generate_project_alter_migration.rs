@@ -0,0 +1,319 @@
+use crate::{
+    attribute_type::ListColumnTypeRecords,
+    migration,
+    model::{ListModelOverviewRecords, ModelOverview, SqlDialect},
+    FoundationResult,
+};
+
+pub struct Request {
+    pub project_slug: String,
+
+    /// Earlier export of the project, the snapshot returned by
+    /// [`super::get_project_snapshot`].
+    pub baseline: String,
+
+    /// Attributes renamed since the baseline. Any other removed and added
+    /// attributes are dropped and added, even when their types match.
+    pub renamed_attributes: Vec<RenamedAttribute>,
+}
+
+pub struct RenamedAttribute {
+    pub model_name: String,
+
+    /// Name of the attribute in the baseline.
+    pub from: String,
+
+    /// Current name of the attribute.
+    pub to: String,
+}
+
+pub struct Response {
+    /// PostgreSQL statements migrating the baseline to the current models.
+    pub up: String,
+
+    /// PostgreSQL statements reverting the `up` statements.
+    pub down: String,
+}
+
+pub async fn execute(
+    repo: &(impl ListModelOverviewRecords + ListColumnTypeRecords),
+    request: Request,
+) -> FoundationResult<Response> {
+    let Request {
+        project_slug,
+        baseline,
+        renamed_attributes,
+    } = request;
+
+    let baseline = migration::parse_snapshot(&baseline)?;
+
+    let model_overviews: Vec<ModelOverview> = repo
+        .list_model_overview_records(&project_slug)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    let baseline_tables = migration::tables(&baseline)?;
+    let tables = migration::tables(&model_overviews)?;
+
+    let renames = renamed_attributes
+        .iter()
+        .map(|renamed_attribute| {
+            migration::attribute_rename(
+                &renamed_attribute.model_name,
+                &renamed_attribute.from,
+                &renamed_attribute.to,
+            )
+        })
+        .collect::<FoundationResult<Vec<migration::ColumnRename>>>()?;
+
+    let migration::Migration { up, down } = migration::diff(&baseline_tables, &tables, &renames);
+    let type_mappings = migration::type_mappings(
+        repo.list_column_type_records(SqlDialect::PostgreSql)
+            .await?,
+    );
+
+    let response = Response {
+        up: migration::postgresql_alter_tables(&up, &type_mappings),
+        down: migration::postgresql_alter_tables(&down, &type_mappings),
+    };
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::get_project_snapshot,
+        tests::{customer_orders_repo, project_record_fixture},
+        FoundationErrorCode,
+    };
+    use serde_json::{json, Value};
+
+    fn baseline(models: Value) -> String {
+        json!({ "version": 1, "models": models }).to_string()
+    }
+
+    fn attribute(name: &str, type_name: &str) -> Value {
+        json!({
+            "name": name,
+            "type": { "name": type_name, "slug": type_name.to_lowercase().replace(' ', "-") },
+        })
+    }
+
+    fn association(name: &str, kind: &str, model_name: &str) -> Value {
+        json!({
+            "name": name,
+            "kind": kind,
+            "model": { "name": model_name, "slug": model_name.to_lowercase() },
+        })
+    }
+
+    #[tokio::test]
+    async fn it_generates_migration_from_baseline() -> FoundationResult<()> {
+        let response = execute(
+            &customer_orders_repo(),
+            Request {
+                project_slug: project_record_fixture(Default::default()).slug,
+                baseline: baseline(json!([
+                    {
+                        "name": "Coupon",
+                        "slug": "coupon",
+                        "attributes": [attribute("Code", "Text")],
+                        "associations": [],
+                    },
+                    {
+                        "name": "Customer",
+                        "slug": "customer",
+                        "attributes": [
+                            attribute("Email", "Text"),
+                            attribute("Name", "Text"),
+                        ],
+                        "associations": [association("Orders", "has_many", "Order")],
+                    },
+                    {
+                        "name": "Order",
+                        "slug": "order",
+                        "attributes": [
+                            attribute("Placed At", "Date Time"),
+                            attribute("Total", "Integer"),
+                        ],
+                        "associations": [association("Customer", "belongs_to", "Customer")],
+                    },
+                ])),
+                renamed_attributes: vec![],
+            },
+        )
+        .await?;
+
+        assert_eq!(
+            response.up,
+            r#"ALTER TABLE "order" DROP CONSTRAINT order_customer_id_fkey;
+
+ALTER TABLE customer DROP COLUMN email;
+
+ALTER TABLE "order" DROP COLUMN customer_id;
+
+DROP TABLE coupon;
+
+CREATE TABLE address (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    customer_id UUID NOT NULL
+);
+
+ALTER TABLE customer ADD COLUMN active BOOLEAN;
+
+ALTER TABLE "order" ADD COLUMN buyer_id UUID NOT NULL;
+
+ALTER TABLE "order" ALTER COLUMN total TYPE TEXT USING total::TEXT;
+
+ALTER TABLE "order" ADD CONSTRAINT order_buyer_id_fkey FOREIGN KEY (buyer_id) REFERENCES customer (id);
+
+ALTER TABLE address ADD CONSTRAINT address_customer_id_fkey FOREIGN KEY (customer_id) REFERENCES customer (id);
+"#
+        );
+        assert_eq!(
+            response.down,
+            r#"ALTER TABLE address DROP CONSTRAINT address_customer_id_fkey;
+
+ALTER TABLE "order" DROP CONSTRAINT order_buyer_id_fkey;
+
+ALTER TABLE "order" ALTER COLUMN total TYPE INTEGER USING total::INTEGER;
+
+ALTER TABLE "order" DROP COLUMN buyer_id;
+
+ALTER TABLE customer DROP COLUMN active;
+
+DROP TABLE address;
+
+CREATE TABLE coupon (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    code TEXT
+);
+
+ALTER TABLE "order" ADD COLUMN customer_id UUID NOT NULL;
+
+ALTER TABLE customer ADD COLUMN email TEXT;
+
+ALTER TABLE "order" ADD CONSTRAINT order_customer_id_fkey FOREIGN KEY (customer_id) REFERENCES customer (id);
+"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_renames_only_the_renamed_attributes() -> FoundationResult<()> {
+        let response = execute(
+            &customer_orders_repo(),
+            Request {
+                project_slug: project_record_fixture(Default::default()).slug,
+                baseline: baseline(json!([
+                    {
+                        "name": "Address",
+                        "slug": "address",
+                        "attributes": [],
+                        "associations": [association("Customer", "belongs_to", "Customer")],
+                    },
+                    {
+                        "name": "Customer",
+                        "slug": "customer",
+                        "attributes": [
+                            attribute("Active", "Boolean"),
+                            attribute("Full Name", "Text"),
+                            attribute("Nickname", "Text"),
+                        ],
+                        "associations": [
+                            association("Address", "has_one", "Address"),
+                            association("Orders", "has_many", "Order"),
+                        ],
+                    },
+                    {
+                        "name": "Order",
+                        "slug": "order",
+                        "attributes": [
+                            attribute("Placed At", "Date Time"),
+                            attribute("Total", "Money"),
+                        ],
+                        "associations": [association("Buyer", "belongs_to", "Customer")],
+                    },
+                ])),
+                renamed_attributes: vec![RenamedAttribute {
+                    model_name: "Customer".to_string(),
+                    from: "Full Name".to_string(),
+                    to: "Name".to_string(),
+                }],
+            },
+        )
+        .await?;
+
+        assert_eq!(
+            response.up,
+            r#"ALTER TABLE customer DROP COLUMN nickname;
+
+ALTER TABLE customer RENAME COLUMN full_name TO name;
+"#
+        );
+        assert_eq!(
+            response.down,
+            r#"ALTER TABLE customer RENAME COLUMN name TO full_name;
+
+ALTER TABLE customer ADD COLUMN nickname TEXT;
+"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_returns_empty_migration_for_unchanged_project() -> FoundationResult<()> {
+        let repo = customer_orders_repo();
+        let project_slug = project_record_fixture(Default::default()).slug;
+
+        let snapshot = get_project_snapshot::execute(
+            &repo,
+            get_project_snapshot::Request {
+                project_slug: project_slug.clone(),
+            },
+        )
+        .await?
+        .snapshot;
+
+        let response = execute(
+            &repo,
+            Request {
+                project_slug,
+                baseline: snapshot,
+                renamed_attributes: vec![],
+            },
+        )
+        .await?;
+
+        assert_eq!(response.up, "");
+        assert_eq!(response.down, "");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_returns_error_for_invalid_baseline() -> FoundationResult<()> {
+        let error = execute(
+            &customer_orders_repo(),
+            Request {
+                project_slug: project_record_fixture(Default::default()).slug,
+                baseline: "classDiagram".to_string(),
+                renamed_attributes: vec![],
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+        assert!(matches!(error.code(), FoundationErrorCode::InvalidArgument));
+        assert!(error.message().starts_with("invalid snapshot, "));
+
+        Ok(())
+    }
+}
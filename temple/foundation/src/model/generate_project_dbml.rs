@@ -0,0 +1,120 @@
+use crate::{
+    codegen,
+    model::{ListModelOverviewRecords, ModelOverview},
+    FoundationResult,
+};
+
+pub struct Request {
+    pub project_slug: String,
+}
+
+pub struct Response {
+    /// DBML of the project models, see [`codegen::dbml`].
+    pub dbml: String,
+}
+
+pub async fn execute(
+    repo: &impl ListModelOverviewRecords,
+    request: Request,
+) -> FoundationResult<Response> {
+    let Request { project_slug } = request;
+
+    let model_overviews: Vec<ModelOverview> = repo
+        .list_model_overview_records(&project_slug)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    let response = Response {
+        dbml: codegen::dbml(&model_overviews)?,
+    };
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::{self, tests::Repo},
+        tests::{
+            assert_imported_customer_orders, customer_orders_repo, project_record_fixture,
+            ProjectRepo,
+        },
+    };
+
+    #[tokio::test]
+    async fn it_generates_dbml() -> FoundationResult<()> {
+        let response = execute(
+            &customer_orders_repo(),
+            Request {
+                project_slug: project_record_fixture(Default::default()).slug,
+            },
+        )
+        .await?;
+
+        assert_eq!(
+            response.dbml,
+            r#"Table address {
+    id bigint [pk, increment]
+    customer_id bigint
+}
+
+Table customer {
+    id bigint [pk, increment]
+    active boolean
+    name text [note: '''
+Full name,
+as written on the orders.
+''']
+
+    Note: 'Buyer of the orders.'
+}
+
+Table order {
+    id bigint [pk, increment]
+    placed_at timestamp
+    total money
+    buyer_id bigint [note: 'Customer who placed the order.']
+}
+
+Ref "Customer/Address": address.customer_id - customer.id
+Ref "Buyer/Orders": order.buyer_id > customer.id
+"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_round_trips_through_import() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+
+        let response = execute(
+            &customer_orders_repo(),
+            Request {
+                project_slug: project_record.slug.clone(),
+            },
+        )
+        .await?;
+
+        let imported_repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            ..Default::default()
+        };
+
+        model::import_dbml::execute(
+            &imported_repo,
+            model::import_dbml::Request {
+                project_slug: project_record.slug.clone(),
+                dbml: response.dbml,
+            },
+        )
+        .await?;
+
+        assert_imported_customer_orders(&imported_repo).await?;
+
+        Ok(())
+    }
+}
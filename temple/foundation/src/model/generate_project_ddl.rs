@@ -0,0 +1,173 @@
+use crate::{
+    attribute_type::ListColumnTypeRecords,
+    migration,
+    model::{ListModelOverviewRecords, ModelOverview, SqlDialect},
+    FoundationResult,
+};
+
+pub struct Request {
+    pub project_slug: String,
+
+    pub dialect: SqlDialect,
+}
+
+pub struct Response {
+    /// `CREATE TABLE` statements of the project models.
+    pub sql: String,
+}
+
+/// Generates the tables in the dialect, with the column types set for the
+/// attribute types in the dialect or else the dialect defaults.
+pub async fn execute(
+    repo: &(impl ListModelOverviewRecords + ListColumnTypeRecords),
+    request: Request,
+) -> FoundationResult<Response> {
+    let Request {
+        project_slug,
+        dialect,
+    } = request;
+
+    let model_overviews: Vec<ModelOverview> = repo
+        .list_model_overview_records(&project_slug)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    let tables = migration::tables(&model_overviews)?;
+    let type_mappings = migration::type_mappings(repo.list_column_type_records(dialect).await?);
+
+    let response = Response {
+        sql: migration::create_tables(dialect, &type_mappings, &tables),
+    };
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        attribute_type::{tests::ColumnTypeRepo, ColumnType},
+        model::tests::Repo,
+        tests::{customer_orders_repo, project_record_fixture},
+    };
+
+    fn repo() -> Repo {
+        Repo {
+            column_type_repo: ColumnTypeRepo::seed(vec![
+                ColumnType {
+                    attribute_type_slug: "money".to_string(),
+                    dialect: SqlDialect::PostgreSql,
+                    column_type: "NUMERIC(12, 2)".to_string(),
+                },
+                ColumnType {
+                    attribute_type_slug: "money".to_string(),
+                    dialect: SqlDialect::MySql,
+                    column_type: "DECIMAL(12, 2)".to_string(),
+                },
+            ]),
+            ..customer_orders_repo()
+        }
+    }
+
+    async fn sql(dialect: SqlDialect) -> FoundationResult<String> {
+        let response = execute(
+            &repo(),
+            Request {
+                project_slug: project_record_fixture(Default::default()).slug,
+                dialect,
+            },
+        )
+        .await?;
+
+        Ok(response.sql)
+    }
+
+    #[tokio::test]
+    async fn it_generates_postgresql_ddl() -> FoundationResult<()> {
+        assert_eq!(
+            sql(SqlDialect::PostgreSql).await?,
+            r#"CREATE TABLE customer (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    active BOOLEAN,
+    name TEXT
+);
+
+CREATE TABLE address (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    customer_id UUID NOT NULL,
+    CONSTRAINT address_customer_id_fkey FOREIGN KEY (customer_id) REFERENCES customer (id)
+);
+
+CREATE TABLE "order" (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    placed_at TIMESTAMPTZ,
+    total NUMERIC(12, 2),
+    buyer_id UUID NOT NULL,
+    CONSTRAINT order_buyer_id_fkey FOREIGN KEY (buyer_id) REFERENCES customer (id)
+);
+"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_generates_sqlite_ddl() -> FoundationResult<()> {
+        assert_eq!(
+            sql(SqlDialect::Sqlite).await?,
+            r#"CREATE TABLE customer (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    active INTEGER,
+    name TEXT
+);
+
+CREATE TABLE address (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    customer_id INTEGER NOT NULL,
+    CONSTRAINT address_customer_id_fkey FOREIGN KEY (customer_id) REFERENCES customer (id)
+);
+
+CREATE TABLE "order" (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    placed_at TEXT,
+    total TEXT,
+    buyer_id INTEGER NOT NULL,
+    CONSTRAINT order_buyer_id_fkey FOREIGN KEY (buyer_id) REFERENCES customer (id)
+);
+"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_generates_mysql_ddl() -> FoundationResult<()> {
+        assert_eq!(
+            sql(SqlDialect::MySql).await?,
+            r#"CREATE TABLE customer (
+    id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+    active BOOLEAN,
+    name TEXT
+);
+
+CREATE TABLE address (
+    id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+    customer_id BIGINT UNSIGNED NOT NULL,
+    CONSTRAINT address_customer_id_fkey FOREIGN KEY (customer_id) REFERENCES customer (id)
+);
+
+CREATE TABLE `order` (
+    id BIGINT UNSIGNED AUTO_INCREMENT PRIMARY KEY,
+    placed_at DATETIME,
+    total DECIMAL(12, 2),
+    buyer_id BIGINT UNSIGNED NOT NULL,
+    CONSTRAINT order_buyer_id_fkey FOREIGN KEY (buyer_id) REFERENCES customer (id)
+);
+"#
+        );
+
+        Ok(())
+    }
+}
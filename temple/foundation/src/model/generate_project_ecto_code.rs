@@ -0,0 +1,341 @@
+use crate::{
+    codegen,
+    model::{GeneratedFile, ListModelOverviewRecords, ModelOverview},
+    FoundationResult, Utc, UtcDateTime,
+};
+
+pub struct Request {
+    pub project_slug: String,
+
+    /// Version of the migration, the current time when not given.
+    pub migration_timestamp: Option<UtcDateTime>,
+}
+
+pub struct Response {
+    /// Schema module per model followed by the migration creating the tables.
+    pub files: Vec<GeneratedFile>,
+}
+
+pub async fn execute(
+    repo: &impl ListModelOverviewRecords,
+    request: Request,
+) -> FoundationResult<Response> {
+    let Request {
+        project_slug,
+        migration_timestamp,
+    } = request;
+
+    let model_overviews: Vec<ModelOverview> = repo
+        .list_model_overview_records(&project_slug)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    let files = codegen::ecto_files(
+        &model_overviews,
+        &project_slug,
+        migration_timestamp.unwrap_or_else(Utc::now),
+    )?;
+
+    let response = Response { files };
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        attribute_type::{AttributeType, AttributeTypeRecord},
+        datastore,
+        model::tests::Repo,
+        tests::{
+            model_association_record_fixture, model_attribute_record_fixture, model_record_fixture,
+            project_record_fixture, ModelAssociationRecordFixture, ModelAssociationRepo,
+            ModelAttributeRecordFixture, ModelAttributeRepo, ModelRecordFixture, ModelRepo,
+            ProjectRepo,
+        },
+        FoundationErrorCode,
+    };
+    use chrono::TimeZone;
+
+    fn migration_timestamp() -> UtcDateTime {
+        chrono::Utc.with_ymd_and_hms(2023, 9, 4, 12, 30, 0).unwrap()
+    }
+
+    #[tokio::test]
+    async fn it_generates_schemas_and_migration() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            description: Some("Printed book.\n\nAlso an e-book.".to_string()),
+            ..Default::default()
+        });
+        let model_attribute_record = model_attribute_record_fixture(ModelAttributeRecordFixture {
+            model_id: Some(model_record.id),
+            description: Some("Full title.".to_string()),
+            ..Default::default()
+        });
+        let associated_model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            name: Some("Publisher".to_string()),
+            slug: Some("publisher".to_string()),
+            ..Default::default()
+        });
+        let associated_model_attribute_record =
+            model_attribute_record_fixture(ModelAttributeRecordFixture {
+                model_id: Some(associated_model_record.id),
+                name: Some("Founded At".to_string()),
+                r#type: Some(AttributeTypeRecord {
+                    inner: AttributeType {
+                        description: None,
+                        name: "DateTime".to_string(),
+                        slug: "date-time".to_string(),
+                    },
+                    ..Default::default()
+                }),
+                ..Default::default()
+            });
+        let model_association_record =
+            model_association_record_fixture(ModelAssociationRecordFixture {
+                model_id: Some(model_record.id),
+                associated_model: Some(associated_model_record.clone()),
+                name: Some("Imprint".to_string()),
+                ..Default::default()
+            });
+        let inverse_model_association_record =
+            model_association_record_fixture(ModelAssociationRecordFixture {
+                model_id: Some(associated_model_record.id),
+                associated_model: Some(model_record.clone()),
+                kind: Some(datastore::model::AssociationKind::HasMany),
+                name: Some("Book".to_string()),
+                description: Some("Books in print.".to_string()),
+            });
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![model_record, associated_model_record]),
+            model_attribute_repo: ModelAttributeRepo::seed(vec![
+                model_attribute_record,
+                associated_model_attribute_record,
+            ]),
+            model_association_repo: ModelAssociationRepo::seed(vec![
+                model_association_record,
+                inverse_model_association_record,
+            ]),
+            ..Default::default()
+        };
+
+        let response = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug,
+                migration_timestamp: Some(migration_timestamp()),
+            },
+        )
+        .await?;
+
+        assert_eq!(
+            response.files,
+            vec![
+                GeneratedFile {
+                    path: "lib/book_store/book.ex".to_string(),
+                    content: r#"defmodule BookStore.Book do
+  @moduledoc """
+  Printed book.
+
+  Also an e-book.
+  """
+
+  use Ecto.Schema
+  import Ecto.Changeset
+
+  schema "books" do
+    # Full title.
+    field :title, :string
+
+    belongs_to :imprint, BookStore.Publisher
+  end
+
+  @doc false
+  def changeset(book, attrs) do
+    book
+    |> cast(attrs, [:title, :imprint_id])
+    |> validate_required([:imprint_id])
+    |> assoc_constraint(:imprint)
+  end
+end
+"#
+                    .to_string(),
+                },
+                GeneratedFile {
+                    path: "lib/book_store/publisher.ex".to_string(),
+                    content: r#"defmodule BookStore.Publisher do
+  use Ecto.Schema
+  import Ecto.Changeset
+
+  schema "publishers" do
+    field :founded_at, :utc_datetime
+
+    # Books in print.
+    has_many :books, BookStore.Book, foreign_key: :imprint_id
+  end
+
+  @doc false
+  def changeset(publisher, attrs) do
+    publisher
+    |> cast(attrs, [:founded_at])
+  end
+end
+"#
+                    .to_string(),
+                },
+                GeneratedFile {
+                    path: "priv/repo/migrations/20230904123000_create_book_store_tables.exs"
+                        .to_string(),
+                    content: r#"defmodule BookStore.Repo.Migrations.CreateBookStoreTables do
+  use Ecto.Migration
+
+  def change do
+    create table(:publishers) do
+      add :founded_at, :utc_datetime
+    end
+
+    create table(:books, comment: "Printed book.\n\nAlso an e-book.") do
+      add :title, :string, comment: "Full title."
+      add :imprint_id, references(:publishers), null: false
+    end
+
+    create index(:books, [:imprint_id])
+  end
+end
+"#
+                    .to_string(),
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_references_tables_of_cycles_after_creating_them() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = |name: &str| {
+            model_record_fixture(ModelRecordFixture {
+                project_id: Some(project_record.id),
+                name: Some(name.to_string()),
+                slug: Some(name.to_lowercase()),
+                ..Default::default()
+            })
+        };
+        let employee_record = model_record("Employee");
+        let department_record = model_record("Department");
+        let association_record =
+            |model: &datastore::model::Model, associated_model: &datastore::model::Model| {
+                model_association_record_fixture(ModelAssociationRecordFixture {
+                    model_id: Some(model.id),
+                    associated_model: Some(associated_model.clone()),
+                    name: Some(associated_model.name.clone()),
+                    ..Default::default()
+                })
+            };
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![employee_record.clone(), department_record.clone()]),
+            model_association_repo: ModelAssociationRepo::seed(vec![
+                association_record(&employee_record, &department_record),
+                association_record(&department_record, &employee_record),
+            ]),
+            ..Default::default()
+        };
+
+        let response = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug,
+                migration_timestamp: Some(migration_timestamp()),
+            },
+        )
+        .await?;
+
+        assert!(response.files[2].content.contains(
+            r#"    create table(:departments) do
+      add :employee_id, :bigint, null: false
+    end
+
+    create index(:departments, [:employee_id])
+
+    create table(:employees) do
+      add :department_id, references(:departments), null: false
+    end
+
+    create index(:employees, [:department_id])
+
+    alter table(:departments) do
+      modify :employee_id, references(:employees), from: :bigint
+    end
+"#
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_fails_on_field_name_clash() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            ..Default::default()
+        });
+        let model_attribute_record = model_attribute_record_fixture(ModelAttributeRecordFixture {
+            model_id: Some(model_record.id),
+            name: Some("Publisher Id".to_string()),
+            ..Default::default()
+        });
+        let associated_model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            name: Some("Publisher".to_string()),
+            slug: Some("publisher".to_string()),
+            ..Default::default()
+        });
+        let model_association_record =
+            model_association_record_fixture(ModelAssociationRecordFixture {
+                model_id: Some(model_record.id),
+                associated_model: Some(associated_model_record.clone()),
+                ..Default::default()
+            });
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![model_record, associated_model_record]),
+            model_attribute_repo: ModelAttributeRepo::seed(vec![model_attribute_record]),
+            model_association_repo: ModelAssociationRepo::seed(vec![model_association_record]),
+            ..Default::default()
+        };
+
+        let error = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug,
+                migration_timestamp: Some(migration_timestamp()),
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+        assert!(matches!(
+            error.code(),
+            FoundationErrorCode::FailedPrecondition
+        ));
+        assert_eq!(
+            error.message(),
+            "code can't be generated, `Book` has more than one field named `publisher_id`"
+        );
+
+        Ok(())
+    }
+}
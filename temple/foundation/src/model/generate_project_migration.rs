@@ -0,0 +1,259 @@
+use crate::{
+    attribute_type::ListColumnTypeRecords,
+    migration,
+    model::{ListModelOverviewRecords, ModelOverview, SqlDialect},
+    FoundationResult,
+};
+
+pub struct Request {
+    pub project_slug: String,
+}
+
+pub struct Response {
+    /// PostgreSQL `CREATE TABLE` statements of the project models.
+    pub sql: String,
+}
+
+pub async fn execute(
+    repo: &(impl ListModelOverviewRecords + ListColumnTypeRecords),
+    request: Request,
+) -> FoundationResult<Response> {
+    let Request { project_slug } = request;
+
+    let model_overviews: Vec<ModelOverview> = repo
+        .list_model_overview_records(&project_slug)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    let tables = migration::tables(&model_overviews)?;
+    let type_mappings = migration::type_mappings(
+        repo.list_column_type_records(SqlDialect::PostgreSql)
+            .await?,
+    );
+
+    let response = Response {
+        sql: migration::create_tables(SqlDialect::PostgreSql, &type_mappings, &tables),
+    };
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        attribute_type::{AttributeType, AttributeTypeRecord},
+        datastore,
+        model::tests::Repo,
+        tests::{
+            model_association_record_fixture, model_attribute_record_fixture, model_record_fixture,
+            project_record_fixture, ModelAssociationRecordFixture, ModelAssociationRepo,
+            ModelAttributeRecordFixture, ModelAttributeRepo, ModelRecordFixture, ModelRepo,
+            ProjectRepo,
+        },
+        FoundationErrorCode,
+    };
+
+    #[tokio::test]
+    async fn it_generates_tables_in_dependency_order() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            ..Default::default()
+        });
+        let model_attribute_record = model_attribute_record_fixture(ModelAttributeRecordFixture {
+            model_id: Some(model_record.id),
+            ..Default::default()
+        });
+        let associated_model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            name: Some("Publisher".to_string()),
+            slug: Some("publisher".to_string()),
+            ..Default::default()
+        });
+        let associated_model_attribute_record =
+            model_attribute_record_fixture(ModelAttributeRecordFixture {
+                model_id: Some(associated_model_record.id),
+                name: Some("Founded At".to_string()),
+                r#type: Some(AttributeTypeRecord {
+                    inner: AttributeType {
+                        description: None,
+                        name: "DateTime".to_string(),
+                        slug: "date-time".to_string(),
+                    },
+                    ..Default::default()
+                }),
+                ..Default::default()
+            });
+        let model_association_record =
+            model_association_record_fixture(ModelAssociationRecordFixture {
+                model_id: Some(model_record.id),
+                associated_model: Some(associated_model_record.clone()),
+                ..Default::default()
+            });
+        let inverse_model_association_record =
+            model_association_record_fixture(ModelAssociationRecordFixture {
+                model_id: Some(associated_model_record.id),
+                associated_model: Some(model_record.clone()),
+                kind: Some(datastore::model::AssociationKind::HasMany),
+                name: Some("Books".to_string()),
+                ..Default::default()
+            });
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![
+                model_record.clone(),
+                associated_model_record.clone(),
+            ]),
+            model_attribute_repo: ModelAttributeRepo::seed(vec![
+                model_attribute_record.clone(),
+                associated_model_attribute_record.clone(),
+            ]),
+            model_association_repo: ModelAssociationRepo::seed(vec![
+                model_association_record.clone(),
+                inverse_model_association_record.clone(),
+            ]),
+            ..Default::default()
+        };
+
+        let response = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug.to_string(),
+            },
+        )
+        .await?;
+
+        assert_eq!(
+            response.sql,
+            r#"CREATE TABLE publisher (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    founded_at TIMESTAMPTZ
+);
+
+CREATE TABLE book (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    title TEXT,
+    publisher_id UUID NOT NULL,
+    CONSTRAINT book_publisher_id_fkey FOREIGN KEY (publisher_id) REFERENCES publisher (id)
+);
+"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_adds_constraints_of_cyclic_references_after_tables() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let employee_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            name: Some("Employee".to_string()),
+            slug: Some("employee".to_string()),
+            ..Default::default()
+        });
+        let department_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            name: Some("Department".to_string()),
+            slug: Some("department".to_string()),
+            ..Default::default()
+        });
+        let association_record = |model: &datastore::model::Model,
+                                  associated_model: &datastore::model::Model,
+                                  name: &str| {
+            model_association_record_fixture(ModelAssociationRecordFixture {
+                model_id: Some(model.id),
+                associated_model: Some(associated_model.clone()),
+                name: Some(name.to_string()),
+                ..Default::default()
+            })
+        };
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![employee_record.clone(), department_record.clone()]),
+            model_association_repo: ModelAssociationRepo::seed(vec![
+                association_record(&employee_record, &department_record, "Department"),
+                association_record(&department_record, &employee_record, "Manager"),
+            ]),
+            ..Default::default()
+        };
+
+        let response = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug.to_string(),
+            },
+        )
+        .await?;
+
+        assert_eq!(
+            response.sql,
+            r#"CREATE TABLE department (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    manager_id UUID NOT NULL
+);
+
+CREATE TABLE employee (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    department_id UUID NOT NULL,
+    CONSTRAINT employee_department_id_fkey FOREIGN KEY (department_id) REFERENCES department (id)
+);
+
+ALTER TABLE department ADD CONSTRAINT department_manager_id_fkey FOREIGN KEY (manager_id) REFERENCES employee (id);
+"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_returns_error_for_duplicate_columns() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            ..Default::default()
+        });
+        let model_attribute_record = |name: &str| {
+            model_attribute_record_fixture(ModelAttributeRecordFixture {
+                model_id: Some(model_record.id),
+                name: Some(name.to_string()),
+                ..Default::default()
+            })
+        };
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![model_record.clone()]),
+            model_attribute_repo: ModelAttributeRepo::seed(vec![
+                model_attribute_record("Page Count"),
+                model_attribute_record("PageCount"),
+            ]),
+            ..Default::default()
+        };
+
+        let error = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug.to_string(),
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+        assert!(matches!(
+            error.code(),
+            FoundationErrorCode::FailedPrecondition
+        ));
+        assert_eq!(
+            error.message(),
+            "migration can't be generated, `Book` has more than one column named `page_count`"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{
+    codegen,
+    model::{ListModelOverviewRecords, ModelOverview},
+    FoundationResult,
+};
+
+pub struct Request {
+    pub project_slug: String,
+}
+
+pub struct Response {
+    /// `schema.prisma` of the project models, see [`codegen::prisma_schema`].
+    pub prisma_schema: String,
+}
+
+pub async fn execute(
+    repo: &impl ListModelOverviewRecords,
+    request: Request,
+) -> FoundationResult<Response> {
+    let Request { project_slug } = request;
+
+    let model_overviews: Vec<ModelOverview> = repo
+        .list_model_overview_records(&project_slug)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    let response = Response {
+        prisma_schema: codegen::prisma_schema(&model_overviews)?,
+    };
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::{self, tests::Repo},
+        tests::{
+            assert_imported_customer_orders, customer_orders_repo, project_record_fixture,
+            ProjectRepo,
+        },
+    };
+
+    #[tokio::test]
+    async fn it_generates_prisma_schema() -> FoundationResult<()> {
+        let response = execute(
+            &customer_orders_repo(),
+            Request {
+                project_slug: project_record_fixture(Default::default()).slug,
+            },
+        )
+        .await?;
+
+        assert_eq!(
+            response.prisma_schema,
+            r#"datasource db {
+  provider = "postgresql"
+  url      = env("DATABASE_URL")
+}
+
+generator client {
+  provider = "prisma-client-js"
+}
+
+model Address {
+  id         Int      @id @default(autoincrement())
+  customer   Customer @relation(fields: [customerId], references: [id])
+  customerId Int      @unique
+}
+
+/// Buyer of the orders.
+model Customer {
+  id      Int      @id @default(autoincrement())
+  active  Boolean
+  /// Full name,
+  /// as written on the orders.
+  name    String
+  address Address?
+  orders  Order[]
+}
+
+model Order {
+  id       Int      @id @default(autoincrement())
+  placedAt DateTime
+  /// @metropolis.type("Money")
+  total    String
+  /// Customer who placed the order.
+  buyer    Customer @relation(fields: [buyerId], references: [id])
+  buyerId  Int
+}
+"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_round_trips_through_import() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+
+        let response = execute(
+            &customer_orders_repo(),
+            Request {
+                project_slug: project_record.slug.clone(),
+            },
+        )
+        .await?;
+
+        let imported_repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            ..Default::default()
+        };
+
+        model::import_prisma_schema::execute(
+            &imported_repo,
+            model::import_prisma_schema::Request {
+                project_slug: project_record.slug.clone(),
+                prisma_schema: response.prisma_schema,
+            },
+        )
+        .await?;
+
+        assert_imported_customer_orders(&imported_repo).await?;
+
+        Ok(())
+    }
+}
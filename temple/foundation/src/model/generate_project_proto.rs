@@ -0,0 +1,246 @@
+use crate::{
+    codegen, datastore,
+    model::{
+        CreateModelFieldNumberRecord, ListModelFieldNumberRecords, ListModelOverviewRecords,
+        ModelOverview,
+    },
+    FoundationResult,
+};
+
+pub struct Request {
+    pub project_slug: String,
+}
+
+pub struct Response {
+    /// `.proto` file with a message per model.
+    pub proto: String,
+}
+
+pub async fn execute(
+    repo: &(impl ListModelOverviewRecords + ListModelFieldNumberRecords + CreateModelFieldNumberRecord),
+    request: Request,
+) -> FoundationResult<Response> {
+    let Request { project_slug } = request;
+
+    let model_overview_records = repo.list_model_overview_records(&project_slug).await?;
+    let model_records: Vec<datastore::model::Model> = model_overview_records
+        .iter()
+        .map(|model_overview_record| model_overview_record.model.clone())
+        .collect();
+
+    let mut field_numbers = codegen::FieldNumbers::new();
+
+    for field_number_record in repo.list_model_field_number_records(&project_slug).await? {
+        let Some(model_record) = model_records
+            .iter()
+            .find(|model_record| model_record.id == field_number_record.model_id)
+        else {
+            continue;
+        };
+
+        field_numbers
+            .entry(model_record.slug.clone())
+            .or_default()
+            .push(field_number_record.into());
+    }
+
+    let model_overviews: Vec<ModelOverview> =
+        model_overview_records.into_iter().map(Into::into).collect();
+
+    let proto = codegen::proto_file(&model_overviews, &project_slug, &field_numbers)?;
+
+    for (model_slug, field_number) in proto.assigned_field_numbers {
+        let model_record = model_records
+            .iter()
+            .find(|model_record| model_record.slug == model_slug)
+            .cloned()
+            .expect("field numbers are assigned to the listed models");
+
+        repo.create_model_field_number_record(model_record, field_number)
+            .await?;
+    }
+
+    let response = Response {
+        proto: proto.content,
+    };
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        attribute_type::{AttributeType, AttributeTypeRecord},
+        model::tests::Repo,
+        tests::{
+            model_association_record_fixture, model_attribute_record_fixture,
+            model_field_number_record_fixture, model_record_fixture, project_record_fixture,
+            ModelAssociationRecordFixture, ModelAssociationRepo, ModelAttributeRecordFixture,
+            ModelAttributeRepo, ModelFieldNumberRecordFixture, ModelFieldNumberRepo,
+            ModelRecordFixture, ModelRepo, ProjectRepo,
+        },
+        FoundationErrorCode,
+    };
+
+    #[tokio::test]
+    async fn it_generates_messages_and_keeps_field_numbers() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            description: Some("Printed book.".to_string()),
+            ..Default::default()
+        });
+        let model_attribute_record = model_attribute_record_fixture(ModelAttributeRecordFixture {
+            model_id: Some(model_record.id),
+            description: Some("Full title.".to_string()),
+            ..Default::default()
+        });
+        let associated_model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            name: Some("Publisher".to_string()),
+            slug: Some("publisher".to_string()),
+            ..Default::default()
+        });
+        let associated_model_attribute_record =
+            model_attribute_record_fixture(ModelAttributeRecordFixture {
+                model_id: Some(associated_model_record.id),
+                name: Some("Founded At".to_string()),
+                r#type: Some(AttributeTypeRecord {
+                    inner: AttributeType {
+                        description: None,
+                        name: "Date Time".to_string(),
+                        slug: "date-time".to_string(),
+                    },
+                    ..Default::default()
+                }),
+                ..Default::default()
+            });
+        let model_association_record =
+            model_association_record_fixture(ModelAssociationRecordFixture {
+                model_id: Some(model_record.id),
+                associated_model: Some(associated_model_record.clone()),
+                ..Default::default()
+            });
+        let inverse_model_association_record =
+            model_association_record_fixture(ModelAssociationRecordFixture {
+                model_id: Some(associated_model_record.id),
+                associated_model: Some(model_record.clone()),
+                kind: Some(datastore::model::AssociationKind::HasMany),
+                name: Some("Books".to_string()),
+                ..Default::default()
+            });
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![model_record.clone(), associated_model_record]),
+            model_attribute_repo: ModelAttributeRepo::seed(vec![
+                model_attribute_record,
+                associated_model_attribute_record,
+            ]),
+            model_association_repo: ModelAssociationRepo::seed(vec![
+                model_association_record,
+                inverse_model_association_record,
+            ]),
+            model_field_number_repo: ModelFieldNumberRepo::seed(vec![
+                model_field_number_record_fixture(ModelFieldNumberRecordFixture {
+                    model_id: Some(model_record.id),
+                    name: Some("isbn".to_string()),
+                    number: Some(1),
+                }),
+                model_field_number_record_fixture(ModelFieldNumberRecordFixture {
+                    model_id: Some(model_record.id),
+                    name: Some("publisher".to_string()),
+                    number: Some(2),
+                }),
+            ]),
+            ..Default::default()
+        };
+
+        let expected_proto = r#"syntax = "proto3";
+
+import "google/protobuf/timestamp.proto";
+
+package book_store.v1;
+
+// Printed book.
+message Book {
+    reserved 1;
+    reserved "isbn";
+
+    Publisher publisher = 2;
+
+    // Full title.
+    string title = 3;
+}
+
+message Publisher {
+    google.protobuf.Timestamp founded_at = 1;
+
+    repeated Book books = 2;
+}
+"#;
+
+        let response = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug.clone(),
+            },
+        )
+        .await?;
+
+        assert_eq!(response.proto, expected_proto);
+        assert_eq!(repo.model_field_number_repo.records().await.len(), 5);
+
+        let response = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug,
+            },
+        )
+        .await?;
+
+        assert_eq!(response.proto, expected_proto);
+        assert_eq!(repo.model_field_number_repo.records().await.len(), 5);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_rejects_names_which_are_not_identifiers() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            name: Some("Libro Éditorial".to_string()),
+            slug: Some("libro-editorial".to_string()),
+            ..Default::default()
+        });
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![model_record]),
+            ..Default::default()
+        };
+
+        let error = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug,
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+        assert!(matches!(
+            error.code(),
+            FoundationErrorCode::FailedPrecondition
+        ));
+        assert_eq!(
+            error.message(),
+            "code can't be generated, `Libro Éditorial` can't be used as a Protobuf identifier"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,267 @@
+use crate::{
+    codegen,
+    model::{GeneratedFile, ListModelOverviewRecords, ModelOverview},
+    FoundationResult, Utc, UtcDateTime,
+};
+
+pub struct Request {
+    pub project_slug: String,
+
+    /// Version of the migration, the current time when not given.
+    pub migration_timestamp: Option<UtcDateTime>,
+}
+
+pub struct Response {
+    /// Model class per model followed by the migration creating the tables.
+    pub files: Vec<GeneratedFile>,
+}
+
+pub async fn execute(
+    repo: &impl ListModelOverviewRecords,
+    request: Request,
+) -> FoundationResult<Response> {
+    let Request {
+        project_slug,
+        migration_timestamp,
+    } = request;
+
+    let model_overviews: Vec<ModelOverview> = repo
+        .list_model_overview_records(&project_slug)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    let files = codegen::rails_files(
+        &model_overviews,
+        &project_slug,
+        migration_timestamp.unwrap_or_else(Utc::now),
+    )?;
+
+    let response = Response { files };
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        datastore,
+        model::tests::Repo,
+        tests::{
+            model_association_record_fixture, model_attribute_record_fixture, model_record_fixture,
+            project_record_fixture, ModelAssociationRecordFixture, ModelAssociationRepo,
+            ModelAttributeRecordFixture, ModelAttributeRepo, ModelRecordFixture, ModelRepo,
+            ProjectRepo,
+        },
+        FoundationErrorCode,
+    };
+    use chrono::TimeZone;
+
+    fn migration_timestamp() -> UtcDateTime {
+        chrono::Utc.with_ymd_and_hms(2023, 9, 4, 12, 30, 0).unwrap()
+    }
+
+    #[tokio::test]
+    async fn it_generates_models_and_migration() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            description: Some("Printed book.\n\nAlso an e-book.".to_string()),
+            ..Default::default()
+        });
+        let model_attribute_record = model_attribute_record_fixture(ModelAttributeRecordFixture {
+            model_id: Some(model_record.id),
+            description: Some("Full \"title\".".to_string()),
+            ..Default::default()
+        });
+        let associated_model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            name: Some("Publisher".to_string()),
+            slug: Some("publisher".to_string()),
+            ..Default::default()
+        });
+        let model_association_record =
+            model_association_record_fixture(ModelAssociationRecordFixture {
+                model_id: Some(model_record.id),
+                associated_model: Some(associated_model_record.clone()),
+                name: Some("Imprint".to_string()),
+                ..Default::default()
+            });
+        let inverse_model_association_record =
+            model_association_record_fixture(ModelAssociationRecordFixture {
+                model_id: Some(associated_model_record.id),
+                associated_model: Some(model_record.clone()),
+                kind: Some(datastore::model::AssociationKind::HasMany),
+                name: Some("Book".to_string()),
+                description: Some("Books in print.".to_string()),
+            });
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![model_record, associated_model_record]),
+            model_attribute_repo: ModelAttributeRepo::seed(vec![model_attribute_record]),
+            model_association_repo: ModelAssociationRepo::seed(vec![
+                model_association_record,
+                inverse_model_association_record,
+            ]),
+            ..Default::default()
+        };
+
+        let response = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug.clone(),
+                migration_timestamp: Some(migration_timestamp()),
+            },
+        )
+        .await?;
+
+        assert_eq!(
+            response.files,
+            vec![
+                GeneratedFile {
+                    path: "app/models/book.rb".to_string(),
+                    content: r#"# Printed book.
+#
+# Also an e-book.
+class Book < ApplicationRecord
+  belongs_to :imprint, class_name: "Publisher"
+end
+"#
+                    .to_string(),
+                },
+                GeneratedFile {
+                    path: "app/models/publisher.rb".to_string(),
+                    content: r#"class Publisher < ApplicationRecord
+  # Books in print.
+  has_many :books, foreign_key: :imprint_id
+end
+"#
+                    .to_string(),
+                },
+                GeneratedFile {
+                    path: "db/migrate/20230904123000_create_book_store_tables.rb".to_string(),
+                    content: r#"class CreateBookStoreTables < ActiveRecord::Migration[7.1]
+  def change
+    create_table :publishers do |t|
+    end
+
+    create_table :books, comment: "Printed book.\n\nAlso an e-book." do |t|
+      t.string :title, comment: "Full \"title\"."
+      t.references :imprint, null: false, foreign_key: { to_table: :publishers }
+    end
+  end
+end
+"#
+                    .to_string(),
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_adds_foreign_keys_of_cycles_after_the_tables() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = |name: &str| {
+            model_record_fixture(ModelRecordFixture {
+                project_id: Some(project_record.id),
+                name: Some(name.to_string()),
+                slug: Some(name.to_lowercase()),
+                ..Default::default()
+            })
+        };
+        let employee_record = model_record("Employee");
+        let department_record = model_record("Department");
+        let association_record =
+            |model: &datastore::model::Model, associated_model: &datastore::model::Model| {
+                model_association_record_fixture(ModelAssociationRecordFixture {
+                    model_id: Some(model.id),
+                    associated_model: Some(associated_model.clone()),
+                    name: Some(associated_model.name.clone()),
+                    ..Default::default()
+                })
+            };
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![employee_record.clone(), department_record.clone()]),
+            model_association_repo: ModelAssociationRepo::seed(vec![
+                association_record(&employee_record, &department_record),
+                association_record(&department_record, &employee_record),
+            ]),
+            ..Default::default()
+        };
+
+        let response = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug,
+                migration_timestamp: Some(migration_timestamp()),
+            },
+        )
+        .await?;
+
+        assert!(response.files[2].content.contains(
+            r#"    create_table :departments do |t|
+      t.references :employee, null: false
+    end
+
+    create_table :employees do |t|
+      t.references :department, null: false, foreign_key: true
+    end
+
+    add_foreign_key :departments, :employees
+"#
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_fails_on_class_name_clash() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = |name: &str, slug: &str| {
+            model_record_fixture(ModelRecordFixture {
+                project_id: Some(project_record.id),
+                name: Some(name.to_string()),
+                slug: Some(slug.to_string()),
+                ..Default::default()
+            })
+        };
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![
+                model_record("Book", "book"),
+                model_record("Books", "books"),
+            ]),
+            ..Default::default()
+        };
+
+        let error = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug,
+                migration_timestamp: Some(migration_timestamp()),
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+        assert!(matches!(
+            error.code(),
+            FoundationErrorCode::FailedPrecondition
+        ));
+        assert_eq!(
+            error.message(),
+            "code can't be generated, `Books` and `Book` share the same class name `Book`"
+        );
+
+        Ok(())
+    }
+}
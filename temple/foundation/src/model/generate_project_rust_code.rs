@@ -0,0 +1,215 @@
+use crate::{
+    codegen,
+    model::{GeneratedFile, ListModelOverviewRecords, ModelOverview},
+    FoundationResult,
+};
+
+pub struct Request {
+    pub project_slug: String,
+
+    /// Derives `serde::Deserialize` and `serde::Serialize` for the structs.
+    pub serde: bool,
+}
+
+pub struct Response {
+    /// `mod.rs` followed by a module per model.
+    pub files: Vec<GeneratedFile>,
+}
+
+pub async fn execute(
+    repo: &impl ListModelOverviewRecords,
+    request: Request,
+) -> FoundationResult<Response> {
+    let Request {
+        project_slug,
+        serde,
+    } = request;
+
+    let model_overviews: Vec<ModelOverview> = repo
+        .list_model_overview_records(&project_slug)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    let files = codegen::rust_modules(&model_overviews, serde)?;
+
+    let response = Response { files };
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        datastore,
+        model::tests::Repo,
+        tests::{
+            customer_orders_repo, model_association_record_fixture, model_record_fixture,
+            project_record_fixture, ModelAssociationRecordFixture, ModelAssociationRepo,
+            ModelRecordFixture, ModelRepo, ProjectRepo,
+        },
+    };
+
+    #[tokio::test]
+    async fn it_generates_module_per_model() -> FoundationResult<()> {
+        let response = execute(
+            &customer_orders_repo(),
+            Request {
+                project_slug: project_record_fixture(Default::default()).slug,
+                serde: true,
+            },
+        )
+        .await?;
+
+        assert_eq!(
+            response.files,
+            vec![
+                GeneratedFile {
+                    path: "mod.rs".to_string(),
+                    content: r#"mod address;
+mod customer;
+mod order;
+
+pub use address::Address;
+pub use customer::Customer;
+pub use order::Order;
+"#
+                    .to_string(),
+                },
+                GeneratedFile {
+                    path: "address.rs".to_string(),
+                    content: r#"use super::Customer;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Address {
+    pub customer: Option<Box<Customer>>,
+}
+"#
+                    .to_string(),
+                },
+                GeneratedFile {
+                    path: "customer.rs".to_string(),
+                    content: r#"use super::{Address, Order};
+use serde::{Deserialize, Serialize};
+
+/// Buyer of the orders.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Customer {
+    pub active: bool,
+
+    /// Full name,
+    /// as written on the orders.
+    pub name: String,
+
+    pub address: Option<Box<Address>>,
+
+    pub orders: Vec<Order>,
+}
+"#
+                    .to_string(),
+                },
+                GeneratedFile {
+                    path: "order.rs".to_string(),
+                    content: r#"use super::Customer;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Order {
+    pub placed_at: chrono::DateTime<chrono::Utc>,
+
+    pub total: String,
+
+    /// Customer who placed the order.
+    pub buyer: Option<Customer>,
+}
+"#
+                    .to_string(),
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_boxes_associations_referring_back() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = |name: &str| {
+            model_record_fixture(ModelRecordFixture {
+                project_id: Some(project_record.id),
+                name: Some(name.to_string()),
+                slug: Some(name.to_lowercase()),
+                ..Default::default()
+            })
+        };
+        let employee_record = model_record("Employee");
+        let department_record = model_record("Department");
+        let association_record = |model: &datastore::model::Model,
+                                  associated_model: &datastore::model::Model,
+                                  kind: datastore::model::AssociationKind,
+                                  name: &str| {
+            model_association_record_fixture(ModelAssociationRecordFixture {
+                model_id: Some(model.id),
+                associated_model: Some(associated_model.clone()),
+                kind: Some(kind),
+                name: Some(name.to_string()),
+                ..Default::default()
+            })
+        };
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![employee_record.clone(), department_record.clone()]),
+            model_association_repo: ModelAssociationRepo::seed(vec![
+                association_record(
+                    &employee_record,
+                    &department_record,
+                    datastore::model::AssociationKind::BelongsTo,
+                    "Department",
+                ),
+                association_record(
+                    &department_record,
+                    &employee_record,
+                    datastore::model::AssociationKind::HasOne,
+                    "Manager",
+                ),
+            ]),
+            ..Default::default()
+        };
+
+        let response = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug,
+                serde: false,
+            },
+        )
+        .await?;
+
+        assert_eq!(
+            response.files[1].content,
+            r#"use super::Employee;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Department {
+    pub manager: Option<Box<Employee>>,
+}
+"#
+        );
+        assert_eq!(
+            response.files[2].content,
+            r#"use super::Department;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Employee {
+    pub department: Option<Box<Department>>,
+}
+"#
+        );
+
+        Ok(())
+    }
+}
@@ -1,16 +1,23 @@
 use crate::{
     diagram,
-    model::{GetModelOverviewRecord, ModelOverview},
+    model::{DiagramFormat, DiagramOptions, GetModelOverviewRecord, ModelOverview},
     FoundationResult,
 };
 
 pub struct Request {
     pub project_slug: String,
     pub model_slug: String,
+    pub format: DiagramFormat,
+
+    pub options: DiagramOptions,
 }
 
 pub struct Response {
+    /// Mermaid, PlantUML or SVG source code of the diagram.
     pub diagram: String,
+
+    /// PNG image, present only for the [`DiagramFormat::Png`] format.
+    pub image: Option<Vec<u8>>,
 }
 
 pub async fn execute(
@@ -20,6 +27,8 @@ pub async fn execute(
     let Request {
         project_slug,
         model_slug,
+        format,
+        options,
     } = request;
 
     let model_overview: ModelOverview = repo
@@ -27,13 +36,10 @@ pub async fn execute(
         .await?
         .into();
 
-    let diagram = diagram::model_class_diagram(diagram::ModelClass {
-        model: &model_overview.model,
-        attributes: &model_overview.attributes,
-        associations: &model_overview.associations,
-    });
+    let diagram::RenderedDiagram { diagram, image } =
+        diagram::model_class_diagram((&model_overview).into(), format, &options)?;
 
-    let response = Response { diagram };
+    let response = Response { diagram, image };
 
     Ok(response)
 }
@@ -95,6 +101,8 @@ mod tests {
             Request {
                 project_slug: project_record.slug.to_string(),
                 model_slug: model_record.slug.to_string(),
+                format: DiagramFormat::Mermaid,
+                options: Default::default(),
             },
         )
         .await?;
@@ -106,7 +114,66 @@ mod tests {
         +String Title
     }
 
-    Book --> Publisher
+    Book "*" --> "1" Publisher : Publisher
+"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_labels_associations_with_descriptions() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            ..Default::default()
+        });
+        let associated_model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            name: Some("Publisher".to_string()),
+            slug: Some("publisher".to_string()),
+            ..Default::default()
+        });
+        let model_association_record =
+            model_association_record_fixture(ModelAssociationRecordFixture {
+                model_id: Some(model_record.id),
+                associated_model: Some(associated_model_record.clone()),
+                description: Some("Printed by".to_string()),
+                ..Default::default()
+            });
+
+        let project_repo = ProjectRepo::seed(vec![project_record.clone()]);
+        let model_repo =
+            ModelRepo::seed(vec![model_record.clone(), associated_model_record.clone()]);
+        let model_association_repo =
+            ModelAssociationRepo::seed(vec![model_association_record.clone()]);
+
+        let repo = Repo {
+            project_repo,
+            model_repo,
+            model_association_repo,
+            ..Default::default()
+        };
+
+        let response = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug.to_string(),
+                model_slug: model_record.slug.to_string(),
+                format: DiagramFormat::Mermaid,
+                options: Default::default(),
+            },
+        )
+        .await?;
+
+        assert_eq!(
+            response.diagram,
+            r#"classDiagram
+    class Book {
+
+    }
+
+    Book "*" --> "1" Publisher : Printed by
 "#
         );
 
@@ -0,0 +1,262 @@
+use crate::{
+    diagram,
+    model::{DiagramFormat, DiagramOptions, ListModelOverviewRecords, ModelOverview},
+    FoundationError, FoundationResult,
+};
+use std::collections::HashSet;
+
+pub struct Request {
+    pub project_slug: String,
+    pub model_slug: String,
+
+    /// Number of association hops from the model. Associations are followed
+    /// in both directions.
+    pub depth: u32,
+
+    pub format: DiagramFormat,
+
+    pub options: DiagramOptions,
+}
+
+pub struct Response {
+    /// Mermaid, PlantUML or SVG source code of the diagram.
+    pub diagram: String,
+
+    /// PNG image, present only for the [`DiagramFormat::Png`] format.
+    pub image: Option<Vec<u8>>,
+}
+
+pub async fn execute(
+    repo: &impl ListModelOverviewRecords,
+    request: Request,
+) -> FoundationResult<Response> {
+    let Request {
+        project_slug,
+        model_slug,
+        depth,
+        format,
+        options,
+    } = request;
+
+    let model_overviews: Vec<ModelOverview> = repo
+        .list_model_overview_records(&project_slug)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    if !model_overviews
+        .iter()
+        .any(|model_overview| model_overview.model.slug == model_slug)
+    {
+        return Err(FoundationError::not_found("model not found"));
+    }
+
+    let slugs = neighbourhood_slugs(&model_overviews, &model_slug, depth);
+
+    // Associations pointing outside of the neighbourhood are left out, so that
+    // every drawn class has its body.
+    let neighbourhood: Vec<ModelOverview> = model_overviews
+        .into_iter()
+        .filter(|model_overview| slugs.contains(&model_overview.model.slug))
+        .map(|mut model_overview| {
+            model_overview
+                .associations
+                .retain(|association| slugs.contains(&association.model.slug));
+
+            model_overview
+        })
+        .collect();
+
+    let diagram_model_classes = neighbourhood.iter().map(Into::into).collect();
+
+    let diagram::RenderedDiagram { diagram, image } =
+        diagram::project_class_diagram(diagram_model_classes, format, &options)?;
+
+    let response = Response { diagram, image };
+
+    Ok(response)
+}
+
+/// Slugs of the models reachable from the model in at most `depth` hops,
+/// following both outgoing and incoming associations.
+fn neighbourhood_slugs(
+    model_overviews: &[ModelOverview],
+    model_slug: &str,
+    depth: u32,
+) -> HashSet<String> {
+    let mut slugs = HashSet::from([model_slug.to_string()]);
+    let mut frontier = vec![model_slug.to_string()];
+
+    for _ in 0..depth {
+        let mut next_frontier = vec![];
+
+        for model_overview in model_overviews {
+            let model_slug = &model_overview.model.slug;
+
+            for association in &model_overview.associations {
+                let associated_model_slug = &association.model.slug;
+
+                let neighbour = if frontier.contains(model_slug) {
+                    associated_model_slug
+                } else if frontier.contains(associated_model_slug) {
+                    model_slug
+                } else {
+                    continue;
+                };
+
+                if slugs.insert(neighbour.clone()) {
+                    next_frontier.push(neighbour.clone());
+                }
+            }
+        }
+
+        if next_frontier.is_empty() {
+            break;
+        }
+
+        frontier = next_frontier;
+    }
+
+    slugs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        datastore,
+        model::tests::Repo,
+        tests::{
+            model_association_record_fixture, model_attribute_record_fixture, model_record_fixture,
+            project_record_fixture, ModelAssociationRecordFixture, ModelAssociationRepo,
+            ModelAttributeRecordFixture, ModelAttributeRepo, ModelRecordFixture, ModelRepo,
+            ProjectRepo,
+        },
+        FoundationErrorCode,
+    };
+
+    fn repo() -> Repo {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = |name: &str| {
+            model_record_fixture(ModelRecordFixture {
+                project_id: Some(project_record.id),
+                name: Some(name.to_string()),
+                slug: Some(name.to_lowercase()),
+                ..Default::default()
+            })
+        };
+        let association_record =
+            |model: &datastore::model::Model, associated_model: &datastore::model::Model| {
+                model_association_record_fixture(ModelAssociationRecordFixture {
+                    model_id: Some(model.id),
+                    associated_model: Some(associated_model.clone()),
+                    name: Some(associated_model.name.clone()),
+                    ..Default::default()
+                })
+            };
+
+        let book_record = model_record("Book");
+        let publisher_record = model_record("Publisher");
+        let review_record = model_record("Review");
+        let country_record = model_record("Country");
+
+        let book_attribute_record = model_attribute_record_fixture(ModelAttributeRecordFixture {
+            model_id: Some(book_record.id),
+            ..Default::default()
+        });
+        let review_attribute_record = model_attribute_record_fixture(ModelAttributeRecordFixture {
+            model_id: Some(review_record.id),
+            name: Some("Rating".to_string()),
+            ..Default::default()
+        });
+
+        Repo {
+            model_association_repo: ModelAssociationRepo::seed(vec![
+                association_record(&book_record, &publisher_record),
+                association_record(&review_record, &book_record),
+                association_record(&publisher_record, &country_record),
+            ]),
+            model_attribute_repo: ModelAttributeRepo::seed(vec![
+                book_attribute_record,
+                review_attribute_record,
+            ]),
+            model_repo: ModelRepo::seed(vec![
+                book_record,
+                publisher_record,
+                review_record,
+                country_record,
+            ]),
+            project_repo: ProjectRepo::seed(vec![project_record]),
+            ..Default::default()
+        }
+    }
+
+    fn request(model_slug: &str, depth: u32) -> Request {
+        Request {
+            project_slug: project_record_fixture(Default::default()).slug,
+            model_slug: model_slug.to_string(),
+            depth,
+            format: DiagramFormat::Mermaid,
+            options: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_returns_models_within_depth_in_both_directions() -> FoundationResult<()> {
+        let response = execute(&repo(), request("book", 1)).await?;
+
+        assert_eq!(
+            response.diagram,
+            r#"classDiagram
+    class Book {
+        +String Title
+    }
+    class Publisher {
+
+    }
+    class Review {
+        +String Rating
+    }
+
+    Book "*" --> "1" Publisher : Publisher
+    Review "*" --> "1" Book : Book
+"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_follows_associations_up_to_depth() -> FoundationResult<()> {
+        let response = execute(&repo(), request("review", 0)).await?;
+
+        assert_eq!(
+            response.diagram,
+            r#"classDiagram
+    class Review {
+        +String Rating
+    }
+"#
+        );
+
+        let response = execute(&repo(), request("review", 3)).await?;
+
+        assert!(response.diagram.contains("class Country"));
+        assert!(response
+            .diagram
+            .contains(r#"Publisher "*" --> "1" Country : Country"#));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_returns_not_found_for_unknown_model() -> FoundationResult<()> {
+        let error = execute(&repo(), request("author", 1)).await.err().unwrap();
+
+        assert!(matches!(error.code(), FoundationErrorCode::NotFound));
+        assert_eq!(error.message(), "model not found");
+
+        Ok(())
+    }
+}
@@ -1,22 +1,33 @@
 use crate::{
     diagram,
-    model::{ListModelOverviewRecords, ModelOverview},
+    model::{DiagramFormat, DiagramOptions, ListModelOverviewRecords, ModelOverview},
     FoundationResult,
 };
 
 pub struct Request {
     pub project_slug: String,
+    pub format: DiagramFormat,
+
+    pub options: DiagramOptions,
 }
 
 pub struct Response {
+    /// Mermaid, PlantUML or SVG source code of the diagram.
     pub diagram: String,
+
+    /// PNG image, present only for the [`DiagramFormat::Png`] format.
+    pub image: Option<Vec<u8>>,
 }
 
 pub async fn execute(
     repo: &impl ListModelOverviewRecords,
     request: Request,
 ) -> FoundationResult<Response> {
-    let Request { project_slug } = request;
+    let Request {
+        project_slug,
+        format,
+        options,
+    } = request;
 
     let model_overviews: Vec<ModelOverview> = repo
         .list_model_overview_records(&project_slug)
@@ -25,18 +36,12 @@ pub async fn execute(
         .map(Into::into)
         .collect();
 
-    let diagram_model_classes = model_overviews
-        .iter()
-        .map(|model_overview| diagram::ModelClass {
-            model: &model_overview.model,
-            associations: model_overview.associations.as_slice(),
-            attributes: model_overview.attributes.as_slice(),
-        })
-        .collect();
+    let diagram_model_classes = model_overviews.iter().map(Into::into).collect();
 
-    let diagram = diagram::project_class_diagram(diagram_model_classes);
+    let diagram::RenderedDiagram { diagram, image } =
+        diagram::project_class_diagram(diagram_model_classes, format, &options)?;
 
-    let response = Response { diagram };
+    let response = Response { diagram, image };
 
     Ok(response)
 }
@@ -45,7 +50,8 @@ pub async fn execute(
 mod tests {
     use super::*;
     use crate::{
-        model::tests::Repo,
+        datastore,
+        model::{tests::Repo, DiagramDirection},
         tests::{
             model_association_record_fixture, model_attribute_record_fixture, model_record_fixture,
             project_record_fixture, ModelAssociationRecordFixture, ModelAssociationRepo,
@@ -106,6 +112,8 @@ mod tests {
             &repo,
             Request {
                 project_slug: project_record.slug.to_string(),
+                format: DiagramFormat::Mermaid,
+                options: Default::default(),
             },
         )
         .await?;
@@ -120,7 +128,259 @@ mod tests {
         +String Name
     }
 
-    Book --> Publisher
+    Book "*" --> "1" Publisher : Publisher
+"#
+        );
+        assert!(response.image.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_renders_project_class_diagram_as_image() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            ..Default::default()
+        });
+        let model_attribute_record = model_attribute_record_fixture(ModelAttributeRecordFixture {
+            model_id: Some(model_record.id),
+            ..Default::default()
+        });
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![model_record.clone()]),
+            model_attribute_repo: ModelAttributeRepo::seed(vec![model_attribute_record.clone()]),
+            ..Default::default()
+        };
+
+        let svg_response = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug.to_string(),
+                format: DiagramFormat::Svg,
+                options: Default::default(),
+            },
+        )
+        .await?;
+
+        assert!(svg_response.diagram.starts_with("<svg "));
+        assert!(svg_response.diagram.contains(">+String Title</text>"));
+        assert!(svg_response.image.is_none());
+
+        let png_response = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug.to_string(),
+                format: DiagramFormat::Png,
+                options: Default::default(),
+            },
+        )
+        .await;
+
+        #[cfg(feature = "png")]
+        {
+            let png_response = png_response?;
+
+            assert_eq!(png_response.diagram, svg_response.diagram);
+            assert!(png_response
+                .image
+                .is_some_and(|image| image.starts_with(b"\x89PNG")));
+        }
+
+        #[cfg(not(feature = "png"))]
+        assert!(matches!(
+            png_response.err().unwrap().code(),
+            crate::FoundationErrorCode::FailedPrecondition
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_returns_project_class_diagram_in_plantuml() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            ..Default::default()
+        });
+        let model_attribute_record = model_attribute_record_fixture(ModelAttributeRecordFixture {
+            model_id: Some(model_record.id),
+            ..Default::default()
+        });
+        let associated_model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            name: Some("Book Publisher".to_string()),
+            slug: Some("book-publisher".to_string()),
+            ..Default::default()
+        });
+        let model_association_record =
+            model_association_record_fixture(ModelAssociationRecordFixture {
+                model_id: Some(model_record.id),
+                associated_model: Some(associated_model_record.clone()),
+                ..Default::default()
+            });
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![
+                model_record.clone(),
+                associated_model_record.clone(),
+            ]),
+            model_attribute_repo: ModelAttributeRepo::seed(vec![model_attribute_record.clone()]),
+            model_association_repo: ModelAssociationRepo::seed(vec![
+                model_association_record.clone()
+            ]),
+            ..Default::default()
+        };
+
+        let response = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug.to_string(),
+                format: DiagramFormat::PlantUml,
+                options: Default::default(),
+            },
+        )
+        .await?;
+
+        assert_eq!(
+            response.diagram,
+            r#"@startuml
+class Book {
+    +Title : String
+}
+class "Book Publisher" as Book_Publisher
+
+Book "*" --> "1" Book_Publisher : Publisher
+@enduml
+"#
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_applies_diagram_options() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = |name: &str, description: Option<&str>| {
+            model_record_fixture(ModelRecordFixture {
+                project_id: Some(project_record.id),
+                name: Some(name.to_string()),
+                slug: Some(name.to_lowercase()),
+                description: description.map(ToString::to_string),
+            })
+        };
+        let review_record = model_record("Review", None);
+        let book_record = model_record("Book", Some("Printed book"));
+        let publisher_record = model_record("Publisher", None);
+
+        let book_attribute_record = |name: &str, description: Option<&str>| {
+            model_attribute_record_fixture(ModelAttributeRecordFixture {
+                model_id: Some(book_record.id),
+                name: Some(name.to_string()),
+                description: description.map(ToString::to_string),
+                ..Default::default()
+            })
+        };
+        let association_record =
+            |model: &datastore::model::Model, associated_model: &datastore::model::Model| {
+                model_association_record_fixture(ModelAssociationRecordFixture {
+                    model_id: Some(model.id),
+                    associated_model: Some(associated_model.clone()),
+                    name: Some(associated_model.name.clone()),
+                    ..Default::default()
+                })
+            };
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![
+                review_record.clone(),
+                book_record.clone(),
+                publisher_record.clone(),
+            ]),
+            model_attribute_repo: ModelAttributeRepo::seed(vec![
+                book_attribute_record("Title", Some("Full title")),
+                book_attribute_record("Isbn", None),
+            ]),
+            model_association_repo: ModelAssociationRepo::seed(vec![
+                association_record(&book_record, &publisher_record),
+                association_record(&review_record, &book_record),
+            ]),
+            ..Default::default()
+        };
+
+        let request = |format: DiagramFormat, options: DiagramOptions| Request {
+            project_slug: project_record.slug.to_string(),
+            format,
+            options,
+        };
+
+        let response = execute(
+            &repo,
+            request(
+                DiagramFormat::Mermaid,
+                DiagramOptions {
+                    direction: Some(DiagramDirection::LeftToRight),
+                    model_slugs: vec!["book".to_string(), "review".to_string()],
+                    descriptions_as_notes: true,
+                    sort_alphabetically: true,
+                    ..Default::default()
+                },
+            ),
+        )
+        .await?;
+
+        assert_eq!(
+            response.diagram,
+            r#"classDiagram
+    direction LR
+    class Book {
+        +String Isbn
+        +String Title
+    }
+    class Review {
+
+    }
+
+    Review "*" --> "1" Book : Book
+
+    note for Book "Printed book\nTitle: Full title"
+"#
+        );
+
+        let response = execute(
+            &repo,
+            request(
+                DiagramFormat::PlantUml,
+                DiagramOptions {
+                    direction: Some(DiagramDirection::RightToLeft),
+                    hide_attributes: true,
+                    descriptions_as_notes: true,
+                    ..Default::default()
+                },
+            ),
+        )
+        .await?;
+
+        assert_eq!(
+            response.diagram,
+            r#"@startuml
+left to right direction
+class Book
+class Publisher
+class Review
+
+Book "*" --> "1" Publisher : Publisher
+Review "*" --> "1" Book : Book
+
+note right of Book
+Printed book
+Title: Full title
+end note
+@enduml
 "#
         );
 
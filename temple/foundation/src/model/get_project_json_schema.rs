@@ -0,0 +1,194 @@
+use crate::{
+    codegen,
+    model::{GeneratedFile, ListModelOverviewRecords, ModelOverview},
+    FoundationResult,
+};
+
+pub struct Request {
+    pub project_slug: String,
+}
+
+pub struct Response {
+    /// `{model slug}.schema.json` schema per model.
+    pub model_schemas: Vec<GeneratedFile>,
+
+    /// Schema bundling the model schemas in its `$defs`.
+    pub project_schema: String,
+}
+
+pub async fn execute(
+    repo: &impl ListModelOverviewRecords,
+    request: Request,
+) -> FoundationResult<Response> {
+    let Request { project_slug } = request;
+
+    let model_overviews: Vec<ModelOverview> = repo
+        .list_model_overview_records(&project_slug)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    let (model_schemas, project_schema) = codegen::json_schemas(&model_overviews, &project_slug)?;
+
+    let response = Response {
+        model_schemas,
+        project_schema,
+    };
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        attribute_type::{AttributeType, AttributeTypeRecord},
+        datastore,
+        model::tests::Repo,
+        tests::{
+            model_association_record_fixture, model_attribute_record_fixture, model_record_fixture,
+            project_record_fixture, ModelAssociationRecordFixture, ModelAssociationRepo,
+            ModelAttributeRecordFixture, ModelAttributeRepo, ModelRecordFixture, ModelRepo,
+            ProjectRepo,
+        },
+    };
+    use serde_json::{json, Value};
+
+    #[tokio::test]
+    async fn it_generates_model_and_project_schemas() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            description: Some("Printed book.".to_string()),
+            ..Default::default()
+        });
+        let model_attribute_record = model_attribute_record_fixture(ModelAttributeRecordFixture {
+            model_id: Some(model_record.id),
+            description: Some("Full title.".to_string()),
+            ..Default::default()
+        });
+        let associated_model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            name: Some("Publisher".to_string()),
+            slug: Some("publisher".to_string()),
+            ..Default::default()
+        });
+        let associated_model_attribute_record =
+            model_attribute_record_fixture(ModelAttributeRecordFixture {
+                model_id: Some(associated_model_record.id),
+                name: Some("Founded At".to_string()),
+                r#type: Some(AttributeTypeRecord {
+                    inner: AttributeType {
+                        description: None,
+                        name: "Date Time".to_string(),
+                        slug: "date-time".to_string(),
+                    },
+                    ..Default::default()
+                }),
+                ..Default::default()
+            });
+        let model_association_record =
+            model_association_record_fixture(ModelAssociationRecordFixture {
+                model_id: Some(model_record.id),
+                associated_model: Some(associated_model_record.clone()),
+                ..Default::default()
+            });
+        let inverse_model_association_record =
+            model_association_record_fixture(ModelAssociationRecordFixture {
+                model_id: Some(associated_model_record.id),
+                associated_model: Some(model_record.clone()),
+                kind: Some(datastore::model::AssociationKind::HasMany),
+                name: Some("Books".to_string()),
+                description: Some("Published books.".to_string()),
+            });
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![model_record, associated_model_record]),
+            model_attribute_repo: ModelAttributeRepo::seed(vec![
+                model_attribute_record,
+                associated_model_attribute_record,
+            ]),
+            model_association_repo: ModelAssociationRepo::seed(vec![
+                model_association_record,
+                inverse_model_association_record,
+            ]),
+            ..Default::default()
+        };
+
+        let response = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug,
+            },
+        )
+        .await?;
+
+        let model_schema_paths: Vec<&str> = response
+            .model_schemas
+            .iter()
+            .map(|model_schema| model_schema.path.as_str())
+            .collect();
+
+        assert_eq!(
+            model_schema_paths,
+            vec!["book.schema.json", "publisher.schema.json"]
+        );
+
+        let book_schema: Value = serde_json::from_str(&response.model_schemas[0].content).unwrap();
+
+        assert_eq!(
+            book_schema,
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "$id": "book.schema.json",
+                "title": "Book",
+                "description": "Printed book.",
+                "type": "object",
+                "properties": {
+                    "title": { "type": "string", "description": "Full title." },
+                    "publisher": { "$ref": "publisher.schema.json" },
+                },
+                "required": ["title"],
+            })
+        );
+
+        let project_schema: Value = serde_json::from_str(&response.project_schema).unwrap();
+
+        assert_eq!(
+            project_schema,
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "$id": "book-store.schema.json",
+                "$defs": {
+                    "book": {
+                        "title": "Book",
+                        "description": "Printed book.",
+                        "type": "object",
+                        "properties": {
+                            "title": { "type": "string", "description": "Full title." },
+                            "publisher": { "$ref": "#/$defs/publisher" },
+                        },
+                        "required": ["title"],
+                    },
+                    "publisher": {
+                        "title": "Publisher",
+                        "type": "object",
+                        "properties": {
+                            "founded_at": { "type": "string", "format": "date-time" },
+                            "books": {
+                                "type": "array",
+                                "items": { "$ref": "#/$defs/book" },
+                                "description": "Published books.",
+                            },
+                        },
+                        "required": ["founded_at"],
+                    },
+                },
+            })
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{
+    migration,
+    model::{ListModelOverviewRecords, ModelOverview},
+    FoundationResult,
+};
+
+pub struct Request {
+    pub project_slug: String,
+}
+
+pub struct Response {
+    /// JSON snapshot of the project models, the baseline of
+    /// [`super::generate_project_alter_migration`].
+    pub snapshot: String,
+}
+
+pub async fn execute(
+    repo: &impl ListModelOverviewRecords,
+    request: Request,
+) -> FoundationResult<Response> {
+    let Request { project_slug } = request;
+
+    let model_overviews: Vec<ModelOverview> = repo
+        .list_model_overview_records(&project_slug)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    let response = Response {
+        snapshot: migration::snapshot(&model_overviews)?,
+    };
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::tests::Repo,
+        tests::{
+            model_association_record_fixture, model_attribute_record_fixture, model_record_fixture,
+            project_record_fixture, ModelAssociationRecordFixture, ModelAssociationRepo,
+            ModelAttributeRecordFixture, ModelAttributeRepo, ModelRecordFixture, ModelRepo,
+            ProjectRepo,
+        },
+    };
+
+    #[tokio::test]
+    async fn it_returns_project_snapshot() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            description: Some("Printed book".to_string()),
+            ..Default::default()
+        });
+        let model_attribute_record = model_attribute_record_fixture(ModelAttributeRecordFixture {
+            model_id: Some(model_record.id),
+            ..Default::default()
+        });
+        let associated_model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            name: Some("Publisher".to_string()),
+            slug: Some("publisher".to_string()),
+            ..Default::default()
+        });
+        let model_association_record =
+            model_association_record_fixture(ModelAssociationRecordFixture {
+                model_id: Some(model_record.id),
+                associated_model: Some(associated_model_record.clone()),
+                ..Default::default()
+            });
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![model_record, associated_model_record]),
+            model_attribute_repo: ModelAttributeRepo::seed(vec![model_attribute_record]),
+            model_association_repo: ModelAssociationRepo::seed(vec![model_association_record]),
+            ..Default::default()
+        };
+
+        let response = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug.to_string(),
+            },
+        )
+        .await?;
+
+        assert_eq!(
+            response.snapshot,
+            r#"{
+  "version": 1,
+  "models": [
+    {
+      "name": "Book",
+      "slug": "book",
+      "description": "Printed book",
+      "attributes": [
+        {
+          "name": "Title",
+          "description": null,
+          "type": {
+            "name": "String",
+            "slug": "string",
+            "description": null
+          }
+        }
+      ],
+      "associations": [
+        {
+          "name": "Publisher",
+          "description": null,
+          "kind": "belongs_to",
+          "model": {
+            "name": "Publisher",
+            "slug": "publisher",
+            "description": null
+          }
+        }
+      ]
+    },
+    {
+      "name": "Publisher",
+      "slug": "publisher",
+      "description": null,
+      "attributes": [],
+      "associations": []
+    }
+  ]
+}
+"#
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{
+    codegen,
+    model::{ListModelOverviewRecords, ModelOverview},
+    FoundationResult,
+};
+use std::collections::HashMap;
+
+pub struct Request {
+    pub project_slug: String,
+
+    /// TypeScript types by the attribute type slug, e.g. `"money": "Money"`.
+    /// Attribute types that aren't mapped get a default type.
+    pub type_mappings: HashMap<String, String>,
+}
+
+pub struct Response {
+    /// TypeScript `interface` declarations.
+    pub definitions: String,
+}
+
+pub async fn execute(
+    repo: &impl ListModelOverviewRecords,
+    request: Request,
+) -> FoundationResult<Response> {
+    let Request {
+        project_slug,
+        type_mappings,
+    } = request;
+
+    let model_overviews: Vec<ModelOverview> = repo
+        .list_model_overview_records(&project_slug)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    let definitions = codegen::typescript_interfaces(&model_overviews, &type_mappings)?;
+
+    let response = Response { definitions };
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        attribute_type::{AttributeType, AttributeTypeRecord},
+        datastore,
+        model::tests::Repo,
+        tests::{
+            model_association_record_fixture, model_attribute_record_fixture, model_record_fixture,
+            project_record_fixture, ModelAssociationRecordFixture, ModelAssociationRepo,
+            ModelAttributeRecordFixture, ModelAttributeRepo, ModelRecordFixture, ModelRepo,
+            ProjectRepo,
+        },
+    };
+
+    #[tokio::test]
+    async fn it_generates_interfaces() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            description: Some("Printed book.\n\nAlso an e-book.".to_string()),
+            ..Default::default()
+        });
+        let model_attribute_record = model_attribute_record_fixture(ModelAttributeRecordFixture {
+            model_id: Some(model_record.id),
+            description: Some("Full title.".to_string()),
+            ..Default::default()
+        });
+        let associated_model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            name: Some("Publisher".to_string()),
+            slug: Some("publisher".to_string()),
+            ..Default::default()
+        });
+        let associated_model_attribute_record =
+            model_attribute_record_fixture(ModelAttributeRecordFixture {
+                model_id: Some(associated_model_record.id),
+                name: Some("Founded At".to_string()),
+                r#type: Some(AttributeTypeRecord {
+                    inner: AttributeType {
+                        description: None,
+                        name: "DateTime".to_string(),
+                        slug: "date-time".to_string(),
+                    },
+                    ..Default::default()
+                }),
+                ..Default::default()
+            });
+        let model_association_record =
+            model_association_record_fixture(ModelAssociationRecordFixture {
+                model_id: Some(model_record.id),
+                associated_model: Some(associated_model_record.clone()),
+                ..Default::default()
+            });
+        let inverse_model_association_record =
+            model_association_record_fixture(ModelAssociationRecordFixture {
+                model_id: Some(associated_model_record.id),
+                associated_model: Some(model_record.clone()),
+                kind: Some(datastore::model::AssociationKind::HasMany),
+                name: Some("Books In Print".to_string()),
+                description: Some("Books currently in print.".to_string()),
+            });
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![model_record, associated_model_record]),
+            model_attribute_repo: ModelAttributeRepo::seed(vec![
+                model_attribute_record,
+                associated_model_attribute_record,
+            ]),
+            model_association_repo: ModelAssociationRepo::seed(vec![
+                model_association_record,
+                inverse_model_association_record,
+            ]),
+            ..Default::default()
+        };
+
+        let response = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug,
+                type_mappings: HashMap::from([("date-time".to_string(), "Date".to_string())]),
+            },
+        )
+        .await?;
+
+        assert_eq!(
+            response.definitions,
+            r#"/**
+ * Printed book.
+ *
+ * Also an e-book.
+ */
+export interface Book {
+  /** Full title. */
+  title: string;
+
+  publisher?: Publisher;
+}
+
+export interface Publisher {
+  foundedAt: Date;
+
+  /** Books currently in print. */
+  booksInPrint?: Book[];
+}
+"#
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+use crate::{
+    attribute_type::{CreateAttributeTypeRecord, ListAttributeTypeRecords},
+    import,
+    model::{
+        CreateModelAssociationRecord, CreateModelAttributeRecord, CreateModelRecord, ImportSummary,
+        ListModelOverviewRecords,
+    },
+    project::GetProjectRecord,
+    FoundationResult,
+};
+
+pub struct Request {
+    pub project_slug: String,
+
+    /// Mermaid `classDiagram`.
+    pub diagram: String,
+}
+
+pub struct Response {
+    pub summary: ImportSummary,
+}
+
+pub async fn execute(
+    repo: &(impl GetProjectRecord
+          + ListModelOverviewRecords
+          + ListAttributeTypeRecords
+          + CreateAttributeTypeRecord
+          + CreateModelRecord
+          + CreateModelAttributeRecord
+          + CreateModelAssociationRecord),
+    request: Request,
+) -> FoundationResult<Response> {
+    let Request {
+        project_slug,
+        diagram,
+    } = request;
+
+    let schema = import::class_diagram_schema(&diagram)?;
+
+    let project_record = repo.get_project_record(&project_slug).await?;
+
+    let summary = import::create_records(repo, project_record, schema).await?;
+
+    let response = Response { summary };
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        attribute_type::tests::{attribute_type_record_fixture, AttributeTypeRepo},
+        model::{tests::Repo, AssociationKind, Attribute, SkippedConstruct},
+        tests::{
+            model_attribute_record_fixture, model_record_fixture, project_record_fixture,
+            ModelAttributeRecordFixture, ModelAttributeRepo, ModelRecordFixture, ModelRepo,
+            ProjectRepo,
+        },
+        FoundationErrorCode,
+    };
+
+    #[tokio::test]
+    async fn it_imports_class_diagram_into_project() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            ..Default::default()
+        });
+        let attribute_type_repo = AttributeTypeRepo::new();
+        let attribute_type_record = attribute_type_record_fixture(&attribute_type_repo).await;
+        let model_attribute_record = model_attribute_record_fixture(ModelAttributeRecordFixture {
+            model_id: Some(model_record.id),
+            r#type: Some(attribute_type_record.clone()),
+            name: Some("pages".to_string()),
+            ..Default::default()
+        });
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![model_record.clone()]),
+            model_attribute_repo: ModelAttributeRepo::seed(vec![model_attribute_record]),
+            attribute_type_repo,
+            ..Default::default()
+        };
+
+        let response = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug.clone(),
+                diagram: r#"classDiagram
+    class Book {
+        +Bigint pages
+        +Bigint year
+    }
+    class Publisher {
+        +String name
+    }
+
+    Book "*" --> "1" Publisher : Publisher
+    Book "*" --> "1" Publisher : Publisher
+    Publisher "1" o-- "*" Book : Books
+    Publisher <|-- Imprint
+"#
+                .to_string(),
+            },
+        )
+        .await?;
+
+        assert_eq!(
+            response
+                .summary
+                .models
+                .iter()
+                .map(|model| model.name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["Publisher", "Imprint"]
+        );
+
+        assert_eq!(
+            response.summary.attributes,
+            vec![
+                Attribute {
+                    description: None,
+                    r#type: attribute_type_record.into(),
+                    name: "year".to_string(),
+                },
+                Attribute {
+                    description: None,
+                    r#type: response.summary.attribute_types[0].clone(),
+                    name: "name".to_string(),
+                },
+            ]
+        );
+
+        assert_eq!(
+            response
+                .summary
+                .attribute_types
+                .iter()
+                .map(|attribute_type| attribute_type.name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["String"]
+        );
+
+        assert_eq!(
+            response
+                .summary
+                .associations
+                .iter()
+                .map(|association| (association.name.as_str(), association.kind.clone()))
+                .collect::<Vec<(&str, AssociationKind)>>(),
+            vec![
+                ("Publisher", AssociationKind::BelongsTo),
+                ("Books", AssociationKind::HasMany)
+            ]
+        );
+
+        assert_eq!(
+            response.summary.skipped,
+            vec![
+                SkippedConstruct {
+                    location: "Publisher.Imprint".to_string(),
+                    construct: "inheritance".to_string(),
+                    reason: "only associations, aggregations and compositions are imported"
+                        .to_string(),
+                },
+                SkippedConstruct {
+                    location: "Book.pages".to_string(),
+                    construct: "attribute".to_string(),
+                    reason: "the model already has an attribute or association with this name"
+                        .to_string(),
+                },
+                SkippedConstruct {
+                    location: "Book.Publisher".to_string(),
+                    construct: "association".to_string(),
+                    reason: "the model already has an attribute or association with this name"
+                        .to_string(),
+                },
+            ]
+        );
+
+        assert_eq!(repo.model_repo.records().await.len(), 3);
+        assert_eq!(repo.model_attribute_repo.records().await.len(), 3);
+        assert_eq!(repo.model_association_repo.records().await.len(), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_returns_invalid_argument_error() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            ..Default::default()
+        };
+
+        let error = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug,
+                diagram: "erDiagram".to_string(),
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+        assert!(matches!(error.code(), FoundationErrorCode::InvalidArgument));
+        assert_eq!(
+            error.message(),
+            "invalid diagram, line 1: expected `classDiagram` header"
+        );
+
+        Ok(())
+    }
+}
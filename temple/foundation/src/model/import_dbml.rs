@@ -0,0 +1,172 @@
+use crate::{
+    attribute_type::{CreateAttributeTypeRecord, ListAttributeTypeRecords},
+    import,
+    model::{
+        CreateModelAssociationRecord, CreateModelAttributeRecord, CreateModelRecord, ImportSummary,
+        ListModelOverviewRecords,
+    },
+    project::GetProjectRecord,
+    FoundationResult,
+};
+
+pub struct Request {
+    pub project_slug: String,
+
+    /// DBML schema, like the one exported by dbdiagram.io.
+    pub dbml: String,
+}
+
+pub struct Response {
+    pub summary: ImportSummary,
+}
+
+pub async fn execute(
+    repo: &(impl GetProjectRecord
+          + ListModelOverviewRecords
+          + ListAttributeTypeRecords
+          + CreateAttributeTypeRecord
+          + CreateModelRecord
+          + CreateModelAttributeRecord
+          + CreateModelAssociationRecord),
+    request: Request,
+) -> FoundationResult<Response> {
+    let Request { project_slug, dbml } = request;
+
+    let schema = import::dbml_schema(&dbml)?;
+
+    let project_record = repo.get_project_record(&project_slug).await?;
+
+    let summary = import::create_records(repo, project_record, schema).await?;
+
+    let response = Response { summary };
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::{tests::Repo, AssociationKind},
+        tests::{project_record_fixture, ProjectRepo},
+        FoundationErrorCode,
+    };
+
+    #[tokio::test]
+    async fn it_imports_dbml_into_project() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            ..Default::default()
+        };
+
+        let response = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug,
+                dbml: r#"
+Project bookstore {
+  database_type: 'PostgreSQL'
+}
+
+Table publishers {
+  id integer [pk]
+  name varchar(255) [note: 'Registered name']
+}
+
+Table books {
+  id integer [pk, increment]
+  title varchar
+  publisher_id integer [ref: > publishers.id]
+}
+"#
+                .to_string(),
+            },
+        )
+        .await?;
+
+        assert_eq!(
+            response
+                .summary
+                .models
+                .iter()
+                .map(|model| model.name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["Publisher", "Book"]
+        );
+
+        assert_eq!(
+            response
+                .summary
+                .attributes
+                .iter()
+                .map(|attribute| (
+                    attribute.name.as_str(),
+                    attribute.description.as_deref(),
+                    attribute.r#type.name.as_str()
+                ))
+                .collect::<Vec<(&str, Option<&str>, &str)>>(),
+            vec![
+                ("Name", Some("Registered name"), "Text"),
+                ("Title", None, "Text"),
+            ]
+        );
+
+        assert_eq!(
+            response
+                .summary
+                .associations
+                .iter()
+                .map(|association| (
+                    association.name.as_str(),
+                    association.kind.clone(),
+                    association.model.name.as_str()
+                ))
+                .collect::<Vec<(&str, AssociationKind, &str)>>(),
+            vec![
+                ("Books", AssociationKind::HasMany, "Book"),
+                ("Publisher", AssociationKind::BelongsTo, "Publisher"),
+            ]
+        );
+
+        assert_eq!(
+            response
+                .summary
+                .skipped
+                .iter()
+                .map(|skipped| (skipped.location.as_str(), skipped.construct.as_str()))
+                .collect::<Vec<(&str, &str)>>(),
+            vec![("line 2", "Project")]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_rejects_invalid_dbml() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            ..Default::default()
+        };
+
+        let error = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug,
+                dbml: "Table books {\n  id integer [pk\n}\n".to_string(),
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+        assert!(matches!(error.code(), FoundationErrorCode::InvalidArgument));
+        assert_eq!(error.message(), "invalid DBML, line 3: expected `]`");
+        assert!(repo.model_repo.records().await.is_empty());
+
+        Ok(())
+    }
+}
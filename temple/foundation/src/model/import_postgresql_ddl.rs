@@ -0,0 +1,223 @@
+use crate::{
+    attribute_type::{CreateAttributeTypeRecord, ListAttributeTypeRecords},
+    import,
+    model::{
+        CreateModelAssociationRecord, CreateModelAttributeRecord, CreateModelRecord, ImportSummary,
+        ListModelOverviewRecords,
+    },
+    project::{CreateProjectRecord, Project},
+    util, FoundationResult,
+};
+
+pub struct Request {
+    pub name: String,
+    pub description: String,
+
+    /// `CREATE TABLE` statements, along with the `ALTER TABLE` statements
+    /// adding their foreign keys.
+    pub sql: String,
+}
+
+pub struct Response {
+    pub project: Project,
+    pub summary: ImportSummary,
+}
+
+pub async fn execute(
+    repo: &(impl CreateProjectRecord
+          + ListModelOverviewRecords
+          + ListAttributeTypeRecords
+          + CreateAttributeTypeRecord
+          + CreateModelRecord
+          + CreateModelAttributeRecord
+          + CreateModelAssociationRecord),
+    request: Request,
+) -> FoundationResult<Response> {
+    let Request {
+        name,
+        description,
+        sql,
+    } = request;
+
+    let schema = import::postgresql_schema(&sql)?;
+
+    let project_record = repo
+        .create_project_record(Project {
+            slug: util::slug::sluggify(&name),
+            name,
+            description: util::string::optional(&description),
+        })
+        .await?;
+
+    let summary = import::create_records(repo, project_record.clone(), schema).await?;
+
+    let response = Response {
+        project: project_record.into(),
+        summary,
+    };
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        attribute_type::{tests::AttributeTypeRepo, AttributeType, AttributeTypeRecord},
+        model::{tests::Repo, AssociationKind, SkippedConstruct},
+        FoundationErrorCode,
+    };
+
+    #[tokio::test]
+    async fn it_imports_tables_into_a_new_project() -> FoundationResult<()> {
+        let attribute_type_repo = AttributeTypeRepo::new();
+        let attribute_type_record = AttributeTypeRecord {
+            inner: AttributeType {
+                description: None,
+                name: "Big Integer".to_string(),
+                slug: "big-integer".to_string(),
+            },
+            ..Default::default()
+        };
+        attribute_type_repo
+            .save(attribute_type_record.clone())
+            .await;
+
+        let repo = Repo {
+            attribute_type_repo,
+            ..Default::default()
+        };
+
+        let response = execute(
+            &repo,
+            Request {
+                name: "Book store".to_string(),
+                description: "".to_string(),
+                sql: r#"
+CREATE TABLE publishers (
+    id bigserial PRIMARY KEY,
+    name varchar(255) NOT NULL
+);
+
+CREATE TABLE books (
+    id bigserial PRIMARY KEY,
+    title text NOT NULL,
+    pages bigint CHECK (pages > 0),
+    publisher_id bigint NOT NULL REFERENCES publishers (id) ON DELETE CASCADE
+);
+
+COMMENT ON TABLE books IS 'Printed book.';
+COMMENT ON COLUMN books.title IS 'Full title.';
+
+CREATE INDEX books_title_index ON books (title);
+"#
+                .to_string(),
+            },
+        )
+        .await?;
+
+        assert_eq!(response.project.slug, "book-store");
+        assert_eq!(response.project.description, None);
+
+        assert_eq!(
+            response
+                .summary
+                .models
+                .iter()
+                .map(|model| (model.name.as_str(), model.description.as_deref()))
+                .collect::<Vec<(&str, Option<&str>)>>(),
+            vec![("Publisher", None), ("Book", Some("Printed book."))]
+        );
+
+        assert_eq!(
+            response
+                .summary
+                .attributes
+                .iter()
+                .map(|attribute| (
+                    attribute.name.as_str(),
+                    attribute.r#type.name.as_str(),
+                    attribute.description.as_deref()
+                ))
+                .collect::<Vec<(&str, &str, Option<&str>)>>(),
+            vec![
+                ("Name", "Text", None),
+                ("Title", "Text", Some("Full title.")),
+                ("Pages", attribute_type_record.inner.name.as_str(), None),
+            ]
+        );
+
+        assert_eq!(
+            response
+                .summary
+                .attribute_types
+                .iter()
+                .map(|attribute_type| attribute_type.name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["Text"]
+        );
+
+        assert_eq!(
+            response
+                .summary
+                .associations
+                .iter()
+                .map(|association| (
+                    association.name.as_str(),
+                    association.kind.clone(),
+                    association.model.name.as_str()
+                ))
+                .collect::<Vec<(&str, AssociationKind, &str)>>(),
+            vec![
+                ("Books", AssociationKind::HasMany, "Book"),
+                ("Publisher", AssociationKind::BelongsTo, "Publisher"),
+            ]
+        );
+
+        assert_eq!(
+            response.summary.skipped,
+            vec![
+                SkippedConstruct {
+                    location: "line 17".to_string(),
+                    construct: "CREATE INDEX".to_string(),
+                    reason: "only tables, foreign keys and comments are imported".to_string(),
+                },
+                SkippedConstruct {
+                    location: "books.pages".to_string(),
+                    construct: "CHECK".to_string(),
+                    reason: "attributes only have a name and a type".to_string(),
+                },
+            ]
+        );
+
+        assert_eq!(repo.project_repo.records().await.len(), 1);
+        assert_eq!(repo.model_repo.records().await.len(), 2);
+        assert_eq!(repo.model_attribute_repo.records().await.len(), 3);
+        assert_eq!(repo.model_association_repo.records().await.len(), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_rejects_invalid_ddl() -> FoundationResult<()> {
+        let repo = Repo::default();
+
+        let error = execute(
+            &repo,
+            Request {
+                name: "Book store".to_string(),
+                description: "".to_string(),
+                sql: "CREATE TABLE books (title text".to_string(),
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+        assert!(matches!(error.code(), FoundationErrorCode::InvalidArgument));
+        assert_eq!(error.message(), "invalid DDL, line 1: expected `)`");
+        assert!(repo.project_repo.records().await.is_empty());
+
+        Ok(())
+    }
+}
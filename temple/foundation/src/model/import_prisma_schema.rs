@@ -0,0 +1,188 @@
+use crate::{
+    attribute_type::{CreateAttributeTypeRecord, ListAttributeTypeRecords},
+    import,
+    model::{
+        CreateModelAssociationRecord, CreateModelAttributeRecord, CreateModelRecord, ImportSummary,
+        ListModelOverviewRecords,
+    },
+    project::GetProjectRecord,
+    FoundationResult,
+};
+
+pub struct Request {
+    pub project_slug: String,
+
+    /// Content of the `schema.prisma` file.
+    pub prisma_schema: String,
+}
+
+pub struct Response {
+    pub summary: ImportSummary,
+}
+
+pub async fn execute(
+    repo: &(impl GetProjectRecord
+          + ListModelOverviewRecords
+          + ListAttributeTypeRecords
+          + CreateAttributeTypeRecord
+          + CreateModelRecord
+          + CreateModelAttributeRecord
+          + CreateModelAssociationRecord),
+    request: Request,
+) -> FoundationResult<Response> {
+    let Request {
+        project_slug,
+        prisma_schema,
+    } = request;
+
+    let schema = import::prisma_schema(&prisma_schema)?;
+
+    let project_record = repo.get_project_record(&project_slug).await?;
+
+    let summary = import::create_records(repo, project_record, schema).await?;
+
+    let response = Response { summary };
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::{tests::Repo, AssociationKind},
+        tests::{project_record_fixture, ProjectRepo},
+        FoundationErrorCode,
+    };
+
+    #[tokio::test]
+    async fn it_imports_prisma_schema_into_project() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            ..Default::default()
+        };
+
+        let response = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug,
+                prisma_schema: r#"
+datasource db {
+  provider = "postgresql"
+  url      = env("DATABASE_URL")
+}
+
+model User {
+  id    Int    @id @default(autoincrement())
+  /// Contact address.
+  email String
+  posts Post[]
+}
+
+model Post {
+  id       Int    @id @default(autoincrement())
+  title    String
+  author   User   @relation(fields: [authorId], references: [id])
+  authorId Int
+}
+
+enum Role {
+  USER
+  ADMIN
+}
+"#
+                .to_string(),
+            },
+        )
+        .await?;
+
+        assert_eq!(
+            response
+                .summary
+                .models
+                .iter()
+                .map(|model| model.name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["User", "Post"]
+        );
+
+        assert_eq!(
+            response
+                .summary
+                .attributes
+                .iter()
+                .map(|attribute| (
+                    attribute.name.as_str(),
+                    attribute.description.as_deref(),
+                    attribute.r#type.name.as_str()
+                ))
+                .collect::<Vec<(&str, Option<&str>, &str)>>(),
+            vec![
+                ("Email", Some("Contact address."), "Text"),
+                ("Title", None, "Text"),
+            ]
+        );
+
+        assert_eq!(
+            response
+                .summary
+                .associations
+                .iter()
+                .map(|association| (
+                    association.name.as_str(),
+                    association.kind.clone(),
+                    association.model.name.as_str()
+                ))
+                .collect::<Vec<(&str, AssociationKind, &str)>>(),
+            vec![
+                ("Posts", AssociationKind::HasMany, "Post"),
+                ("Author", AssociationKind::BelongsTo, "User"),
+            ]
+        );
+
+        assert_eq!(
+            response
+                .summary
+                .skipped
+                .iter()
+                .map(|skipped| (skipped.location.as_str(), skipped.construct.as_str()))
+                .collect::<Vec<(&str, &str)>>(),
+            vec![("Role", "enum")]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_rejects_invalid_prisma_schema() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            ..Default::default()
+        };
+
+        let error = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug,
+                prisma_schema: "model Post {\n  id Int @id\n  title String @db.VarChar(255\n}\n"
+                    .to_string(),
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+        assert!(matches!(error.code(), FoundationErrorCode::InvalidArgument));
+        assert_eq!(
+            error.message(),
+            "invalid Prisma schema, line 4: expected `)`"
+        );
+        assert!(repo.model_repo.records().await.is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,214 @@
+use crate::{
+    attribute_type::{CreateAttributeTypeRecord, ListAttributeTypeRecords},
+    import,
+    model::{
+        CreateModelAssociationRecord, CreateModelAttributeRecord, CreateModelRecord, ImportSummary,
+        ListModelOverviewRecords,
+    },
+    project::{CreateProjectRecord, GetProjectRecord, Project},
+    util, FoundationResult,
+};
+
+pub struct Request {
+    pub target: Target,
+
+    /// Content of the `db/schema.rb` file.
+    pub schema: String,
+}
+
+/// Project receiving the imported models.
+pub enum Target {
+    Project { slug: String },
+    NewProject { name: String, description: String },
+}
+
+pub struct Response {
+    pub project: Project,
+    pub summary: ImportSummary,
+}
+
+pub async fn execute(
+    repo: &(impl GetProjectRecord
+          + CreateProjectRecord
+          + ListModelOverviewRecords
+          + ListAttributeTypeRecords
+          + CreateAttributeTypeRecord
+          + CreateModelRecord
+          + CreateModelAttributeRecord
+          + CreateModelAssociationRecord),
+    request: Request,
+) -> FoundationResult<Response> {
+    let Request { target, schema } = request;
+
+    let schema = import::rails_schema(&schema)?;
+
+    let project_record = match target {
+        Target::Project { slug } => repo.get_project_record(&slug).await?,
+        Target::NewProject { name, description } => {
+            repo.create_project_record(Project {
+                slug: util::slug::sluggify(&name),
+                name,
+                description: util::string::optional(&description),
+            })
+            .await?
+        }
+    };
+
+    let summary = import::create_records(repo, project_record.clone(), schema).await?;
+
+    let response = Response {
+        project: project_record.into(),
+        summary,
+    };
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::{tests::Repo, AssociationKind},
+        tests::{
+            model_record_fixture, project_record_fixture, ModelRecordFixture, ModelRepo,
+            ProjectRepo,
+        },
+        FoundationErrorCode,
+    };
+
+    const SCHEMA: &str = r#"ActiveRecord::Schema[7.0].define(version: 2023_09_15_101500) do
+  create_table "books", force: :cascade do |t|
+    t.string "title", null: false
+    t.references "publisher", null: false, foreign_key: true
+  end
+
+  create_table "publishers", force: :cascade do |t|
+    t.string "name", null: false
+  end
+end
+"#;
+
+    #[tokio::test]
+    async fn it_imports_schema_into_existing_project() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            ..Default::default()
+        });
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![model_record]),
+            ..Default::default()
+        };
+
+        let response = execute(
+            &repo,
+            Request {
+                target: Target::Project {
+                    slug: project_record.slug.clone(),
+                },
+                schema: SCHEMA.to_string(),
+            },
+        )
+        .await?;
+
+        assert_eq!(response.project, project_record.into());
+
+        assert_eq!(
+            response
+                .summary
+                .models
+                .iter()
+                .map(|model| model.name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["Publisher"]
+        );
+
+        assert_eq!(
+            response
+                .summary
+                .associations
+                .iter()
+                .map(|association| (association.name.as_str(), association.kind.clone()))
+                .collect::<Vec<(&str, AssociationKind)>>(),
+            vec![
+                ("Publisher", AssociationKind::BelongsTo),
+                ("Books", AssociationKind::HasMany),
+            ]
+        );
+
+        assert_eq!(repo.project_repo.records().await.len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_imports_schema_into_new_project() -> FoundationResult<()> {
+        let repo = Repo::default();
+
+        let response = execute(
+            &repo,
+            Request {
+                target: Target::NewProject {
+                    name: "Theater".to_string(),
+                    description: "Plays and their cast.".to_string(),
+                },
+                schema: SCHEMA.to_string(),
+            },
+        )
+        .await?;
+
+        assert_eq!(
+            response.project,
+            Project {
+                description: Some("Plays and their cast.".to_string()),
+                name: "Theater".to_string(),
+                slug: "theater".to_string(),
+            }
+        );
+
+        assert_eq!(response.summary.models.len(), 2);
+        assert_eq!(response.summary.attributes.len(), 2);
+        assert_eq!(response.summary.associations.len(), 2);
+        assert_eq!(
+            response
+                .summary
+                .attribute_types
+                .iter()
+                .map(|attribute_type| attribute_type.name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["String"]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_rejects_invalid_schema() -> FoundationResult<()> {
+        let repo = Repo::default();
+
+        let error = execute(
+            &repo,
+            Request {
+                target: Target::NewProject {
+                    name: "Theater".to_string(),
+                    description: "".to_string(),
+                },
+                schema: "create_table \"books\" do |t|\nend\n".to_string(),
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+        assert!(matches!(error.code(), FoundationErrorCode::InvalidArgument));
+        assert_eq!(
+            error.message(),
+            "invalid schema, line 1: expected `ActiveRecord::Schema.define`"
+        );
+        assert!(repo.project_repo.records().await.is_empty());
+
+        Ok(())
+    }
+}
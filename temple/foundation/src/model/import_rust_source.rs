@@ -0,0 +1,166 @@
+use crate::{
+    attribute_type::{CreateAttributeTypeRecord, ListAttributeTypeRecords},
+    import,
+    model::{
+        CreateModelAssociationRecord, CreateModelAttributeRecord, CreateModelRecord, ImportSummary,
+        ListModelOverviewRecords,
+    },
+    project::GetProjectRecord,
+    FoundationResult,
+};
+
+pub struct Request {
+    pub project_slug: String,
+
+    /// Rust source file defining the structs.
+    pub source: String,
+}
+
+pub struct Response {
+    pub summary: ImportSummary,
+}
+
+pub async fn execute(
+    repo: &(impl GetProjectRecord
+          + ListModelOverviewRecords
+          + ListAttributeTypeRecords
+          + CreateAttributeTypeRecord
+          + CreateModelRecord
+          + CreateModelAttributeRecord
+          + CreateModelAssociationRecord),
+    request: Request,
+) -> FoundationResult<Response> {
+    let Request {
+        project_slug,
+        source,
+    } = request;
+
+    let schema = import::rust_schema(&source)?;
+
+    let project_record = repo.get_project_record(&project_slug).await?;
+
+    let summary = import::create_records(repo, project_record, schema).await?;
+
+    let response = Response { summary };
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::{tests::Repo, AssociationKind, SkippedConstruct},
+        tests::{
+            model_record_fixture, project_record_fixture, ModelRecordFixture, ModelRepo,
+            ProjectRepo,
+        },
+        FoundationErrorCode,
+    };
+
+    #[tokio::test]
+    async fn it_imports_structs_into_project() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            ..Default::default()
+        });
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![model_record]),
+            ..Default::default()
+        };
+
+        let response = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug,
+                source: r#"
+/// Company publishing books.
+pub struct Publisher {
+    pub name: String,
+    pub books: Vec<Book>,
+}
+
+pub struct Book {
+    pub publisher: Publisher,
+    pub pages: i64,
+}
+
+pub type Isbn = String;
+"#
+                .to_string(),
+            },
+        )
+        .await?;
+
+        assert_eq!(
+            response
+                .summary
+                .models
+                .iter()
+                .map(|model| (model.name.as_str(), model.description.as_deref()))
+                .collect::<Vec<(&str, Option<&str>)>>(),
+            vec![("Publisher", Some("Company publishing books."))]
+        );
+
+        assert_eq!(
+            response
+                .summary
+                .attributes
+                .iter()
+                .map(|attribute| (attribute.name.as_str(), attribute.r#type.name.as_str()))
+                .collect::<Vec<(&str, &str)>>(),
+            vec![("Name", "String"), ("Pages", "i64")]
+        );
+
+        assert_eq!(
+            response
+                .summary
+                .associations
+                .iter()
+                .map(|association| (
+                    association.name.as_str(),
+                    association.kind.clone(),
+                    association.model.name.as_str()
+                ))
+                .collect::<Vec<(&str, AssociationKind, &str)>>(),
+            vec![
+                ("Books", AssociationKind::HasMany, "Book"),
+                ("Publisher", AssociationKind::BelongsTo, "Publisher"),
+            ]
+        );
+
+        assert_eq!(response.summary.skipped, Vec::<SkippedConstruct>::new());
+        assert_eq!(repo.model_repo.records().await.len(), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_rejects_invalid_source() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            ..Default::default()
+        };
+
+        let error = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug,
+                source: "pub struct Book {".to_string(),
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+        assert!(matches!(error.code(), FoundationErrorCode::InvalidArgument));
+        assert!(error.message().starts_with("invalid Rust source, "));
+
+        Ok(())
+    }
+}
@@ -4,12 +4,34 @@ pub mod create_attribute;
 pub mod delete;
 pub mod delete_association;
 pub mod delete_attribute;
+pub mod generate_project_alter_migration;
+pub mod generate_project_dbml;
+pub mod generate_project_ddl;
+pub mod generate_project_ecto_code;
+pub mod generate_project_migration;
+pub mod generate_project_prisma_schema;
+pub mod generate_project_proto;
+pub mod generate_project_rails_code;
+pub mod generate_project_rust_code;
 pub mod get;
 pub mod get_class_diagram;
+pub mod get_neighbourhood_class_diagram;
 pub mod get_project_class_diagram;
+pub mod get_project_dot_graph;
+pub mod get_project_er_diagram;
+pub mod get_project_json_schema;
+pub mod get_project_snapshot;
+pub mod get_project_typescript_definitions;
+pub mod import_class_diagram;
+pub mod import_dbml;
+pub mod import_postgresql_ddl;
+pub mod import_prisma_schema;
+pub mod import_rails_schema;
+pub mod import_rust_source;
 pub mod list;
+pub mod run_project_generator;
 
-mod tests;
+pub mod tests;
 
 use std::str::FromStr;
 
@@ -125,6 +147,23 @@ pub trait CreateModelAssociationRecord {
     ) -> FoundationResult<datastore::model::Association>;
 }
 
+#[async_trait::async_trait]
+pub trait ListModelFieldNumberRecords {
+    async fn list_model_field_number_records(
+        &self,
+        project_slug: &str,
+    ) -> FoundationResult<Vec<datastore::model::FieldNumber>>;
+}
+
+#[async_trait::async_trait]
+pub trait CreateModelFieldNumberRecord {
+    async fn create_model_field_number_record(
+        &self,
+        model: datastore::model::Model,
+        field_number: FieldNumber,
+    ) -> FoundationResult<datastore::model::FieldNumber>;
+}
+
 #[derive(Clone, Debug)]
 pub struct Model {
     pub description: Option<String>,
@@ -163,6 +202,114 @@ pub enum AssociationKind {
     HasMany,
 }
 
+/// Output format of the class diagrams.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DiagramFormat {
+    /// Mermaid source code.
+    #[default]
+    Mermaid,
+
+    /// Standalone SVG document.
+    Svg,
+
+    /// PNG image rasterized from the SVG document.
+    Png,
+
+    /// PlantUML source code.
+    PlantUml,
+}
+
+/// SQL dialect of the generated schema.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SqlDialect {
+    #[default]
+    PostgreSql,
+
+    Sqlite,
+
+    MySql,
+}
+
+/// Layout direction of the class diagrams.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DiagramDirection {
+    TopToBottom,
+    BottomToTop,
+    LeftToRight,
+    RightToLeft,
+}
+
+/// Rendering options of the class diagrams.
+#[derive(Clone, Debug, Default)]
+pub struct DiagramOptions {
+    /// Direction of the layout, the format default when empty.
+    pub direction: Option<DiagramDirection>,
+
+    /// Draws the classes without attributes.
+    pub hide_attributes: bool,
+
+    /// Slugs of the models to draw, all models when empty.
+    pub model_slugs: Vec<String>,
+
+    /// Attaches model and attribute descriptions to the classes as notes.
+    pub descriptions_as_notes: bool,
+
+    /// Orders classes, attributes and associations by name instead of the
+    /// order they were created in.
+    pub sort_alphabetically: bool,
+}
+
+/// Source file produced by a code generator.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeneratedFile {
+    /// Path relative to the root of the generated files.
+    pub path: String,
+
+    pub content: String,
+}
+
+/// Number of a field of the model's Protobuf message, kept once assigned so
+/// the messages stay wire compatible.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldNumber {
+    /// `snake_case` name of the field.
+    pub name: String,
+
+    pub number: i32,
+}
+
+/// Records an importer added to the project.
+#[derive(Clone, Debug, Default)]
+pub struct ImportSummary {
+    /// Models that were missing in the project and were created.
+    pub models: Vec<Model>,
+
+    /// Attributes that were added to the models.
+    pub attributes: Vec<Attribute>,
+
+    /// Associations that were added to the models.
+    pub associations: Vec<Association>,
+
+    /// Attribute types that didn't exist and were created for the attributes.
+    pub attribute_types: Vec<AttributeType>,
+
+    /// Constructs of the imported source that the models can't represent.
+    pub skipped: Vec<SkippedConstruct>,
+}
+
+/// Part of an imported source which was left out of the project.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SkippedConstruct {
+    /// Where the construct is, like `books.title` or `line 3`.
+    pub location: String,
+
+    /// What was skipped, like `DEFAULT` or `CREATE INDEX`.
+    pub construct: String,
+
+    /// Why it was skipped.
+    pub reason: String,
+}
+
 #[derive(Clone, Debug)]
 pub struct ModelOverview {
     pub model: Model,
@@ -250,6 +397,21 @@ impl From<datastore::model::Association> for Association {
     }
 }
 
+impl From<datastore::model::FieldNumber> for FieldNumber {
+    fn from(value: datastore::model::FieldNumber) -> Self {
+        let datastore::model::FieldNumber {
+            id: _,
+            model_id: _,
+            name,
+            number,
+            inserted_at: _,
+            updated_at: _,
+        } = value;
+
+        Self { name, number }
+    }
+}
+
 impl From<datastore::model::AssociationKind> for AssociationKind {
     fn from(value: datastore::model::AssociationKind) -> Self {
         use datastore::model::AssociationKind::*;
@@ -0,0 +1,249 @@
+use crate::{
+    codegen,
+    generator::{Generator, GetGeneratorRecord},
+    model::{GeneratedFile, ListModelOverviewRecords, ModelOverview},
+    FoundationError, FoundationResult,
+};
+
+pub struct Request {
+    pub project_slug: String,
+    pub generator_slug: String,
+}
+
+pub struct Response {
+    /// Files rendered from the templates of the generator.
+    pub files: Vec<GeneratedFile>,
+}
+
+pub async fn execute(
+    repo: &(impl GetGeneratorRecord + ListModelOverviewRecords),
+    request: Request,
+) -> FoundationResult<Response> {
+    let Request {
+        project_slug,
+        generator_slug,
+    } = request;
+
+    let Generator { templates, .. } = repo
+        .get_generator_record(&generator_slug)
+        .await?
+        .ok_or(FoundationError::not_found("generator not found"))?
+        .into();
+
+    let model_overviews: Vec<ModelOverview> = repo
+        .list_model_overview_records(&project_slug)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    let files = codegen::generator_files(&templates, &model_overviews, &project_slug)?;
+
+    Ok(Response { files })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        datastore,
+        datastore::tests::RecordFactory,
+        generator::{tests::GeneratorRepo, GeneratorTemplate, TemplateScope},
+        model::tests::Repo,
+        tests::{
+            model_association_record_fixture, model_attribute_record_fixture, model_record_fixture,
+            project_record_fixture, ModelAssociationRecordFixture, ModelAssociationRepo,
+            ModelAttributeRecordFixture, ModelAttributeRepo, ModelRecordFixture, ModelRepo,
+            ProjectRepo,
+        },
+        FoundationErrorCode,
+    };
+
+    async fn generator_repo(templates: Vec<GeneratorTemplate>) -> GeneratorRepo {
+        let generator_repo = GeneratorRepo::new();
+
+        generator_repo
+            .save(RecordFactory::build(&Generator {
+                description: None,
+                name: "Model Docs".to_string(),
+                slug: "model-docs".to_string(),
+                templates,
+            }))
+            .await;
+
+        generator_repo
+    }
+
+    #[tokio::test]
+    async fn it_renders_project_and_model_templates() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            description: Some("Printed book.".to_string()),
+            ..Default::default()
+        });
+        let model_attribute_record = model_attribute_record_fixture(ModelAttributeRecordFixture {
+            model_id: Some(model_record.id),
+            ..Default::default()
+        });
+        let associated_model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            name: Some("Publisher".to_string()),
+            slug: Some("publisher".to_string()),
+            ..Default::default()
+        });
+        let model_association_record =
+            model_association_record_fixture(ModelAssociationRecordFixture {
+                model_id: Some(model_record.id),
+                associated_model: Some(associated_model_record.clone()),
+                ..Default::default()
+            });
+        let inverse_model_association_record =
+            model_association_record_fixture(ModelAssociationRecordFixture {
+                model_id: Some(associated_model_record.id),
+                associated_model: Some(model_record.clone()),
+                kind: Some(datastore::model::AssociationKind::HasMany),
+                name: Some("Books".to_string()),
+                ..Default::default()
+            });
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![model_record, associated_model_record]),
+            model_attribute_repo: ModelAttributeRepo::seed(vec![model_attribute_record]),
+            model_association_repo: ModelAssociationRepo::seed(vec![
+                model_association_record,
+                inverse_model_association_record,
+            ]),
+            generator_repo: generator_repo(vec![
+                GeneratorTemplate {
+                    path: "{{ project.slug }}.md".to_string(),
+                    content: r#"{% for model in models %}- [{{ model.name }}](models/{{ model.slug }}.md)
+{% endfor %}"#
+                        .to_string(),
+                    scope: TemplateScope::Project,
+                },
+                GeneratorTemplate {
+                    path: "models/{{ model.slug }}.md".to_string(),
+                    content: r#"# {{ model.name | pascal_case }}
+{% if model.description %}
+{{ model.description }}
+{% endif %}
+{% for attribute in model.attributes %}- `{{ attribute.name | snake_case }}`: {{ attribute.type.kind }}
+{% endfor %}{% for association in model.associations %}- `{{ association.name | snake_case }}`: {{ association.kind }} {{ association.model.name | pluralize }}
+{% endfor %}"#
+                        .to_string(),
+                    scope: TemplateScope::Model,
+                },
+            ])
+            .await,
+            ..Default::default()
+        };
+
+        let response = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug,
+                generator_slug: "model-docs".to_string(),
+            },
+        )
+        .await?;
+
+        assert_eq!(
+            response.files,
+            vec![
+                GeneratedFile {
+                    path: "book-store.md".to_string(),
+                    content: "- [Book](models/book.md)\n- [Publisher](models/publisher.md)\n"
+                        .to_string(),
+                },
+                GeneratedFile {
+                    path: "models/book.md".to_string(),
+                    content: "# Book\n\nPrinted book.\n\n- `title`: text\n- `publisher`: belongs_to Publishers\n"
+                        .to_string(),
+                },
+                GeneratedFile {
+                    path: "models/publisher.md".to_string(),
+                    content: "# Publisher\n\n- `books`: has_many Books\n".to_string(),
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_rejects_model_templates_sharing_a_path() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+        let model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            ..Default::default()
+        });
+        let other_model_record = model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            name: Some("Publisher".to_string()),
+            slug: Some("publisher".to_string()),
+            ..Default::default()
+        });
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            model_repo: ModelRepo::seed(vec![model_record, other_model_record]),
+            generator_repo: generator_repo(vec![GeneratorTemplate {
+                path: "model.md".to_string(),
+                content: "# {{ model.name }}\n".to_string(),
+                scope: TemplateScope::Model,
+            }])
+            .await,
+            ..Default::default()
+        };
+
+        let error = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug,
+                generator_slug: "model-docs".to_string(),
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+        assert!(matches!(
+            error.code(),
+            FoundationErrorCode::FailedPrecondition
+        ));
+        assert_eq!(
+            error.message(),
+            "code can't be generated, more than one file has the path `model.md`"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn it_returns_not_found_error() -> FoundationResult<()> {
+        let project_record = project_record_fixture(Default::default());
+
+        let repo = Repo {
+            project_repo: ProjectRepo::seed(vec![project_record.clone()]),
+            ..Default::default()
+        };
+
+        let error = execute(
+            &repo,
+            Request {
+                project_slug: project_record.slug,
+                generator_slug: "model-docs".to_string(),
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+        assert!(matches!(error.code(), FoundationErrorCode::NotFound));
+        assert_eq!(error.message(), "generator not found");
+
+        Ok(())
+    }
+}
@@ -2,12 +2,18 @@
 
 use super::*;
 use crate::{
-    attribute_type::{tests::AttributeTypeRepo, AttributeTypeRecord, GetAttributeTypeRecord},
-    project::GetProjectRecord,
+    attribute_type::{
+        tests::{AttributeTypeRepo, ColumnTypeRepo},
+        AttributeType, AttributeTypeRecord, ColumnTypeRecord, CreateAttributeTypeRecord,
+        GetAttributeTypeRecord, ListAttributeTypeRecords, ListColumnTypeRecords,
+    },
+    generator::{tests::GeneratorRepo, GeneratorRecord, GetGeneratorRecord},
+    project::{CreateProjectRecord, GetProjectRecord, Project},
     tests::{
         model_attribute_record_fixture, model_record_fixture, ModelAssociationRepo,
-        ModelAttributeRepo, ModelRepo, ProjectRepo,
+        ModelAttributeRepo, ModelFieldNumberRepo, ModelRepo, ProjectRepo,
     },
+    Uuid,
 };
 
 #[async_trait::async_trait]
@@ -39,6 +45,16 @@ impl CreateModelRecord for Repo {
     }
 }
 
+#[async_trait::async_trait]
+impl CreateProjectRecord for Repo {
+    async fn create_project_record(
+        &self,
+        project: Project,
+    ) -> FoundationResult<datastore::project::Project> {
+        self.project_repo.create_project_record(project).await
+    }
+}
+
 #[async_trait::async_trait]
 impl GetProjectRecord for Repo {
     async fn get_project_record(
@@ -144,8 +160,13 @@ impl ListModelOverviewRecords for Repo {
             Vec::with_capacity(model_records.len());
 
         for model_record in model_records {
-            let associations = self.model_association_repo.list(model_record.id).await?;
-            let attributes = self.model_attribute_repo.list(model_record.id).await?;
+            let mut associations = self.model_association_repo.list(model_record.id).await?;
+            let mut attributes = self.model_attribute_repo.list(model_record.id).await?;
+
+            // The attributes and associations repos don't keep the insertion
+            // order.
+            associations.sort_by(|a, b| a.name.cmp(&b.name));
+            attributes.sort_by(|a, b| a.name.cmp(&b.name));
 
             model_overviews.push(datastore::model::ModelOverview {
                 model: model_record,
@@ -276,6 +297,7 @@ impl CreateModelAssociationRecord for Repo {
         let mut model_association_records = self.model_association_repo.records.write().await;
 
         let model_association_record = datastore::model::Association {
+            id: Uuid::new_v4(),
             model_id: model_record.id,
             description: description.unwrap_or_default(),
             name,
@@ -293,6 +315,66 @@ impl CreateModelAssociationRecord for Repo {
     }
 }
 
+#[async_trait::async_trait]
+impl ListModelFieldNumberRecords for Repo {
+    async fn list_model_field_number_records(
+        &self,
+        project_slug: &str,
+    ) -> FoundationResult<Vec<datastore::model::FieldNumber>> {
+        let project_record = self.project_repo.find_by_slug(project_slug).await?;
+
+        let mut model_field_number_records = vec![];
+
+        for model_record in self.model_repo.records().await {
+            if model_record.project_id == project_record.id {
+                model_field_number_records
+                    .extend(self.model_field_number_repo.list(model_record.id).await?);
+            }
+        }
+
+        Ok(model_field_number_records)
+    }
+}
+
+#[async_trait::async_trait]
+impl CreateModelFieldNumberRecord for Repo {
+    async fn create_model_field_number_record(
+        &self,
+        model_record: datastore::model::Model,
+        field_number: FieldNumber,
+    ) -> FoundationResult<datastore::model::FieldNumber> {
+        let FieldNumber { name, number } = field_number;
+
+        let mut model_field_number_records = self.model_field_number_repo.records.write().await;
+
+        let model_field_number_record = datastore::model::FieldNumber {
+            model_id: model_record.id,
+            name,
+            number,
+            ..Default::default()
+        };
+
+        model_field_number_records.insert(
+            model_field_number_record.id,
+            model_field_number_record.clone(),
+        );
+
+        Ok(model_field_number_record)
+    }
+}
+
+#[async_trait::async_trait]
+impl CreateAttributeTypeRecord for Repo {
+    async fn create_attribute_type_record(
+        &self,
+        attribute_type: AttributeType,
+    ) -> FoundationResult<AttributeTypeRecord> {
+        self.attribute_type_repo
+            .create_attribute_type_record(attribute_type)
+            .await
+    }
+}
+
 #[async_trait::async_trait]
 impl GetAttributeTypeRecord for Repo {
     async fn get_attribute_type_record(
@@ -305,12 +387,41 @@ impl GetAttributeTypeRecord for Repo {
     }
 }
 
+#[async_trait::async_trait]
+impl ListAttributeTypeRecords for Repo {
+    async fn list_attribute_type_records(&self) -> FoundationResult<Vec<AttributeTypeRecord>> {
+        self.attribute_type_repo.list_attribute_type_records().await
+    }
+}
+
+#[async_trait::async_trait]
+impl ListColumnTypeRecords for Repo {
+    async fn list_column_type_records(
+        &self,
+        dialect: SqlDialect,
+    ) -> FoundationResult<Vec<ColumnTypeRecord>> {
+        self.column_type_repo
+            .list_column_type_records(dialect)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl GetGeneratorRecord for Repo {
+    async fn get_generator_record(&self, slug: &str) -> FoundationResult<Option<GeneratorRecord>> {
+        self.generator_repo.get_generator_record(slug).await
+    }
+}
+
 pub struct Repo {
     pub project_repo: ProjectRepo,
     pub model_repo: ModelRepo,
     pub model_attribute_repo: ModelAttributeRepo,
     pub model_association_repo: ModelAssociationRepo,
+    pub model_field_number_repo: ModelFieldNumberRepo,
     pub attribute_type_repo: AttributeTypeRepo,
+    pub column_type_repo: ColumnTypeRepo,
+    pub generator_repo: GeneratorRepo,
 }
 
 impl Default for Repo {
@@ -320,7 +431,10 @@ impl Default for Repo {
             model_repo: ModelRepo::seed(vec![]),
             model_attribute_repo: ModelAttributeRepo::seed(vec![]),
             model_association_repo: ModelAssociationRepo::seed(vec![]),
+            model_field_number_repo: ModelFieldNumberRepo::seed(vec![]),
             attribute_type_repo: AttributeTypeRepo::new(),
+            column_type_repo: ColumnTypeRepo::default(),
+            generator_repo: GeneratorRepo::new(),
         }
     }
 }
@@ -151,3 +151,11 @@ impl From<ValidationError> for FoundationError {
         }
     }
 }
+
+impl From<mermaid::MermaidError> for FoundationError {
+    fn from(value: mermaid::MermaidError) -> Self {
+        let message = format!("diagram can't be generated, {value}");
+
+        FoundationError::failed_precondition(message)
+    }
+}
@@ -4,6 +4,7 @@ use super::*;
 use crate::{
     attribute_type::{AttributeType, AttributeTypeRecord},
     datastore::model::AssociationKind,
+    model::ListModelOverviewRecords,
     project::{
         ArchiveProjectRecord, CreateProjectRecord, DeleteProjectRecord, GetProjectRecord,
         ListProjectRecordFilterArchive, ListProjectRecordFilters, ListProjectRecords, Project,
@@ -325,6 +326,42 @@ impl ModelAssociationRepo {
     }
 }
 
+pub struct ModelFieldNumberRepo {
+    pub records: RwLock<HashMap<Uuid, datastore::model::FieldNumber>>,
+}
+
+impl ModelFieldNumberRepo {
+    pub fn seed(records: Vec<datastore::model::FieldNumber>) -> Self {
+        let iter: HashMap<Uuid, datastore::model::FieldNumber> = records
+            .into_iter()
+            .map(|record| (record.id, record))
+            .collect();
+
+        Self {
+            records: RwLock::new(HashMap::from_iter(iter)),
+        }
+    }
+
+    pub async fn list(
+        &self,
+        model_id: Uuid,
+    ) -> FoundationResult<Vec<datastore::model::FieldNumber>> {
+        let records = self.records.read().await;
+
+        let list = records
+            .values()
+            .filter(|record| record.model_id == model_id)
+            .cloned()
+            .collect();
+
+        Ok(list)
+    }
+
+    pub async fn records(&self) -> Vec<datastore::model::FieldNumber> {
+        self.records.read().await.values().cloned().collect()
+    }
+}
+
 #[derive(Default)]
 pub struct ProjectRecordFixture {
     pub name: Option<String>,
@@ -430,6 +467,7 @@ pub fn model_association_record_fixture(
     } = fixture;
 
     datastore::model::Association {
+        id: Uuid::new_v4(),
         model_id: model_id.unwrap_or(Uuid::new_v4()),
         associated_model: associated_model.unwrap_or(model_record_fixture(Default::default())),
         description: description.unwrap_or_default(),
@@ -438,3 +476,179 @@ pub fn model_association_record_fixture(
         ..Default::default()
     }
 }
+
+#[derive(Default)]
+pub struct ModelFieldNumberRecordFixture {
+    pub model_id: Option<Uuid>,
+    pub name: Option<String>,
+    pub number: Option<i32>,
+}
+
+pub fn model_field_number_record_fixture(
+    fixture: ModelFieldNumberRecordFixture,
+) -> datastore::model::FieldNumber {
+    let ModelFieldNumberRecordFixture {
+        model_id,
+        name,
+        number,
+    } = fixture;
+
+    datastore::model::FieldNumber {
+        model_id: model_id.unwrap_or(Uuid::new_v4()),
+        name: name.unwrap_or("Title".to_string()),
+        number: number.unwrap_or(1),
+        ..Default::default()
+    }
+}
+
+/// Customers, each with one address and many orders, the project of the code
+/// generation tests.
+pub fn customer_orders_repo() -> model::tests::Repo {
+    let project_record = project_record_fixture(Default::default());
+    let model_record = |name: &str, description: Option<&str>| {
+        model_record_fixture(ModelRecordFixture {
+            project_id: Some(project_record.id),
+            name: Some(name.to_string()),
+            slug: Some(name.to_lowercase()),
+            description: description.map(str::to_string),
+        })
+    };
+    let attribute_type_record = |name: &str, slug: &str| AttributeTypeRecord {
+        inner: AttributeType {
+            description: None,
+            name: name.to_string(),
+            slug: slug.to_string(),
+        },
+        ..Default::default()
+    };
+    let attribute_record =
+        |model: &datastore::model::Model, name: &str, description: Option<&str>, r#type| {
+            model_attribute_record_fixture(ModelAttributeRecordFixture {
+                model_id: Some(model.id),
+                name: Some(name.to_string()),
+                description: description.map(str::to_string),
+                r#type: Some(r#type),
+            })
+        };
+    let association_record = |model: &datastore::model::Model,
+                              associated_model: &datastore::model::Model,
+                              name: &str,
+                              description: Option<&str>,
+                              kind| {
+        model_association_record_fixture(ModelAssociationRecordFixture {
+            model_id: Some(model.id),
+            associated_model: Some(associated_model.clone()),
+            name: Some(name.to_string()),
+            description: description.map(str::to_string),
+            kind: Some(kind),
+        })
+    };
+
+    let customer_record = model_record("Customer", Some("Buyer of the orders."));
+    let address_record = model_record("Address", None);
+    let order_record = model_record("Order", None);
+
+    model::tests::Repo {
+        project_repo: ProjectRepo::seed(vec![project_record]),
+        model_repo: ModelRepo::seed(vec![
+            customer_record.clone(),
+            address_record.clone(),
+            order_record.clone(),
+        ]),
+        model_attribute_repo: ModelAttributeRepo::seed(vec![
+            attribute_record(
+                &customer_record,
+                "Name",
+                Some("Full name,\nas written on the orders."),
+                attribute_type_record("Text", "text"),
+            ),
+            attribute_record(
+                &customer_record,
+                "Active",
+                None,
+                attribute_type_record("Boolean", "boolean"),
+            ),
+            attribute_record(
+                &order_record,
+                "Total",
+                None,
+                attribute_type_record("Money", "money"),
+            ),
+            attribute_record(
+                &order_record,
+                "Placed At",
+                None,
+                attribute_type_record("Date Time", "date-time"),
+            ),
+        ]),
+        model_association_repo: ModelAssociationRepo::seed(vec![
+            association_record(
+                &customer_record,
+                &address_record,
+                "Address",
+                None,
+                AssociationKind::HasOne,
+            ),
+            association_record(
+                &address_record,
+                &customer_record,
+                "Customer",
+                None,
+                AssociationKind::BelongsTo,
+            ),
+            association_record(
+                &order_record,
+                &customer_record,
+                "Buyer",
+                Some("Customer who placed the order."),
+                AssociationKind::BelongsTo,
+            ),
+            association_record(
+                &customer_record,
+                &order_record,
+                "Orders",
+                None,
+                AssociationKind::HasMany,
+            ),
+        ]),
+        ..Default::default()
+    }
+}
+
+/// Asserts that the models imported into the repo are the ones of
+/// [`customer_orders_repo`], for the round trips of the exports through their
+/// imports.
+pub async fn assert_imported_customer_orders(
+    imported_repo: &model::tests::Repo,
+) -> FoundationResult<()> {
+    let project_slug = project_record_fixture(Default::default()).slug;
+
+    let expected_model_overviews: Vec<model::ModelOverview> = customer_orders_repo()
+        .list_model_overview_records(&project_slug)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+    let imported_model_overviews: Vec<model::ModelOverview> = imported_repo
+        .list_model_overview_records(&project_slug)
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    assert_eq!(
+        imported_model_overviews.len(),
+        expected_model_overviews.len()
+    );
+
+    for (imported, expected) in imported_model_overviews
+        .iter()
+        .zip(&expected_model_overviews)
+    {
+        assert_eq!(imported.model, expected.model);
+        assert_eq!(imported.attributes, expected.attributes);
+        assert_eq!(imported.associations, expected.associations);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,276 @@
+//! English inflections of `snake_case` identifiers, following the default
+//! rules of [Active Support](https://api.rubyonrails.org/classes/ActiveSupport/Inflector.html).
+
+/// Words which are the same in the singular and the plural.
+const UNCOUNTABLES: &[&str] = &[
+    "equipment",
+    "information",
+    "rice",
+    "money",
+    "species",
+    "series",
+    "fish",
+    "sheep",
+    "jeans",
+    "police",
+];
+
+/// Rules turning a singular into a plural, by precedence.
+const PLURALS: &[Rule] = &[
+    // Irregulars.
+    Rule::suffix("person", "people"),
+    Rule::suffix("people", "people"),
+    Rule::suffix("man", "men"),
+    Rule::suffix("men", "men"),
+    Rule::suffix("child", "children"),
+    Rule::suffix("children", "children"),
+    Rule::suffix("sex", "sexes"),
+    Rule::suffix("sexes", "sexes"),
+    Rule::suffix("move", "moves"),
+    Rule::suffix("moves", "moves"),
+    Rule::suffix("zombie", "zombies"),
+    Rule::suffix("zombies", "zombies"),
+    // Regulars.
+    Rule::suffix("quiz", "quizzes"),
+    Rule::word("oxen", "oxen"),
+    Rule::word("ox", "oxen"),
+    Rule::word("mice", "mice"),
+    Rule::word("lice", "lice"),
+    Rule::word("mouse", "mice"),
+    Rule::word("louse", "lice"),
+    Rule::suffix("matrix", "matrices"),
+    Rule::suffix("matrex", "matrices"),
+    Rule::suffix("vertix", "vertices"),
+    Rule::suffix("vertex", "vertices"),
+    Rule::suffix("indix", "indices"),
+    Rule::suffix("index", "indices"),
+    Rule::suffix("x", "xes"),
+    Rule::suffix("ch", "ches"),
+    Rule::suffix("ss", "sses"),
+    Rule::suffix("sh", "shes"),
+    Rule::suffix("quy", "quies"),
+    Rule::not_after("aeiouy", "y", "ies"),
+    Rule::suffix("hive", "hives"),
+    Rule::not_after("f", "fe", "ves"),
+    Rule::suffix("lf", "lves"),
+    Rule::suffix("rf", "rves"),
+    Rule::suffix("sis", "ses"),
+    Rule::suffix("ta", "ta"),
+    Rule::suffix("ia", "ia"),
+    Rule::suffix("tum", "ta"),
+    Rule::suffix("ium", "ia"),
+    Rule::suffix("buffalo", "buffaloes"),
+    Rule::suffix("tomato", "tomatoes"),
+    Rule::suffix("bus", "buses"),
+    Rule::suffix("alias", "aliases"),
+    Rule::suffix("status", "statuses"),
+    Rule::suffix("octopi", "octopi"),
+    Rule::suffix("viri", "viri"),
+    Rule::suffix("octopus", "octopi"),
+    Rule::suffix("virus", "viri"),
+    Rule::word("axis", "axes"),
+    Rule::word("testis", "testes"),
+    Rule::suffix("s", "s"),
+    Rule::suffix("", "s"),
+];
+
+/// Rules turning a plural into a singular, by precedence.
+const SINGULARS: &[Rule] = &[
+    // Irregulars.
+    Rule::suffix("people", "person"),
+    Rule::suffix("person", "person"),
+    Rule::suffix("men", "man"),
+    Rule::suffix("man", "man"),
+    Rule::suffix("children", "child"),
+    Rule::suffix("child", "child"),
+    Rule::suffix("sexes", "sex"),
+    Rule::suffix("sex", "sex"),
+    Rule::suffix("moves", "move"),
+    Rule::suffix("move", "move"),
+    Rule::suffix("zombies", "zombie"),
+    Rule::suffix("zombie", "zombie"),
+    // Regulars.
+    Rule::suffix("databases", "database"),
+    Rule::suffix("quizzes", "quiz"),
+    Rule::suffix("matrices", "matrix"),
+    Rule::suffix("vertices", "vertex"),
+    Rule::suffix("indices", "index"),
+    Rule::word("oxen", "ox"),
+    Rule::suffix("aliases", "alias"),
+    Rule::suffix("alias", "alias"),
+    Rule::suffix("statuses", "status"),
+    Rule::suffix("status", "status"),
+    Rule::suffix("octopus", "octopus"),
+    Rule::suffix("octopi", "octopus"),
+    Rule::suffix("virus", "virus"),
+    Rule::suffix("viri", "virus"),
+    Rule::word("axis", "axis"),
+    Rule::word("axes", "axis"),
+    Rule::suffix("crisis", "crisis"),
+    Rule::suffix("crises", "crisis"),
+    Rule::suffix("testis", "testis"),
+    Rule::suffix("testes", "testis"),
+    Rule::suffix("shoes", "shoe"),
+    Rule::suffix("oes", "o"),
+    Rule::suffix("buses", "bus"),
+    Rule::suffix("bus", "bus"),
+    Rule::word("mice", "mouse"),
+    Rule::word("lice", "louse"),
+    Rule::suffix("xes", "x"),
+    Rule::suffix("ches", "ch"),
+    Rule::suffix("sses", "ss"),
+    Rule::suffix("shes", "sh"),
+    Rule::suffix("movies", "movie"),
+    Rule::suffix("series", "series"),
+    Rule::suffix("quies", "quy"),
+    Rule::not_after("aeiouy", "ies", "y"),
+    Rule::suffix("lves", "lf"),
+    Rule::suffix("rves", "rf"),
+    Rule::suffix("tives", "tive"),
+    Rule::suffix("hives", "hive"),
+    Rule::not_after("f", "ves", "fe"),
+    Rule::word("analysis", "analysis"),
+    Rule::word("analyses", "analysis"),
+    Rule::suffix("analysis", "analysis"),
+    Rule::suffix("analyses", "analysis"),
+    Rule::suffix("basis", "basis"),
+    Rule::suffix("bases", "basis"),
+    Rule::suffix("diagnosis", "diagnosis"),
+    Rule::suffix("diagnoses", "diagnosis"),
+    Rule::suffix("parenthesis", "parenthesis"),
+    Rule::suffix("parentheses", "parenthesis"),
+    Rule::suffix("prognosis", "prognosis"),
+    Rule::suffix("prognoses", "prognosis"),
+    Rule::suffix("synopsis", "synopsis"),
+    Rule::suffix("synopses", "synopsis"),
+    Rule::suffix("thesis", "thesis"),
+    Rule::suffix("theses", "thesis"),
+    Rule::suffix("ta", "tum"),
+    Rule::suffix("ia", "ium"),
+    Rule::suffix("news", "news"),
+    Rule::suffix("ss", "ss"),
+    Rule::suffix("s", ""),
+];
+
+/// Plural of the last word of the `snake_case` identifier, e.g. `order_items`
+/// for `order_item`.
+pub fn pluralize(word: &str) -> String {
+    inflect(word, PLURALS)
+}
+
+/// Singular of the last word of the `snake_case` identifier, e.g. `order_item`
+/// for `order_items`.
+pub fn singularize(word: &str) -> String {
+    inflect(word, SINGULARS)
+}
+
+fn inflect(word: &str, rules: &[Rule]) -> String {
+    if word.is_empty() || UNCOUNTABLES.contains(&word) {
+        return word.to_string();
+    }
+
+    rules
+        .iter()
+        .find_map(|rule| rule.apply(word))
+        .unwrap_or_else(|| word.to_string())
+}
+
+/// Replacement of the suffix of a word.
+struct Rule {
+    suffix: &'static str,
+    replacement: &'static str,
+    condition: Condition,
+}
+
+enum Condition {
+    /// Any word ending with the suffix.
+    None,
+
+    /// Only the suffix itself.
+    Word,
+
+    /// Words where the suffix follows a letter other than these ones.
+    NotAfter(&'static str),
+}
+
+impl Rule {
+    const fn suffix(suffix: &'static str, replacement: &'static str) -> Self {
+        Self {
+            suffix,
+            replacement,
+            condition: Condition::None,
+        }
+    }
+
+    const fn word(suffix: &'static str, replacement: &'static str) -> Self {
+        Self {
+            suffix,
+            replacement,
+            condition: Condition::Word,
+        }
+    }
+
+    const fn not_after(
+        letters: &'static str,
+        suffix: &'static str,
+        replacement: &'static str,
+    ) -> Self {
+        Self {
+            suffix,
+            replacement,
+            condition: Condition::NotAfter(letters),
+        }
+    }
+
+    fn apply(&self, word: &str) -> Option<String> {
+        let stem = word.strip_suffix(self.suffix)?;
+
+        let applies = match self.condition {
+            Condition::None => true,
+            Condition::Word => stem.is_empty(),
+            Condition::NotAfter(letters) => stem
+                .chars()
+                .last()
+                .is_some_and(|letter| !letters.contains(letter)),
+        };
+
+        applies.then(|| format!("{stem}{}", self.replacement))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_pluralizes_words() {
+        for (singular, plural) in [
+            ("book", "books"),
+            ("order_item", "order_items"),
+            ("category", "categories"),
+            ("day", "days"),
+            ("address", "addresses"),
+            ("box", "boxes"),
+            ("wife", "wives"),
+            ("half", "halves"),
+            ("datum", "data"),
+            ("status", "statuses"),
+            ("sales_person", "sales_people"),
+            ("child", "children"),
+            ("ox", "oxen"),
+            ("sheep", "sheep"),
+            ("analysis", "analyses"),
+        ] {
+            assert_eq!(pluralize(singular), plural);
+            assert_eq!(singularize(plural), singular);
+        }
+    }
+
+    #[test]
+    fn it_keeps_inflected_words() {
+        assert_eq!(pluralize("books"), "books");
+        assert_eq!(singularize("book"), "book");
+        assert_eq!(singularize("moves"), "move");
+        assert_eq!(singularize("news"), "news");
+    }
+}
@@ -1,3 +1,4 @@
+pub mod inflection;
 pub mod slug;
 pub mod string;
 pub mod validator;
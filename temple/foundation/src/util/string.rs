@@ -2,6 +2,75 @@ pub fn optional(value: &str) -> Option<String> {
     (!value.is_empty()).then(|| value.to_owned())
 }
 
+/// Converts a human readable name, like `Order Item` or `OrderItem`, into a
+/// `snake_case` identifier.
+pub fn snake_case(value: &str) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    let mut words: Vec<String> = vec![];
+    let mut word = String::new();
+
+    for (index, char) in chars.iter().enumerate() {
+        if !char.is_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+
+            continue;
+        }
+
+        if char.is_uppercase() && !word.is_empty() {
+            let previous_is_lowercase = chars[index - 1].is_lowercase();
+            let next_is_lowercase = chars.get(index + 1).is_some_and(|c| c.is_lowercase());
+            let previous_is_uppercase = chars[index - 1].is_uppercase();
+
+            if previous_is_lowercase || (previous_is_uppercase && next_is_lowercase) {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+
+        word.extend(char.to_lowercase());
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words.join("_")
+}
+
+/// Converts a human readable name, like `Order Item` or `order_item`, into a
+/// `PascalCase` identifier.
+pub fn pascal_case(value: &str) -> String {
+    snake_case(value)
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Converts an identifier, like `order_item` or `OrderItem`, into a human
+/// readable name of capitalized words, like `Order Item`.
+pub fn title_case(value: &str) -> String {
+    snake_case(value)
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -12,4 +81,29 @@ mod tests {
 
         assert_eq!(None, optional(""));
     }
+
+    #[test]
+    fn it_converts_string_to_snake_case() {
+        assert_eq!("publisher", snake_case("Publisher"));
+        assert_eq!("order_item", snake_case("Order Item"));
+        assert_eq!("order_item", snake_case("OrderItem"));
+        assert_eq!("http_server", snake_case("HTTPServer"));
+        assert_eq!("first_name", snake_case("first_name"));
+    }
+
+    #[test]
+    fn it_converts_string_to_pascal_case() {
+        assert_eq!("Publisher", pascal_case("publisher"));
+        assert_eq!("OrderItem", pascal_case("Order Item"));
+        assert_eq!("OrderItem", pascal_case("order_item"));
+        assert_eq!("HttpServer", pascal_case("HTTPServer"));
+    }
+
+    #[test]
+    fn it_converts_string_to_title_case() {
+        assert_eq!("Publisher", title_case("publisher"));
+        assert_eq!("Order Item", title_case("order_item"));
+        assert_eq!("Order Item", title_case("OrderItem"));
+        assert_eq!("Http Server", title_case("HTTPServer"));
+    }
 }
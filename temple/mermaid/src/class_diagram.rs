@@ -1,3 +1,9 @@
+pub mod owned;
+pub mod parser;
+pub mod render;
+
+use crate::{escape, MermaidResult};
+
 pub struct ClassDiagram<'a> {
     pub classes: &'a [Class<'a>],
     pub associations: &'a [Association<'a>],
@@ -15,85 +21,119 @@ pub struct Attribute<'a> {
 
 pub struct Association<'a> {
     pub class_name: &'a str,
+    pub class_cardinality: Option<&'a str>,
+    pub relationship: Relationship,
     pub associated_class_name: &'a str,
-    pub description: Option<&'a str>,
+    pub associated_class_cardinality: Option<&'a str>,
+    pub label: Option<&'a str>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Relationship {
+    /// Solid line with an arrow: `-->`.
+    Association,
+
+    /// Solid line with a hollow diamond: `o--`.
+    Aggregation,
+
+    /// Solid line with a filled diamond: `*--`.
+    Composition,
+
+    /// Dashed line with an arrow: `..>`.
+    Dependency,
+
+    /// Solid line with a hollow triangle: `<|--`.
+    Inheritance,
+
+    /// Solid line without arrows: `--`.
+    Link,
+}
+
+/// Layout direction: `direction LR`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
+    TopToBottom,
+    BottomToTop,
+    LeftToRight,
+    RightToLeft,
+}
+
+/// Note attached to a class (`note for Book "text"`), or a floating note
+/// when the class name is `None`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Note {
+    pub class_name: Option<String>,
+    pub text: String,
 }
 
 impl<'a> ClassDiagram<'a> {
-    pub fn generate(&self) -> String {
-        let ClassDiagram {
-            classes,
-            associations,
-        } = self;
-
-        let code = "classDiagram".to_string();
-
-        let code = if classes.is_empty() {
-            code
-        } else {
-            format!("{code}\n{}\n", self.classes_diagram_code())
-        };
+    /// Generates mermaid code, see [`owned::ClassDiagram::generate`].
+    pub fn generate(&self) -> MermaidResult<String> {
+        owned::ClassDiagram::from(self).generate()
+    }
+}
 
-        if associations.is_empty() {
-            code
-        } else {
-            format!("{code}\n{}\n", self.associations_diagram_code())
+impl Note {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            class_name: None,
+            text: text.into(),
         }
     }
 
-    fn classes_diagram_code(&self) -> String {
-        self.classes
-            .iter()
-            .map(|class| class.generate())
-            .collect::<Vec<String>>()
-            .join("\n")
+    pub fn class(class_name: impl Into<String>, text: impl Into<String>) -> Self {
+        Self {
+            class_name: Some(class_name.into()),
+            text: text.into(),
+        }
     }
 
-    fn associations_diagram_code(&self) -> String {
-        self.associations
-            .iter()
-            .map(|association| association.generate())
-            .collect::<Vec<String>>()
-            .join("\n")
-    }
-}
+    fn generate(&self, identifiers: &mut escape::Identifiers) -> MermaidResult<String> {
+        let text = escape::note_text(&self.text)?;
+
+        let Some(class_name) = &self.class_name else {
+            return Ok(format!("    note \"{text}\""));
+        };
 
-impl<'a> Class<'a> {
-    fn generate(&self) -> String {
-        let attributes_string = self
-            .attributes
-            .iter()
-            .map(|attribute| {
-                let Attribute { kind, name } = attribute;
-
-                format!("        +{kind} {name}")
-            })
-            .collect::<Vec<String>>()
-            .join("\n");
-
-        format!(
-            r#"    class {} {{
-{}
-    }}"#,
-            self.name, attributes_string
-        )
+        Ok(format!(
+            "    note for {} \"{text}\"",
+            identifiers.register(class_name)?
+        ))
     }
 }
 
-impl<'a> Association<'a> {
-    fn generate(&self) -> String {
-        let Association {
-            class_name,
-            associated_class_name,
-            description,
-        } = self;
-
-        let code = format!("    {class_name} --> {associated_class_name}");
+impl Direction {
+    fn code(&self) -> &'static str {
+        match self {
+            Direction::TopToBottom => "TB",
+            Direction::BottomToTop => "BT",
+            Direction::LeftToRight => "LR",
+            Direction::RightToLeft => "RL",
+        }
+    }
 
-        let Some(description) = description else  {
-            return code;
+    fn from_code(code: &str) -> Option<Self> {
+        let direction = match code {
+            "TB" | "TD" => Direction::TopToBottom,
+            "BT" => Direction::BottomToTop,
+            "LR" => Direction::LeftToRight,
+            "RL" => Direction::RightToLeft,
+            _ => return None,
         };
 
-        format!("{code} : {description}")
+        Some(direction)
+    }
+}
+
+impl Relationship {
+    fn code(&self) -> &'static str {
+        match self {
+            Relationship::Association => "-->",
+            Relationship::Aggregation => "o--",
+            Relationship::Composition => "*--",
+            Relationship::Dependency => "..>",
+            Relationship::Inheritance => "<|--",
+            Relationship::Link => "--",
+        }
     }
 }
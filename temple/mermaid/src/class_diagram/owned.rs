@@ -0,0 +1,421 @@
+//! Owned class diagram model, for diagrams that are assembled or edited
+//! incrementally (parsing, imports, exports).
+
+use super::{Direction, Note, Relationship};
+use crate::{escape, MermaidResult};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClassDiagram {
+    pub direction: Option<Direction>,
+    pub classes: Vec<Class>,
+    pub associations: Vec<Association>,
+    pub notes: Vec<Note>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Class {
+    pub name: String,
+    pub attributes: Vec<Attribute>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attribute {
+    pub kind: String,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Association {
+    pub class_name: String,
+    pub class_cardinality: Option<String>,
+    pub relationship: Relationship,
+    pub associated_class_name: String,
+    pub associated_class_cardinality: Option<String>,
+    pub label: Option<String>,
+}
+
+/// Fluent [`ClassDiagram`] construction.
+///
+/// ```
+/// use mermaid::class_diagram::owned::{Association, ClassDiagram};
+///
+/// let diagram = ClassDiagram::builder()
+///     .class("Book")
+///     .attribute("String", "title")
+///     .class("Author")
+///     .attribute("String", "name")
+///     .association(Association::new("Book", "Author").label("Author"))
+///     .build();
+///
+/// assert_eq!(diagram.classes.len(), 2);
+/// assert!(diagram.generate().is_ok());
+/// ```
+#[derive(Default)]
+pub struct ClassDiagramBuilder {
+    diagram: ClassDiagram,
+    current_class: Option<usize>,
+}
+
+impl ClassDiagram {
+    pub fn builder() -> ClassDiagramBuilder {
+        ClassDiagramBuilder::default()
+    }
+
+    /// Generates mermaid code. Names that are not valid mermaid identifiers
+    /// are sanitized, and the original name is kept as the class label.
+    pub fn generate(&self) -> MermaidResult<String> {
+        let ClassDiagram {
+            direction,
+            classes,
+            associations,
+            notes,
+        } = self;
+
+        let mut identifiers = escape::Identifiers::default();
+
+        let code = "classDiagram".to_string();
+
+        let code = match direction {
+            Some(direction) => format!("{code}\n    direction {}", direction.code()),
+            None => code,
+        };
+
+        let code = if classes.is_empty() {
+            code
+        } else {
+            format!("{code}\n{}\n", self.classes_diagram_code(&mut identifiers)?)
+        };
+
+        let code = if associations.is_empty() {
+            code
+        } else {
+            format!(
+                "{code}\n{}\n",
+                self.associations_diagram_code(&mut identifiers)?
+            )
+        };
+
+        let code = if notes.is_empty() {
+            code
+        } else {
+            format!("{code}\n{}\n", self.notes_diagram_code(&mut identifiers)?)
+        };
+
+        Ok(code)
+    }
+
+    /// Adds a class. Attributes of a class with the same name are merged
+    /// into the existing one.
+    pub fn add_class(&mut self, class: Class) -> &mut Self {
+        let Class { name, attributes } = class;
+
+        match self.class_mut(&name) {
+            Some(existing_class) => existing_class.attributes.extend(attributes),
+            None => self.classes.push(Class { name, attributes }),
+        }
+
+        self
+    }
+
+    pub fn add_association(&mut self, association: Association) -> &mut Self {
+        self.associations.push(association);
+
+        self
+    }
+
+    pub fn add_note(&mut self, note: Note) -> &mut Self {
+        self.notes.push(note);
+
+        self
+    }
+
+    pub fn class(&self, name: &str) -> Option<&Class> {
+        self.classes.iter().find(|class| class.name == name)
+    }
+
+    pub fn class_mut(&mut self, name: &str) -> Option<&mut Class> {
+        self.classes.iter_mut().find(|class| class.name == name)
+    }
+
+    /// Removes the class together with all associations it participates in.
+    pub fn remove_class(&mut self, name: &str) -> Option<Class> {
+        let index = self.classes.iter().position(|class| class.name == name)?;
+
+        self.associations.retain(|association| {
+            association.class_name != name && association.associated_class_name != name
+        });
+        self.notes
+            .retain(|note| note.class_name.as_deref() != Some(name));
+
+        Some(self.classes.remove(index))
+    }
+
+    fn classes_diagram_code(&self, identifiers: &mut escape::Identifiers) -> MermaidResult<String> {
+        let code = self
+            .classes
+            .iter()
+            .map(|class| class.generate(identifiers))
+            .collect::<MermaidResult<Vec<String>>>()?
+            .join("\n");
+
+        Ok(code)
+    }
+
+    fn associations_diagram_code(
+        &self,
+        identifiers: &mut escape::Identifiers,
+    ) -> MermaidResult<String> {
+        let code = self
+            .associations
+            .iter()
+            .map(|association| association.generate(identifiers))
+            .collect::<MermaidResult<Vec<String>>>()?
+            .join("\n");
+
+        Ok(code)
+    }
+
+    fn notes_diagram_code(&self, identifiers: &mut escape::Identifiers) -> MermaidResult<String> {
+        let code = self
+            .notes
+            .iter()
+            .map(|note| note.generate(identifiers))
+            .collect::<MermaidResult<Vec<String>>>()?
+            .join("\n");
+
+        Ok(code)
+    }
+}
+
+impl ClassDiagramBuilder {
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.diagram.direction = Some(direction);
+
+        self
+    }
+
+    /// Starts or reopens a class. Subsequent [`ClassDiagramBuilder::attribute`]
+    /// calls are applied to it.
+    pub fn class(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+
+        let index = match self
+            .diagram
+            .classes
+            .iter()
+            .position(|class| class.name == name)
+        {
+            Some(index) => index,
+            None => {
+                self.diagram.classes.push(Class::new(name));
+
+                self.diagram.classes.len() - 1
+            }
+        };
+
+        self.current_class = Some(index);
+
+        self
+    }
+
+    /// Adds an attribute to the class of the latest
+    /// [`ClassDiagramBuilder::class`] call. Ignored when no class was started
+    /// yet.
+    pub fn attribute(mut self, kind: impl Into<String>, name: impl Into<String>) -> Self {
+        if let Some(class) = self
+            .current_class
+            .and_then(|index| self.diagram.classes.get_mut(index))
+        {
+            class.attributes.push(Attribute::new(kind, name));
+        }
+
+        self
+    }
+
+    pub fn association(mut self, association: Association) -> Self {
+        self.diagram.add_association(association);
+
+        self
+    }
+
+    pub fn note(mut self, note: Note) -> Self {
+        self.diagram.add_note(note);
+
+        self
+    }
+
+    pub fn build(self) -> ClassDiagram {
+        self.diagram
+    }
+}
+
+impl Class {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            attributes: vec![],
+        }
+    }
+
+    pub fn attribute(mut self, kind: impl Into<String>, name: impl Into<String>) -> Self {
+        self.attributes.push(Attribute::new(kind, name));
+
+        self
+    }
+
+    fn generate(&self, identifiers: &mut escape::Identifiers) -> MermaidResult<String> {
+        let identifier = identifiers.register(&self.name)?;
+
+        let declaration = if identifier == self.name {
+            identifier
+        } else {
+            format!("{identifier}[\"{}\"]", escape::text(&self.name)?)
+        };
+
+        let attributes_string = self
+            .attributes
+            .iter()
+            .map(|attribute| {
+                let Attribute { kind, name } = attribute;
+
+                Ok(format!(
+                    "        +{} {}",
+                    escape::generic_type(kind)?,
+                    escape::member_name(name)?
+                ))
+            })
+            .collect::<MermaidResult<Vec<String>>>()?
+            .join("\n");
+
+        Ok(format!(
+            r#"    class {declaration} {{
+{attributes_string}
+    }}"#
+        ))
+    }
+}
+
+impl Attribute {
+    pub fn new(kind: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            kind: kind.into(),
+            name: name.into(),
+        }
+    }
+}
+
+impl Association {
+    /// Plain association (`-->`) without cardinalities and a label.
+    pub fn new(class_name: impl Into<String>, associated_class_name: impl Into<String>) -> Self {
+        Self {
+            class_name: class_name.into(),
+            class_cardinality: None,
+            relationship: Relationship::Association,
+            associated_class_name: associated_class_name.into(),
+            associated_class_cardinality: None,
+            label: None,
+        }
+    }
+
+    pub fn relationship(mut self, relationship: Relationship) -> Self {
+        self.relationship = relationship;
+
+        self
+    }
+
+    pub fn cardinalities(
+        mut self,
+        class_cardinality: impl Into<String>,
+        associated_class_cardinality: impl Into<String>,
+    ) -> Self {
+        self.class_cardinality = Some(class_cardinality.into());
+        self.associated_class_cardinality = Some(associated_class_cardinality.into());
+
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+
+        self
+    }
+
+    fn generate(&self, identifiers: &mut escape::Identifiers) -> MermaidResult<String> {
+        let Association {
+            class_name,
+            class_cardinality,
+            relationship,
+            associated_class_name,
+            associated_class_cardinality,
+            label,
+        } = self;
+
+        let class_identifier = identifiers.register(class_name)?;
+        let associated_class_identifier = identifiers.register(associated_class_name)?;
+
+        let class_side = match class_cardinality {
+            Some(cardinality) => {
+                format!("{class_identifier} \"{}\"", escape::text(cardinality)?)
+            }
+            None => class_identifier,
+        };
+
+        let associated_class_side = match associated_class_cardinality {
+            Some(cardinality) => format!(
+                "\"{}\" {associated_class_identifier}",
+                escape::text(cardinality)?
+            ),
+            None => associated_class_identifier,
+        };
+
+        let code = format!(
+            "    {class_side} {} {associated_class_side}",
+            relationship.code()
+        );
+
+        let Some(label) = label else {
+            return Ok(code);
+        };
+
+        Ok(format!("{code} : {}", escape::text(label)?))
+    }
+}
+
+impl<'a> From<&super::ClassDiagram<'a>> for ClassDiagram {
+    fn from(diagram: &super::ClassDiagram<'a>) -> Self {
+        Self {
+            direction: None,
+            classes: diagram.classes.iter().map(Class::from).collect(),
+            associations: diagram.associations.iter().map(Association::from).collect(),
+            notes: vec![],
+        }
+    }
+}
+
+impl<'a> From<&super::Class<'a>> for Class {
+    fn from(class: &super::Class<'a>) -> Self {
+        Self {
+            name: class.name.to_string(),
+            attributes: class
+                .attributes
+                .iter()
+                .map(|attribute| Attribute::new(attribute.kind, attribute.name))
+                .collect(),
+        }
+    }
+}
+
+impl<'a> From<&super::Association<'a>> for Association {
+    fn from(association: &super::Association<'a>) -> Self {
+        Self {
+            class_name: association.class_name.to_string(),
+            class_cardinality: association.class_cardinality.map(str::to_string),
+            relationship: association.relationship,
+            associated_class_name: association.associated_class_name.to_string(),
+            associated_class_cardinality: association
+                .associated_class_cardinality
+                .map(str::to_string),
+            label: association.label.map(str::to_string),
+        }
+    }
+}
@@ -0,0 +1,392 @@
+//! Parser of the mermaid `classDiagram` source code.
+//!
+//! The result of the parsing is an owned [`ClassDiagram`] (also available
+//! as [`ClassDiagramAst`]), which can be turned back into mermaid code with
+//! [`ClassDiagram::generate`].
+
+use super::owned::{Association, Attribute, Class, ClassDiagram};
+use super::{Direction, Note, Relationship};
+use crate::escape;
+use std::collections::HashMap;
+
+pub type ClassDiagramAst = ClassDiagram;
+
+pub type ClassNode = Class;
+
+pub type AttributeNode = Attribute;
+
+pub type AssociationNode = Association;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    /// One-based number of the line where the error was found.
+    pub line: usize,
+
+    /// Description of the problem.
+    pub message: String,
+}
+
+/// Parse `classDiagram` source code.
+///
+/// Supported constructs are class declarations (with or without a body),
+/// typed attributes declared inside of the class body or with the
+/// `ClassName : +Type name` syntax, and relationships with optional
+/// cardinalities and labels, the `direction` statement and notes. Methods
+/// and `%%` comments are skipped.
+///
+/// Classes declared with a label, e.g. `class Order_Item["Order Item"]`, are
+/// named after the label, and generic types written as `Vec~String~` are
+/// translated back into `Vec<String>`.
+pub fn parse(code: &str) -> Result<ClassDiagram, ParseError> {
+    let mut lines = code
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with("%%"));
+
+    match lines.next() {
+        Some((_, "classDiagram")) => {}
+        Some((line, _)) => return Err(ParseError::new(line, "expected `classDiagram` header")),
+        None => return Err(ParseError::new(1, "expected `classDiagram` header")),
+    }
+
+    let mut diagram = ClassDiagram::default();
+    let mut open_class: Option<(usize, Class)> = None;
+    let mut names: HashMap<String, String> = HashMap::new();
+
+    for (line_number, line) in lines {
+        if let Some((_, class)) = open_class.as_mut() {
+            if line == "}" {
+                let (_, class) = open_class.take().unwrap();
+                diagram.add_class(class);
+            } else if let Some(attribute) = parse_member(line_number, line)? {
+                class.attributes.push(attribute);
+            }
+
+            continue;
+        }
+
+        if let Some(code) = line.strip_prefix("direction ") {
+            let direction = Direction::from_code(code.trim()).ok_or_else(|| {
+                ParseError::new(
+                    line_number,
+                    format!("unsupported direction `{}`", code.trim()),
+                )
+            })?;
+
+            diagram.direction = Some(direction);
+
+            continue;
+        }
+
+        if let Some(note) = line.strip_prefix("note ") {
+            diagram.add_note(parse_note(line_number, note)?);
+
+            continue;
+        }
+
+        if let Some(declaration) = line.strip_prefix("class ") {
+            let declaration = declaration.trim();
+
+            let (declaration, is_open) = match declaration.strip_suffix('{') {
+                Some(declaration) => (declaration, true),
+                None => (declaration, false),
+            };
+
+            let (identifier, name) = parse_class_declaration(line_number, declaration)?;
+            names.insert(identifier, name.clone());
+
+            if is_open {
+                open_class = Some((line_number, Class::new(name)));
+            } else {
+                diagram.add_class(Class::new(name));
+            }
+
+            continue;
+        }
+
+        if let Some(association) = parse_association(line_number, line)? {
+            diagram.add_association(association);
+
+            continue;
+        }
+
+        if let Some((name, member)) = line.split_once(':') {
+            let name = parse_class_name(line_number, name)?;
+            let name = names.get(&name).cloned().unwrap_or(name);
+
+            if let Some(attribute) = parse_member(line_number, member.trim())? {
+                diagram.add_class(Class {
+                    name,
+                    attributes: vec![attribute],
+                });
+            }
+
+            continue;
+        }
+
+        return Err(ParseError::new(
+            line_number,
+            format!("unsupported statement `{line}`"),
+        ));
+    }
+
+    if let Some((line_number, class)) = open_class {
+        return Err(ParseError::new(
+            line_number,
+            format!("class `{}` is not closed", class.name),
+        ));
+    }
+
+    for association in diagram.associations.iter_mut() {
+        for name in [
+            &mut association.class_name,
+            &mut association.associated_class_name,
+        ] {
+            if let Some(labeled_name) = names.get(name) {
+                *name = labeled_name.clone();
+            }
+        }
+    }
+
+    for note in diagram.notes.iter_mut() {
+        if let Some(name) = note.class_name.as_mut() {
+            if let Some(labeled_name) = names.get(name) {
+                *name = labeled_name.clone();
+            }
+        }
+    }
+
+    Ok(diagram)
+}
+
+impl ParseError {
+    fn new(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn parse_class_name(line_number: usize, name: &str) -> Result<String, ParseError> {
+    let name = name.trim();
+
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return Err(ParseError::new(
+            line_number,
+            format!("invalid class name `{name}`"),
+        ));
+    }
+
+    Ok(name.to_string())
+}
+
+/// Parses `Name` or `Identifier["Label"]` into the identifier and the name.
+fn parse_class_declaration(
+    line_number: usize,
+    declaration: &str,
+) -> Result<(String, String), ParseError> {
+    let declaration = declaration.trim();
+
+    let Some((identifier, label)) = declaration.split_once('[') else {
+        let name = parse_class_name(line_number, declaration)?;
+
+        return Ok((name.clone(), name));
+    };
+
+    let identifier = parse_class_name(line_number, identifier)?;
+
+    let label = label
+        .strip_suffix(']')
+        .map(str::trim)
+        .filter(|label| is_quoted(label))
+        .ok_or_else(|| ParseError::new(line_number, format!("invalid class label `[{label}`")))?;
+
+    Ok((identifier, unquote(label)))
+}
+
+/// Parses `for ClassName "text"` or `"text"`.
+fn parse_note(line_number: usize, note: &str) -> Result<Note, ParseError> {
+    let note = note.trim();
+
+    let (class_name, text) = match note.strip_prefix("for ") {
+        Some(note) => match note.trim().split_once(char::is_whitespace) {
+            Some((class_name, text)) => (
+                Some(parse_class_name(line_number, class_name)?),
+                text.trim(),
+            ),
+            None => (None, note),
+        },
+        None => (None, note),
+    };
+
+    if !is_quoted(text) {
+        return Err(ParseError::new(
+            line_number,
+            format!("expected quoted note text, got `{text}`"),
+        ));
+    }
+
+    Ok(Note {
+        class_name,
+        text: escape::unescape_note_text(&text[1..text.len() - 1]),
+    })
+}
+
+/// Returns `None` for the members that are not attributes, i.e. methods.
+fn parse_member(line_number: usize, member: &str) -> Result<Option<Attribute>, ParseError> {
+    if member.contains('(') {
+        return Ok(None);
+    }
+
+    let member = member.trim_start_matches(['+', '-', '#', '~']);
+
+    match member.split_whitespace().collect::<Vec<&str>>().as_slice() {
+        [kind, name] => Ok(Some(Attribute::new(
+            escape::unescape_generic_type(kind),
+            *name,
+        ))),
+        _ => Err(ParseError::new(
+            line_number,
+            format!("expected attribute in the `Type name` form, got `{member}`"),
+        )),
+    }
+}
+
+/// Returns `None` when the line is not a relationship statement.
+fn parse_association(line_number: usize, line: &str) -> Result<Option<Association>, ParseError> {
+    let (statement, label) = match line.split_once(':') {
+        Some((statement, label)) => (statement, Some(escape::unescape_text(label.trim()))),
+        None => (line, None),
+    };
+
+    let tokens = tokenize(line_number, statement)?;
+
+    let Some(arrow_index) = tokens.iter().position(|token| parse_arrow(token).is_some()) else {
+        return Ok(None);
+    };
+
+    let (left, right) = (&tokens[..arrow_index], &tokens[arrow_index + 1..]);
+
+    let (left_name, left_cardinality) = match left {
+        [name] => (name, None),
+        [name, cardinality] if is_quoted(cardinality) => (name, Some(unquote(cardinality))),
+        _ => {
+            return Err(ParseError::new(
+                line_number,
+                "invalid left side of relationship",
+            ))
+        }
+    };
+
+    let (right_name, right_cardinality) = match right {
+        [name] => (name, None),
+        [cardinality, name] if is_quoted(cardinality) => (name, Some(unquote(cardinality))),
+        _ => {
+            return Err(ParseError::new(
+                line_number,
+                "invalid right side of relationship",
+            ))
+        }
+    };
+
+    let left_name = parse_class_name(line_number, left_name)?;
+    let right_name = parse_class_name(line_number, right_name)?;
+
+    let (relationship, reversed) = parse_arrow(&tokens[arrow_index]).unwrap();
+
+    let association = if reversed {
+        Association {
+            class_name: right_name,
+            class_cardinality: right_cardinality,
+            relationship,
+            associated_class_name: left_name,
+            associated_class_cardinality: left_cardinality,
+            label: label.filter(|label| !label.is_empty()),
+        }
+    } else {
+        Association {
+            class_name: left_name,
+            class_cardinality: left_cardinality,
+            relationship,
+            associated_class_name: right_name,
+            associated_class_cardinality: right_cardinality,
+            label: label.filter(|label| !label.is_empty()),
+        }
+    };
+
+    Ok(Some(association))
+}
+
+/// Returns relationship and a flag indicating that the arrow is written from
+/// right to left, e.g. `Author <-- Book`.
+fn parse_arrow(token: &str) -> Option<(Relationship, bool)> {
+    let arrow = match token {
+        "-->" => (Relationship::Association, false),
+        "<--" => (Relationship::Association, true),
+        "o--" => (Relationship::Aggregation, false),
+        "--o" => (Relationship::Aggregation, true),
+        "*--" => (Relationship::Composition, false),
+        "--*" => (Relationship::Composition, true),
+        "..>" => (Relationship::Dependency, false),
+        "<.." => (Relationship::Dependency, true),
+        "<|--" => (Relationship::Inheritance, false),
+        "--|>" => (Relationship::Inheritance, true),
+        "--" => (Relationship::Link, false),
+        _ => return None,
+    };
+
+    Some(arrow)
+}
+
+/// Splits a statement by whitespace, keeping quoted strings intact.
+fn tokenize(line_number: usize, statement: &str) -> Result<Vec<String>, ParseError> {
+    let mut tokens = vec![];
+    let mut chars = statement.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        if char.is_whitespace() {
+            continue;
+        }
+
+        let mut token = String::from(char);
+
+        if char == '"' {
+            loop {
+                match chars.next() {
+                    Some('"') => {
+                        token.push('"');
+                        break;
+                    }
+                    Some(char) => token.push(char),
+                    None => return Err(ParseError::new(line_number, "unterminated string")),
+                }
+            }
+        } else {
+            while let Some(char) = chars.next_if(|char| !char.is_whitespace()) {
+                token.push(char);
+            }
+        }
+
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+fn is_quoted(token: &str) -> bool {
+    token.len() >= 2 && token.starts_with('"') && token.ends_with('"')
+}
+
+fn unquote(token: &str) -> String {
+    escape::unescape_text(&token[1..token.len() - 1])
+}
@@ -0,0 +1,638 @@
+//! Standalone rendering of the [`ClassDiagram`] without a browser.
+//!
+//! Classes are laid out in layers following the direction of associations:
+//! starting from the classes nobody points to, each class is placed one layer
+//! below the class it is first reached from. Associations are drawn as straight lines between
+//! the class boxes.
+//!
+//! The diagram direction is respected, notes are not drawn.
+//!
+//! PNG rasterization is available with the `png` feature.
+
+use super::owned::{Association, ClassDiagram};
+use super::{Direction, Relationship};
+use crate::{MermaidError, MermaidResult};
+use std::collections::HashMap;
+
+const FONT_SIZE: f64 = 14.0;
+const CHAR_WIDTH: f64 = FONT_SIZE * 0.6;
+const LINE_HEIGHT: f64 = 22.0;
+const PADDING: f64 = 10.0;
+const MIN_BOX_WIDTH: f64 = 80.0;
+const HORIZONTAL_GAP: f64 = 60.0;
+const VERTICAL_GAP: f64 = 80.0;
+const MARGIN: f64 = 20.0;
+const MARKER_SIZE: f64 = 12.0;
+const PARALLEL_GAP: f64 = 40.0;
+
+/// Renders the diagram as a standalone SVG document.
+pub fn svg(diagram: &ClassDiagram) -> MermaidResult<String> {
+    let layout = Layout::new(diagram)?;
+
+    let mut elements = vec![];
+
+    for class_box in &layout.boxes {
+        elements.push(class_box.svg());
+    }
+
+    let mut pair_counts: HashMap<(&str, &str), usize> = HashMap::new();
+
+    for association in &diagram.associations {
+        *pair_counts.entry(pair(association)).or_default() += 1;
+    }
+
+    let mut pair_indexes: HashMap<(&str, &str), usize> = HashMap::new();
+
+    for association in &diagram.associations {
+        let from = layout.class_box(&association.class_name);
+        let to = layout.class_box(&association.associated_class_name);
+
+        // Associations between the same classes are spread apart.
+        let index = pair_indexes.entry(pair(association)).or_default();
+        let offset =
+            (*index as f64 - (pair_counts[&pair(association)] - 1) as f64 / 2.0) * PARALLEL_GAP;
+        *index += 1;
+
+        elements.push(association_svg(association, from, to, offset));
+    }
+
+    Ok(format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="monospace" font-size="{FONT_SIZE}">
+<rect width="100%" height="100%" fill="#ffffff"/>
+{}
+</svg>
+"##,
+        elements.join("\n"),
+        width = number(layout.width),
+        height = number(layout.height),
+    ))
+}
+
+/// Rasterizes an SVG document produced by [`svg`] into a PNG image, so the
+/// diagram isn't laid out again. Text is drawn with the first monospaced
+/// system font.
+#[cfg(feature = "png")]
+pub fn png(svg: &str) -> MermaidResult<Vec<u8>> {
+    use resvg::{tiny_skia, usvg};
+    use usvg::{fontdb, TreeParsing, TreeTextToPath};
+
+    let render_error = |message: String| MermaidError::Render { message };
+
+    let mut fontdb = fontdb::Database::new();
+    fontdb.load_system_fonts();
+
+    let monospace_family = fontdb
+        .faces()
+        .filter(|face| face.monospaced)
+        .find_map(|face| face.families.first())
+        .map(|(family, _)| family.clone());
+
+    if let Some(family) = monospace_family {
+        fontdb.set_monospace_family(family);
+    }
+
+    let mut tree = usvg::Tree::from_str(svg, &usvg::Options::default())
+        .map_err(|err| render_error(err.to_string()))?;
+    tree.convert_text(&fontdb);
+
+    let tree = resvg::Tree::from_usvg(&tree);
+    let size = tree.size.to_int_size();
+
+    let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height())
+        .ok_or_else(|| render_error(format!("invalid image size {size:?}")))?;
+    tree.render(tiny_skia::Transform::default(), &mut pixmap.as_mut());
+
+    pixmap
+        .encode_png()
+        .map_err(|err| render_error(err.to_string()))
+}
+
+/// Classes connected by the association, independently of the direction.
+fn pair(association: &Association) -> (&str, &str) {
+    let (class_name, associated_class_name) = (
+        association.class_name.as_str(),
+        association.associated_class_name.as_str(),
+    );
+
+    if class_name <= associated_class_name {
+        (class_name, associated_class_name)
+    } else {
+        (associated_class_name, class_name)
+    }
+}
+
+struct Layout {
+    boxes: Vec<ClassBox>,
+    width: f64,
+    height: f64,
+}
+
+struct ClassBox {
+    name: String,
+    members: Vec<String>,
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+#[derive(Clone, Copy)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+impl Layout {
+    fn new(diagram: &ClassDiagram) -> MermaidResult<Self> {
+        let mut boxes: Vec<ClassBox> = vec![];
+
+        for class in &diagram.classes {
+            let members = class
+                .attributes
+                .iter()
+                .map(|attribute| format!("+{} {}", attribute.kind, attribute.name))
+                .collect();
+
+            boxes.push(ClassBox::new(&class.name, members)?);
+        }
+
+        for association in &diagram.associations {
+            for name in [&association.class_name, &association.associated_class_name] {
+                if !boxes.iter().any(|class_box| &class_box.name == name) {
+                    boxes.push(ClassBox::new(name, vec![])?);
+                }
+            }
+
+            for text in [
+                &association.class_cardinality,
+                &association.associated_class_cardinality,
+                &association.label,
+            ]
+            .into_iter()
+            .flatten()
+            {
+                validate_text(text)?;
+            }
+        }
+
+        let layers = layers(&boxes, &diagram.associations);
+
+        let direction = diagram.direction.unwrap_or(Direction::TopToBottom);
+
+        // Layers are stacked along the main axis, boxes of a layer are placed
+        // next to each other along the cross axis.
+        let is_horizontal = matches!(direction, Direction::LeftToRight | Direction::RightToLeft);
+        let (sibling_gap, layer_gap) = if is_horizontal {
+            (VERTICAL_GAP / 2.0, HORIZONTAL_GAP * 2.0)
+        } else {
+            (HORIZONTAL_GAP, VERTICAL_GAP)
+        };
+
+        let main_size = |class_box: &ClassBox| {
+            if is_horizontal {
+                class_box.width
+            } else {
+                class_box.height
+            }
+        };
+        let cross_size = |class_box: &ClassBox| {
+            if is_horizontal {
+                class_box.height
+            } else {
+                class_box.width
+            }
+        };
+
+        let layer_cross_sizes: Vec<f64> = layers
+            .iter()
+            .map(|layer| {
+                let boxes_size: f64 = layer.iter().map(|index| cross_size(&boxes[*index])).sum();
+
+                boxes_size + sibling_gap * (layer.len().saturating_sub(1)) as f64
+            })
+            .collect();
+
+        let content_cross_size = layer_cross_sizes.iter().copied().fold(0.0, f64::max);
+
+        let mut positions = vec![(0.0, 0.0); boxes.len()];
+        let mut main = 0.0;
+
+        for (layer, layer_cross_size) in layers.iter().zip(layer_cross_sizes) {
+            let mut cross = (content_cross_size - layer_cross_size) / 2.0;
+            let mut layer_main_size: f64 = 0.0;
+
+            for index in layer {
+                positions[*index] = (main, cross);
+
+                cross += cross_size(&boxes[*index]) + sibling_gap;
+                layer_main_size = layer_main_size.max(main_size(&boxes[*index]));
+            }
+
+            main += layer_main_size + layer_gap;
+        }
+
+        let content_main_size = if layers.is_empty() {
+            0.0
+        } else {
+            main - layer_gap
+        };
+
+        let is_reversed = matches!(direction, Direction::BottomToTop | Direction::RightToLeft);
+
+        for (class_box, (main, cross)) in boxes.iter_mut().zip(positions) {
+            let main = if is_reversed {
+                content_main_size - main - main_size(class_box)
+            } else {
+                main
+            };
+
+            let (x, y) = if is_horizontal {
+                (main, cross)
+            } else {
+                (cross, main)
+            };
+
+            class_box.x = MARGIN + x;
+            class_box.y = MARGIN + y;
+        }
+
+        let (width, height) = if is_horizontal {
+            (content_main_size, content_cross_size)
+        } else {
+            (content_cross_size, content_main_size)
+        };
+
+        Ok(Self {
+            boxes,
+            width: width + MARGIN * 2.0,
+            height: height + MARGIN * 2.0,
+        })
+    }
+
+    fn class_box(&self, name: &str) -> &ClassBox {
+        self.boxes
+            .iter()
+            .find(|class_box| class_box.name == name)
+            .expect("every associated class has a box")
+    }
+}
+
+/// Groups box indexes into layers. Each box is placed at the depth it is
+/// first reached with from the boxes without incoming associations.
+fn layers(boxes: &[ClassBox], associations: &[Association]) -> Vec<Vec<usize>> {
+    let index_by_name: HashMap<&str, usize> = boxes
+        .iter()
+        .enumerate()
+        .map(|(index, class_box)| (class_box.name.as_str(), index))
+        .collect();
+
+    let edges: Vec<(usize, usize)> = associations
+        .iter()
+        .map(|association| {
+            (
+                index_by_name[association.class_name.as_str()],
+                index_by_name[association.associated_class_name.as_str()],
+            )
+        })
+        .filter(|(from, to)| from != to)
+        .collect();
+
+    let mut depths: Vec<Option<usize>> = vec![None; boxes.len()];
+
+    while let Some(root) = (0..boxes.len())
+        .filter(|index| depths[*index].is_none())
+        .find(|index| {
+            !edges
+                .iter()
+                .any(|(from, to)| to == index && depths[*from].is_none())
+        })
+        .or_else(|| depths.iter().position(Option::is_none))
+    {
+        depths[root] = Some(0);
+
+        let mut queue = std::collections::VecDeque::from([root]);
+
+        while let Some(from) = queue.pop_front() {
+            let depth = depths[from].unwrap_or_default();
+
+            for (_, to) in edges.iter().filter(|(edge_from, _)| *edge_from == from) {
+                if depths[*to].is_none() {
+                    depths[*to] = Some(depth + 1);
+                    queue.push_back(*to);
+                }
+            }
+        }
+    }
+
+    let mut layers: Vec<Vec<usize>> = vec![];
+
+    for (index, depth) in depths.into_iter().enumerate() {
+        let depth = depth.unwrap_or_default();
+
+        if layers.len() <= depth {
+            layers.resize(depth + 1, vec![]);
+        }
+
+        layers[depth].push(index);
+    }
+
+    layers.retain(|layer| !layer.is_empty());
+
+    layers
+}
+
+impl ClassBox {
+    fn new(name: &str, members: Vec<String>) -> MermaidResult<Self> {
+        validate_text(name)?;
+
+        for member in &members {
+            validate_text(member)?;
+        }
+
+        let longest_line = members
+            .iter()
+            .map(|member| member.chars().count())
+            .chain([name.chars().count()])
+            .max()
+            .unwrap_or_default();
+
+        let width = (longest_line as f64 * CHAR_WIDTH + PADDING * 2.0).max(MIN_BOX_WIDTH);
+        let height = LINE_HEIGHT * (members.len() + 1) as f64 + PADDING * 2.0;
+
+        Ok(Self {
+            name: name.to_string(),
+            members,
+            x: 0.0,
+            y: 0.0,
+            width,
+            height,
+        })
+    }
+
+    fn center(&self) -> Point {
+        Point {
+            x: self.x + self.width / 2.0,
+            y: self.y + self.height / 2.0,
+        }
+    }
+
+    /// Point on the box border where the line from the `origin` inside of
+    /// the box towards the `target` point leaves it.
+    fn border_point(&self, target: Point, origin: Point) -> Point {
+        let center = Point {
+            x: origin.x.clamp(self.x, self.x + self.width),
+            y: origin.y.clamp(self.y, self.y + self.height),
+        };
+        let (dx, dy) = (target.x - center.x, target.y - center.y);
+
+        if dx == 0.0 && dy == 0.0 {
+            return center;
+        }
+
+        let scale = |delta: f64, start: f64, size: f64, origin: f64| {
+            if delta > 0.0 {
+                (start + size - origin) / delta
+            } else if delta < 0.0 {
+                (origin - start) / -delta
+            } else {
+                f64::INFINITY
+            }
+        };
+
+        let scale =
+            scale(dx, self.x, self.width, center.x).min(scale(dy, self.y, self.height, center.y));
+
+        Point {
+            x: center.x + dx * scale,
+            y: center.y + dy * scale,
+        }
+    }
+
+    fn svg(&self) -> String {
+        let ClassBox {
+            name,
+            members,
+            x,
+            y,
+            width,
+            height,
+        } = self;
+
+        let header_y = y + PADDING + LINE_HEIGHT;
+
+        let mut elements = vec![
+            format!(
+                r##"<rect x="{}" y="{}" width="{}" height="{}" fill="#ececff" stroke="#9370db"/>"##,
+                number(*x),
+                number(*y),
+                number(*width),
+                number(*height)
+            ),
+            format!(
+                r#"<text x="{}" y="{}" text-anchor="middle" font-weight="bold">{}</text>"#,
+                number(x + width / 2.0),
+                number(header_y - 6.0),
+                xml(name)
+            ),
+            format!(
+                r##"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="#9370db"/>"##,
+                number(*x),
+                number(header_y),
+                number(x + width),
+                number(header_y)
+            ),
+        ];
+
+        for (index, member) in members.iter().enumerate() {
+            elements.push(format!(
+                r#"<text x="{}" y="{}">{}</text>"#,
+                number(x + PADDING),
+                number(header_y + LINE_HEIGHT * (index + 1) as f64 - 6.0),
+                xml(member)
+            ));
+        }
+
+        format!("<g class=\"class\">\n{}\n</g>", elements.join("\n"))
+    }
+}
+
+/// Draws the association line shifted sideways by the `offset`.
+fn association_svg(
+    association: &Association,
+    from: &ClassBox,
+    to: &ClassBox,
+    offset: f64,
+) -> String {
+    let (from_center, to_center) = (from.center(), to.center());
+
+    // The normal is taken in a direction independent way, so that the
+    // associations pointing both ways are shifted consistently.
+    let (_, normal) = if association.class_name <= association.associated_class_name {
+        direction(from_center, to_center)
+    } else {
+        direction(to_center, from_center)
+    };
+
+    let shift = |point: Point| Point {
+        x: point.x + normal.x * offset,
+        y: point.y + normal.y * offset,
+    };
+
+    let start = from.border_point(shift(to_center), shift(from_center));
+    let end = to.border_point(shift(from_center), shift(to_center));
+
+    let dash = match association.relationship {
+        Relationship::Dependency => r#" stroke-dasharray="6 4""#,
+        _ => "",
+    };
+
+    let mut elements = vec![format!(
+        r##"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="#333333"{dash}/>"##,
+        number(start.x),
+        number(start.y),
+        number(end.x),
+        number(end.y)
+    )];
+
+    let marker = match association.relationship {
+        Relationship::Association | Relationship::Dependency => Some((end, start, Marker::Arrow)),
+        Relationship::Aggregation => Some((start, end, Marker::HollowDiamond)),
+        Relationship::Composition => Some((start, end, Marker::FilledDiamond)),
+        Relationship::Inheritance => Some((start, end, Marker::Triangle)),
+        Relationship::Link => None,
+    };
+
+    if let Some((tip, tail, marker)) = marker {
+        elements.push(marker.svg(tip, tail));
+    }
+
+    if let Some(cardinality) = &association.class_cardinality {
+        elements.push(end_label_svg(start, end, cardinality));
+    }
+
+    if let Some(cardinality) = &association.associated_class_cardinality {
+        elements.push(end_label_svg(end, start, cardinality));
+    }
+
+    if let Some(label) = &association.label {
+        elements.push(format!(
+            r##"<text x="{}" y="{}" text-anchor="middle" fill="#333333" stroke="#ffffff" stroke-width="3" paint-order="stroke">{}</text>"##,
+            number((start.x + end.x) / 2.0),
+            number((start.y + end.y) / 2.0 - 4.0),
+            xml(label)
+        ));
+    }
+
+    format!("<g class=\"association\">\n{}\n</g>", elements.join("\n"))
+}
+
+/// Text placed next to the `point` end of the line going to `other`.
+fn end_label_svg(point: Point, other: Point, text: &str) -> String {
+    let (direction, normal) = direction(point, other);
+    let offset = MARKER_SIZE * 1.5;
+
+    format!(
+        r##"<text x="{}" y="{}" text-anchor="middle" fill="#333333" stroke="#ffffff" stroke-width="3" paint-order="stroke">{}</text>"##,
+        number(point.x + direction.x * offset + normal.x * offset),
+        number(point.y + direction.y * offset + normal.y * offset + FONT_SIZE / 3.0),
+        xml(text)
+    )
+}
+
+enum Marker {
+    Arrow,
+    HollowDiamond,
+    FilledDiamond,
+    Triangle,
+}
+
+impl Marker {
+    fn svg(&self, tip: Point, tail: Point) -> String {
+        let (direction, normal) = direction(tip, tail);
+
+        let at = |along: f64, across: f64| Point {
+            x: tip.x + direction.x * along + normal.x * across,
+            y: tip.y + direction.y * along + normal.y * across,
+        };
+
+        let half = MARKER_SIZE / 2.0;
+
+        let (points, fill) = match self {
+            Marker::Arrow => (
+                vec![tip, at(MARKER_SIZE, half), at(MARKER_SIZE, -half)],
+                "#333333",
+            ),
+            Marker::Triangle => (
+                vec![tip, at(MARKER_SIZE, half), at(MARKER_SIZE, -half)],
+                "#ffffff",
+            ),
+            Marker::HollowDiamond => (
+                vec![tip, at(half, half), at(MARKER_SIZE, 0.0), at(half, -half)],
+                "#ffffff",
+            ),
+            Marker::FilledDiamond => (
+                vec![tip, at(half, half), at(MARKER_SIZE, 0.0), at(half, -half)],
+                "#333333",
+            ),
+        };
+
+        let points = points
+            .iter()
+            .map(|point| format!("{},{}", number(point.x), number(point.y)))
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        format!(r##"<polygon points="{points}" fill="{fill}" stroke="#333333"/>"##)
+    }
+}
+
+/// Unit vector from `from` towards `to` and its normal.
+fn direction(from: Point, to: Point) -> (Point, Point) {
+    let (dx, dy) = (to.x - from.x, to.y - from.y);
+    let length = (dx * dx + dy * dy).sqrt();
+
+    if length == 0.0 {
+        return (Point { x: 0.0, y: 0.0 }, Point { x: 0.0, y: 0.0 });
+    }
+
+    let direction = Point {
+        x: dx / length,
+        y: dy / length,
+    };
+    let normal = Point {
+        x: -direction.y,
+        y: direction.x,
+    };
+
+    (direction, normal)
+}
+
+/// Control characters can't be represented in an XML document.
+fn validate_text(text: &str) -> MermaidResult<()> {
+    if text.chars().any(char::is_control) {
+        return Err(MermaidError::UnsupportedText {
+            text: text.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+fn xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Formats coordinates with at most one decimal place.
+fn number(value: f64) -> String {
+    let value = (value * 10.0).round() / 10.0;
+
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{value:.1}")
+    }
+}
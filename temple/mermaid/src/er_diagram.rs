@@ -0,0 +1,83 @@
+pub mod owned;
+
+use crate::MermaidResult;
+
+pub struct ErDiagram<'a> {
+    pub entities: &'a [Entity<'a>],
+    pub relationships: &'a [Relationship<'a>],
+}
+
+pub struct Entity<'a> {
+    pub name: &'a str,
+    pub attributes: &'a [Attribute<'a>],
+}
+
+pub struct Attribute<'a> {
+    pub kind: &'a str,
+    pub name: &'a str,
+    pub key: Option<Key>,
+}
+
+pub struct Relationship<'a> {
+    pub entity_name: &'a str,
+    pub entity_cardinality: Cardinality,
+    pub related_entity_name: &'a str,
+    pub related_entity_cardinality: Cardinality,
+    pub label: &'a str,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Key {
+    Primary,
+
+    Foreign,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Cardinality {
+    ZeroOrOne,
+
+    ExactlyOne,
+
+    ZeroOrMore,
+
+    OneOrMore,
+}
+
+impl<'a> ErDiagram<'a> {
+    /// Generates mermaid code, see [`owned::ErDiagram::generate`].
+    pub fn generate(&self) -> MermaidResult<String> {
+        owned::ErDiagram::from(self).generate()
+    }
+}
+
+impl Key {
+    fn code(&self) -> &'static str {
+        match self {
+            Key::Primary => "PK",
+            Key::Foreign => "FK",
+        }
+    }
+}
+
+impl Cardinality {
+    /// Crow's foot marker placed to the left of the relationship line.
+    fn left_code(&self) -> &'static str {
+        match self {
+            Cardinality::ZeroOrOne => "|o",
+            Cardinality::ExactlyOne => "||",
+            Cardinality::ZeroOrMore => "}o",
+            Cardinality::OneOrMore => "}|",
+        }
+    }
+
+    /// Crow's foot marker placed to the right of the relationship line.
+    fn right_code(&self) -> &'static str {
+        match self {
+            Cardinality::ZeroOrOne => "o|",
+            Cardinality::ExactlyOne => "||",
+            Cardinality::ZeroOrMore => "o{",
+            Cardinality::OneOrMore => "|{",
+        }
+    }
+}
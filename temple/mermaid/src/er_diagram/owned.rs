@@ -0,0 +1,305 @@
+//! Owned ER diagram model, for diagrams that are assembled incrementally.
+
+use super::{Cardinality, Key};
+use crate::{escape, MermaidResult};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ErDiagram {
+    pub entities: Vec<Entity>,
+    pub relationships: Vec<Relationship>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entity {
+    pub name: String,
+    pub attributes: Vec<Attribute>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attribute {
+    pub kind: String,
+    pub name: String,
+    pub key: Option<Key>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Relationship {
+    pub entity_name: String,
+    pub entity_cardinality: Cardinality,
+    pub related_entity_name: String,
+    pub related_entity_cardinality: Cardinality,
+    pub label: String,
+}
+
+/// Fluent [`ErDiagram`] construction.
+///
+/// ```
+/// use mermaid::er_diagram::owned::{ErDiagram, Relationship};
+/// use mermaid::er_diagram::{Cardinality, Key};
+///
+/// let diagram = ErDiagram::builder()
+///     .entity("Book")
+///     .attribute("uuid", "id", Some(Key::Primary))
+///     .attribute("uuid", "author_id", Some(Key::Foreign))
+///     .entity("Author")
+///     .attribute("uuid", "id", Some(Key::Primary))
+///     .relationship(Relationship::new(
+///         "Book",
+///         Cardinality::ZeroOrMore,
+///         "Author",
+///         Cardinality::ExactlyOne,
+///         "Author",
+///     ))
+///     .build();
+///
+/// assert_eq!(diagram.entities.len(), 2);
+/// ```
+#[derive(Default)]
+pub struct ErDiagramBuilder {
+    diagram: ErDiagram,
+}
+
+impl ErDiagram {
+    pub fn builder() -> ErDiagramBuilder {
+        ErDiagramBuilder::default()
+    }
+
+    pub fn add_entity(&mut self, entity: Entity) -> &mut Self {
+        self.entities.push(entity);
+
+        self
+    }
+
+    pub fn add_relationship(&mut self, relationship: Relationship) -> &mut Self {
+        self.relationships.push(relationship);
+
+        self
+    }
+
+    pub fn entity_mut(&mut self, name: &str) -> Option<&mut Entity> {
+        self.entities.iter_mut().find(|entity| entity.name == name)
+    }
+
+    /// Generates mermaid code. Names that are not valid mermaid identifiers
+    /// are sanitized, and the original name is kept as the entity alias.
+    pub fn generate(&self) -> MermaidResult<String> {
+        let ErDiagram {
+            entities,
+            relationships,
+        } = self;
+
+        let mut identifiers = escape::Identifiers::default();
+
+        let code = "erDiagram".to_string();
+
+        let code = if entities.is_empty() {
+            code
+        } else {
+            format!(
+                "{code}\n{}\n",
+                self.entities_diagram_code(&mut identifiers)?
+            )
+        };
+
+        let code = if relationships.is_empty() {
+            code
+        } else {
+            format!(
+                "{code}\n{}\n",
+                self.relationships_diagram_code(&mut identifiers)?
+            )
+        };
+
+        Ok(code)
+    }
+
+    fn entities_diagram_code(
+        &self,
+        identifiers: &mut escape::Identifiers,
+    ) -> MermaidResult<String> {
+        let code = self
+            .entities
+            .iter()
+            .map(|entity| entity.generate(identifiers))
+            .collect::<MermaidResult<Vec<String>>>()?
+            .join("\n");
+
+        Ok(code)
+    }
+
+    fn relationships_diagram_code(
+        &self,
+        identifiers: &mut escape::Identifiers,
+    ) -> MermaidResult<String> {
+        let code = self
+            .relationships
+            .iter()
+            .map(|relationship| relationship.generate(identifiers))
+            .collect::<MermaidResult<Vec<String>>>()?
+            .join("\n");
+
+        Ok(code)
+    }
+}
+
+impl ErDiagramBuilder {
+    /// Starts a new entity. Subsequent [`ErDiagramBuilder::attribute`] calls
+    /// are applied to it.
+    pub fn entity(mut self, name: impl Into<String>) -> Self {
+        self.diagram.add_entity(Entity::new(name));
+
+        self
+    }
+
+    /// Adds an attribute to the most recently started entity. Ignored when
+    /// no entity was started yet.
+    pub fn attribute(
+        mut self,
+        kind: impl Into<String>,
+        name: impl Into<String>,
+        key: Option<Key>,
+    ) -> Self {
+        if let Some(entity) = self.diagram.entities.last_mut() {
+            entity.attributes.push(Attribute::new(kind, name, key));
+        }
+
+        self
+    }
+
+    pub fn relationship(mut self, relationship: Relationship) -> Self {
+        self.diagram.add_relationship(relationship);
+
+        self
+    }
+
+    pub fn build(self) -> ErDiagram {
+        self.diagram
+    }
+}
+
+impl Entity {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            attributes: vec![],
+        }
+    }
+
+    fn generate(&self, identifiers: &mut escape::Identifiers) -> MermaidResult<String> {
+        let identifier = identifiers.register(&self.name)?;
+
+        let declaration = if identifier == self.name {
+            identifier
+        } else {
+            format!("{identifier}[\"{}\"]", escape::text(&self.name)?)
+        };
+
+        let attributes_string = self
+            .attributes
+            .iter()
+            .map(|attribute| {
+                let Attribute { kind, name, key } = attribute;
+
+                let kind = escape::generic_type(kind)?;
+                let name = escape::member_name(name)?;
+
+                Ok(match key {
+                    Some(key) => format!("        {kind} {name} {}", key.code()),
+                    None => format!("        {kind} {name}"),
+                })
+            })
+            .collect::<MermaidResult<Vec<String>>>()?
+            .join("\n");
+
+        Ok(format!(
+            r#"    {declaration} {{
+{attributes_string}
+    }}"#
+        ))
+    }
+}
+
+impl Attribute {
+    pub fn new(kind: impl Into<String>, name: impl Into<String>, key: Option<Key>) -> Self {
+        Self {
+            kind: kind.into(),
+            name: name.into(),
+            key,
+        }
+    }
+}
+
+impl Relationship {
+    pub fn new(
+        entity_name: impl Into<String>,
+        entity_cardinality: Cardinality,
+        related_entity_name: impl Into<String>,
+        related_entity_cardinality: Cardinality,
+        label: impl Into<String>,
+    ) -> Self {
+        Self {
+            entity_name: entity_name.into(),
+            entity_cardinality,
+            related_entity_name: related_entity_name.into(),
+            related_entity_cardinality,
+            label: label.into(),
+        }
+    }
+
+    fn generate(&self, identifiers: &mut escape::Identifiers) -> MermaidResult<String> {
+        let Relationship {
+            entity_name,
+            entity_cardinality,
+            related_entity_name,
+            related_entity_cardinality,
+            label,
+        } = self;
+
+        Ok(format!(
+            "    {} {}--{} {} : \"{}\"",
+            identifiers.register(entity_name)?,
+            entity_cardinality.left_code(),
+            related_entity_cardinality.right_code(),
+            identifiers.register(related_entity_name)?,
+            escape::text(label)?
+        ))
+    }
+}
+
+impl<'a> From<&super::ErDiagram<'a>> for ErDiagram {
+    fn from(diagram: &super::ErDiagram<'a>) -> Self {
+        Self {
+            entities: diagram.entities.iter().map(Entity::from).collect(),
+            relationships: diagram
+                .relationships
+                .iter()
+                .map(Relationship::from)
+                .collect(),
+        }
+    }
+}
+
+impl<'a> From<&super::Entity<'a>> for Entity {
+    fn from(entity: &super::Entity<'a>) -> Self {
+        Self {
+            name: entity.name.to_string(),
+            attributes: entity
+                .attributes
+                .iter()
+                .map(|attribute| Attribute::new(attribute.kind, attribute.name, attribute.key))
+                .collect(),
+        }
+    }
+}
+
+impl<'a> From<&super::Relationship<'a>> for Relationship {
+    fn from(relationship: &super::Relationship<'a>) -> Self {
+        Self::new(
+            relationship.entity_name,
+            relationship.entity_cardinality,
+            relationship.related_entity_name,
+            relationship.related_entity_cardinality,
+            relationship.label,
+        )
+    }
+}
@@ -0,0 +1,238 @@
+//! Helpers that turn arbitrary names and text into valid mermaid code.
+
+use crate::{MermaidError, MermaidResult};
+use std::collections::HashMap;
+
+/// Unique mermaid identifiers for the names used in a diagram.
+#[derive(Default)]
+pub struct Identifiers {
+    identifiers: HashMap<String, String>,
+    names: HashMap<String, String>,
+}
+
+impl Identifiers {
+    /// Registers a name and returns its identifier. Registering the same
+    /// name again returns the same identifier.
+    pub fn register(&mut self, name: &str) -> MermaidResult<String> {
+        if let Some(identifier) = self.identifiers.get(name) {
+            return Ok(identifier.clone());
+        }
+
+        let identifier = identifier(name)?;
+
+        if let Some(other_name) = self.names.get(&identifier) {
+            return Err(MermaidError::IdentifierCollision {
+                name: name.to_string(),
+                other_name: other_name.clone(),
+                identifier,
+            });
+        }
+
+        self.identifiers
+            .insert(name.to_string(), identifier.clone());
+        self.names.insert(identifier.clone(), name.to_string());
+
+        Ok(identifier)
+    }
+}
+
+/// Replaces characters that are not allowed in mermaid identifiers with `_`.
+pub fn identifier(name: &str) -> MermaidResult<String> {
+    let name = name.trim();
+
+    if !name.chars().any(|char| char.is_alphanumeric()) {
+        return Err(MermaidError::EmptyIdentifier);
+    }
+
+    let identifier = name
+        .chars()
+        .map(|char| {
+            if char.is_alphanumeric() || char == '_' || char == '-' {
+                char
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    Ok(identifier)
+}
+
+/// Attribute names can't contain whitespace.
+pub fn member_name(name: &str) -> MermaidResult<String> {
+    let name = name.trim();
+
+    if name.is_empty() {
+        return Err(MermaidError::EmptyIdentifier);
+    }
+
+    Ok(name.split_whitespace().collect::<Vec<&str>>().join("_"))
+}
+
+/// Translates `Vec<String>` into mermaid `Vec~String~` syntax.
+pub fn generic_type(kind: &str) -> MermaidResult<String> {
+    let unsupported_type = || MermaidError::UnsupportedType {
+        kind: kind.to_string(),
+    };
+
+    let mut depth: usize = 0;
+
+    for char in kind.chars() {
+        match char {
+            '<' => depth += 1,
+            '>' if depth > 0 => depth -= 1,
+            '>' | ',' | '~' => return Err(unsupported_type()),
+            _ => {}
+        }
+    }
+
+    if depth != 0 {
+        return Err(unsupported_type());
+    }
+
+    Ok(member_name(kind)?.replace(['<', '>'], "~"))
+}
+
+/// Translates mermaid `Vec~String~` syntax back into `Vec<String>`. A tilde
+/// followed by a type name opens a generic, any other tilde closes it.
+pub fn unescape_generic_type(kind: &str) -> String {
+    let chars: Vec<char> = kind.chars().collect();
+
+    chars
+        .iter()
+        .enumerate()
+        .map(|(index, char)| match char {
+            '~' if chars
+                .get(index + 1)
+                .is_some_and(|next| next.is_alphanumeric() || *next == '_') =>
+            {
+                '<'
+            }
+            '~' => '>',
+            char => *char,
+        })
+        .collect()
+}
+
+/// Escapes quotes and angle brackets with mermaid entity codes.
+pub fn text(text: &str) -> MermaidResult<String> {
+    if text.chars().any(|char| char.is_control()) {
+        return Err(MermaidError::UnsupportedText {
+            text: text.to_string(),
+        });
+    }
+
+    Ok(text
+        .replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;"))
+}
+
+/// Notes can span multiple lines, line breaks are written as `\n`.
+pub fn note_text(note: &str) -> MermaidResult<String> {
+    let lines = note
+        .lines()
+        .map(text)
+        .collect::<MermaidResult<Vec<String>>>()?;
+
+    Ok(lines.join("\\n"))
+}
+
+pub fn unescape_note_text(note: &str) -> String {
+    unescape_text(&note.replace("\\n", "\n"))
+}
+
+pub fn unescape_text(text: &str) -> String {
+    text.replace("#quot;", "\"")
+        .replace("#lt;", "<")
+        .replace("#gt;", ">")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_sanitizes_identifiers() {
+        assert_eq!(identifier("Order Item"), Ok("Order_Item".to_string()));
+        assert_eq!(identifier("Book"), Ok("Book".to_string()));
+        assert_eq!(identifier(" "), Err(MermaidError::EmptyIdentifier));
+    }
+
+    #[test]
+    fn it_reports_identifier_collisions() {
+        let mut identifiers = Identifiers::default();
+
+        assert_eq!(
+            identifiers.register("Order Item"),
+            Ok("Order_Item".to_string())
+        );
+        assert_eq!(
+            identifiers.register("Order Item"),
+            Ok("Order_Item".to_string())
+        );
+        assert_eq!(
+            identifiers.register("Order.Item"),
+            Err(MermaidError::IdentifierCollision {
+                name: "Order.Item".to_string(),
+                other_name: "Order Item".to_string(),
+                identifier: "Order_Item".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn it_translates_generic_types() {
+        assert_eq!(generic_type("Vec<String>"), Ok("Vec~String~".to_string()));
+        assert_eq!(
+            generic_type("List<List<int>>"),
+            Ok("List~List~int~~".to_string())
+        );
+        assert_eq!(
+            generic_type("Map<K, V>"),
+            Err(MermaidError::UnsupportedType {
+                kind: "Map<K, V>".to_string()
+            })
+        );
+        assert_eq!(
+            generic_type("Vec<String"),
+            Err(MermaidError::UnsupportedType {
+                kind: "Vec<String".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn it_translates_generic_types_back() {
+        assert_eq!(unescape_generic_type("Vec~String~"), "Vec<String>");
+        assert_eq!(unescape_generic_type("List~List~int~~"), "List<List<int>>");
+        assert_eq!(unescape_generic_type("String"), "String");
+    }
+
+    #[test]
+    fn it_escapes_text() {
+        assert_eq!(
+            text(r#"Says "hi" <loudly>"#),
+            Ok("Says #quot;hi#quot; #lt;loudly#gt;".to_string())
+        );
+        assert_eq!(
+            text("two\nlines"),
+            Err(MermaidError::UnsupportedText {
+                text: "two\nlines".to_string()
+            })
+        );
+        assert_eq!(unescape_text("#quot;hi#quot;"), "\"hi\"");
+    }
+
+    #[test]
+    fn it_escapes_note_text() {
+        assert_eq!(
+            note_text("Book\r\nwith <pages>"),
+            Ok("Book\\nwith #lt;pages#gt;".to_string())
+        );
+        assert_eq!(
+            unescape_note_text("Book\\nwith #lt;pages#gt;"),
+            "Book\nwith <pages>"
+        );
+    }
+}
@@ -1 +1,7 @@
 pub mod class_diagram;
+pub mod er_diagram;
+
+mod escape;
+mod result;
+
+pub use result::{MermaidError, MermaidResult};
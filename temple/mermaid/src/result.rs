@@ -0,0 +1,49 @@
+pub type MermaidResult<T> = Result<T, MermaidError>;
+
+/// Reasons why a diagram can't be represented as mermaid code.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MermaidError {
+    /// Class or entity name is empty or consists only of unsupported characters.
+    EmptyIdentifier,
+
+    /// Two different names are sanitized into the same identifier.
+    IdentifierCollision {
+        name: String,
+        other_name: String,
+        identifier: String,
+    },
+
+    /// Type can't be expressed with mermaid generics, e.g. `Map<K, V>`.
+    UnsupportedType { kind: String },
+
+    /// Text contains characters that can't be escaped, e.g. line breaks.
+    UnsupportedText { text: String },
+
+    /// Diagram can't be rendered into an image.
+    Render { message: String },
+}
+
+impl std::fmt::Display for MermaidError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MermaidError::EmptyIdentifier => write!(f, "identifier can't be blank"),
+            MermaidError::IdentifierCollision {
+                name,
+                other_name,
+                identifier,
+            } => write!(
+                f,
+                "`{name}` and `{other_name}` share the same identifier `{identifier}`"
+            ),
+            MermaidError::UnsupportedType { kind } => {
+                write!(f, "type `{kind}` can't be represented in mermaid")
+            }
+            MermaidError::UnsupportedText { text } => {
+                write!(f, "text `{text}` can't be represented in mermaid")
+            }
+            MermaidError::Render { message } => write!(f, "diagram can't be rendered, {message}"),
+        }
+    }
+}
+
+impl std::error::Error for MermaidError {}
@@ -0,0 +1,160 @@
+use mermaid::class_diagram::{
+    parser::{parse, AssociationNode, AttributeNode, ClassDiagramAst, ClassNode, ParseError},
+    Direction, Note, Relationship,
+};
+
+fn manifest_dir() -> String {
+    std::env::var("CARGO_MANIFEST_DIR").unwrap()
+}
+
+fn resources_dir() -> String {
+    "tests/resources/class_diagram".to_string()
+}
+
+fn diagram_code(file_name: &str) -> String {
+    let path = format!("{}/{}/{file_name}", manifest_dir(), resources_dir());
+
+    std::fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn it_round_trips_generated_class_diagrams() {
+    for file_name in [
+        "book_full.mermaid",
+        "book_classes.mermaid",
+        "book_associations.mermaid",
+        "book_cardinalities.mermaid",
+        "order_escaped.mermaid",
+        "book_notes.mermaid",
+    ] {
+        let code = diagram_code(file_name);
+
+        let ast = parse(&code).unwrap();
+
+        assert_eq!(ast.generate().unwrap(), code, "{file_name}");
+    }
+}
+
+#[test]
+fn it_parses_hand_written_class_diagram() {
+    let ast = parse(&diagram_code("library_handwritten.mermaid")).unwrap();
+
+    assert_eq!(
+        ast,
+        ClassDiagramAst {
+            classes: vec![
+                ClassNode {
+                    name: "Library".to_string(),
+                    attributes: vec![AttributeNode {
+                        kind: "String".to_string(),
+                        name: "name".to_string(),
+                    }],
+                },
+                ClassNode {
+                    name: "Member".to_string(),
+                    attributes: vec![
+                        AttributeNode {
+                            kind: "String".to_string(),
+                            name: "email".to_string(),
+                        },
+                        AttributeNode {
+                            kind: "Integer".to_string(),
+                            name: "age".to_string(),
+                        },
+                    ],
+                },
+            ],
+            associations: vec![
+                AssociationNode {
+                    class_name: "Library".to_string(),
+                    class_cardinality: Some("1".to_string()),
+                    relationship: Relationship::Aggregation,
+                    associated_class_name: "Member".to_string(),
+                    associated_class_cardinality: Some("*".to_string()),
+                    label: Some("Members".to_string()),
+                },
+                AssociationNode {
+                    class_name: "Book".to_string(),
+                    class_cardinality: None,
+                    relationship: Relationship::Association,
+                    associated_class_name: "Library".to_string(),
+                    associated_class_cardinality: None,
+                    label: Some("Library".to_string()),
+                },
+            ],
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn it_parses_labeled_classes_and_generic_types() {
+    let diagram = parse(&diagram_code("order_escaped.mermaid")).unwrap();
+
+    assert_eq!(diagram.classes[0].name, "Order Item");
+    assert_eq!(diagram.classes[0].attributes[1].kind, "Option<Vec<u8>>");
+    assert_eq!(diagram.associations[0].associated_class_name, "Order Item");
+    assert_eq!(
+        diagram.associations[0].label.as_deref(),
+        Some("Items <ordered>")
+    );
+    assert_eq!(diagram.associations[1].class_name, "Order Item");
+}
+
+#[test]
+fn it_parses_direction_and_notes() {
+    let diagram = parse(&diagram_code("book_notes.mermaid")).unwrap();
+
+    assert_eq!(diagram.direction, Some(Direction::LeftToRight));
+    assert_eq!(
+        diagram.notes,
+        vec![
+            Note::class("Book", "Printed book.\ntitle: Full \"title\""),
+            Note::class("Order Item", "Line of an order"),
+            Note::new("Library domain"),
+        ]
+    );
+}
+
+#[test]
+fn it_returns_parse_errors() {
+    let test_table = [
+        ("", 1, "expected `classDiagram` header"),
+        ("flowchart LR", 1, "expected `classDiagram` header"),
+        (
+            "classDiagram\n    class Book {",
+            2,
+            "class `Book` is not closed",
+        ),
+        (
+            "classDiagram\n    class Book {\n        +String\n    }",
+            3,
+            "expected attribute in the `Type name` form, got `String`",
+        ),
+        (
+            "classDiagram\n    direction XY",
+            2,
+            "unsupported direction `XY`",
+        ),
+        (
+            "classDiagram\n    note for Book text",
+            2,
+            "expected quoted note text, got `text`",
+        ),
+        (
+            "classDiagram\n    Book <|.. Author",
+            2,
+            "unsupported statement `Book <|.. Author`",
+        ),
+    ];
+
+    for (code, line, message) in test_table {
+        assert_eq!(
+            parse(code).unwrap_err(),
+            ParseError {
+                line,
+                message: message.to_string()
+            }
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use mermaid::{
+    class_diagram::{
+        owned::{Association, ClassDiagram},
+        render, Direction, Relationship,
+    },
+    MermaidError,
+};
+
+fn manifest_dir() -> String {
+    std::env::var("CARGO_MANIFEST_DIR").unwrap()
+}
+
+fn resources_dir() -> String {
+    "tests/resources/class_diagram".to_string()
+}
+
+fn diagram_code(file_name: &str) -> String {
+    let path = format!("{}/{}/{file_name}", manifest_dir(), resources_dir());
+
+    std::fs::read_to_string(path).unwrap()
+}
+
+fn book_diagram() -> ClassDiagram {
+    ClassDiagram::builder()
+        .class("Book")
+        .attribute("String", "title")
+        .attribute("Integer", "year")
+        .class("Author")
+        .attribute("String", "name")
+        .association(
+            Association::new("Book", "Author")
+                .cardinalities("*", "1")
+                .label("Author"),
+        )
+        .association(
+            Association::new("Author", "Book")
+                .relationship(Relationship::Aggregation)
+                .cardinalities("1", "*")
+                .label("Books"),
+        )
+        .association(
+            Association::new("Author", "Biography")
+                .relationship(Relationship::Composition)
+                .cardinalities("1", "0..1"),
+        )
+        .build()
+}
+
+#[test]
+fn it_renders_class_diagram_as_svg() {
+    assert_eq!(
+        render::svg(&book_diagram()).unwrap(),
+        diagram_code("book_rendered.svg")
+    );
+}
+
+#[test]
+fn it_renders_class_diagram_in_direction() {
+    let mut diagram = book_diagram();
+    diagram.direction = Some(Direction::LeftToRight);
+
+    let svg = render::svg(&diagram).unwrap();
+
+    // Book, Author and Biography are laid out left to right in three layers.
+    assert!(
+        svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="625.6" height="126""#)
+    );
+
+    diagram.direction = Some(Direction::BottomToTop);
+
+    let svg = render::svg(&diagram).unwrap();
+
+    assert!(svg.contains(r#"<rect x="20" y="286" width="129.2" height="86""#));
+}
+
+#[test]
+fn it_renders_empty_class_diagram_as_svg() {
+    let svg = render::svg(&ClassDiagram::default()).unwrap();
+
+    assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="40" height="40""#));
+}
+
+#[test]
+fn it_escapes_svg_text() {
+    let diagram = ClassDiagram::builder()
+        .class("Order & Item")
+        .attribute("Vec<String>", "tags")
+        .build();
+
+    let svg = render::svg(&diagram).unwrap();
+
+    assert!(svg.contains(">Order &amp; Item</text>"));
+    assert!(svg.contains(">+Vec&lt;String&gt; tags</text>"));
+}
+
+#[test]
+fn it_returns_error_for_text_that_cannot_be_rendered() {
+    let diagram = ClassDiagram::builder().class("two\nlines").build();
+
+    assert_eq!(
+        render::svg(&diagram),
+        Err(MermaidError::UnsupportedText {
+            text: "two\nlines".to_string()
+        })
+    );
+}
+
+#[cfg(feature = "png")]
+#[test]
+fn it_renders_class_diagram_as_png() {
+    let svg = render::svg(&book_diagram()).unwrap();
+
+    let png = render::png(&svg).unwrap();
+
+    assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+}
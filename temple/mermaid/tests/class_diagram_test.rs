@@ -1,4 +1,7 @@
-use mermaid::class_diagram::{Association, Attribute, Class, ClassDiagram};
+use mermaid::class_diagram::{
+    owned, Association, Attribute, Class, ClassDiagram, Direction, Note, Relationship,
+};
+use mermaid::MermaidError;
 
 fn manifest_dir() -> String {
     std::env::var("CARGO_MANIFEST_DIR").unwrap()
@@ -19,8 +22,11 @@ fn it_generates_class_diagram_with_classes_and_associations() {
     let class_diagram = ClassDiagram {
         associations: &[Association {
             class_name: "Book",
+            class_cardinality: None,
+            relationship: Relationship::Association,
             associated_class_name: "Author",
-            description: Some("Belongs to Author"),
+            associated_class_cardinality: None,
+            label: Some("Belongs to Author"),
         }],
         classes: &[
             Class {
@@ -64,7 +70,10 @@ fn it_generates_class_diagram_with_classes_and_associations() {
         ],
     };
 
-    assert_eq!(class_diagram.generate(), diagram_code("book_full.mermaid"));
+    assert_eq!(
+        class_diagram.generate().unwrap(),
+        diagram_code("book_full.mermaid")
+    );
 }
 
 #[test]
@@ -114,7 +123,7 @@ fn it_generates_class_diagram_with_classes_only() {
     };
 
     assert_eq!(
-        class_diagram.generate(),
+        class_diagram.generate().unwrap(),
         diagram_code("book_classes.mermaid")
     );
 }
@@ -124,14 +133,223 @@ fn it_generates_class_diagram_with_associations_only() {
     let class_diagram = ClassDiagram {
         associations: &[Association {
             class_name: "Book",
+            class_cardinality: None,
+            relationship: Relationship::Association,
             associated_class_name: "Author",
-            description: Some("Belongs to Author"),
+            associated_class_cardinality: None,
+            label: Some("Belongs to Author"),
         }],
         classes: &[],
     };
 
     assert_eq!(
-        class_diagram.generate(),
+        class_diagram.generate().unwrap(),
         diagram_code("book_associations.mermaid")
     );
 }
+
+#[test]
+fn it_generates_class_diagram_with_association_cardinalities() {
+    let class_diagram = ClassDiagram {
+        associations: &[
+            Association {
+                class_name: "Book",
+                class_cardinality: Some("*"),
+                relationship: Relationship::Association,
+                associated_class_name: "Author",
+                associated_class_cardinality: Some("1"),
+                label: Some("Author"),
+            },
+            Association {
+                class_name: "Author",
+                class_cardinality: Some("1"),
+                relationship: Relationship::Composition,
+                associated_class_name: "Biography",
+                associated_class_cardinality: Some("0..1"),
+                label: Some("Biography"),
+            },
+            Association {
+                class_name: "Author",
+                class_cardinality: Some("1"),
+                relationship: Relationship::Aggregation,
+                associated_class_name: "Book",
+                associated_class_cardinality: Some("*"),
+                label: None,
+            },
+        ],
+        classes: &[],
+    };
+
+    assert_eq!(
+        class_diagram.generate().unwrap(),
+        diagram_code("book_cardinalities.mermaid")
+    );
+}
+
+#[test]
+fn it_builds_class_diagram_fluently() {
+    let class_diagram = owned::ClassDiagram::builder()
+        .class("Book")
+        .attribute("String", "title")
+        .attribute("Integer", "year")
+        .attribute("Boolean", "is_new")
+        .class("Author")
+        .attribute("String", "first_name")
+        .attribute("String", "last_name")
+        .attribute("Integer", "number_of_books")
+        .attribute("Boolean", "is_bestseller")
+        .association(owned::Association::new("Book", "Author").label("Belongs to Author"))
+        .build();
+
+    assert_eq!(
+        class_diagram.generate().unwrap(),
+        diagram_code("book_full.mermaid")
+    );
+}
+
+#[test]
+fn it_mutates_class_diagram_after_construction() {
+    let mut class_diagram = owned::ClassDiagram::builder()
+        .class("Book")
+        .attribute("String", "title")
+        .class("Publisher")
+        .association(owned::Association::new("Book", "Publisher"))
+        .build();
+
+    class_diagram
+        .add_class(owned::Class::new("Book").attribute("Integer", "year"))
+        .add_class(owned::Class::new("Author").attribute("String", "first_name"))
+        .add_association(owned::Association::new("Book", "Author"));
+
+    class_diagram.remove_class("Publisher");
+
+    if let Some(class) = class_diagram.class_mut("Author") {
+        class
+            .attributes
+            .push(owned::Attribute::new("String", "last_name"));
+    }
+
+    assert_eq!(
+        class_diagram,
+        owned::ClassDiagram::builder()
+            .class("Book")
+            .attribute("String", "title")
+            .attribute("Integer", "year")
+            .class("Author")
+            .attribute("String", "first_name")
+            .attribute("String", "last_name")
+            .association(owned::Association::new("Book", "Author"))
+            .build()
+    );
+}
+
+#[test]
+fn it_escapes_identifiers_generic_types_and_labels() {
+    let class_diagram = owned::ClassDiagram::builder()
+        .class("Order Item")
+        .attribute("Vec<String>", "tags")
+        .attribute("Option<Vec<u8>>", "unit price")
+        .class("Order")
+        .attribute("String", "number")
+        .association(
+            owned::Association::new("Order", "Order Item")
+                .relationship(Relationship::Aggregation)
+                .cardinalities("1", "*")
+                .label("Items <ordered>"),
+        )
+        .association(
+            owned::Association::new("Order Item", "Order")
+                .cardinalities("*", "1")
+                .label("\"Order\""),
+        )
+        .build();
+
+    assert_eq!(
+        class_diagram.generate().unwrap(),
+        diagram_code("order_escaped.mermaid")
+    );
+}
+
+#[test]
+fn it_returns_errors_for_names_that_cannot_be_represented() {
+    let test_table = [
+        (
+            owned::ClassDiagram::builder()
+                .class("Order Item")
+                .class("Order-Item")
+                .class("Order.Item")
+                .build(),
+            MermaidError::IdentifierCollision {
+                name: "Order.Item".to_string(),
+                other_name: "Order Item".to_string(),
+                identifier: "Order_Item".to_string(),
+            },
+        ),
+        (
+            owned::ClassDiagram::builder().class("???").build(),
+            MermaidError::EmptyIdentifier,
+        ),
+        (
+            owned::ClassDiagram::builder()
+                .class("Order")
+                .attribute("HashMap<String, u8>", "totals")
+                .build(),
+            MermaidError::UnsupportedType {
+                kind: "HashMap<String, u8>".to_string(),
+            },
+        ),
+        (
+            owned::ClassDiagram::builder()
+                .association(owned::Association::new("Order", "Item").label("two\nlines"))
+                .build(),
+            MermaidError::UnsupportedText {
+                text: "two\nlines".to_string(),
+            },
+        ),
+    ];
+
+    for (class_diagram, error) in test_table {
+        assert_eq!(class_diagram.generate(), Err(error));
+    }
+}
+
+#[test]
+fn it_generates_class_diagram_with_direction_and_notes() {
+    let class_diagram = owned::ClassDiagram::builder()
+        .direction(Direction::LeftToRight)
+        .class("Book")
+        .attribute("String", "title")
+        .class("Order Item")
+        .association(owned::Association::new("Order Item", "Book").label("Book"))
+        .note(Note::class("Book", "Printed book.\ntitle: Full \"title\""))
+        .note(Note::class("Order Item", "Line of an order"))
+        .note(Note::new("Library domain"))
+        .build();
+
+    assert_eq!(
+        class_diagram.generate().unwrap(),
+        diagram_code("book_notes.mermaid")
+    );
+}
+
+#[test]
+fn it_adds_attributes_to_the_reopened_class() {
+    let class_diagram = owned::ClassDiagram::builder()
+        .attribute("String", "ignored")
+        .class("Book")
+        .attribute("String", "title")
+        .class("Author")
+        .class("Book")
+        .attribute("Integer", "year")
+        .build();
+
+    assert_eq!(
+        class_diagram.classes,
+        vec![
+            owned::Class::new("Book")
+                .attribute("String", "title")
+                .attribute("Integer", "year"),
+            owned::Class::new("Author"),
+        ]
+    );
+}
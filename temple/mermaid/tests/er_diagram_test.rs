@@ -0,0 +1,182 @@
+use mermaid::er_diagram::{owned, Attribute, Cardinality, Entity, ErDiagram, Key, Relationship};
+
+fn manifest_dir() -> String {
+    std::env::var("CARGO_MANIFEST_DIR").unwrap()
+}
+
+fn resources_dir() -> String {
+    "tests/resources/er_diagram".to_string()
+}
+
+fn diagram_code(file_name: &str) -> String {
+    let path = format!("{}/{}/{file_name}", manifest_dir(), resources_dir());
+
+    std::fs::read_to_string(path).unwrap()
+}
+
+fn book_entities<'a>() -> [Entity<'a>; 2] {
+    [
+        Entity {
+            name: "Book",
+            attributes: &[
+                Attribute {
+                    kind: "uuid",
+                    name: "id",
+                    key: Some(Key::Primary),
+                },
+                Attribute {
+                    kind: "String",
+                    name: "title",
+                    key: None,
+                },
+                Attribute {
+                    kind: "Integer",
+                    name: "year",
+                    key: None,
+                },
+                Attribute {
+                    kind: "uuid",
+                    name: "author_id",
+                    key: Some(Key::Foreign),
+                },
+            ],
+        },
+        Entity {
+            name: "Author",
+            attributes: &[
+                Attribute {
+                    kind: "uuid",
+                    name: "id",
+                    key: Some(Key::Primary),
+                },
+                Attribute {
+                    kind: "String",
+                    name: "first_name",
+                    key: None,
+                },
+                Attribute {
+                    kind: "String",
+                    name: "last_name",
+                    key: None,
+                },
+            ],
+        },
+    ]
+}
+
+#[test]
+fn it_generates_er_diagram_with_entities_and_relationships() {
+    let er_diagram = ErDiagram {
+        entities: &book_entities(),
+        relationships: &[Relationship {
+            entity_name: "Book",
+            entity_cardinality: Cardinality::ZeroOrMore,
+            related_entity_name: "Author",
+            related_entity_cardinality: Cardinality::ExactlyOne,
+            label: "Author",
+        }],
+    };
+
+    assert_eq!(
+        er_diagram.generate().unwrap(),
+        diagram_code("book_full.mermaid")
+    );
+}
+
+#[test]
+fn it_generates_er_diagram_with_entities_only() {
+    let er_diagram = ErDiagram {
+        entities: &book_entities(),
+        relationships: &[],
+    };
+
+    assert_eq!(
+        er_diagram.generate().unwrap(),
+        diagram_code("book_entities.mermaid")
+    );
+}
+
+#[test]
+fn it_generates_er_diagram_with_relationships_only() {
+    let er_diagram = ErDiagram {
+        entities: &[],
+        relationships: &[
+            Relationship {
+                entity_name: "Author",
+                entity_cardinality: Cardinality::ExactlyOne,
+                related_entity_name: "Book",
+                related_entity_cardinality: Cardinality::ZeroOrMore,
+                label: "Books",
+            },
+            Relationship {
+                entity_name: "Author",
+                entity_cardinality: Cardinality::ExactlyOne,
+                related_entity_name: "Biography",
+                related_entity_cardinality: Cardinality::ZeroOrOne,
+                label: "Biography",
+            },
+            Relationship {
+                entity_name: "Book",
+                entity_cardinality: Cardinality::OneOrMore,
+                related_entity_name: "Author",
+                related_entity_cardinality: Cardinality::ExactlyOne,
+                label: "Author",
+            },
+        ],
+    };
+
+    assert_eq!(
+        er_diagram.generate().unwrap(),
+        diagram_code("book_relationships.mermaid")
+    );
+}
+
+#[test]
+fn it_builds_er_diagram_fluently() {
+    let er_diagram = owned::ErDiagram::builder()
+        .entity("Book")
+        .attribute("uuid", "id", Some(Key::Primary))
+        .attribute("String", "title", None)
+        .attribute("Integer", "year", None)
+        .attribute("uuid", "author_id", Some(Key::Foreign))
+        .entity("Author")
+        .attribute("uuid", "id", Some(Key::Primary))
+        .attribute("String", "first_name", None)
+        .attribute("String", "last_name", None)
+        .relationship(owned::Relationship::new(
+            "Book",
+            Cardinality::ZeroOrMore,
+            "Author",
+            Cardinality::ExactlyOne,
+            "Author",
+        ))
+        .build();
+
+    assert_eq!(
+        er_diagram.generate().unwrap(),
+        diagram_code("book_full.mermaid")
+    );
+}
+
+#[test]
+fn it_escapes_entity_names_and_labels() {
+    let er_diagram = owned::ErDiagram::builder()
+        .entity("Order Item")
+        .attribute("uuid", "id", Some(Key::Primary))
+        .attribute("Vec<String>", "tags", None)
+        .entity("Order")
+        .attribute("uuid", "id", Some(Key::Primary))
+        .relationship(owned::Relationship::new(
+            "Order Item",
+            Cardinality::ZeroOrMore,
+            "Order",
+            Cardinality::ExactlyOne,
+            "\"Order\"",
+        ))
+        .build();
+
+    assert_eq!(
+        er_diagram.generate().unwrap(),
+        diagram_code("order_escaped.mermaid")
+    );
+}
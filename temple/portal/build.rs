@@ -10,6 +10,7 @@ fn compile_proto_gymnasium_v1() -> Result<(), Box<dyn std::error::Error>> {
         "projects/projects.proto",
         "models/models.proto",
         "attribute_types/attribute_types.proto",
+        "generators/generators.proto",
     ];
 
     let protos: Vec<String> = files
@@ -25,7 +26,11 @@ fn compile_proto_gymnasium_v1() -> Result<(), Box<dyn std::error::Error>> {
 }
 
 fn compile_proto_temple_v1() -> Result<(), Box<dyn std::error::Error>> {
-    let files = vec!["projects.proto", "attribute_types/attribute_types.proto"];
+    let files = vec![
+        "projects.proto",
+        "attribute_types/attribute_types.proto",
+        "generators/generators.proto",
+    ];
 
     let protos: Vec<String> = files
         .iter()
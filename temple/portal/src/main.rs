@@ -23,12 +23,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         attribute_types_repo: repo::AttributeTypesRepo {
             connection_string: configuration.database()?.connection_string()?,
         },
+        generators_repo: repo::GeneratorsRepo {
+            connection_string: configuration.database()?.connection_string()?,
+        },
     };
     let attribute_types_server = servers::attribute_types_server::AttributeTypesServer {
         attribute_types_repo: repo::AttributeTypesRepo {
             connection_string: configuration.database()?.connection_string()?,
         },
     };
+    let generators_server = servers::generators_server::GeneratorsServer {
+        generators_repo: repo::GeneratorsRepo {
+            connection_string: configuration.database()?.connection_string()?,
+        },
+    };
 
     println!("Running server::proto::projects_server::ProjectsServer with tonic::transport::Server using http://{server_socket_address}");
 
@@ -41,6 +49,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 attribute_types_server,
             ),
         )
+        .add_service(
+            servers::generators_server::rpc::generators_server::GeneratorsServer::new(
+                generators_server,
+            ),
+        )
         .serve(server_socket_address)
         .await?;
 
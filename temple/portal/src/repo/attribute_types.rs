@@ -2,9 +2,12 @@ use super::map_status_error;
 use crate::util;
 use foundation::{
     attribute_type::{
-        AttributeType, AttributeTypeRecord, CreateAttributeTypeRecord, DeleteAttributeTypeRecord,
-        GetAttributeTypeRecord, ListAttributeTypeRecords, UpdateAttributeTypeRecord,
+        AttributeType, AttributeTypeRecord, ColumnType, ColumnTypeRecord,
+        CreateAttributeTypeRecord, DeleteAttributeTypeRecord, GetAttributeTypeRecord,
+        ListAttributeTypeRecords, ListColumnTypeRecords, SetColumnTypeRecord,
+        UpdateAttributeTypeRecord,
     },
+    model::SqlDialect,
     FoundationError, FoundationResult,
 };
 use prost_types::FieldMask;
@@ -156,6 +159,57 @@ impl DeleteAttributeTypeRecord for AttributeTypesRepo {
     }
 }
 
+#[async_trait::async_trait]
+impl SetColumnTypeRecord for AttributeTypesRepo {
+    async fn set_column_type_record(
+        &self,
+        attribute_type_record: AttributeTypeRecord,
+        column_type: ColumnType,
+    ) -> FoundationResult<ColumnTypeRecord> {
+        let mut client = self.client().await?;
+
+        let proto_column_type = client
+            .set_column_type(rpc::SetColumnTypeRequest {
+                attribute_type_id: attribute_type_record.id.to_string(),
+                dialect: dialect_to_proto(column_type.dialect).to_string(),
+                column_type: column_type.column_type,
+            })
+            .await
+            .map_err(map_status_error)?
+            .into_inner();
+
+        let column_type_record = column_type_record_from_proto(proto_column_type)?;
+
+        Ok(column_type_record)
+    }
+}
+
+#[async_trait::async_trait]
+impl ListColumnTypeRecords for AttributeTypesRepo {
+    async fn list_column_type_records(
+        &self,
+        dialect: SqlDialect,
+    ) -> FoundationResult<Vec<ColumnTypeRecord>> {
+        let mut client = self.client().await?;
+
+        let response = client
+            .list_column_types(rpc::ListColumnTypesRequest {
+                dialect: dialect_to_proto(dialect).to_string(),
+            })
+            .await
+            .map_err(map_status_error)?
+            .into_inner();
+
+        let column_type_records = response
+            .column_types
+            .into_iter()
+            .map(column_type_record_from_proto)
+            .collect::<FoundationResult<Vec<ColumnTypeRecord>>>()?;
+
+        Ok(column_type_records)
+    }
+}
+
 impl AttributeTypesRepo {
     async fn client(
         &self,
@@ -199,3 +253,54 @@ fn record_from_proto(
 
     Ok(record)
 }
+
+fn column_type_record_from_proto(
+    proto_column_type: rpc::ColumnType,
+) -> FoundationResult<ColumnTypeRecord> {
+    let create_time = proto_column_type
+        .create_time
+        .ok_or(FoundationError::internal(
+            "missing #create_time for column type",
+        ))?;
+
+    let update_time = proto_column_type
+        .update_time
+        .ok_or(FoundationError::internal(
+            "missing #update_time for column type",
+        ))?;
+
+    let record = ColumnTypeRecord {
+        id: util::proto::uuid_from_proto_string(&proto_column_type.id, "id")
+            .map_err(map_status_error)?,
+        inner: ColumnType {
+            attribute_type_slug: proto_column_type.attribute_type_slug,
+            dialect: dialect_from_proto(&proto_column_type.dialect)?,
+            column_type: proto_column_type.column_type,
+        },
+        inserted_at: util::proto::from_proto_timestamp(create_time, "insert_time")
+            .map_err(map_status_error)?,
+        updated_at: util::proto::from_proto_timestamp(update_time, "update_time")
+            .map_err(map_status_error)?,
+    };
+
+    Ok(record)
+}
+
+fn dialect_to_proto(dialect: SqlDialect) -> &'static str {
+    match dialect {
+        SqlDialect::PostgreSql => "postgresql",
+        SqlDialect::Sqlite => "sqlite",
+        SqlDialect::MySql => "mysql",
+    }
+}
+
+fn dialect_from_proto(dialect: &str) -> FoundationResult<SqlDialect> {
+    match dialect {
+        "postgresql" => Ok(SqlDialect::PostgreSql),
+        "sqlite" => Ok(SqlDialect::Sqlite),
+        "mysql" => Ok(SqlDialect::MySql),
+        _ => Err(FoundationError::internal(format!(
+            "unknown dialect `{dialect}` of column type"
+        ))),
+    }
+}
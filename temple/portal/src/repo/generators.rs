@@ -0,0 +1,196 @@
+use super::map_status_error;
+use crate::util;
+use foundation::{
+    generator::{
+        CreateGeneratorRecord, DeleteGeneratorRecord, Generator, GeneratorRecord,
+        GeneratorTemplate, GetGeneratorRecord, ListGeneratorRecords, TemplateScope,
+    },
+    FoundationError, FoundationResult,
+};
+
+mod rpc {
+    tonic::include_proto!("proto.gymnasium.v1.generators");
+}
+
+pub struct GeneratorsRepo {
+    pub connection_string: String,
+}
+
+#[async_trait::async_trait]
+impl ListGeneratorRecords for GeneratorsRepo {
+    async fn list_generator_records(&self) -> FoundationResult<Vec<GeneratorRecord>> {
+        let mut client = self.client().await?;
+
+        let response = client
+            .list_generators(rpc::ListGeneratorsRequest {})
+            .await
+            .map_err(map_status_error)?
+            .into_inner();
+
+        let generator_records = response
+            .generators
+            .into_iter()
+            .map(record_from_proto)
+            .collect::<FoundationResult<Vec<GeneratorRecord>>>()?;
+
+        Ok(generator_records)
+    }
+}
+
+#[async_trait::async_trait]
+impl GetGeneratorRecord for GeneratorsRepo {
+    async fn get_generator_record(&self, slug: &str) -> FoundationResult<Option<GeneratorRecord>> {
+        let mut client = self.client().await?;
+
+        let result = client
+            .find_generator(rpc::FindGeneratorRequest {
+                slug: slug.to_owned(),
+            })
+            .await;
+
+        let proto_generator = match result {
+            Ok(response) => response.into_inner(),
+            Err(status) if status.code() == tonic::Code::NotFound => return Ok(None),
+            Err(status) => return Err(map_status_error(status)),
+        };
+
+        let generator_record = record_from_proto(proto_generator)?;
+
+        Ok(Some(generator_record))
+    }
+}
+
+#[async_trait::async_trait]
+impl CreateGeneratorRecord for GeneratorsRepo {
+    async fn create_generator_record(
+        &self,
+        generator: Generator,
+    ) -> FoundationResult<GeneratorRecord> {
+        let mut client = self.client().await?;
+
+        let Generator {
+            description,
+            name,
+            slug,
+            templates,
+        } = generator;
+
+        let proto_generator = client
+            .create_generator(rpc::CreateGeneratorRequest {
+                description: description.unwrap_or_default(),
+                name,
+                slug,
+                templates: templates.into_iter().map(to_proto_template).collect(),
+            })
+            .await
+            .map_err(map_status_error)?
+            .into_inner();
+
+        let generator_record = record_from_proto(proto_generator)?;
+
+        Ok(generator_record)
+    }
+}
+
+#[async_trait::async_trait]
+impl DeleteGeneratorRecord for GeneratorsRepo {
+    async fn delete_generator_record(
+        &self,
+        generator_record: GeneratorRecord,
+    ) -> FoundationResult<()> {
+        let mut client = self.client().await?;
+
+        let GeneratorRecord { id, .. } = generator_record;
+
+        client
+            .delete_generator(rpc::DeleteGeneratorRequest { id: id.to_string() })
+            .await
+            .map_err(map_status_error)?;
+
+        Ok(())
+    }
+}
+
+impl GeneratorsRepo {
+    async fn client(
+        &self,
+    ) -> FoundationResult<rpc::generators_client::GeneratorsClient<tonic::transport::Channel>> {
+        rpc::generators_client::GeneratorsClient::connect(self.connection_string.clone())
+            .await
+            .map_err(|err| FoundationError::internal(err.to_string()))
+    }
+}
+
+fn to_proto_template(template: GeneratorTemplate) -> rpc::GeneratorTemplate {
+    let GeneratorTemplate {
+        path,
+        content,
+        scope,
+    } = template;
+
+    let scope = match scope {
+        TemplateScope::Project => rpc::TemplateScope::Project,
+        TemplateScope::Model => rpc::TemplateScope::Model,
+    };
+
+    rpc::GeneratorTemplate {
+        path,
+        content,
+        scope: scope.into(),
+    }
+}
+
+fn template_from_proto(
+    proto_template: rpc::GeneratorTemplate,
+) -> FoundationResult<GeneratorTemplate> {
+    let scope = match rpc::TemplateScope::from_i32(proto_template.scope) {
+        Some(rpc::TemplateScope::Project) => TemplateScope::Project,
+        Some(rpc::TemplateScope::Model) => TemplateScope::Model,
+        Some(rpc::TemplateScope::Unspecified) | None => {
+            return Err(FoundationError::internal(
+                "unknown #scope for generator template",
+            ))
+        }
+    };
+
+    Ok(GeneratorTemplate {
+        path: proto_template.path,
+        content: proto_template.content,
+        scope,
+    })
+}
+
+fn record_from_proto(proto_generator: rpc::Generator) -> FoundationResult<GeneratorRecord> {
+    let create_time = proto_generator
+        .create_time
+        .ok_or(FoundationError::internal(
+            "missing #create_time for generator",
+        ))?;
+
+    let update_time = proto_generator
+        .update_time
+        .ok_or(FoundationError::internal(
+            "missing #update_time for generator",
+        ))?;
+
+    let record = GeneratorRecord {
+        id: util::proto::uuid_from_proto_string(&proto_generator.id, "id")
+            .map_err(map_status_error)?,
+        inner: Generator {
+            description: Some(proto_generator.description),
+            name: proto_generator.name,
+            slug: proto_generator.slug,
+            templates: proto_generator
+                .templates
+                .into_iter()
+                .map(template_from_proto)
+                .collect::<FoundationResult<Vec<GeneratorTemplate>>>()?,
+        },
+        inserted_at: util::proto::from_proto_timestamp(create_time, "insert_time")
+            .map_err(map_status_error)?,
+        updated_at: util::proto::from_proto_timestamp(update_time, "update_time")
+            .map_err(map_status_error)?,
+    };
+
+    Ok(record)
+}
@@ -1,10 +1,12 @@
 mod attribute_types;
+mod generators;
 mod models;
 mod projects;
 
 use foundation::FoundationError;
 
 pub use attribute_types::AttributeTypesRepo;
+pub use generators::GeneratorsRepo;
 pub use models::ModelsRepo;
 pub use projects::ProjectsRepo;
 
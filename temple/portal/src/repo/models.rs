@@ -5,10 +5,10 @@ use foundation::{
     datastore,
     model::{
         Association, AssociationKind, Attribute, CreateModelAssociationRecord,
-        CreateModelAttributeRecord, CreateModelRecord, DeleteModelAssociationRecord,
-        DeleteModelAttributeRecord, DeleteModelRecord, GetModelAssociationRecord,
-        GetModelAttributeRecord, GetModelOverviewRecord, GetModelRecord, ListModelOverviewRecords,
-        ListModelRecords, Model,
+        CreateModelAttributeRecord, CreateModelFieldNumberRecord, CreateModelRecord,
+        DeleteModelAssociationRecord, DeleteModelAttributeRecord, DeleteModelRecord, FieldNumber,
+        GetModelAssociationRecord, GetModelAttributeRecord, GetModelOverviewRecord, GetModelRecord,
+        ListModelFieldNumberRecords, ListModelOverviewRecords, ListModelRecords, Model,
     },
     FoundationError, FoundationResult,
 };
@@ -311,6 +311,55 @@ impl CreateModelAssociationRecord for ModelsRepo {
     }
 }
 
+#[async_trait::async_trait]
+impl ListModelFieldNumberRecords for ModelsRepo {
+    async fn list_model_field_number_records(
+        &self,
+        project_slug: &str,
+    ) -> FoundationResult<Vec<datastore::model::FieldNumber>> {
+        let mut client = self.client().await?;
+
+        let field_numbers = client
+            .list_project_model_field_numbers(rpc::ListProjectModelFieldNumbersRequest {
+                project_slug: project_slug.to_owned(),
+            })
+            .await
+            .map_err(map_status_error)?
+            .into_inner()
+            .field_numbers
+            .into_iter()
+            .map(datastore_model_field_number)
+            .collect::<FoundationResult<Vec<datastore::model::FieldNumber>>>()?;
+
+        Ok(field_numbers)
+    }
+}
+
+#[async_trait::async_trait]
+impl CreateModelFieldNumberRecord for ModelsRepo {
+    async fn create_model_field_number_record(
+        &self,
+        model_record: datastore::model::Model,
+        field_number: FieldNumber,
+    ) -> FoundationResult<datastore::model::FieldNumber> {
+        let mut client = self.client().await?;
+
+        let proto_field_number = client
+            .create_field_number(rpc::CreateFieldNumberRequest {
+                model_id: model_record.id.to_string(),
+                name: field_number.name,
+                number: field_number.number,
+            })
+            .await
+            .map_err(map_status_error)?
+            .into_inner();
+
+        let field_number = datastore_model_field_number(proto_field_number)?;
+
+        Ok(field_number)
+    }
+}
+
 fn datastore_model(proto_model: rpc::Model) -> FoundationResult<datastore::model::Model> {
     let create_time = proto_model
         .create_time
@@ -459,6 +508,37 @@ pub fn datastore_model_association(
     Ok(model_attribute)
 }
 
+fn datastore_model_field_number(
+    proto_field_number: rpc::FieldNumber,
+) -> FoundationResult<datastore::model::FieldNumber> {
+    let create_time = proto_field_number
+        .create_time
+        .ok_or(FoundationError::internal(
+            "missing #create_time for FieldNumber",
+        ))?;
+
+    let update_time = proto_field_number
+        .update_time
+        .ok_or(FoundationError::internal(
+            "missing #update_time for FieldNumber",
+        ))?;
+
+    let field_number = datastore::model::FieldNumber {
+        id: util::proto::uuid_from_proto_string(&proto_field_number.id, "id")
+            .map_err(map_status_error)?,
+        model_id: util::proto::uuid_from_proto_string(&proto_field_number.model_id, "model_id")
+            .map_err(map_status_error)?,
+        name: proto_field_number.name,
+        number: proto_field_number.number,
+        inserted_at: util::proto::from_proto_timestamp(create_time, "insert_time")
+            .map_err(map_status_error)?,
+        updated_at: util::proto::from_proto_timestamp(update_time, "update_time")
+            .map_err(map_status_error)?,
+    };
+
+    Ok(field_number)
+}
+
 fn datastore_model_overview(
     proto_model_overview: rpc::ModelOverview,
 ) -> FoundationResult<datastore::model::ModelOverview> {
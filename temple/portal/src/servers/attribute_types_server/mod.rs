@@ -1,6 +1,9 @@
 use crate::repo::AttributeTypesRepo;
 use crate::PortalError;
-use foundation::attribute_type::{self, AttributeType};
+use foundation::{
+    attribute_type::{self, AttributeType, ColumnType},
+    model::SqlDialect,
+};
 use tonic::Response;
 
 pub mod rpc {
@@ -102,6 +105,32 @@ impl rpc::attribute_types_server::AttributeTypes for AttributeTypesServer {
 
         Ok(Response::new(()))
     }
+
+    async fn set_column_type(
+        &self,
+        request: tonic::Request<rpc::SetColumnTypeRequest>,
+    ) -> std::result::Result<tonic::Response<rpc::ColumnType>, tonic::Status> {
+        println!("Got a request: {:?}", request);
+
+        let rpc::SetColumnTypeRequest {
+            attribute_type_slug,
+            dialect,
+            column_type,
+        } = request.into_inner();
+
+        let column_type = attribute_type::set_column_type(
+            &self.attribute_types_repo,
+            attribute_type::SetColumnTypeRequest {
+                attribute_type_slug,
+                dialect: to_sql_dialect(dialect)?,
+                column_type,
+            },
+        )
+        .await
+        .map_err(Into::<PortalError>::into)?;
+
+        Ok(Response::new(to_proto_column_type(column_type)))
+    }
 }
 
 fn to_proto_attribute_type(attribute_type: AttributeType) -> rpc::AttributeType {
@@ -119,3 +148,33 @@ fn from_proto_attribute_type(proto_attribute_type: rpc::AttributeType) -> Attrib
         slug: proto_attribute_type.slug,
     }
 }
+
+fn to_proto_column_type(column_type: ColumnType) -> rpc::ColumnType {
+    let dialect = match column_type.dialect {
+        SqlDialect::PostgreSql => rpc::SqlDialect::Postgresql,
+        SqlDialect::Sqlite => rpc::SqlDialect::Sqlite,
+        SqlDialect::MySql => rpc::SqlDialect::Mysql,
+    };
+
+    rpc::ColumnType {
+        attribute_type_slug: column_type.attribute_type_slug,
+        dialect: dialect.into(),
+        column_type: column_type.column_type,
+    }
+}
+
+fn to_sql_dialect(dialect: i32) -> Result<SqlDialect, tonic::Status> {
+    use rpc::SqlDialect as ProtoSqlDialect;
+
+    let Some(dialect) = ProtoSqlDialect::from_i32(dialect) else {
+        return Err(PortalError::invalid_argument("dialect").into());
+    };
+
+    let dialect = match dialect {
+        ProtoSqlDialect::Unspecified | ProtoSqlDialect::Postgresql => SqlDialect::PostgreSql,
+        ProtoSqlDialect::Sqlite => SqlDialect::Sqlite,
+        ProtoSqlDialect::Mysql => SqlDialect::MySql,
+    };
+
+    Ok(dialect)
+}
@@ -0,0 +1,135 @@
+use crate::repo::GeneratorsRepo;
+use crate::PortalError;
+use foundation::generator::{self, Generator, GeneratorTemplate, TemplateScope};
+use tonic::{Response, Status};
+
+pub mod rpc {
+    tonic::include_proto!("proto.temple.v1.generators"); // The string specified here must match the proto package name
+}
+
+pub struct GeneratorsServer {
+    pub generators_repo: GeneratorsRepo,
+}
+
+#[tonic::async_trait]
+impl rpc::generators_server::Generators for GeneratorsServer {
+    async fn create_generator(
+        &self,
+        request: tonic::Request<rpc::CreateGeneratorRequest>,
+    ) -> std::result::Result<tonic::Response<rpc::Generator>, tonic::Status> {
+        println!("Got a request: {:?}", request);
+
+        let rpc::CreateGeneratorRequest {
+            description,
+            name,
+            templates,
+        } = request.into_inner();
+
+        let generator = generator::create(
+            &self.generators_repo,
+            generator::CreateRequest {
+                description,
+                name,
+                templates: templates
+                    .into_iter()
+                    .map(from_proto_template)
+                    .collect::<Result<Vec<GeneratorTemplate>, Status>>()?,
+            },
+        )
+        .await
+        .map_err(Into::<PortalError>::into)?;
+
+        Ok(Response::new(to_proto_generator(generator)))
+    }
+
+    async fn get_generator(
+        &self,
+        request: tonic::Request<rpc::GetGeneratorRequest>,
+    ) -> std::result::Result<tonic::Response<rpc::Generator>, tonic::Status> {
+        println!("Got a request: {:?}", request);
+
+        let rpc::GetGeneratorRequest { slug } = request.into_inner();
+
+        let generator = generator::get(&self.generators_repo, &slug)
+            .await
+            .map_err(Into::<PortalError>::into)?;
+
+        Ok(Response::new(to_proto_generator(generator)))
+    }
+
+    async fn list_generators(
+        &self,
+        request: tonic::Request<rpc::ListGeneratorsRequest>,
+    ) -> std::result::Result<tonic::Response<rpc::ListGeneratorsResponse>, tonic::Status> {
+        println!("Got a request: {:?}", request);
+
+        let generators: Vec<rpc::Generator> = generator::list(&self.generators_repo)
+            .await
+            .map_err(Into::<PortalError>::into)?
+            .into_iter()
+            .map(to_proto_generator)
+            .collect();
+
+        Ok(Response::new(rpc::ListGeneratorsResponse { generators }))
+    }
+
+    async fn delete_generator(
+        &self,
+        request: tonic::Request<rpc::DeleteGeneratorRequest>,
+    ) -> std::result::Result<tonic::Response<()>, tonic::Status> {
+        println!("Got a request: {:?}", request);
+
+        let rpc::DeleteGeneratorRequest { slug } = request.into_inner();
+
+        generator::delete(&self.generators_repo, &slug)
+            .await
+            .map_err(Into::<PortalError>::into)?;
+
+        Ok(Response::new(()))
+    }
+}
+
+fn to_proto_generator(generator: Generator) -> rpc::Generator {
+    rpc::Generator {
+        description: generator.description.unwrap_or_default(),
+        name: generator.name,
+        slug: generator.slug,
+        templates: generator
+            .templates
+            .into_iter()
+            .map(to_proto_template)
+            .collect(),
+    }
+}
+
+fn to_proto_template(template: GeneratorTemplate) -> rpc::GeneratorTemplate {
+    let scope = match template.scope {
+        TemplateScope::Project => rpc::TemplateScope::Project,
+        TemplateScope::Model => rpc::TemplateScope::Model,
+    };
+
+    rpc::GeneratorTemplate {
+        path: template.path,
+        content: template.content,
+        scope: scope.into(),
+    }
+}
+
+fn from_proto_template(
+    proto_template: rpc::GeneratorTemplate,
+) -> Result<GeneratorTemplate, Status> {
+    let Some(scope) = rpc::TemplateScope::from_i32(proto_template.scope) else {
+        return Err(PortalError::invalid_argument("scope").into());
+    };
+
+    let scope = match scope {
+        rpc::TemplateScope::Unspecified | rpc::TemplateScope::Project => TemplateScope::Project,
+        rpc::TemplateScope::Model => TemplateScope::Model,
+    };
+
+    Ok(GeneratorTemplate {
+        path: proto_template.path,
+        content: proto_template.content,
+        scope,
+    })
+}
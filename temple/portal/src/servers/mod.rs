@@ -1,2 +1,3 @@
 pub mod attribute_types_server;
+pub mod generators_server;
 pub mod projects_server;
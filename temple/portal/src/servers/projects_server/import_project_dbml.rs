@@ -0,0 +1,22 @@
+use super::{import_repo::ImportRepo, rpc, to_proto_import_summary, ProjectsServer};
+use crate::PortalError;
+use foundation::model;
+use tonic::{Request, Response, Status};
+
+pub async fn execute(
+    server: &ProjectsServer,
+    request: Request<rpc::ImportProjectDbmlRequest>,
+) -> Result<Response<rpc::ImportProjectDbmlResponse>, Status> {
+    let repo = ImportRepo::new(server);
+
+    let rpc::ImportProjectDbmlRequest { project_slug, dbml } = request.into_inner();
+
+    let response =
+        model::import_dbml::execute(&repo, model::import_dbml::Request { project_slug, dbml })
+            .await
+            .map_err(Into::<PortalError>::into)?;
+
+    Ok(Response::new(rpc::ImportProjectDbmlResponse {
+        summary: Some(to_proto_import_summary(response.summary)),
+    }))
+}
@@ -0,0 +1,30 @@
+use super::{import_repo::ImportRepo, rpc, to_proto_import_summary, ProjectsServer};
+use crate::PortalError;
+use foundation::model;
+use tonic::{Request, Response, Status};
+
+pub async fn execute(
+    server: &ProjectsServer,
+    request: Request<rpc::ImportProjectPrismaSchemaRequest>,
+) -> Result<Response<rpc::ImportProjectPrismaSchemaResponse>, Status> {
+    let repo = ImportRepo::new(server);
+
+    let rpc::ImportProjectPrismaSchemaRequest {
+        project_slug,
+        prisma_schema,
+    } = request.into_inner();
+
+    let response = model::import_prisma_schema::execute(
+        &repo,
+        model::import_prisma_schema::Request {
+            project_slug,
+            prisma_schema,
+        },
+    )
+    .await
+    .map_err(Into::<PortalError>::into)?;
+
+    Ok(Response::new(rpc::ImportProjectPrismaSchemaResponse {
+        summary: Some(to_proto_import_summary(response.summary)),
+    }))
+}
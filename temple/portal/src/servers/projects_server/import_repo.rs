@@ -0,0 +1,113 @@
+use super::ProjectsServer;
+use crate::repo::{AttributeTypesRepo, ModelsRepo, ProjectsRepo};
+use foundation::{
+    attribute_type::{
+        AttributeType, AttributeTypeRecord, CreateAttributeTypeRecord, ListAttributeTypeRecords,
+    },
+    datastore,
+    model::{
+        Association, Attribute, CreateModelAssociationRecord, CreateModelAttributeRecord,
+        CreateModelRecord, ListModelOverviewRecords, Model,
+    },
+    project::GetProjectRecord,
+    FoundationResult,
+};
+
+/// Records of the imports, which find the project and add the models to it.
+pub struct ImportRepo<'a> {
+    projects_repo: &'a ProjectsRepo,
+    models_repo: &'a ModelsRepo,
+    attribute_types_repo: &'a AttributeTypesRepo,
+}
+
+impl<'a> ImportRepo<'a> {
+    pub fn new(server: &'a ProjectsServer) -> Self {
+        Self {
+            projects_repo: &server.projects_repo,
+            models_repo: &server.models_repo,
+            attribute_types_repo: &server.attribute_types_repo,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> GetProjectRecord for ImportRepo<'a> {
+    async fn get_project_record(
+        &self,
+        slug: &str,
+    ) -> FoundationResult<datastore::project::Project> {
+        self.projects_repo.get_project_record(slug).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> ListModelOverviewRecords for ImportRepo<'a> {
+    async fn list_model_overview_records(
+        &self,
+        project_slug: &str,
+    ) -> FoundationResult<Vec<datastore::model::ModelOverview>> {
+        self.models_repo
+            .list_model_overview_records(project_slug)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> ListAttributeTypeRecords for ImportRepo<'a> {
+    async fn list_attribute_type_records(&self) -> FoundationResult<Vec<AttributeTypeRecord>> {
+        self.attribute_types_repo
+            .list_attribute_type_records()
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> CreateAttributeTypeRecord for ImportRepo<'a> {
+    async fn create_attribute_type_record(
+        &self,
+        attribute_type: AttributeType,
+    ) -> FoundationResult<AttributeTypeRecord> {
+        self.attribute_types_repo
+            .create_attribute_type_record(attribute_type)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> CreateModelRecord for ImportRepo<'a> {
+    async fn create_model_record(
+        &self,
+        project: datastore::project::Project,
+        model: Model,
+    ) -> FoundationResult<datastore::model::Model> {
+        self.models_repo.create_model_record(project, model).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> CreateModelAttributeRecord for ImportRepo<'a> {
+    async fn create_model_attribute_record(
+        &self,
+        model: datastore::model::Model,
+        attribute_type_record: AttributeTypeRecord,
+        attribute: Attribute,
+    ) -> FoundationResult<datastore::model::Attribute> {
+        self.models_repo
+            .create_model_attribute_record(model, attribute_type_record, attribute)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> CreateModelAssociationRecord for ImportRepo<'a> {
+    async fn create_model_association_record(
+        &self,
+        model: datastore::model::Model,
+        associated_model: datastore::model::Model,
+        association: Association,
+    ) -> FoundationResult<datastore::model::Association> {
+        self.models_repo
+            .create_model_association_record(model, associated_model, association)
+            .await
+    }
+}
@@ -0,0 +1,48 @@
+use super::ProjectsServer;
+use crate::repo::{AttributeTypesRepo, ModelsRepo};
+use foundation::{
+    attribute_type::{ColumnTypeRecord, ListColumnTypeRecords},
+    datastore,
+    model::{ListModelOverviewRecords, SqlDialect},
+    FoundationResult,
+};
+
+/// Records of the SQL generation, the models and the column types of their
+/// attribute types.
+pub struct MigrationRepo<'a> {
+    models_repo: &'a ModelsRepo,
+    attribute_types_repo: &'a AttributeTypesRepo,
+}
+
+impl<'a> MigrationRepo<'a> {
+    pub fn new(server: &'a ProjectsServer) -> Self {
+        Self {
+            models_repo: &server.models_repo,
+            attribute_types_repo: &server.attribute_types_repo,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> ListModelOverviewRecords for MigrationRepo<'a> {
+    async fn list_model_overview_records(
+        &self,
+        project_slug: &str,
+    ) -> FoundationResult<Vec<datastore::model::ModelOverview>> {
+        self.models_repo
+            .list_model_overview_records(project_slug)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> ListColumnTypeRecords for MigrationRepo<'a> {
+    async fn list_column_type_records(
+        &self,
+        dialect: SqlDialect,
+    ) -> FoundationResult<Vec<ColumnTypeRecord>> {
+        self.attribute_types_repo
+            .list_column_type_records(dialect)
+            .await
+    }
+}
@@ -1,17 +1,23 @@
 // use crate::{datastore::Repo, model::Project, service, util};
 use crate::{
-    repo::{AttributeTypesRepo, ModelsRepo, ProjectsRepo},
-    PortalError,
+    repo::{AttributeTypesRepo, GeneratorsRepo, ModelsRepo, ProjectsRepo},
+    util, PortalError,
 };
 use foundation::{
     attribute_type,
     model::{self, Model},
     project::{self, Project},
 };
+use migration_repo::MigrationRepo;
 use tonic::{Request, Response, Status};
 
 mod create_model;
 mod create_model_attribute;
+mod import_project_dbml;
+mod import_project_prisma_schema;
+mod import_repo;
+mod migration_repo;
+mod run_project_generator;
 
 pub mod rpc {
     tonic::include_proto!("proto.temple.v1"); // The string specified here must match the proto package name
@@ -21,6 +27,7 @@ pub struct ProjectsServer {
     pub projects_repo: ProjectsRepo,
     pub models_repo: ModelsRepo,
     pub attribute_types_repo: AttributeTypesRepo,
+    pub generators_repo: GeneratorsRepo,
 }
 
 #[tonic::async_trait]
@@ -371,6 +378,8 @@ impl rpc::projects_server::Projects for ProjectsServer {
         let rpc::GetModelClassDiagramRequest {
             project_slug,
             model_slug,
+            format,
+            options,
         } = request.into_inner();
 
         let response = model::get_class_diagram::execute(
@@ -378,6 +387,8 @@ impl rpc::projects_server::Projects for ProjectsServer {
             model::get_class_diagram::Request {
                 project_slug,
                 model_slug,
+                format: to_diagram_format(format)?,
+                options: to_diagram_options(options)?,
             },
         )
         .await
@@ -385,28 +396,461 @@ impl rpc::projects_server::Projects for ProjectsServer {
 
         Ok(Response::new(rpc::GetModelClassDiagramResponse {
             diagram: response.diagram,
+            image: response.image.unwrap_or_default(),
         }))
     }
 
+    async fn get_model_neighbourhood_class_diagram(
+        &self,
+        request: Request<rpc::GetModelNeighbourhoodClassDiagramRequest>, // Accept request of type HelloRequest
+    ) -> Result<Response<rpc::GetModelNeighbourhoodClassDiagramResponse>, Status> {
+        println!("Got a request: {:?}", request);
+
+        let rpc::GetModelNeighbourhoodClassDiagramRequest {
+            project_slug,
+            model_slug,
+            depth,
+            format,
+            options,
+        } = request.into_inner();
+
+        let response = model::get_neighbourhood_class_diagram::execute(
+            &self.models_repo,
+            model::get_neighbourhood_class_diagram::Request {
+                project_slug,
+                model_slug,
+                depth,
+                format: to_diagram_format(format)?,
+                options: to_diagram_options(options)?,
+            },
+        )
+        .await
+        .map_err(Into::<PortalError>::into)?;
+
+        Ok(Response::new(
+            rpc::GetModelNeighbourhoodClassDiagramResponse {
+                diagram: response.diagram,
+                image: response.image.unwrap_or_default(),
+            },
+        ))
+    }
+
     async fn get_project_class_diagram(
         &self,
         request: Request<rpc::GetProjectClassDiagramRequest>, // Accept request of type HelloRequest
     ) -> Result<Response<rpc::GetProjectClassDiagramResponse>, Status> {
         println!("Got a request: {:?}", request);
 
-        let rpc::GetProjectClassDiagramRequest { project_slug } = request.into_inner();
+        let rpc::GetProjectClassDiagramRequest {
+            project_slug,
+            format,
+            options,
+        } = request.into_inner();
 
         let response = model::get_project_class_diagram::execute(
             &self.models_repo,
-            model::get_project_class_diagram::Request { project_slug },
+            model::get_project_class_diagram::Request {
+                project_slug,
+                format: to_diagram_format(format)?,
+                options: to_diagram_options(options)?,
+            },
         )
         .await
         .map_err(Into::<PortalError>::into)?;
 
         Ok(Response::new(rpc::GetProjectClassDiagramResponse {
             diagram: response.diagram,
+            image: response.image.unwrap_or_default(),
+        }))
+    }
+
+    async fn get_project_er_diagram(
+        &self,
+        request: Request<rpc::GetProjectErDiagramRequest>, // Accept request of type HelloRequest
+    ) -> Result<Response<rpc::GetProjectErDiagramResponse>, Status> {
+        println!("Got a request: {:?}", request);
+
+        let rpc::GetProjectErDiagramRequest { project_slug } = request.into_inner();
+
+        let response = model::get_project_er_diagram::execute(
+            &self.models_repo,
+            model::get_project_er_diagram::Request { project_slug },
+        )
+        .await
+        .map_err(Into::<PortalError>::into)?;
+
+        Ok(Response::new(rpc::GetProjectErDiagramResponse {
+            diagram: response.diagram,
+        }))
+    }
+
+    async fn get_project_dot_graph(
+        &self,
+        request: Request<rpc::GetProjectDotGraphRequest>, // Accept request of type HelloRequest
+    ) -> Result<Response<rpc::GetProjectDotGraphResponse>, Status> {
+        println!("Got a request: {:?}", request);
+
+        let rpc::GetProjectDotGraphRequest { project_slug } = request.into_inner();
+
+        let response = model::get_project_dot_graph::execute(
+            &self.models_repo,
+            model::get_project_dot_graph::Request { project_slug },
+        )
+        .await
+        .map_err(Into::<PortalError>::into)?;
+
+        Ok(Response::new(rpc::GetProjectDotGraphResponse {
+            graph: response.graph,
+        }))
+    }
+
+    async fn get_project_type_script_definitions(
+        &self,
+        request: Request<rpc::GetProjectTypeScriptDefinitionsRequest>, // Accept request of type HelloRequest
+    ) -> Result<Response<rpc::GetProjectTypeScriptDefinitionsResponse>, Status> {
+        println!("Got a request: {:?}", request);
+
+        let rpc::GetProjectTypeScriptDefinitionsRequest {
+            project_slug,
+            type_mappings,
+        } = request.into_inner();
+
+        let response = model::get_project_typescript_definitions::execute(
+            &self.models_repo,
+            model::get_project_typescript_definitions::Request {
+                project_slug,
+                type_mappings,
+            },
+        )
+        .await
+        .map_err(Into::<PortalError>::into)?;
+
+        Ok(Response::new(
+            rpc::GetProjectTypeScriptDefinitionsResponse {
+                definitions: response.definitions,
+            },
+        ))
+    }
+
+    async fn get_project_json_schema(
+        &self,
+        request: Request<rpc::GetProjectJsonSchemaRequest>, // Accept request of type HelloRequest
+    ) -> Result<Response<rpc::GetProjectJsonSchemaResponse>, Status> {
+        println!("Got a request: {:?}", request);
+
+        let rpc::GetProjectJsonSchemaRequest { project_slug } = request.into_inner();
+
+        let response = model::get_project_json_schema::execute(
+            &self.models_repo,
+            model::get_project_json_schema::Request { project_slug },
+        )
+        .await
+        .map_err(Into::<PortalError>::into)?;
+
+        Ok(Response::new(rpc::GetProjectJsonSchemaResponse {
+            model_schemas: response
+                .model_schemas
+                .into_iter()
+                .map(to_proto_generated_file)
+                .collect(),
+            project_schema: response.project_schema,
+        }))
+    }
+
+    async fn get_project_snapshot(
+        &self,
+        request: Request<rpc::GetProjectSnapshotRequest>, // Accept request of type HelloRequest
+    ) -> Result<Response<rpc::GetProjectSnapshotResponse>, Status> {
+        println!("Got a request: {:?}", request);
+
+        let rpc::GetProjectSnapshotRequest { project_slug } = request.into_inner();
+
+        let response = model::get_project_snapshot::execute(
+            &self.models_repo,
+            model::get_project_snapshot::Request { project_slug },
+        )
+        .await
+        .map_err(Into::<PortalError>::into)?;
+
+        Ok(Response::new(rpc::GetProjectSnapshotResponse {
+            snapshot: response.snapshot,
+        }))
+    }
+
+    async fn generate_project_migration(
+        &self,
+        request: Request<rpc::GenerateProjectMigrationRequest>, // Accept request of type HelloRequest
+    ) -> Result<Response<rpc::GenerateProjectMigrationResponse>, Status> {
+        println!("Got a request: {:?}", request);
+
+        let rpc::GenerateProjectMigrationRequest { project_slug } = request.into_inner();
+
+        let response = model::generate_project_migration::execute(
+            &MigrationRepo::new(self),
+            model::generate_project_migration::Request { project_slug },
+        )
+        .await
+        .map_err(Into::<PortalError>::into)?;
+
+        Ok(Response::new(rpc::GenerateProjectMigrationResponse {
+            sql: response.sql,
+        }))
+    }
+
+    async fn generate_project_alter_migration(
+        &self,
+        request: Request<rpc::GenerateProjectAlterMigrationRequest>, // Accept request of type HelloRequest
+    ) -> Result<Response<rpc::GenerateProjectAlterMigrationResponse>, Status> {
+        println!("Got a request: {:?}", request);
+
+        let rpc::GenerateProjectAlterMigrationRequest {
+            project_slug,
+            baseline,
+            renamed_attributes,
+        } = request.into_inner();
+
+        let response = model::generate_project_alter_migration::execute(
+            &MigrationRepo::new(self),
+            model::generate_project_alter_migration::Request {
+                project_slug,
+                baseline,
+                renamed_attributes: renamed_attributes
+                    .into_iter()
+                    .map(
+                        |rpc::RenamedAttribute {
+                             model_name,
+                             from,
+                             to,
+                         }| {
+                            model::generate_project_alter_migration::RenamedAttribute {
+                                model_name,
+                                from,
+                                to,
+                            }
+                        },
+                    )
+                    .collect(),
+            },
+        )
+        .await
+        .map_err(Into::<PortalError>::into)?;
+
+        Ok(Response::new(rpc::GenerateProjectAlterMigrationResponse {
+            up: response.up,
+            down: response.down,
+        }))
+    }
+
+    async fn generate_project_ddl(
+        &self,
+        request: Request<rpc::GenerateProjectDdlRequest>, // Accept request of type HelloRequest
+    ) -> Result<Response<rpc::GenerateProjectDdlResponse>, Status> {
+        println!("Got a request: {:?}", request);
+
+        let rpc::GenerateProjectDdlRequest {
+            project_slug,
+            dialect,
+        } = request.into_inner();
+
+        let response = model::generate_project_ddl::execute(
+            &MigrationRepo::new(self),
+            model::generate_project_ddl::Request {
+                project_slug,
+                dialect: to_sql_dialect(dialect)?,
+            },
+        )
+        .await
+        .map_err(Into::<PortalError>::into)?;
+
+        Ok(Response::new(rpc::GenerateProjectDdlResponse {
+            sql: response.sql,
         }))
     }
+
+    async fn generate_project_rust_code(
+        &self,
+        request: Request<rpc::GenerateProjectRustCodeRequest>, // Accept request of type HelloRequest
+    ) -> Result<Response<rpc::GenerateProjectRustCodeResponse>, Status> {
+        println!("Got a request: {:?}", request);
+
+        let rpc::GenerateProjectRustCodeRequest {
+            project_slug,
+            serde,
+        } = request.into_inner();
+
+        let response = model::generate_project_rust_code::execute(
+            &self.models_repo,
+            model::generate_project_rust_code::Request {
+                project_slug,
+                serde,
+            },
+        )
+        .await
+        .map_err(Into::<PortalError>::into)?;
+
+        Ok(Response::new(rpc::GenerateProjectRustCodeResponse {
+            files: response
+                .files
+                .into_iter()
+                .map(to_proto_generated_file)
+                .collect(),
+        }))
+    }
+
+    async fn generate_project_rails_code(
+        &self,
+        request: Request<rpc::GenerateProjectRailsCodeRequest>, // Accept request of type HelloRequest
+    ) -> Result<Response<rpc::GenerateProjectRailsCodeResponse>, Status> {
+        println!("Got a request: {:?}", request);
+
+        let rpc::GenerateProjectRailsCodeRequest {
+            project_slug,
+            migration_timestamp,
+        } = request.into_inner();
+
+        let migration_timestamp = migration_timestamp
+            .map(|timestamp| util::proto::from_proto_timestamp(timestamp, "migration_timestamp"))
+            .transpose()?;
+
+        let response = model::generate_project_rails_code::execute(
+            &self.models_repo,
+            model::generate_project_rails_code::Request {
+                project_slug,
+                migration_timestamp,
+            },
+        )
+        .await
+        .map_err(Into::<PortalError>::into)?;
+
+        Ok(Response::new(rpc::GenerateProjectRailsCodeResponse {
+            files: response
+                .files
+                .into_iter()
+                .map(to_proto_generated_file)
+                .collect(),
+        }))
+    }
+
+    async fn generate_project_ecto_code(
+        &self,
+        request: Request<rpc::GenerateProjectEctoCodeRequest>, // Accept request of type HelloRequest
+    ) -> Result<Response<rpc::GenerateProjectEctoCodeResponse>, Status> {
+        println!("Got a request: {:?}", request);
+
+        let rpc::GenerateProjectEctoCodeRequest {
+            project_slug,
+            migration_timestamp,
+        } = request.into_inner();
+
+        let migration_timestamp = migration_timestamp
+            .map(|timestamp| util::proto::from_proto_timestamp(timestamp, "migration_timestamp"))
+            .transpose()?;
+
+        let response = model::generate_project_ecto_code::execute(
+            &self.models_repo,
+            model::generate_project_ecto_code::Request {
+                project_slug,
+                migration_timestamp,
+            },
+        )
+        .await
+        .map_err(Into::<PortalError>::into)?;
+
+        Ok(Response::new(rpc::GenerateProjectEctoCodeResponse {
+            files: response
+                .files
+                .into_iter()
+                .map(to_proto_generated_file)
+                .collect(),
+        }))
+    }
+
+    async fn generate_project_proto(
+        &self,
+        request: Request<rpc::GenerateProjectProtoRequest>, // Accept request of type HelloRequest
+    ) -> Result<Response<rpc::GenerateProjectProtoResponse>, Status> {
+        println!("Got a request: {:?}", request);
+
+        let rpc::GenerateProjectProtoRequest { project_slug } = request.into_inner();
+
+        let response = model::generate_project_proto::execute(
+            &self.models_repo,
+            model::generate_project_proto::Request { project_slug },
+        )
+        .await
+        .map_err(Into::<PortalError>::into)?;
+
+        Ok(Response::new(rpc::GenerateProjectProtoResponse {
+            proto: response.proto,
+        }))
+    }
+
+    async fn generate_project_dbml(
+        &self,
+        request: Request<rpc::GenerateProjectDbmlRequest>, // Accept request of type HelloRequest
+    ) -> Result<Response<rpc::GenerateProjectDbmlResponse>, Status> {
+        println!("Got a request: {:?}", request);
+
+        let rpc::GenerateProjectDbmlRequest { project_slug } = request.into_inner();
+
+        let response = model::generate_project_dbml::execute(
+            &self.models_repo,
+            model::generate_project_dbml::Request { project_slug },
+        )
+        .await
+        .map_err(Into::<PortalError>::into)?;
+
+        Ok(Response::new(rpc::GenerateProjectDbmlResponse {
+            dbml: response.dbml,
+        }))
+    }
+
+    async fn generate_project_prisma_schema(
+        &self,
+        request: Request<rpc::GenerateProjectPrismaSchemaRequest>, // Accept request of type HelloRequest
+    ) -> Result<Response<rpc::GenerateProjectPrismaSchemaResponse>, Status> {
+        println!("Got a request: {:?}", request);
+
+        let rpc::GenerateProjectPrismaSchemaRequest { project_slug } = request.into_inner();
+
+        let response = model::generate_project_prisma_schema::execute(
+            &self.models_repo,
+            model::generate_project_prisma_schema::Request { project_slug },
+        )
+        .await
+        .map_err(Into::<PortalError>::into)?;
+
+        Ok(Response::new(rpc::GenerateProjectPrismaSchemaResponse {
+            prisma_schema: response.prisma_schema,
+        }))
+    }
+
+    async fn import_project_dbml(
+        &self,
+        request: Request<rpc::ImportProjectDbmlRequest>, // Accept request of type HelloRequest
+    ) -> Result<Response<rpc::ImportProjectDbmlResponse>, Status> {
+        println!("Got a request: {:?}", request);
+
+        import_project_dbml::execute(self, request).await
+    }
+
+    async fn import_project_prisma_schema(
+        &self,
+        request: Request<rpc::ImportProjectPrismaSchemaRequest>, // Accept request of type HelloRequest
+    ) -> Result<Response<rpc::ImportProjectPrismaSchemaResponse>, Status> {
+        println!("Got a request: {:?}", request);
+
+        import_project_prisma_schema::execute(self, request).await
+    }
+
+    async fn run_project_generator(
+        &self,
+        request: Request<rpc::RunProjectGeneratorRequest>, // Accept request of type HelloRequest
+    ) -> Result<Response<rpc::RunProjectGeneratorResponse>, Status> {
+        println!("Got a request: {:?}", request);
+
+        run_project_generator::execute(self, request).await
+    }
 }
 
 fn to_proto_project(project: Project) -> rpc::Project {
@@ -425,6 +869,110 @@ fn to_proto_model(model: Model) -> rpc::Model {
     }
 }
 
+fn to_proto_generated_file(file: model::GeneratedFile) -> rpc::GeneratedFile {
+    rpc::GeneratedFile {
+        path: file.path,
+        content: file.content,
+    }
+}
+
+fn to_proto_import_summary(summary: model::ImportSummary) -> rpc::ImportSummary {
+    rpc::ImportSummary {
+        models: summary.models.into_iter().map(to_proto_model).collect(),
+        attributes: summary
+            .attributes
+            .into_iter()
+            .map(to_proto_model_attribute)
+            .collect(),
+        associations: summary
+            .associations
+            .into_iter()
+            .map(to_proto_model_association)
+            .collect(),
+        attribute_types: summary
+            .attribute_types
+            .into_iter()
+            .map(to_proto_model_attribute_type)
+            .collect(),
+        skipped: summary
+            .skipped
+            .into_iter()
+            .map(|skipped| rpc::SkippedConstruct {
+                location: skipped.location,
+                construct: skipped.construct,
+                reason: skipped.reason,
+            })
+            .collect(),
+    }
+}
+
+fn to_diagram_format(format: i32) -> Result<model::DiagramFormat, Status> {
+    use rpc::DiagramFormat;
+
+    let Some(format) = DiagramFormat::from_i32(format) else {
+        return Err(PortalError::invalid_argument("format").into());
+    };
+
+    let format = match format {
+        DiagramFormat::Unspecified | DiagramFormat::Mermaid => model::DiagramFormat::Mermaid,
+        DiagramFormat::Svg => model::DiagramFormat::Svg,
+        DiagramFormat::Png => model::DiagramFormat::Png,
+        DiagramFormat::Plantuml => model::DiagramFormat::PlantUml,
+    };
+
+    Ok(format)
+}
+
+fn to_sql_dialect(dialect: i32) -> Result<model::SqlDialect, Status> {
+    use rpc::SqlDialect;
+
+    let Some(dialect) = SqlDialect::from_i32(dialect) else {
+        return Err(PortalError::invalid_argument("dialect").into());
+    };
+
+    let dialect = match dialect {
+        SqlDialect::Unspecified | SqlDialect::Postgresql => model::SqlDialect::PostgreSql,
+        SqlDialect::Sqlite => model::SqlDialect::Sqlite,
+        SqlDialect::Mysql => model::SqlDialect::MySql,
+    };
+
+    Ok(dialect)
+}
+
+fn to_diagram_options(
+    options: Option<rpc::DiagramOptions>,
+) -> Result<model::DiagramOptions, Status> {
+    use rpc::DiagramDirection;
+
+    let rpc::DiagramOptions {
+        direction,
+        hide_attributes,
+        model_slugs,
+        descriptions_as_notes,
+        sort_alphabetically,
+    } = options.unwrap_or_default();
+
+    let Some(direction) = DiagramDirection::from_i32(direction) else {
+        return Err(PortalError::invalid_argument("options.direction").into());
+    };
+
+    let direction = match direction {
+        DiagramDirection::Unspecified => None,
+        DiagramDirection::TopToBottom => Some(model::DiagramDirection::TopToBottom),
+        DiagramDirection::BottomToTop => Some(model::DiagramDirection::BottomToTop),
+        DiagramDirection::LeftToRight => Some(model::DiagramDirection::LeftToRight),
+        DiagramDirection::RightToLeft => Some(model::DiagramDirection::RightToLeft),
+    };
+
+    Ok(model::DiagramOptions {
+        direction,
+        hide_attributes,
+        model_slugs,
+        descriptions_as_notes,
+        sort_alphabetically,
+    })
+}
+
 fn to_proto_model_association(model_association: model::Association) -> rpc::ModelAssociation {
     use rpc::ModelAssociationKind::*;
 
@@ -0,0 +1,69 @@
+use super::{rpc, to_proto_generated_file, ProjectsServer};
+use crate::{
+    repo::{GeneratorsRepo, ModelsRepo},
+    PortalError,
+};
+use foundation::{
+    datastore,
+    generator::{GeneratorRecord, GetGeneratorRecord},
+    model::{self, ListModelOverviewRecords},
+    FoundationResult,
+};
+use tonic::{Request, Response, Status};
+
+pub async fn execute(
+    server: &ProjectsServer,
+    request: Request<rpc::RunProjectGeneratorRequest>,
+) -> Result<Response<rpc::RunProjectGeneratorResponse>, Status> {
+    let repo = Repo {
+        generators_repo: &server.generators_repo,
+        models_repo: &server.models_repo,
+    };
+
+    let rpc::RunProjectGeneratorRequest {
+        project_slug,
+        generator_slug,
+    } = request.into_inner();
+
+    let response = model::run_project_generator::execute(
+        &repo,
+        model::run_project_generator::Request {
+            project_slug,
+            generator_slug,
+        },
+    )
+    .await
+    .map_err(Into::<PortalError>::into)?;
+
+    Ok(Response::new(rpc::RunProjectGeneratorResponse {
+        files: response
+            .files
+            .into_iter()
+            .map(to_proto_generated_file)
+            .collect(),
+    }))
+}
+
+struct Repo<'a> {
+    generators_repo: &'a GeneratorsRepo,
+    models_repo: &'a ModelsRepo,
+}
+
+#[async_trait::async_trait]
+impl<'a> GetGeneratorRecord for Repo<'a> {
+    async fn get_generator_record(&self, slug: &str) -> FoundationResult<Option<GeneratorRecord>> {
+        self.generators_repo.get_generator_record(slug).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> ListModelOverviewRecords for Repo<'a> {
+    async fn list_model_overview_records(
+        &self,
+        project_slug: &str,
+    ) -> FoundationResult<Vec<datastore::model::ModelOverview>> {
+        self.models_repo
+            .list_model_overview_records(project_slug)
+            .await
+    }
+}